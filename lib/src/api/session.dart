@@ -135,6 +135,18 @@ class SessionBuilder {
     memoryPattern: enable,
   ));
 
+  /// Enables/disables allocating CPU memory from the allocator shared through the environment, rather than an arena of
+  /// the session's own. Configure the shared arena with `configureEnvArena` before committing the session.
+  SessionBuilder withEnvAllocators(bool enable) => SessionBuilder._(_options.copyWith(
+    envAllocators: enable,
+  ));
+
+  /// Enables/disables the CPU memory arena of the session. Disabling it returns memory to the system as soon as it's
+  /// freed, trading some speed for a lower peak.
+  SessionBuilder withCpuMemoryArena(bool enable) => SessionBuilder._(_options.copyWith(
+    cpuMemoryArena: enable,
+  ));
+
   /// Load an ONNX graph from memory and commit the session.
   Future<Session> commitFromMemory(List<int> modelBytes) async {
     return Session._(await _options.commitFromMemory(modelBytes: modelBytes));
@@ -1,5 +1,6 @@
 import 'dart:collection';
 import 'dart:ffi' as ffi;
+import 'dart:math' as math;
 import 'package:ffi/ffi.dart' as pffi;
 import 'package:ort/src/rust/api/memory.dart';
 import 'dart:typed_data';
@@ -207,7 +208,7 @@ class Tensor<T> {
       TensorElementType.int64 => TensorImpl.freeI64Pointer,
       TensorElementType.string => TensorImpl.freeStringPointer,
       TensorElementType.bool => TensorImpl.freeBoolPointer,
-      TensorElementType.float16 => TensorImpl.freeF32Pointer,
+      TensorElementType.float16 => TensorImpl.freeF16Pointer,
       TensorElementType.float64 => TensorImpl.freeF64Pointer,
       TensorElementType.uint32 => TensorImpl.freeU32Pointer,
       TensorElementType.uint64 => TensorImpl.freeU64Pointer,
@@ -244,6 +245,7 @@ class Tensor<T> {
 
     _finalizer.detach(this);
     _arrayPointerWrapper.dispose();
+    _dataCopy?.dispose();
     _tensor.dispose();
   }
 
@@ -260,9 +262,12 @@ class Tensor<T> {
   /// Extract the raw Tensor's data with the returned List pointing to the
   /// Tensor's underlying data.
   List<T> extractTensor() {
+    // Immutable tensors, like the outputs of a session, don't hand out pointers
+    // to their data, so a copy of it is used instead.
+    final tensor = isMutable ? _tensor : (_dataCopy ??= _tensor.clone());
     switch (dtype) {
       case TensorElementType.float32:
-        final arrayPointerStruct = _tensor.getDataF32Pointer();
+        final arrayPointerStruct = tensor.getDataF32Pointer();
         _arrayPointerWrapper.arrayPointer = arrayPointerStruct;
 
         final arrayPointer = ffi.Pointer<ffi.Float>.fromAddress(arrayPointerStruct.ptr);
@@ -270,7 +275,7 @@ class Tensor<T> {
 
         return _data!.cast<T>();
       case TensorElementType.uint8:
-        final arrayPointerStruct = _tensor.getDataU8Pointer();
+        final arrayPointerStruct = tensor.getDataU8Pointer();
         _arrayPointerWrapper.arrayPointer = arrayPointerStruct;
 
         final arrayPointer = ffi.Pointer<ffi.Uint8>.fromAddress(arrayPointerStruct.ptr);
@@ -278,7 +283,7 @@ class Tensor<T> {
 
         return _data!.cast<T>();
       case TensorElementType.int8:
-        final arrayPointerStruct = _tensor.getDataI8Pointer();
+        final arrayPointerStruct = tensor.getDataI8Pointer();
         _arrayPointerWrapper.arrayPointer = arrayPointerStruct;
 
         final arrayPointer = ffi.Pointer<ffi.Int8>.fromAddress(arrayPointerStruct.ptr);
@@ -286,7 +291,7 @@ class Tensor<T> {
 
         return _data!.cast<T>();
       case TensorElementType.uint16:
-        final arrayPointerStruct = _tensor.getDataU16Pointer();
+        final arrayPointerStruct = tensor.getDataU16Pointer();
         _arrayPointerWrapper.arrayPointer = arrayPointerStruct;
 
         final arrayPointer = ffi.Pointer<ffi.Uint16>.fromAddress(arrayPointerStruct.ptr);
@@ -294,7 +299,7 @@ class Tensor<T> {
 
         return _data!.cast<T>();
       case TensorElementType.int16:
-        final arrayPointerStruct = _tensor.getDataI16Pointer();
+        final arrayPointerStruct = tensor.getDataI16Pointer();
         _arrayPointerWrapper.arrayPointer = arrayPointerStruct;
 
         final arrayPointer = ffi.Pointer<ffi.Int16>.fromAddress(arrayPointerStruct.ptr);
@@ -302,7 +307,7 @@ class Tensor<T> {
 
         return _data!.cast<T>();
       case TensorElementType.int32:
-        final arrayPointerStruct = _tensor.getDataI32Pointer();
+        final arrayPointerStruct = tensor.getDataI32Pointer();
         _arrayPointerWrapper.arrayPointer = arrayPointerStruct;
 
         final arrayPointer = ffi.Pointer<ffi.Int32>.fromAddress(arrayPointerStruct.ptr);
@@ -310,7 +315,7 @@ class Tensor<T> {
 
         return _data!.cast<T>();
       case TensorElementType.int64:
-        final arrayPointerStruct = _tensor.getDataI64Pointer();
+        final arrayPointerStruct = tensor.getDataI64Pointer();
         _arrayPointerWrapper.arrayPointer = arrayPointerStruct;
 
         final arrayPointer = ffi.Pointer<ffi.Int64>.fromAddress(arrayPointerStruct.ptr);
@@ -320,7 +325,7 @@ class Tensor<T> {
       case TensorElementType.string:
       // A String Tensor is not mutable so once the data is pulled that's it.
 
-        final arrayPointerStruct = _tensor.getDataStringPointer();
+        final arrayPointerStruct = tensor.getDataStringPointer();
         _arrayPointerWrapper.arrayPointer = arrayPointerStruct;
 
         final arrayPointer = ffi.Pointer<ffi.Pointer<pffi.Utf8>>.fromAddress(arrayPointerStruct.ptr);
@@ -337,7 +342,7 @@ class Tensor<T> {
 
         return _data!.cast<T>();
       case TensorElementType.bool:
-        final arrayPointerStruct = _tensor.getDataBoolPointer();
+        final arrayPointerStruct = tensor.getDataBoolPointer();
         _arrayPointerWrapper.arrayPointer = arrayPointerStruct;
 
         final arrayPointer = ffi.Pointer<ffi.Int8>.fromAddress(arrayPointerStruct.ptr);
@@ -345,15 +350,17 @@ class Tensor<T> {
 
         return _data!.cast<T>();
       case TensorElementType.float16:
-        final arrayPointerStruct = _tensor.getDataF32Pointer();
+        final arrayPointerStruct = tensor.getDataF16Pointer();
         _arrayPointerWrapper.arrayPointer = arrayPointerStruct;
 
-        final arrayPointer = ffi.Pointer<ffi.Float>.fromAddress(arrayPointerStruct.ptr);
-        _data = arrayPointer.asTypedList(arrayPointerStruct.len);
+        // Dart has no 16-bit float type, so the elements are converted into a
+        // list of doubles that doesn't point to the Tensor's data.
+        final arrayPointer = ffi.Pointer<ffi.Uint16>.fromAddress(arrayPointerStruct.ptr);
+        _data = arrayPointer.asTypedList(arrayPointerStruct.len).map(_halfToDouble).toList(growable: false);
 
         return _data!.cast<T>();
       case TensorElementType.float64:
-        final arrayPointerStruct = _tensor.getDataF64Pointer();
+        final arrayPointerStruct = tensor.getDataF64Pointer();
         _arrayPointerWrapper.arrayPointer = arrayPointerStruct;
 
         final arrayPointer = ffi.Pointer<ffi.Double>.fromAddress(arrayPointerStruct.ptr);
@@ -361,7 +368,7 @@ class Tensor<T> {
 
         return _data!.cast<T>();
       case TensorElementType.uint32:
-        final arrayPointerStruct = _tensor.getDataU32Pointer();
+        final arrayPointerStruct = tensor.getDataU32Pointer();
         _arrayPointerWrapper.arrayPointer = arrayPointerStruct;
 
         final arrayPointer = ffi.Pointer<ffi.Uint32>.fromAddress(arrayPointerStruct.ptr);
@@ -369,7 +376,7 @@ class Tensor<T> {
 
         return _data!.cast<T>();
       case TensorElementType.uint64:
-        final arrayPointerStruct = _tensor.getDataU64Pointer();
+        final arrayPointerStruct = tensor.getDataU64Pointer();
         _arrayPointerWrapper.arrayPointer = arrayPointerStruct;
 
         final arrayPointer = ffi.Pointer<ffi.Uint64>.fromAddress(arrayPointerStruct.ptr);
@@ -401,6 +408,8 @@ class Tensor<T> {
 
   List? _data;
 
+  TensorImpl? _dataCopy;
+
   List<T> get data {
     if (_data != null) return _data!.cast<T>();
 
@@ -433,3 +442,13 @@ class Tensor<T> {
 
   MemoryInfo memoryInfo() => _tensor.memoryInfo();
 }
+
+/// Converts the bits of an IEEE 754 half precision float to a double.
+double _halfToDouble(int bits) {
+  final sign = bits & 0x8000 != 0 ? -1.0 : 1.0;
+  final exponent = (bits >> 10) & 0x1f;
+  final mantissa = bits & 0x3ff;
+  if (exponent == 0) return sign * mantissa * math.pow(2, -24);
+  if (exponent == 0x1f) return mantissa == 0 ? sign * double.infinity : double.nan;
+  return sign * (1 + mantissa / 1024) * math.pow(2, exponent - 15);
+}
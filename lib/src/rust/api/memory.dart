@@ -8,7 +8,17 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'memory.freezed.dart';
 
-// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `from_str`, `new`, `to_ort_allocation_device`
+// These functions are ignored because they are not marked as `pub`: `env_cpu_allocator`, `from_ort`, `from_ort`, `keep_alive`, `lock`, `pinning`, `to_ort`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `from_str`, `to_ort_allocation_device`
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Allocator>>
+abstract class Allocator implements RustOpaqueInterface {
+  /// Returns the default CPU allocator, which isn't tied to any session.
+  static Allocator cpu() => RustLib.instance.api.crateApiMemoryAllocatorCpu();
+
+  /// Describes the memory this allocator allocates.
+  MemoryInfo memoryInfo();
+}
 
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MemoryInfo>>
 abstract class MemoryInfo implements RustOpaqueInterface {
@@ -62,8 +72,33 @@ abstract class MemoryInfo implements RustOpaqueInterface {
   /// # }
   /// ```
   MemoryType memoryType();
+
+  /// Creates a [`MemoryInfo`], describing a memory location on a device allocator.
+  ///
+  /// # Examples
+  /// `MemoryInfo` can be used to specify the device & memory type used by an [`Allocator`] to allocate tensors.
+  /// See [`Allocator`] for more information & potential applications.
+  /// ```ignore
+  /// let allocator = session.allocator(Some(
+  /// 	MemoryInfo::new(AllocationDevice::Cuda, 0, AllocatorType::Device, MemoryType::Default)?
+  /// ))?;
+  ///
+  /// let tensor = TensorImpl::new_with_allocator(&allocator, TensorElementType::Float32, vec![1, 3, 224, 224])?;
+  /// ```
+  factory MemoryInfo({
+    required AllocationDevice allocationDevice,
+    required int deviceId,
+    required AllocatorType allocatorType,
+    required MemoryType memoryType,
+  }) => RustLib.instance.api.crateApiMemoryMemoryInfoNew(
+    allocationDevice: allocationDevice,
+    deviceId: deviceId,
+    allocatorType: allocatorType,
+    memoryType: memoryType,
+  );
 }
 
+/// Represents possible devices that have their own device allocator.
 @freezed
 sealed class AllocationDevice with _$AllocationDevice {
   const AllocationDevice._();
@@ -0,0 +1,42 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../execution_providers.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'arena.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `arena_memory_info`, `entries`, `register_arena`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// Registers a CPU memory arena with the ONNX Runtime environment, to be shared by every session created from now on
+/// with [`SessionBuilderOptions::env_allocators`] set, instead of each growing an arena of its own.
+///
+/// Calling this again replaces the arena for sessions created afterwards; existing sessions keep the one they were
+/// created with. Fails if allocator tracking was enabled, as both replace the environment's CPU allocator.
+///
+/// [`SessionBuilderOptions::env_allocators`]: crate::api::session::SessionBuilderOptions::env_allocators
+void configureEnvArena({required ArenaConfig config}) =>
+    RustLib.instance.api.crateApiMemoryArenaConfigureEnvArena(config: config);
+
+/// How the CPU memory arena shared by sessions grows, see [`configure_env_arena`]. Options left out keep ONNX
+/// Runtime's defaults.
+@freezed
+sealed class ArenaConfig with _$ArenaConfig {
+  const ArenaConfig._();
+  const factory ArenaConfig({
+    /// The most bytes the arena may hold. Allocations that would exceed it fail. Unlimited by default.
+    int? maxMemory,
+
+    /// How much the arena grows by when it runs out of memory.
+    ArenaExtendStrategy? arenaExtendStrategy,
+
+    /// The size of the first block of memory the arena allocates.
+    int? initialChunkSizeBytes,
+  }) = _ArenaConfig;
+  static Future<ArenaConfig> default_() =>
+      RustLib.instance.api.crateApiMemoryArenaArenaConfigDefault();
+}
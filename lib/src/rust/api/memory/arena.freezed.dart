@@ -0,0 +1,277 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'arena.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$ArenaConfig {
+
+/// The most bytes the arena may hold. Allocations that would exceed it fail. Unlimited by default.
+ int? get maxMemory;/// How much the arena grows by when it runs out of memory.
+ ArenaExtendStrategy? get arenaExtendStrategy;/// The size of the first block of memory the arena allocates.
+ int? get initialChunkSizeBytes;
+/// Create a copy of ArenaConfig
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ArenaConfigCopyWith<ArenaConfig> get copyWith => _$ArenaConfigCopyWithImpl<ArenaConfig>(this as ArenaConfig, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ArenaConfig&&(identical(other.maxMemory, maxMemory) || other.maxMemory == maxMemory)&&(identical(other.arenaExtendStrategy, arenaExtendStrategy) || other.arenaExtendStrategy == arenaExtendStrategy)&&(identical(other.initialChunkSizeBytes, initialChunkSizeBytes) || other.initialChunkSizeBytes == initialChunkSizeBytes));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,maxMemory,arenaExtendStrategy,initialChunkSizeBytes);
+
+@override
+String toString() {
+  return 'ArenaConfig(maxMemory: $maxMemory, arenaExtendStrategy: $arenaExtendStrategy, initialChunkSizeBytes: $initialChunkSizeBytes)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ArenaConfigCopyWith<$Res>  {
+  factory $ArenaConfigCopyWith(ArenaConfig value, $Res Function(ArenaConfig) _then) = _$ArenaConfigCopyWithImpl;
+@useResult
+$Res call({
+ int? maxMemory, ArenaExtendStrategy? arenaExtendStrategy, int? initialChunkSizeBytes
+});
+
+
+
+
+}
+/// @nodoc
+class _$ArenaConfigCopyWithImpl<$Res>
+    implements $ArenaConfigCopyWith<$Res> {
+  _$ArenaConfigCopyWithImpl(this._self, this._then);
+
+  final ArenaConfig _self;
+  final $Res Function(ArenaConfig) _then;
+
+/// Create a copy of ArenaConfig
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? maxMemory = freezed,Object? arenaExtendStrategy = freezed,Object? initialChunkSizeBytes = freezed,}) {
+  return _then(_self.copyWith(
+maxMemory: freezed == maxMemory ? _self.maxMemory : maxMemory // ignore: cast_nullable_to_non_nullable
+as int?,arenaExtendStrategy: freezed == arenaExtendStrategy ? _self.arenaExtendStrategy : arenaExtendStrategy // ignore: cast_nullable_to_non_nullable
+as ArenaExtendStrategy?,initialChunkSizeBytes: freezed == initialChunkSizeBytes ? _self.initialChunkSizeBytes : initialChunkSizeBytes // ignore: cast_nullable_to_non_nullable
+as int?,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ArenaConfig].
+extension ArenaConfigPatterns on ArenaConfig {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ArenaConfig value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ArenaConfig() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ArenaConfig value)  $default,){
+final _that = this;
+switch (_that) {
+case _ArenaConfig():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ArenaConfig value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ArenaConfig() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( int? maxMemory,  ArenaExtendStrategy? arenaExtendStrategy,  int? initialChunkSizeBytes)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ArenaConfig() when $default != null:
+return $default(_that.maxMemory,_that.arenaExtendStrategy,_that.initialChunkSizeBytes);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( int? maxMemory,  ArenaExtendStrategy? arenaExtendStrategy,  int? initialChunkSizeBytes)  $default,) {final _that = this;
+switch (_that) {
+case _ArenaConfig():
+return $default(_that.maxMemory,_that.arenaExtendStrategy,_that.initialChunkSizeBytes);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( int? maxMemory,  ArenaExtendStrategy? arenaExtendStrategy,  int? initialChunkSizeBytes)?  $default,) {final _that = this;
+switch (_that) {
+case _ArenaConfig() when $default != null:
+return $default(_that.maxMemory,_that.arenaExtendStrategy,_that.initialChunkSizeBytes);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ArenaConfig extends ArenaConfig {
+  const _ArenaConfig({this.maxMemory, this.arenaExtendStrategy, this.initialChunkSizeBytes}): super._();
+  
+
+/// The most bytes the arena may hold. Allocations that would exceed it fail. Unlimited by default.
+@override final  int? maxMemory;
+/// How much the arena grows by when it runs out of memory.
+@override final  ArenaExtendStrategy? arenaExtendStrategy;
+/// The size of the first block of memory the arena allocates.
+@override final  int? initialChunkSizeBytes;
+
+/// Create a copy of ArenaConfig
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ArenaConfigCopyWith<_ArenaConfig> get copyWith => __$ArenaConfigCopyWithImpl<_ArenaConfig>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ArenaConfig&&(identical(other.maxMemory, maxMemory) || other.maxMemory == maxMemory)&&(identical(other.arenaExtendStrategy, arenaExtendStrategy) || other.arenaExtendStrategy == arenaExtendStrategy)&&(identical(other.initialChunkSizeBytes, initialChunkSizeBytes) || other.initialChunkSizeBytes == initialChunkSizeBytes));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,maxMemory,arenaExtendStrategy,initialChunkSizeBytes);
+
+@override
+String toString() {
+  return 'ArenaConfig(maxMemory: $maxMemory, arenaExtendStrategy: $arenaExtendStrategy, initialChunkSizeBytes: $initialChunkSizeBytes)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ArenaConfigCopyWith<$Res> implements $ArenaConfigCopyWith<$Res> {
+  factory _$ArenaConfigCopyWith(_ArenaConfig value, $Res Function(_ArenaConfig) _then) = __$ArenaConfigCopyWithImpl;
+@override @useResult
+$Res call({
+ int? maxMemory, ArenaExtendStrategy? arenaExtendStrategy, int? initialChunkSizeBytes
+});
+
+
+
+
+}
+/// @nodoc
+class __$ArenaConfigCopyWithImpl<$Res>
+    implements _$ArenaConfigCopyWith<$Res> {
+  __$ArenaConfigCopyWithImpl(this._self, this._then);
+
+  final _ArenaConfig _self;
+  final $Res Function(_ArenaConfig) _then;
+
+/// Create a copy of ArenaConfig
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? maxMemory = freezed,Object? arenaExtendStrategy = freezed,Object? initialChunkSizeBytes = freezed,}) {
+  return _then(_ArenaConfig(
+maxMemory: freezed == maxMemory ? _self.maxMemory : maxMemory // ignore: cast_nullable_to_non_nullable
+as int?,arenaExtendStrategy: freezed == arenaExtendStrategy ? _self.arenaExtendStrategy : arenaExtendStrategy // ignore: cast_nullable_to_non_nullable
+as ArenaExtendStrategy?,initialChunkSizeBytes: freezed == initialChunkSizeBytes ? _self.initialChunkSizeBytes : initialChunkSizeBytes // ignore: cast_nullable_to_non_nullable
+as int?,
+  ));
+}
+
+
+}
+
+// dart format on
@@ -0,0 +1,62 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'budget.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `add`, `admit`, `budget`, `bytes`, `charge`, `charge`, `evict`, `evict_one`, `evictables`, `evictables`, `exceeded`, `name`, `new`, `new`, `next_use`, `register_evictable`, `register_evictable`, `reserve`, `reserve`, `usage`, `used_bytes`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `clone`, `clone`, `drop`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `set_eviction_handler`
+// These functions are ignored (category: IgnoreBecauseNotAllowedOwner): `default`
+
+/// Returns the limit of the memory budget and what is counted against it.
+MemoryBudgetUsage memoryBudgetUsage() =>
+    RustLib.instance.api.crateApiMemoryBudgetMemoryBudgetUsage();
+
+/// Sets the most bytes live tensors and committed sessions may hold together, or removes the limit with `None`, and
+/// what happens when a new tensor or session doesn't fit.
+///
+/// Tensors count the data they own; views and memory they don't own, like external buffers or memory-mapped files,
+/// aren't counted. Sessions count the size of their model while committed. That's only a rough estimate of their
+/// footprint: it leaves out the arena and the buffers of their execution providers, and optimizations can make the
+/// weights smaller or larger than the model, so leave some headroom below the device's real limit. Copies
+/// made with [`TensorImpl::clone`] and the outputs of a run are counted but never refused, as their memory is already
+/// allocated. Lowering the limit below what's in use doesn't release anything, it only refuses new requests.
+///
+/// [`TensorImpl::clone`]: crate::api::tensor::TensorImpl::clone
+void setMemoryBudget({int? limitBytes, required EvictionPolicy policy}) =>
+    RustLib.instance.api.crateApiMemoryBudgetSetMemoryBudget(
+      limitBytes: limitBytes,
+      policy: policy,
+    );
+
+/// What happens when a tensor or session doesn't fit into the memory budget, see [`set_memory_budget`].
+enum EvictionPolicy {
+  /// The allocation or commit fails.
+  fail,
+
+  /// Sessions that aren't running are suspended, least recently used first, until the request fits, see
+  /// [`SessionImpl::suspend`]. Fails if it still doesn't fit once every idle session is suspended.
+  ///
+  /// [`SessionImpl::suspend`]: crate::api::session::SessionImpl::suspend
+  suspendIdleSessions,
+}
+
+/// The memory counted against the budget, see [`memory_budget_usage`].
+@freezed
+sealed class MemoryBudgetUsage with _$MemoryBudgetUsage {
+  const factory MemoryBudgetUsage({
+    /// The most bytes tensors and sessions may hold, or `None` if there's no budget.
+    int? limitBytes,
+
+    /// Bytes of data owned by live tensors.
+    required int tensorBytes,
+
+    /// The estimated footprint of committed sessions, i.e. the size of their models, see [`set_memory_budget`].
+    required int sessionBytes,
+  }) = _MemoryBudgetUsage;
+}
@@ -0,0 +1,277 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'budget.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$MemoryBudgetUsage {
+
+/// The most bytes tensors and sessions may hold, or `None` if there's no budget.
+ int? get limitBytes;/// Bytes of data owned by live tensors.
+ int get tensorBytes;/// The estimated footprint of committed sessions, i.e. the size of their models, see [`set_memory_budget`].
+ int get sessionBytes;
+/// Create a copy of MemoryBudgetUsage
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$MemoryBudgetUsageCopyWith<MemoryBudgetUsage> get copyWith => _$MemoryBudgetUsageCopyWithImpl<MemoryBudgetUsage>(this as MemoryBudgetUsage, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MemoryBudgetUsage&&(identical(other.limitBytes, limitBytes) || other.limitBytes == limitBytes)&&(identical(other.tensorBytes, tensorBytes) || other.tensorBytes == tensorBytes)&&(identical(other.sessionBytes, sessionBytes) || other.sessionBytes == sessionBytes));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,limitBytes,tensorBytes,sessionBytes);
+
+@override
+String toString() {
+  return 'MemoryBudgetUsage(limitBytes: $limitBytes, tensorBytes: $tensorBytes, sessionBytes: $sessionBytes)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $MemoryBudgetUsageCopyWith<$Res>  {
+  factory $MemoryBudgetUsageCopyWith(MemoryBudgetUsage value, $Res Function(MemoryBudgetUsage) _then) = _$MemoryBudgetUsageCopyWithImpl;
+@useResult
+$Res call({
+ int? limitBytes, int tensorBytes, int sessionBytes
+});
+
+
+
+
+}
+/// @nodoc
+class _$MemoryBudgetUsageCopyWithImpl<$Res>
+    implements $MemoryBudgetUsageCopyWith<$Res> {
+  _$MemoryBudgetUsageCopyWithImpl(this._self, this._then);
+
+  final MemoryBudgetUsage _self;
+  final $Res Function(MemoryBudgetUsage) _then;
+
+/// Create a copy of MemoryBudgetUsage
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? limitBytes = freezed,Object? tensorBytes = null,Object? sessionBytes = null,}) {
+  return _then(_self.copyWith(
+limitBytes: freezed == limitBytes ? _self.limitBytes : limitBytes // ignore: cast_nullable_to_non_nullable
+as int?,tensorBytes: null == tensorBytes ? _self.tensorBytes : tensorBytes // ignore: cast_nullable_to_non_nullable
+as int,sessionBytes: null == sessionBytes ? _self.sessionBytes : sessionBytes // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [MemoryBudgetUsage].
+extension MemoryBudgetUsagePatterns on MemoryBudgetUsage {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _MemoryBudgetUsage value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _MemoryBudgetUsage() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _MemoryBudgetUsage value)  $default,){
+final _that = this;
+switch (_that) {
+case _MemoryBudgetUsage():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _MemoryBudgetUsage value)?  $default,){
+final _that = this;
+switch (_that) {
+case _MemoryBudgetUsage() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( int? limitBytes,  int tensorBytes,  int sessionBytes)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _MemoryBudgetUsage() when $default != null:
+return $default(_that.limitBytes,_that.tensorBytes,_that.sessionBytes);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( int? limitBytes,  int tensorBytes,  int sessionBytes)  $default,) {final _that = this;
+switch (_that) {
+case _MemoryBudgetUsage():
+return $default(_that.limitBytes,_that.tensorBytes,_that.sessionBytes);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( int? limitBytes,  int tensorBytes,  int sessionBytes)?  $default,) {final _that = this;
+switch (_that) {
+case _MemoryBudgetUsage() when $default != null:
+return $default(_that.limitBytes,_that.tensorBytes,_that.sessionBytes);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _MemoryBudgetUsage implements MemoryBudgetUsage {
+  const _MemoryBudgetUsage({this.limitBytes, required this.tensorBytes, required this.sessionBytes});
+  
+
+/// The most bytes tensors and sessions may hold, or `None` if there's no budget.
+@override final  int? limitBytes;
+/// Bytes of data owned by live tensors.
+@override final  int tensorBytes;
+/// The estimated footprint of committed sessions, i.e. the size of their models, see [`set_memory_budget`].
+@override final  int sessionBytes;
+
+/// Create a copy of MemoryBudgetUsage
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$MemoryBudgetUsageCopyWith<_MemoryBudgetUsage> get copyWith => __$MemoryBudgetUsageCopyWithImpl<_MemoryBudgetUsage>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _MemoryBudgetUsage&&(identical(other.limitBytes, limitBytes) || other.limitBytes == limitBytes)&&(identical(other.tensorBytes, tensorBytes) || other.tensorBytes == tensorBytes)&&(identical(other.sessionBytes, sessionBytes) || other.sessionBytes == sessionBytes));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,limitBytes,tensorBytes,sessionBytes);
+
+@override
+String toString() {
+  return 'MemoryBudgetUsage(limitBytes: $limitBytes, tensorBytes: $tensorBytes, sessionBytes: $sessionBytes)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$MemoryBudgetUsageCopyWith<$Res> implements $MemoryBudgetUsageCopyWith<$Res> {
+  factory _$MemoryBudgetUsageCopyWith(_MemoryBudgetUsage value, $Res Function(_MemoryBudgetUsage) _then) = __$MemoryBudgetUsageCopyWithImpl;
+@override @useResult
+$Res call({
+ int? limitBytes, int tensorBytes, int sessionBytes
+});
+
+
+
+
+}
+/// @nodoc
+class __$MemoryBudgetUsageCopyWithImpl<$Res>
+    implements _$MemoryBudgetUsageCopyWith<$Res> {
+  __$MemoryBudgetUsageCopyWithImpl(this._self, this._then);
+
+  final _MemoryBudgetUsage _self;
+  final $Res Function(_MemoryBudgetUsage) _then;
+
+/// Create a copy of MemoryBudgetUsage
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? limitBytes = freezed,Object? tensorBytes = null,Object? sessionBytes = null,}) {
+  return _then(_MemoryBudgetUsage(
+limitBytes: freezed == limitBytes ? _self.limitBytes : limitBytes // ignore: cast_nullable_to_non_nullable
+as int?,tensorBytes: null == tensorBytes ? _self.tensorBytes : tensorBytes // ignore: cast_nullable_to_non_nullable
+as int,sessionBytes: null == sessionBytes ? _self.sessionBytes : sessionBytes // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+// dart format on
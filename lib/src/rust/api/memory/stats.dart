@@ -0,0 +1,129 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'stats.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `allocate`, `allocator_get_stats`, `arena_stats`, `finish`, `from_pairs`, `layout`, `register_tracking_allocator`, `start`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `drop`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+
+/// Returns the usage statistics of the tracked allocator, which serves every session created after
+/// [`enable_allocator_tracking`]. All zeros if tracking was never enabled.
+///
+/// Only memory ONNX Runtime allocates on the CPU for those sessions is counted: their initializers, intermediate values
+/// and outputs, and tensors created through their allocators.
+AllocatorStats allocatorStats() =>
+    RustLib.instance.api.crateApiMemoryStatsAllocatorStats();
+
+/// A debug mode that routes the CPU memory of every session created from now on through a shared allocator that
+/// keeps the statistics returned by [`allocator_stats`], and records the peak memory use of each of their runs.
+///
+/// It changes the allocation behavior it measures, so prefer [`SessionImpl::arena_stats`], which reports what a
+/// session's own arena holds without replacing it, and only turn this on to see every allocation of a run.
+///
+/// ONNX Runtime only accepts custom allocators for the whole environment, so the statistics are process-wide: they
+/// add up every session that uses the tracked allocator, and can't be told apart per session.
+///
+/// The tracked allocator also replaces the CPU memory arena of each of these sessions. Every allocation of a run then
+/// goes to the system allocator, memory freed is returned rather than kept for the next run, and every allocation
+/// during a run takes a lock to record its peak, which makes runs slower. Sessions created before this call, or with
+/// [`SessionBuilderOptions::env_allocators`] set to `false`, keep their arenas and aren't tracked.
+///
+/// Fails if an arena was configured with [`configure_env_arena`], as both replace the environment's CPU allocator.
+///
+/// [`SessionBuilderOptions::env_allocators`]: crate::api::session::SessionBuilderOptions::env_allocators
+/// [`configure_env_arena`]: crate::api::memory::arena::configure_env_arena
+/// [`SessionImpl::arena_stats`]: crate::api::session::SessionImpl::arena_stats
+void enableAllocatorTracking() =>
+    RustLib.instance.api.crateApiMemoryStatsEnableAllocatorTracking();
+
+/// If sessions created now have their memory tracked, see [`enable_allocator_tracking`].
+bool isAllocatorTrackingEnabled() =>
+    RustLib.instance.api.crateApiMemoryStatsIsAllocatorTrackingEnabled();
+
+/// Resets [`AllocatorStats::peak_bytes_in_use`] to the bytes currently in use, e.g. before a phase of interest.
+void resetPeakAllocatorStats() =>
+    RustLib.instance.api.crateApiMemoryStatsResetPeakAllocatorStats();
+
+/// Usage statistics of the tracked allocator, see [`allocator_stats`].
+@freezed
+sealed class AllocatorStats with _$AllocatorStats {
+  const AllocatorStats._();
+  const factory AllocatorStats({
+    /// Bytes currently allocated and not yet freed.
+    required int bytesInUse,
+
+    /// The most bytes that have been in use at once.
+    required int peakBytesInUse,
+
+    /// Bytes allocated so far, including those that have been freed again.
+    required int totalAllocatedBytes,
+    required int numAllocs,
+    required int numFrees,
+
+    /// The size of the largest single allocation.
+    required int largestBlock,
+
+    /// Bytes of `bytes_in_use` that ONNX Runtime requested through the allocator's `Reserve` function rather than
+    /// `Alloc`. What it reserves that way is up to ONNX Runtime, so this is no measure of a session's fixed footprint.
+    required int reservedBytes,
+  }) = _AllocatorStats;
+  static Future<AllocatorStats> default_() =>
+      RustLib.instance.api.crateApiMemoryStatsAllocatorStatsDefault();
+}
+
+/// Statistics ONNX Runtime keeps for one of a session's allocators, see [`SessionImpl::arena_stats`]. Statistics
+/// ONNX Runtime doesn't report for an allocator are 0.
+///
+/// [`SessionImpl::arena_stats`]: crate::api::session::SessionImpl::arena_stats
+@freezed
+sealed class ArenaStats with _$ArenaStats {
+  const ArenaStats._();
+  const factory ArenaStats({
+    /// The most bytes the arena may take from the system.
+    required int limitBytes,
+
+    /// Bytes handed out by the arena and not yet freed.
+    required int bytesInUse,
+
+    /// The most bytes that have been in use at once.
+    required int peakBytesInUse,
+
+    /// Bytes the arena has taken from the system, whether they're in use or not.
+    required int totalAllocatedBytes,
+    required int numAllocs,
+
+    /// Allocations made through the allocator's `Reserve` function, which bypass the arena's chunks.
+    required int numReserves,
+
+    /// How often the arena has grown.
+    required int numArenaExtensions,
+
+    /// How often the arena has been shrunk, see [`trim_memory`](crate::api::memory::trim::trim_memory).
+    required int numArenaShrinkages,
+
+    /// The size of the largest single allocation.
+    required int largestAllocBytes,
+  }) = _ArenaStats;
+  static Future<ArenaStats> default_() =>
+      RustLib.instance.api.crateApiMemoryStatsArenaStatsDefault();
+}
+
+/// Memory used by a single run of a session whose memory is tracked, see [`SessionImpl::last_run_memory`].
+///
+/// [`SessionImpl::last_run_memory`]: crate::api::session::SessionImpl::last_run_memory
+@freezed
+sealed class RunMemoryUsage with _$RunMemoryUsage {
+  const factory RunMemoryUsage({
+    /// The most bytes in use at once by the tracked allocator during the run, including memory held by other sessions.
+    required int peakBytesInUse,
+
+    /// How far the bytes in use rose above what was in use when the run started, i.e. roughly what the run itself
+    /// needed.
+    required int peakIncreaseBytes,
+  }) = _RunMemoryUsage;
+}
@@ -0,0 +1,846 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'stats.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$AllocatorStats {
+
+/// Bytes currently allocated and not yet freed.
+ int get bytesInUse;/// The most bytes that have been in use at once.
+ int get peakBytesInUse;/// Bytes allocated so far, including those that have been freed again.
+ int get totalAllocatedBytes; int get numAllocs; int get numFrees;/// The size of the largest single allocation.
+ int get largestBlock;/// Bytes of `bytes_in_use` that ONNX Runtime requested through the allocator's `Reserve` function rather than
+/// `Alloc`. What it reserves that way is up to ONNX Runtime, so this is no measure of a session's fixed footprint.
+ int get reservedBytes;
+/// Create a copy of AllocatorStats
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$AllocatorStatsCopyWith<AllocatorStats> get copyWith => _$AllocatorStatsCopyWithImpl<AllocatorStats>(this as AllocatorStats, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is AllocatorStats&&(identical(other.bytesInUse, bytesInUse) || other.bytesInUse == bytesInUse)&&(identical(other.peakBytesInUse, peakBytesInUse) || other.peakBytesInUse == peakBytesInUse)&&(identical(other.totalAllocatedBytes, totalAllocatedBytes) || other.totalAllocatedBytes == totalAllocatedBytes)&&(identical(other.numAllocs, numAllocs) || other.numAllocs == numAllocs)&&(identical(other.numFrees, numFrees) || other.numFrees == numFrees)&&(identical(other.largestBlock, largestBlock) || other.largestBlock == largestBlock)&&(identical(other.reservedBytes, reservedBytes) || other.reservedBytes == reservedBytes));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,bytesInUse,peakBytesInUse,totalAllocatedBytes,numAllocs,numFrees,largestBlock,reservedBytes);
+
+@override
+String toString() {
+  return 'AllocatorStats(bytesInUse: $bytesInUse, peakBytesInUse: $peakBytesInUse, totalAllocatedBytes: $totalAllocatedBytes, numAllocs: $numAllocs, numFrees: $numFrees, largestBlock: $largestBlock, reservedBytes: $reservedBytes)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $AllocatorStatsCopyWith<$Res>  {
+  factory $AllocatorStatsCopyWith(AllocatorStats value, $Res Function(AllocatorStats) _then) = _$AllocatorStatsCopyWithImpl;
+@useResult
+$Res call({
+ int bytesInUse, int peakBytesInUse, int totalAllocatedBytes, int numAllocs, int numFrees, int largestBlock, int reservedBytes
+});
+
+
+
+
+}
+/// @nodoc
+class _$AllocatorStatsCopyWithImpl<$Res>
+    implements $AllocatorStatsCopyWith<$Res> {
+  _$AllocatorStatsCopyWithImpl(this._self, this._then);
+
+  final AllocatorStats _self;
+  final $Res Function(AllocatorStats) _then;
+
+/// Create a copy of AllocatorStats
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? bytesInUse = null,Object? peakBytesInUse = null,Object? totalAllocatedBytes = null,Object? numAllocs = null,Object? numFrees = null,Object? largestBlock = null,Object? reservedBytes = null,}) {
+  return _then(_self.copyWith(
+bytesInUse: null == bytesInUse ? _self.bytesInUse : bytesInUse // ignore: cast_nullable_to_non_nullable
+as int,peakBytesInUse: null == peakBytesInUse ? _self.peakBytesInUse : peakBytesInUse // ignore: cast_nullable_to_non_nullable
+as int,totalAllocatedBytes: null == totalAllocatedBytes ? _self.totalAllocatedBytes : totalAllocatedBytes // ignore: cast_nullable_to_non_nullable
+as int,numAllocs: null == numAllocs ? _self.numAllocs : numAllocs // ignore: cast_nullable_to_non_nullable
+as int,numFrees: null == numFrees ? _self.numFrees : numFrees // ignore: cast_nullable_to_non_nullable
+as int,largestBlock: null == largestBlock ? _self.largestBlock : largestBlock // ignore: cast_nullable_to_non_nullable
+as int,reservedBytes: null == reservedBytes ? _self.reservedBytes : reservedBytes // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [AllocatorStats].
+extension AllocatorStatsPatterns on AllocatorStats {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _AllocatorStats value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _AllocatorStats() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _AllocatorStats value)  $default,){
+final _that = this;
+switch (_that) {
+case _AllocatorStats():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _AllocatorStats value)?  $default,){
+final _that = this;
+switch (_that) {
+case _AllocatorStats() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( int bytesInUse,  int peakBytesInUse,  int totalAllocatedBytes,  int numAllocs,  int numFrees,  int largestBlock,  int reservedBytes)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _AllocatorStats() when $default != null:
+return $default(_that.bytesInUse,_that.peakBytesInUse,_that.totalAllocatedBytes,_that.numAllocs,_that.numFrees,_that.largestBlock,_that.reservedBytes);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( int bytesInUse,  int peakBytesInUse,  int totalAllocatedBytes,  int numAllocs,  int numFrees,  int largestBlock,  int reservedBytes)  $default,) {final _that = this;
+switch (_that) {
+case _AllocatorStats():
+return $default(_that.bytesInUse,_that.peakBytesInUse,_that.totalAllocatedBytes,_that.numAllocs,_that.numFrees,_that.largestBlock,_that.reservedBytes);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( int bytesInUse,  int peakBytesInUse,  int totalAllocatedBytes,  int numAllocs,  int numFrees,  int largestBlock,  int reservedBytes)?  $default,) {final _that = this;
+switch (_that) {
+case _AllocatorStats() when $default != null:
+return $default(_that.bytesInUse,_that.peakBytesInUse,_that.totalAllocatedBytes,_that.numAllocs,_that.numFrees,_that.largestBlock,_that.reservedBytes);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _AllocatorStats extends AllocatorStats {
+  const _AllocatorStats({required this.bytesInUse, required this.peakBytesInUse, required this.totalAllocatedBytes, required this.numAllocs, required this.numFrees, required this.largestBlock, required this.reservedBytes}): super._();
+  
+
+/// Bytes currently allocated and not yet freed.
+@override final  int bytesInUse;
+/// The most bytes that have been in use at once.
+@override final  int peakBytesInUse;
+/// Bytes allocated so far, including those that have been freed again.
+@override final  int totalAllocatedBytes;
+@override final  int numAllocs;
+@override final  int numFrees;
+/// The size of the largest single allocation.
+@override final  int largestBlock;
+/// Bytes of `bytes_in_use` that ONNX Runtime requested through the allocator's `Reserve` function rather than
+/// `Alloc`. What it reserves that way is up to ONNX Runtime, so this is no measure of a session's fixed footprint.
+@override final  int reservedBytes;
+
+/// Create a copy of AllocatorStats
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$AllocatorStatsCopyWith<_AllocatorStats> get copyWith => __$AllocatorStatsCopyWithImpl<_AllocatorStats>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _AllocatorStats&&(identical(other.bytesInUse, bytesInUse) || other.bytesInUse == bytesInUse)&&(identical(other.peakBytesInUse, peakBytesInUse) || other.peakBytesInUse == peakBytesInUse)&&(identical(other.totalAllocatedBytes, totalAllocatedBytes) || other.totalAllocatedBytes == totalAllocatedBytes)&&(identical(other.numAllocs, numAllocs) || other.numAllocs == numAllocs)&&(identical(other.numFrees, numFrees) || other.numFrees == numFrees)&&(identical(other.largestBlock, largestBlock) || other.largestBlock == largestBlock)&&(identical(other.reservedBytes, reservedBytes) || other.reservedBytes == reservedBytes));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,bytesInUse,peakBytesInUse,totalAllocatedBytes,numAllocs,numFrees,largestBlock,reservedBytes);
+
+@override
+String toString() {
+  return 'AllocatorStats(bytesInUse: $bytesInUse, peakBytesInUse: $peakBytesInUse, totalAllocatedBytes: $totalAllocatedBytes, numAllocs: $numAllocs, numFrees: $numFrees, largestBlock: $largestBlock, reservedBytes: $reservedBytes)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$AllocatorStatsCopyWith<$Res> implements $AllocatorStatsCopyWith<$Res> {
+  factory _$AllocatorStatsCopyWith(_AllocatorStats value, $Res Function(_AllocatorStats) _then) = __$AllocatorStatsCopyWithImpl;
+@override @useResult
+$Res call({
+ int bytesInUse, int peakBytesInUse, int totalAllocatedBytes, int numAllocs, int numFrees, int largestBlock, int reservedBytes
+});
+
+
+
+
+}
+/// @nodoc
+class __$AllocatorStatsCopyWithImpl<$Res>
+    implements _$AllocatorStatsCopyWith<$Res> {
+  __$AllocatorStatsCopyWithImpl(this._self, this._then);
+
+  final _AllocatorStats _self;
+  final $Res Function(_AllocatorStats) _then;
+
+/// Create a copy of AllocatorStats
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? bytesInUse = null,Object? peakBytesInUse = null,Object? totalAllocatedBytes = null,Object? numAllocs = null,Object? numFrees = null,Object? largestBlock = null,Object? reservedBytes = null,}) {
+  return _then(_AllocatorStats(
+bytesInUse: null == bytesInUse ? _self.bytesInUse : bytesInUse // ignore: cast_nullable_to_non_nullable
+as int,peakBytesInUse: null == peakBytesInUse ? _self.peakBytesInUse : peakBytesInUse // ignore: cast_nullable_to_non_nullable
+as int,totalAllocatedBytes: null == totalAllocatedBytes ? _self.totalAllocatedBytes : totalAllocatedBytes // ignore: cast_nullable_to_non_nullable
+as int,numAllocs: null == numAllocs ? _self.numAllocs : numAllocs // ignore: cast_nullable_to_non_nullable
+as int,numFrees: null == numFrees ? _self.numFrees : numFrees // ignore: cast_nullable_to_non_nullable
+as int,largestBlock: null == largestBlock ? _self.largestBlock : largestBlock // ignore: cast_nullable_to_non_nullable
+as int,reservedBytes: null == reservedBytes ? _self.reservedBytes : reservedBytes // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ArenaStats {
+
+/// The most bytes the arena may take from the system.
+ int get limitBytes;/// Bytes handed out by the arena and not yet freed.
+ int get bytesInUse;/// The most bytes that have been in use at once.
+ int get peakBytesInUse;/// Bytes the arena has taken from the system, whether they're in use or not.
+ int get totalAllocatedBytes; int get numAllocs;/// Allocations made through the allocator's `Reserve` function, which bypass the arena's chunks.
+ int get numReserves;/// How often the arena has grown.
+ int get numArenaExtensions;/// How often the arena has been shrunk, see [`trim_memory`](crate::api::memory::trim::trim_memory).
+ int get numArenaShrinkages;/// The size of the largest single allocation.
+ int get largestAllocBytes;
+/// Create a copy of ArenaStats
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ArenaStatsCopyWith<ArenaStats> get copyWith => _$ArenaStatsCopyWithImpl<ArenaStats>(this as ArenaStats, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ArenaStats&&(identical(other.limitBytes, limitBytes) || other.limitBytes == limitBytes)&&(identical(other.bytesInUse, bytesInUse) || other.bytesInUse == bytesInUse)&&(identical(other.peakBytesInUse, peakBytesInUse) || other.peakBytesInUse == peakBytesInUse)&&(identical(other.totalAllocatedBytes, totalAllocatedBytes) || other.totalAllocatedBytes == totalAllocatedBytes)&&(identical(other.numAllocs, numAllocs) || other.numAllocs == numAllocs)&&(identical(other.numReserves, numReserves) || other.numReserves == numReserves)&&(identical(other.numArenaExtensions, numArenaExtensions) || other.numArenaExtensions == numArenaExtensions)&&(identical(other.numArenaShrinkages, numArenaShrinkages) || other.numArenaShrinkages == numArenaShrinkages)&&(identical(other.largestAllocBytes, largestAllocBytes) || other.largestAllocBytes == largestAllocBytes));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,limitBytes,bytesInUse,peakBytesInUse,totalAllocatedBytes,numAllocs,numReserves,numArenaExtensions,numArenaShrinkages,largestAllocBytes);
+
+@override
+String toString() {
+  return 'ArenaStats(limitBytes: $limitBytes, bytesInUse: $bytesInUse, peakBytesInUse: $peakBytesInUse, totalAllocatedBytes: $totalAllocatedBytes, numAllocs: $numAllocs, numReserves: $numReserves, numArenaExtensions: $numArenaExtensions, numArenaShrinkages: $numArenaShrinkages, largestAllocBytes: $largestAllocBytes)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ArenaStatsCopyWith<$Res>  {
+  factory $ArenaStatsCopyWith(ArenaStats value, $Res Function(ArenaStats) _then) = _$ArenaStatsCopyWithImpl;
+@useResult
+$Res call({
+ int limitBytes, int bytesInUse, int peakBytesInUse, int totalAllocatedBytes, int numAllocs, int numReserves, int numArenaExtensions, int numArenaShrinkages, int largestAllocBytes
+});
+
+
+
+
+}
+/// @nodoc
+class _$ArenaStatsCopyWithImpl<$Res>
+    implements $ArenaStatsCopyWith<$Res> {
+  _$ArenaStatsCopyWithImpl(this._self, this._then);
+
+  final ArenaStats _self;
+  final $Res Function(ArenaStats) _then;
+
+/// Create a copy of ArenaStats
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? limitBytes = null,Object? bytesInUse = null,Object? peakBytesInUse = null,Object? totalAllocatedBytes = null,Object? numAllocs = null,Object? numReserves = null,Object? numArenaExtensions = null,Object? numArenaShrinkages = null,Object? largestAllocBytes = null,}) {
+  return _then(_self.copyWith(
+limitBytes: null == limitBytes ? _self.limitBytes : limitBytes // ignore: cast_nullable_to_non_nullable
+as int,bytesInUse: null == bytesInUse ? _self.bytesInUse : bytesInUse // ignore: cast_nullable_to_non_nullable
+as int,peakBytesInUse: null == peakBytesInUse ? _self.peakBytesInUse : peakBytesInUse // ignore: cast_nullable_to_non_nullable
+as int,totalAllocatedBytes: null == totalAllocatedBytes ? _self.totalAllocatedBytes : totalAllocatedBytes // ignore: cast_nullable_to_non_nullable
+as int,numAllocs: null == numAllocs ? _self.numAllocs : numAllocs // ignore: cast_nullable_to_non_nullable
+as int,numReserves: null == numReserves ? _self.numReserves : numReserves // ignore: cast_nullable_to_non_nullable
+as int,numArenaExtensions: null == numArenaExtensions ? _self.numArenaExtensions : numArenaExtensions // ignore: cast_nullable_to_non_nullable
+as int,numArenaShrinkages: null == numArenaShrinkages ? _self.numArenaShrinkages : numArenaShrinkages // ignore: cast_nullable_to_non_nullable
+as int,largestAllocBytes: null == largestAllocBytes ? _self.largestAllocBytes : largestAllocBytes // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [ArenaStats].
+extension ArenaStatsPatterns on ArenaStats {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _ArenaStats value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _ArenaStats() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _ArenaStats value)  $default,){
+final _that = this;
+switch (_that) {
+case _ArenaStats():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _ArenaStats value)?  $default,){
+final _that = this;
+switch (_that) {
+case _ArenaStats() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( int limitBytes,  int bytesInUse,  int peakBytesInUse,  int totalAllocatedBytes,  int numAllocs,  int numReserves,  int numArenaExtensions,  int numArenaShrinkages,  int largestAllocBytes)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _ArenaStats() when $default != null:
+return $default(_that.limitBytes,_that.bytesInUse,_that.peakBytesInUse,_that.totalAllocatedBytes,_that.numAllocs,_that.numReserves,_that.numArenaExtensions,_that.numArenaShrinkages,_that.largestAllocBytes);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( int limitBytes,  int bytesInUse,  int peakBytesInUse,  int totalAllocatedBytes,  int numAllocs,  int numReserves,  int numArenaExtensions,  int numArenaShrinkages,  int largestAllocBytes)  $default,) {final _that = this;
+switch (_that) {
+case _ArenaStats():
+return $default(_that.limitBytes,_that.bytesInUse,_that.peakBytesInUse,_that.totalAllocatedBytes,_that.numAllocs,_that.numReserves,_that.numArenaExtensions,_that.numArenaShrinkages,_that.largestAllocBytes);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( int limitBytes,  int bytesInUse,  int peakBytesInUse,  int totalAllocatedBytes,  int numAllocs,  int numReserves,  int numArenaExtensions,  int numArenaShrinkages,  int largestAllocBytes)?  $default,) {final _that = this;
+switch (_that) {
+case _ArenaStats() when $default != null:
+return $default(_that.limitBytes,_that.bytesInUse,_that.peakBytesInUse,_that.totalAllocatedBytes,_that.numAllocs,_that.numReserves,_that.numArenaExtensions,_that.numArenaShrinkages,_that.largestAllocBytes);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _ArenaStats extends ArenaStats {
+  const _ArenaStats({required this.limitBytes, required this.bytesInUse, required this.peakBytesInUse, required this.totalAllocatedBytes, required this.numAllocs, required this.numReserves, required this.numArenaExtensions, required this.numArenaShrinkages, required this.largestAllocBytes}): super._();
+  
+
+/// The most bytes the arena may take from the system.
+@override final  int limitBytes;
+/// Bytes handed out by the arena and not yet freed.
+@override final  int bytesInUse;
+/// The most bytes that have been in use at once.
+@override final  int peakBytesInUse;
+/// Bytes the arena has taken from the system, whether they're in use or not.
+@override final  int totalAllocatedBytes;
+@override final  int numAllocs;
+/// Allocations made through the allocator's `Reserve` function, which bypass the arena's chunks.
+@override final  int numReserves;
+/// How often the arena has grown.
+@override final  int numArenaExtensions;
+/// How often the arena has been shrunk, see [`trim_memory`](crate::api::memory::trim::trim_memory).
+@override final  int numArenaShrinkages;
+/// The size of the largest single allocation.
+@override final  int largestAllocBytes;
+
+/// Create a copy of ArenaStats
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$ArenaStatsCopyWith<_ArenaStats> get copyWith => __$ArenaStatsCopyWithImpl<_ArenaStats>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _ArenaStats&&(identical(other.limitBytes, limitBytes) || other.limitBytes == limitBytes)&&(identical(other.bytesInUse, bytesInUse) || other.bytesInUse == bytesInUse)&&(identical(other.peakBytesInUse, peakBytesInUse) || other.peakBytesInUse == peakBytesInUse)&&(identical(other.totalAllocatedBytes, totalAllocatedBytes) || other.totalAllocatedBytes == totalAllocatedBytes)&&(identical(other.numAllocs, numAllocs) || other.numAllocs == numAllocs)&&(identical(other.numReserves, numReserves) || other.numReserves == numReserves)&&(identical(other.numArenaExtensions, numArenaExtensions) || other.numArenaExtensions == numArenaExtensions)&&(identical(other.numArenaShrinkages, numArenaShrinkages) || other.numArenaShrinkages == numArenaShrinkages)&&(identical(other.largestAllocBytes, largestAllocBytes) || other.largestAllocBytes == largestAllocBytes));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,limitBytes,bytesInUse,peakBytesInUse,totalAllocatedBytes,numAllocs,numReserves,numArenaExtensions,numArenaShrinkages,largestAllocBytes);
+
+@override
+String toString() {
+  return 'ArenaStats(limitBytes: $limitBytes, bytesInUse: $bytesInUse, peakBytesInUse: $peakBytesInUse, totalAllocatedBytes: $totalAllocatedBytes, numAllocs: $numAllocs, numReserves: $numReserves, numArenaExtensions: $numArenaExtensions, numArenaShrinkages: $numArenaShrinkages, largestAllocBytes: $largestAllocBytes)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$ArenaStatsCopyWith<$Res> implements $ArenaStatsCopyWith<$Res> {
+  factory _$ArenaStatsCopyWith(_ArenaStats value, $Res Function(_ArenaStats) _then) = __$ArenaStatsCopyWithImpl;
+@override @useResult
+$Res call({
+ int limitBytes, int bytesInUse, int peakBytesInUse, int totalAllocatedBytes, int numAllocs, int numReserves, int numArenaExtensions, int numArenaShrinkages, int largestAllocBytes
+});
+
+
+
+
+}
+/// @nodoc
+class __$ArenaStatsCopyWithImpl<$Res>
+    implements _$ArenaStatsCopyWith<$Res> {
+  __$ArenaStatsCopyWithImpl(this._self, this._then);
+
+  final _ArenaStats _self;
+  final $Res Function(_ArenaStats) _then;
+
+/// Create a copy of ArenaStats
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? limitBytes = null,Object? bytesInUse = null,Object? peakBytesInUse = null,Object? totalAllocatedBytes = null,Object? numAllocs = null,Object? numReserves = null,Object? numArenaExtensions = null,Object? numArenaShrinkages = null,Object? largestAllocBytes = null,}) {
+  return _then(_ArenaStats(
+limitBytes: null == limitBytes ? _self.limitBytes : limitBytes // ignore: cast_nullable_to_non_nullable
+as int,bytesInUse: null == bytesInUse ? _self.bytesInUse : bytesInUse // ignore: cast_nullable_to_non_nullable
+as int,peakBytesInUse: null == peakBytesInUse ? _self.peakBytesInUse : peakBytesInUse // ignore: cast_nullable_to_non_nullable
+as int,totalAllocatedBytes: null == totalAllocatedBytes ? _self.totalAllocatedBytes : totalAllocatedBytes // ignore: cast_nullable_to_non_nullable
+as int,numAllocs: null == numAllocs ? _self.numAllocs : numAllocs // ignore: cast_nullable_to_non_nullable
+as int,numReserves: null == numReserves ? _self.numReserves : numReserves // ignore: cast_nullable_to_non_nullable
+as int,numArenaExtensions: null == numArenaExtensions ? _self.numArenaExtensions : numArenaExtensions // ignore: cast_nullable_to_non_nullable
+as int,numArenaShrinkages: null == numArenaShrinkages ? _self.numArenaShrinkages : numArenaShrinkages // ignore: cast_nullable_to_non_nullable
+as int,largestAllocBytes: null == largestAllocBytes ? _self.largestAllocBytes : largestAllocBytes // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$RunMemoryUsage {
+
+/// The most bytes in use at once by the tracked allocator during the run, including memory held by other sessions.
+ int get peakBytesInUse;/// How far the bytes in use rose above what was in use when the run started, i.e. roughly what the run itself
+/// needed.
+ int get peakIncreaseBytes;
+/// Create a copy of RunMemoryUsage
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$RunMemoryUsageCopyWith<RunMemoryUsage> get copyWith => _$RunMemoryUsageCopyWithImpl<RunMemoryUsage>(this as RunMemoryUsage, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RunMemoryUsage&&(identical(other.peakBytesInUse, peakBytesInUse) || other.peakBytesInUse == peakBytesInUse)&&(identical(other.peakIncreaseBytes, peakIncreaseBytes) || other.peakIncreaseBytes == peakIncreaseBytes));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,peakBytesInUse,peakIncreaseBytes);
+
+@override
+String toString() {
+  return 'RunMemoryUsage(peakBytesInUse: $peakBytesInUse, peakIncreaseBytes: $peakIncreaseBytes)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $RunMemoryUsageCopyWith<$Res>  {
+  factory $RunMemoryUsageCopyWith(RunMemoryUsage value, $Res Function(RunMemoryUsage) _then) = _$RunMemoryUsageCopyWithImpl;
+@useResult
+$Res call({
+ int peakBytesInUse, int peakIncreaseBytes
+});
+
+
+
+
+}
+/// @nodoc
+class _$RunMemoryUsageCopyWithImpl<$Res>
+    implements $RunMemoryUsageCopyWith<$Res> {
+  _$RunMemoryUsageCopyWithImpl(this._self, this._then);
+
+  final RunMemoryUsage _self;
+  final $Res Function(RunMemoryUsage) _then;
+
+/// Create a copy of RunMemoryUsage
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? peakBytesInUse = null,Object? peakIncreaseBytes = null,}) {
+  return _then(_self.copyWith(
+peakBytesInUse: null == peakBytesInUse ? _self.peakBytesInUse : peakBytesInUse // ignore: cast_nullable_to_non_nullable
+as int,peakIncreaseBytes: null == peakIncreaseBytes ? _self.peakIncreaseBytes : peakIncreaseBytes // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [RunMemoryUsage].
+extension RunMemoryUsagePatterns on RunMemoryUsage {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _RunMemoryUsage value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _RunMemoryUsage() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _RunMemoryUsage value)  $default,){
+final _that = this;
+switch (_that) {
+case _RunMemoryUsage():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _RunMemoryUsage value)?  $default,){
+final _that = this;
+switch (_that) {
+case _RunMemoryUsage() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( int peakBytesInUse,  int peakIncreaseBytes)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _RunMemoryUsage() when $default != null:
+return $default(_that.peakBytesInUse,_that.peakIncreaseBytes);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( int peakBytesInUse,  int peakIncreaseBytes)  $default,) {final _that = this;
+switch (_that) {
+case _RunMemoryUsage():
+return $default(_that.peakBytesInUse,_that.peakIncreaseBytes);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( int peakBytesInUse,  int peakIncreaseBytes)?  $default,) {final _that = this;
+switch (_that) {
+case _RunMemoryUsage() when $default != null:
+return $default(_that.peakBytesInUse,_that.peakIncreaseBytes);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _RunMemoryUsage implements RunMemoryUsage {
+  const _RunMemoryUsage({required this.peakBytesInUse, required this.peakIncreaseBytes});
+  
+
+/// The most bytes in use at once by the tracked allocator during the run, including memory held by other sessions.
+@override final  int peakBytesInUse;
+/// How far the bytes in use rose above what was in use when the run started, i.e. roughly what the run itself
+/// needed.
+@override final  int peakIncreaseBytes;
+
+/// Create a copy of RunMemoryUsage
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$RunMemoryUsageCopyWith<_RunMemoryUsage> get copyWith => __$RunMemoryUsageCopyWithImpl<_RunMemoryUsage>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _RunMemoryUsage&&(identical(other.peakBytesInUse, peakBytesInUse) || other.peakBytesInUse == peakBytesInUse)&&(identical(other.peakIncreaseBytes, peakIncreaseBytes) || other.peakIncreaseBytes == peakIncreaseBytes));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,peakBytesInUse,peakIncreaseBytes);
+
+@override
+String toString() {
+  return 'RunMemoryUsage(peakBytesInUse: $peakBytesInUse, peakIncreaseBytes: $peakIncreaseBytes)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$RunMemoryUsageCopyWith<$Res> implements $RunMemoryUsageCopyWith<$Res> {
+  factory _$RunMemoryUsageCopyWith(_RunMemoryUsage value, $Res Function(_RunMemoryUsage) _then) = __$RunMemoryUsageCopyWithImpl;
+@override @useResult
+$Res call({
+ int peakBytesInUse, int peakIncreaseBytes
+});
+
+
+
+
+}
+/// @nodoc
+class __$RunMemoryUsageCopyWithImpl<$Res>
+    implements _$RunMemoryUsageCopyWith<$Res> {
+  __$RunMemoryUsageCopyWithImpl(this._self, this._then);
+
+  final _RunMemoryUsage _self;
+  final $Res Function(_RunMemoryUsage) _then;
+
+/// Create a copy of RunMemoryUsage
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? peakBytesInUse = null,Object? peakIncreaseBytes = null,}) {
+  return _then(_RunMemoryUsage(
+peakBytesInUse: null == peakBytesInUse ? _self.peakBytesInUse : peakBytesInUse // ignore: cast_nullable_to_non_nullable
+as int,peakIncreaseBytes: null == peakIncreaseBytes ? _self.peakIncreaseBytes : peakIncreaseBytes // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+// dart format on
@@ -0,0 +1,44 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'trim.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `new`, `take_shrink`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `eq`, `fmt`
+
+/// Releases the memory of live sessions, e.g. when the app is told that memory is low.
+///
+/// Idle sessions that keep their model are suspended, which releases their weights and arenas right away, and they're
+/// committed again on their next run, see [`SessionImpl::suspend`]. ONNX Runtime only shrinks the arenas of the other
+/// sessions at the end of a run, so those that allocate from a CPU memory arena release what it keeps cached after
+/// their next run, keeping memory still in use, like the outputs of that run.
+///
+/// [`SessionImpl::suspend`]: crate::api::session::SessionImpl::suspend
+MemoryTrimReport trimMemory() =>
+    RustLib.instance.api.crateApiMemoryTrimTrimMemory();
+
+/// What [`trim_memory`] did.
+@freezed
+sealed class MemoryTrimReport with _$MemoryTrimReport {
+  const factory MemoryTrimReport({
+    /// The idle sessions that were suspended, releasing all of their memory, see [`SessionImpl::suspend`].
+    required int sessionsSuspended,
+
+    /// An estimate of the bytes released by suspending sessions: the size of their models, as counted against the
+    /// memory budget, plus what their own CPU arenas had allocated if ONNX Runtime reports it, see
+    /// [`SessionImpl::arena_stats`].
+    required int releasedBytes,
+
+    /// The sessions that couldn't be suspended, as they were running, don't keep their model or have live allocators,
+    /// and whose CPU memory arenas are shrunk at the end of their next run instead. Sessions without an arena, see
+    /// [`SessionBuilderOptions::cpu_memory_arena`], aren't counted.
+    ///
+    /// [`SessionBuilderOptions::cpu_memory_arena`]: crate::api::session::SessionBuilderOptions::cpu_memory_arena
+    required int sessionsScheduled,
+  }) = _MemoryTrimReport;
+}
@@ -0,0 +1,289 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'trim.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$MemoryTrimReport {
+
+/// The idle sessions that were suspended, releasing all of their memory, see [`SessionImpl::suspend`].
+ int get sessionsSuspended;/// An estimate of the bytes released by suspending sessions: the size of their models, as counted against the
+/// memory budget, plus what their own CPU arenas had allocated if ONNX Runtime reports it, see
+/// [`SessionImpl::arena_stats`].
+ int get releasedBytes;/// The sessions that couldn't be suspended, as they were running, don't keep their model or have live allocators,
+/// and whose CPU memory arenas are shrunk at the end of their next run instead. Sessions without an arena, see
+/// [`SessionBuilderOptions::cpu_memory_arena`], aren't counted.
+///
+/// [`SessionBuilderOptions::cpu_memory_arena`]: crate::api::session::SessionBuilderOptions::cpu_memory_arena
+ int get sessionsScheduled;
+/// Create a copy of MemoryTrimReport
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$MemoryTrimReportCopyWith<MemoryTrimReport> get copyWith => _$MemoryTrimReportCopyWithImpl<MemoryTrimReport>(this as MemoryTrimReport, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MemoryTrimReport&&(identical(other.sessionsSuspended, sessionsSuspended) || other.sessionsSuspended == sessionsSuspended)&&(identical(other.releasedBytes, releasedBytes) || other.releasedBytes == releasedBytes)&&(identical(other.sessionsScheduled, sessionsScheduled) || other.sessionsScheduled == sessionsScheduled));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,sessionsSuspended,releasedBytes,sessionsScheduled);
+
+@override
+String toString() {
+  return 'MemoryTrimReport(sessionsSuspended: $sessionsSuspended, releasedBytes: $releasedBytes, sessionsScheduled: $sessionsScheduled)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $MemoryTrimReportCopyWith<$Res>  {
+  factory $MemoryTrimReportCopyWith(MemoryTrimReport value, $Res Function(MemoryTrimReport) _then) = _$MemoryTrimReportCopyWithImpl;
+@useResult
+$Res call({
+ int sessionsSuspended, int releasedBytes, int sessionsScheduled
+});
+
+
+
+
+}
+/// @nodoc
+class _$MemoryTrimReportCopyWithImpl<$Res>
+    implements $MemoryTrimReportCopyWith<$Res> {
+  _$MemoryTrimReportCopyWithImpl(this._self, this._then);
+
+  final MemoryTrimReport _self;
+  final $Res Function(MemoryTrimReport) _then;
+
+/// Create a copy of MemoryTrimReport
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? sessionsSuspended = null,Object? releasedBytes = null,Object? sessionsScheduled = null,}) {
+  return _then(_self.copyWith(
+sessionsSuspended: null == sessionsSuspended ? _self.sessionsSuspended : sessionsSuspended // ignore: cast_nullable_to_non_nullable
+as int,releasedBytes: null == releasedBytes ? _self.releasedBytes : releasedBytes // ignore: cast_nullable_to_non_nullable
+as int,sessionsScheduled: null == sessionsScheduled ? _self.sessionsScheduled : sessionsScheduled // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [MemoryTrimReport].
+extension MemoryTrimReportPatterns on MemoryTrimReport {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _MemoryTrimReport value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _MemoryTrimReport() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _MemoryTrimReport value)  $default,){
+final _that = this;
+switch (_that) {
+case _MemoryTrimReport():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _MemoryTrimReport value)?  $default,){
+final _that = this;
+switch (_that) {
+case _MemoryTrimReport() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( int sessionsSuspended,  int releasedBytes,  int sessionsScheduled)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _MemoryTrimReport() when $default != null:
+return $default(_that.sessionsSuspended,_that.releasedBytes,_that.sessionsScheduled);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( int sessionsSuspended,  int releasedBytes,  int sessionsScheduled)  $default,) {final _that = this;
+switch (_that) {
+case _MemoryTrimReport():
+return $default(_that.sessionsSuspended,_that.releasedBytes,_that.sessionsScheduled);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( int sessionsSuspended,  int releasedBytes,  int sessionsScheduled)?  $default,) {final _that = this;
+switch (_that) {
+case _MemoryTrimReport() when $default != null:
+return $default(_that.sessionsSuspended,_that.releasedBytes,_that.sessionsScheduled);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _MemoryTrimReport implements MemoryTrimReport {
+  const _MemoryTrimReport({required this.sessionsSuspended, required this.releasedBytes, required this.sessionsScheduled});
+  
+
+/// The idle sessions that were suspended, releasing all of their memory, see [`SessionImpl::suspend`].
+@override final  int sessionsSuspended;
+/// An estimate of the bytes released by suspending sessions: the size of their models, as counted against the
+/// memory budget, plus what their own CPU arenas had allocated if ONNX Runtime reports it, see
+/// [`SessionImpl::arena_stats`].
+@override final  int releasedBytes;
+/// The sessions that couldn't be suspended, as they were running, don't keep their model or have live allocators,
+/// and whose CPU memory arenas are shrunk at the end of their next run instead. Sessions without an arena, see
+/// [`SessionBuilderOptions::cpu_memory_arena`], aren't counted.
+///
+/// [`SessionBuilderOptions::cpu_memory_arena`]: crate::api::session::SessionBuilderOptions::cpu_memory_arena
+@override final  int sessionsScheduled;
+
+/// Create a copy of MemoryTrimReport
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$MemoryTrimReportCopyWith<_MemoryTrimReport> get copyWith => __$MemoryTrimReportCopyWithImpl<_MemoryTrimReport>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _MemoryTrimReport&&(identical(other.sessionsSuspended, sessionsSuspended) || other.sessionsSuspended == sessionsSuspended)&&(identical(other.releasedBytes, releasedBytes) || other.releasedBytes == releasedBytes)&&(identical(other.sessionsScheduled, sessionsScheduled) || other.sessionsScheduled == sessionsScheduled));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,sessionsSuspended,releasedBytes,sessionsScheduled);
+
+@override
+String toString() {
+  return 'MemoryTrimReport(sessionsSuspended: $sessionsSuspended, releasedBytes: $releasedBytes, sessionsScheduled: $sessionsScheduled)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$MemoryTrimReportCopyWith<$Res> implements $MemoryTrimReportCopyWith<$Res> {
+  factory _$MemoryTrimReportCopyWith(_MemoryTrimReport value, $Res Function(_MemoryTrimReport) _then) = __$MemoryTrimReportCopyWithImpl;
+@override @useResult
+$Res call({
+ int sessionsSuspended, int releasedBytes, int sessionsScheduled
+});
+
+
+
+
+}
+/// @nodoc
+class __$MemoryTrimReportCopyWithImpl<$Res>
+    implements _$MemoryTrimReportCopyWith<$Res> {
+  __$MemoryTrimReportCopyWithImpl(this._self, this._then);
+
+  final _MemoryTrimReport _self;
+  final $Res Function(_MemoryTrimReport) _then;
+
+/// Create a copy of MemoryTrimReport
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? sessionsSuspended = null,Object? releasedBytes = null,Object? sessionsScheduled = null,}) {
+  return _then(_MemoryTrimReport(
+sessionsSuspended: null == sessionsSuspended ? _self.sessionsSuspended : sessionsSuspended // ignore: cast_nullable_to_non_nullable
+as int,releasedBytes: null == releasedBytes ? _self.releasedBytes : releasedBytes // ignore: cast_nullable_to_non_nullable
+as int,sessionsScheduled: null == sessionsScheduled ? _self.sessionsScheduled : sessionsScheduled // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+// dart format on
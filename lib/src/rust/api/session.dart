@@ -14,17 +14,37 @@ import 'execution_providers/qnn.dart';
 import 'execution_providers/rocm.dart';
 import 'execution_providers/tensorrt.dart';
 import 'execution_providers/xnnpack.dart';
+import 'memory.dart';
+import 'memory/stats.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 import 'session/builder/impl_options.dart';
 import 'tensor.dart';
+import 'tensor/value.dart';
 part 'session.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `build_session`, `from_session`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `batch_sizes`, `build_session`, `check_non_finite`, `commit`, `commit`, `cpu_arena`, `insert`, `is_pinned`, `lock`, `model`, `new`, `ort_allocator`, `run_batch_ref`, `run_ref`, `run_session`, `session`, `size`, `size_estimate`, `uses_env_allocators`, `with_source`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseNotAllowedOwner): `default`
 
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SessionImpl>>
 abstract class SessionImpl implements RustOpaqueInterface {
+  /// Creates an [`Allocator`] for the device described by `memory_info`, which one of the session's execution
+  /// providers must support. Leaving `memory_info` out creates an allocator like the one the session uses for its
+  /// outputs. Fails while the session is suspended.
+  ///
+  /// The allocator, and every tensor allocated with it, keeps the session's memory alive, even after
+  /// [`SessionImpl::suspend`].
+  Allocator allocator({MemoryInfo? memoryInfo});
+
+  /// Returns the statistics ONNX Runtime keeps for the session's allocator for the device described by
+  /// `memory_info`, or the one it uses for its outputs if left out. With a memory arena these are the arena's, which
+  /// is shared with other sessions when it's the environment's, see [`SessionBuilderOptions::env_allocators`].
+  /// Allocators without statistics, like the CPU allocator of a session without an arena, report zeros.
+  ///
+  /// Requires ONNX Runtime 1.23 or newer, and fails while the session is suspended.
+  ArenaStats arenaStats({MemoryInfo? memoryInfo});
+
   /// Creates a new [`SessionBuilder`].
   static SessionBuilderOptions builder() =>
       RustLib.instance.api.crateApiSessionSessionImplBuilder();
@@ -32,9 +52,22 @@ abstract class SessionImpl implements RustOpaqueInterface {
   /// Information about the graph's inputs.
   List<Input> inputs();
 
+  /// If the session was suspended and hasn't been committed again since, see [`SessionImpl::suspend`].
+  bool isSuspended();
+
+  /// The memory used by the last successful run, if the session was created after [`stats::enable_allocator_tracking`].
+  RunMemoryUsage? lastRunMemory();
+
+  /// The names of the outputs of the last checked run that contained NaN or infinite values.
+  List<String> nonFiniteOutputs();
+
   /// Information about the graph's outputs.
   List<Output> outputs();
 
+  /// Commits the session again if it's suspended, e.g. ahead of time to keep the first run after
+  /// [`SessionImpl::suspend`] fast. Fails if the model doesn't fit into the memory budget.
+  Future<void> resume();
+
   /// Run input data through the ONNX graph, performing inference.
   ///
   /// See [`crate::inputs!`] for a convenient macro which will help you create your session inputs from `ndarray`s or
@@ -54,18 +87,68 @@ abstract class SessionImpl implements RustOpaqueInterface {
   Future<Map<String, TensorImpl>> run({
     required Map<String, TensorImpl> inputValues,
   });
+
+  /// Run several independent sets of input data through the ONNX graph.
+  ///
+  /// Compatible inputs (same names, element types and dimensions after the batch dimension) are stacked along the
+  /// batch dimension so the graph only runs once, and the outputs are split back up so that every entry of the
+  /// result belongs to the entry of `batch` at the same index. If the model's inputs or outputs don't have a dynamic
+  /// batch dimension, or the inputs can't be stacked, every entry is run on its own instead.
+  Future<List<Map<String, TensorImpl>>> runBatch({
+    required List<Map<String, TensorImpl>> batch,
+  });
+
+  /// Run input data through the ONNX graph like [`SessionImpl::run`], for models whose inputs or outputs are not
+  /// only tensors but also sequences, maps or optional values.
+  Future<Map<String, ValueImpl>> runValues({
+    required Map<String, ValueImpl> inputValues,
+  });
+
+  /// Sets whether the floating point outputs of every run are checked for NaN and infinite values. Checking reads
+  /// every output once, so it's disabled by default.
+  void setNonFiniteCheck({required NonFiniteCheck check});
+
+  /// Drops the underlying session, and with it the memory of its weights and arena, while keeping everything needed
+  /// to commit it again. The next run commits it again first, which takes as long as committing it did originally.
+  /// Fails for sessions committed with [`SessionBuilderOptions::commit_from_memory`], which don't keep their model.
+  ///
+  /// Meant for apps that move to the background, so that they don't have to rebuild their session objects. Tensors
+  /// and allocators obtained from the session stay valid, but allocators from [`SessionImpl::allocator`] and the
+  /// tensors allocated with them keep the session's memory alive: it's only released, and its charge against the
+  /// memory budget only dropped, once they're dropped too. The memory budget suspends idle sessions without such
+  /// allocators itself with
+  /// [`EvictionPolicy::SuspendIdleSessions`](crate::api::memory::budget::EvictionPolicy::SuspendIdleSessions).
+  void suspend();
 }
 
 /// Information about a [`Session`] input.
 @freezed
 sealed class Input with _$Input {
-  const factory Input({required String name}) = _Input;
+  const factory Input({
+    /// Name of the input.
+    required String name,
+  }) = _Input;
+}
+
+/// What a session does when a run produces outputs containing NaN or infinite values.
+enum NonFiniteCheck {
+  /// Outputs aren't checked.
+  disabled,
+
+  /// Outputs with non-finite values are logged as a warning and listed by [`SessionImpl::non_finite_outputs`].
+  warn,
+
+  /// The run fails with an error naming the outputs with non-finite values.
+  error,
 }
 
 /// Information about a [`Session`] output.
 @freezed
 sealed class Output with _$Output {
-  const factory Output({required String name}) = _Output;
+  const factory Output({
+    /// Name of the output.
+    required String name,
+  }) = _Output;
 }
 
 @freezed
@@ -78,6 +161,15 @@ sealed class SessionBuilderOptions with _$SessionBuilderOptions {
     bool? parallelExecution,
     GraphOptimizationLevel? optimizationLevel,
     bool? memoryPattern,
+
+    /// Allocates CPU memory from the allocator shared through the environment, see
+    /// [`configure_env_arena`](crate::api::memory::arena::configure_env_arena), rather than an arena of the session's
+    /// own. Defaults to `true` if allocator tracking is enabled and `false` otherwise.
+    bool? envAllocators,
+
+    /// Enables/disables the CPU memory arena of the session. Disabling it returns memory to the system as soon as it's
+    /// freed, trading some speed for a lower peak. Takes precedence over the CPU execution provider's setting.
+    bool? cpuMemoryArena,
   }) = _SessionBuilderOptions;
 
   /// Loads an ONNX model from a file and builds the session.
@@ -88,12 +180,25 @@ sealed class SessionBuilderOptions with _$SessionBuilderOptions {
       );
 
   /// Load an ONNX graph from memory and commit the session.
+  ///
+  /// The session doesn't keep `model_bytes`, so it can't be suspended, see
+  /// [`SessionBuilderOptions::commit_from_memory_suspendable`].
   Future<SessionImpl> commitFromMemory({required List<int> modelBytes}) =>
       RustLib.instance.api.crateApiSessionSessionBuilderOptionsCommitFromMemory(
         that: this,
         modelBytes: modelBytes,
       );
 
+  /// Loads an ONNX graph from memory and commits the session, keeping `model_bytes` to commit it again after
+  /// [`SessionImpl::suspend`]. The model stays in memory for as long as the session exists.
+  Future<SessionImpl> commitFromMemorySuspendable({
+    required List<int> modelBytes,
+  }) => RustLib.instance.api
+      .crateApiSessionSessionBuilderOptionsCommitFromMemorySuspendable(
+        that: this,
+        modelBytes: modelBytes,
+      );
+
   static Future<SessionBuilderOptions> default_() =>
       RustLib.instance.api.crateApiSessionSessionBuilderOptionsDefault();
 }
@@ -14,6 +14,7 @@ T _$identity<T>(T value) => value;
 /// @nodoc
 mixin _$Input {
 
+/// Name of the input.
  String get name;
 /// Create a copy of Input
 /// with the given fields replaced by the non-null parameter values.
@@ -203,6 +204,7 @@ class _Input implements Input {
   const _Input({required this.name});
   
 
+/// Name of the input.
 @override final  String name;
 
 /// Create a copy of Input
@@ -265,6 +267,7 @@ as String,
 /// @nodoc
 mixin _$Output {
 
+/// Name of the output.
  String get name;
 /// Create a copy of Output
 /// with the given fields replaced by the non-null parameter values.
@@ -454,6 +457,7 @@ class _Output implements Output {
   const _Output({required this.name});
   
 
+/// Name of the output.
 @override final  String name;
 
 /// Create a copy of Output
@@ -516,7 +520,12 @@ as String,
 /// @nodoc
 mixin _$SessionBuilderOptions {
 
- List<ExecutionProvider>? get executionProviders; int? get intraThreads; int? get interThreads; bool? get parallelExecution; GraphOptimizationLevel? get optimizationLevel; bool? get memoryPattern;
+ List<ExecutionProvider>? get executionProviders; int? get intraThreads; int? get interThreads; bool? get parallelExecution; GraphOptimizationLevel? get optimizationLevel; bool? get memoryPattern;/// Allocates CPU memory from the allocator shared through the environment, see
+/// [`configure_env_arena`](crate::api::memory::arena::configure_env_arena), rather than an arena of the session's
+/// own. Defaults to `true` if allocator tracking is enabled and `false` otherwise.
+ bool? get envAllocators;/// Enables/disables the CPU memory arena of the session. Disabling it returns memory to the system as soon as it's
+/// freed, trading some speed for a lower peak. Takes precedence over the CPU execution provider's setting.
+ bool? get cpuMemoryArena;
 /// Create a copy of SessionBuilderOptions
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
@@ -527,16 +536,16 @@ $SessionBuilderOptionsCopyWith<SessionBuilderOptions> get copyWith => _$SessionB
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is SessionBuilderOptions&&const DeepCollectionEquality().equals(other.executionProviders, executionProviders)&&(identical(other.intraThreads, intraThreads) || other.intraThreads == intraThreads)&&(identical(other.interThreads, interThreads) || other.interThreads == interThreads)&&(identical(other.parallelExecution, parallelExecution) || other.parallelExecution == parallelExecution)&&(identical(other.optimizationLevel, optimizationLevel) || other.optimizationLevel == optimizationLevel)&&(identical(other.memoryPattern, memoryPattern) || other.memoryPattern == memoryPattern));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SessionBuilderOptions&&const DeepCollectionEquality().equals(other.executionProviders, executionProviders)&&(identical(other.intraThreads, intraThreads) || other.intraThreads == intraThreads)&&(identical(other.interThreads, interThreads) || other.interThreads == interThreads)&&(identical(other.parallelExecution, parallelExecution) || other.parallelExecution == parallelExecution)&&(identical(other.optimizationLevel, optimizationLevel) || other.optimizationLevel == optimizationLevel)&&(identical(other.memoryPattern, memoryPattern) || other.memoryPattern == memoryPattern)&&(identical(other.envAllocators, envAllocators) || other.envAllocators == envAllocators)&&(identical(other.cpuMemoryArena, cpuMemoryArena) || other.cpuMemoryArena == cpuMemoryArena));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(executionProviders),intraThreads,interThreads,parallelExecution,optimizationLevel,memoryPattern);
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(executionProviders),intraThreads,interThreads,parallelExecution,optimizationLevel,memoryPattern,envAllocators,cpuMemoryArena);
 
 @override
 String toString() {
-  return 'SessionBuilderOptions(executionProviders: $executionProviders, intraThreads: $intraThreads, interThreads: $interThreads, parallelExecution: $parallelExecution, optimizationLevel: $optimizationLevel, memoryPattern: $memoryPattern)';
+  return 'SessionBuilderOptions(executionProviders: $executionProviders, intraThreads: $intraThreads, interThreads: $interThreads, parallelExecution: $parallelExecution, optimizationLevel: $optimizationLevel, memoryPattern: $memoryPattern, envAllocators: $envAllocators, cpuMemoryArena: $cpuMemoryArena)';
 }
 
 
@@ -547,7 +556,7 @@ abstract mixin class $SessionBuilderOptionsCopyWith<$Res>  {
   factory $SessionBuilderOptionsCopyWith(SessionBuilderOptions value, $Res Function(SessionBuilderOptions) _then) = _$SessionBuilderOptionsCopyWithImpl;
 @useResult
 $Res call({
- List<ExecutionProvider>? executionProviders, int? intraThreads, int? interThreads, bool? parallelExecution, GraphOptimizationLevel? optimizationLevel, bool? memoryPattern
+ List<ExecutionProvider>? executionProviders, int? intraThreads, int? interThreads, bool? parallelExecution, GraphOptimizationLevel? optimizationLevel, bool? memoryPattern, bool? envAllocators, bool? cpuMemoryArena
 });
 
 
@@ -564,7 +573,7 @@ class _$SessionBuilderOptionsCopyWithImpl<$Res>
 
 /// Create a copy of SessionBuilderOptions
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? executionProviders = freezed,Object? intraThreads = freezed,Object? interThreads = freezed,Object? parallelExecution = freezed,Object? optimizationLevel = freezed,Object? memoryPattern = freezed,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? executionProviders = freezed,Object? intraThreads = freezed,Object? interThreads = freezed,Object? parallelExecution = freezed,Object? optimizationLevel = freezed,Object? memoryPattern = freezed,Object? envAllocators = freezed,Object? cpuMemoryArena = freezed,}) {
   return _then(_self.copyWith(
 executionProviders: freezed == executionProviders ? _self.executionProviders : executionProviders // ignore: cast_nullable_to_non_nullable
 as List<ExecutionProvider>?,intraThreads: freezed == intraThreads ? _self.intraThreads : intraThreads // ignore: cast_nullable_to_non_nullable
@@ -572,6 +581,8 @@ as int?,interThreads: freezed == interThreads ? _self.interThreads : interThread
 as int?,parallelExecution: freezed == parallelExecution ? _self.parallelExecution : parallelExecution // ignore: cast_nullable_to_non_nullable
 as bool?,optimizationLevel: freezed == optimizationLevel ? _self.optimizationLevel : optimizationLevel // ignore: cast_nullable_to_non_nullable
 as GraphOptimizationLevel?,memoryPattern: freezed == memoryPattern ? _self.memoryPattern : memoryPattern // ignore: cast_nullable_to_non_nullable
+as bool?,envAllocators: freezed == envAllocators ? _self.envAllocators : envAllocators // ignore: cast_nullable_to_non_nullable
+as bool?,cpuMemoryArena: freezed == cpuMemoryArena ? _self.cpuMemoryArena : cpuMemoryArena // ignore: cast_nullable_to_non_nullable
 as bool?,
   ));
 }
@@ -654,10 +665,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( List<ExecutionProvider>? executionProviders,  int? intraThreads,  int? interThreads,  bool? parallelExecution,  GraphOptimizationLevel? optimizationLevel,  bool? memoryPattern)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( List<ExecutionProvider>? executionProviders,  int? intraThreads,  int? interThreads,  bool? parallelExecution,  GraphOptimizationLevel? optimizationLevel,  bool? memoryPattern,  bool? envAllocators,  bool? cpuMemoryArena)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
 case _SessionBuilderOptions() when $default != null:
-return $default(_that.executionProviders,_that.intraThreads,_that.interThreads,_that.parallelExecution,_that.optimizationLevel,_that.memoryPattern);case _:
+return $default(_that.executionProviders,_that.intraThreads,_that.interThreads,_that.parallelExecution,_that.optimizationLevel,_that.memoryPattern,_that.envAllocators,_that.cpuMemoryArena);case _:
   return orElse();
 
 }
@@ -675,10 +686,10 @@ return $default(_that.executionProviders,_that.intraThreads,_that.interThreads,_
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( List<ExecutionProvider>? executionProviders,  int? intraThreads,  int? interThreads,  bool? parallelExecution,  GraphOptimizationLevel? optimizationLevel,  bool? memoryPattern)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( List<ExecutionProvider>? executionProviders,  int? intraThreads,  int? interThreads,  bool? parallelExecution,  GraphOptimizationLevel? optimizationLevel,  bool? memoryPattern,  bool? envAllocators,  bool? cpuMemoryArena)  $default,) {final _that = this;
 switch (_that) {
 case _SessionBuilderOptions():
-return $default(_that.executionProviders,_that.intraThreads,_that.interThreads,_that.parallelExecution,_that.optimizationLevel,_that.memoryPattern);}
+return $default(_that.executionProviders,_that.intraThreads,_that.interThreads,_that.parallelExecution,_that.optimizationLevel,_that.memoryPattern,_that.envAllocators,_that.cpuMemoryArena);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -692,10 +703,10 @@ return $default(_that.executionProviders,_that.intraThreads,_that.interThreads,_
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( List<ExecutionProvider>? executionProviders,  int? intraThreads,  int? interThreads,  bool? parallelExecution,  GraphOptimizationLevel? optimizationLevel,  bool? memoryPattern)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( List<ExecutionProvider>? executionProviders,  int? intraThreads,  int? interThreads,  bool? parallelExecution,  GraphOptimizationLevel? optimizationLevel,  bool? memoryPattern,  bool? envAllocators,  bool? cpuMemoryArena)?  $default,) {final _that = this;
 switch (_that) {
 case _SessionBuilderOptions() when $default != null:
-return $default(_that.executionProviders,_that.intraThreads,_that.interThreads,_that.parallelExecution,_that.optimizationLevel,_that.memoryPattern);case _:
+return $default(_that.executionProviders,_that.intraThreads,_that.interThreads,_that.parallelExecution,_that.optimizationLevel,_that.memoryPattern,_that.envAllocators,_that.cpuMemoryArena);case _:
   return null;
 
 }
@@ -707,7 +718,7 @@ return $default(_that.executionProviders,_that.intraThreads,_that.interThreads,_
 
 
 class _SessionBuilderOptions extends SessionBuilderOptions {
-  const _SessionBuilderOptions({final  List<ExecutionProvider>? executionProviders, this.intraThreads, this.interThreads, this.parallelExecution, this.optimizationLevel, this.memoryPattern}): _executionProviders = executionProviders,super._();
+  const _SessionBuilderOptions({final  List<ExecutionProvider>? executionProviders, this.intraThreads, this.interThreads, this.parallelExecution, this.optimizationLevel, this.memoryPattern, this.envAllocators, this.cpuMemoryArena}): _executionProviders = executionProviders,super._();
   
 
  final  List<ExecutionProvider>? _executionProviders;
@@ -724,6 +735,13 @@ class _SessionBuilderOptions extends SessionBuilderOptions {
 @override final  bool? parallelExecution;
 @override final  GraphOptimizationLevel? optimizationLevel;
 @override final  bool? memoryPattern;
+/// Allocates CPU memory from the allocator shared through the environment, see
+/// [`configure_env_arena`](crate::api::memory::arena::configure_env_arena), rather than an arena of the session's
+/// own. Defaults to `true` if allocator tracking is enabled and `false` otherwise.
+@override final  bool? envAllocators;
+/// Enables/disables the CPU memory arena of the session. Disabling it returns memory to the system as soon as it's
+/// freed, trading some speed for a lower peak. Takes precedence over the CPU execution provider's setting.
+@override final  bool? cpuMemoryArena;
 
 /// Create a copy of SessionBuilderOptions
 /// with the given fields replaced by the non-null parameter values.
@@ -735,16 +753,16 @@ _$SessionBuilderOptionsCopyWith<_SessionBuilderOptions> get copyWith => __$Sessi
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _SessionBuilderOptions&&const DeepCollectionEquality().equals(other._executionProviders, _executionProviders)&&(identical(other.intraThreads, intraThreads) || other.intraThreads == intraThreads)&&(identical(other.interThreads, interThreads) || other.interThreads == interThreads)&&(identical(other.parallelExecution, parallelExecution) || other.parallelExecution == parallelExecution)&&(identical(other.optimizationLevel, optimizationLevel) || other.optimizationLevel == optimizationLevel)&&(identical(other.memoryPattern, memoryPattern) || other.memoryPattern == memoryPattern));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _SessionBuilderOptions&&const DeepCollectionEquality().equals(other._executionProviders, _executionProviders)&&(identical(other.intraThreads, intraThreads) || other.intraThreads == intraThreads)&&(identical(other.interThreads, interThreads) || other.interThreads == interThreads)&&(identical(other.parallelExecution, parallelExecution) || other.parallelExecution == parallelExecution)&&(identical(other.optimizationLevel, optimizationLevel) || other.optimizationLevel == optimizationLevel)&&(identical(other.memoryPattern, memoryPattern) || other.memoryPattern == memoryPattern)&&(identical(other.envAllocators, envAllocators) || other.envAllocators == envAllocators)&&(identical(other.cpuMemoryArena, cpuMemoryArena) || other.cpuMemoryArena == cpuMemoryArena));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_executionProviders),intraThreads,interThreads,parallelExecution,optimizationLevel,memoryPattern);
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_executionProviders),intraThreads,interThreads,parallelExecution,optimizationLevel,memoryPattern,envAllocators,cpuMemoryArena);
 
 @override
 String toString() {
-  return 'SessionBuilderOptions(executionProviders: $executionProviders, intraThreads: $intraThreads, interThreads: $interThreads, parallelExecution: $parallelExecution, optimizationLevel: $optimizationLevel, memoryPattern: $memoryPattern)';
+  return 'SessionBuilderOptions(executionProviders: $executionProviders, intraThreads: $intraThreads, interThreads: $interThreads, parallelExecution: $parallelExecution, optimizationLevel: $optimizationLevel, memoryPattern: $memoryPattern, envAllocators: $envAllocators, cpuMemoryArena: $cpuMemoryArena)';
 }
 
 
@@ -755,7 +773,7 @@ abstract mixin class _$SessionBuilderOptionsCopyWith<$Res> implements $SessionBu
   factory _$SessionBuilderOptionsCopyWith(_SessionBuilderOptions value, $Res Function(_SessionBuilderOptions) _then) = __$SessionBuilderOptionsCopyWithImpl;
 @override @useResult
 $Res call({
- List<ExecutionProvider>? executionProviders, int? intraThreads, int? interThreads, bool? parallelExecution, GraphOptimizationLevel? optimizationLevel, bool? memoryPattern
+ List<ExecutionProvider>? executionProviders, int? intraThreads, int? interThreads, bool? parallelExecution, GraphOptimizationLevel? optimizationLevel, bool? memoryPattern, bool? envAllocators, bool? cpuMemoryArena
 });
 
 
@@ -772,7 +790,7 @@ class __$SessionBuilderOptionsCopyWithImpl<$Res>
 
 /// Create a copy of SessionBuilderOptions
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? executionProviders = freezed,Object? intraThreads = freezed,Object? interThreads = freezed,Object? parallelExecution = freezed,Object? optimizationLevel = freezed,Object? memoryPattern = freezed,}) {
+@override @pragma('vm:prefer-inline') $Res call({Object? executionProviders = freezed,Object? intraThreads = freezed,Object? interThreads = freezed,Object? parallelExecution = freezed,Object? optimizationLevel = freezed,Object? memoryPattern = freezed,Object? envAllocators = freezed,Object? cpuMemoryArena = freezed,}) {
   return _then(_SessionBuilderOptions(
 executionProviders: freezed == executionProviders ? _self._executionProviders : executionProviders // ignore: cast_nullable_to_non_nullable
 as List<ExecutionProvider>?,intraThreads: freezed == intraThreads ? _self.intraThreads : intraThreads // ignore: cast_nullable_to_non_nullable
@@ -780,6 +798,8 @@ as int?,interThreads: freezed == interThreads ? _self.interThreads : interThread
 as int?,parallelExecution: freezed == parallelExecution ? _self.parallelExecution : parallelExecution // ignore: cast_nullable_to_non_nullable
 as bool?,optimizationLevel: freezed == optimizationLevel ? _self.optimizationLevel : optimizationLevel // ignore: cast_nullable_to_non_nullable
 as GraphOptimizationLevel?,memoryPattern: freezed == memoryPattern ? _self.memoryPattern : memoryPattern // ignore: cast_nullable_to_non_nullable
+as bool?,envAllocators: freezed == envAllocators ? _self.envAllocators : envAllocators // ignore: cast_nullable_to_non_nullable
+as bool?,cpuMemoryArena: freezed == cpuMemoryArena ? _self.cpuMemoryArena : cpuMemoryArena // ignore: cast_nullable_to_non_nullable
 as bool?,
   ));
 }
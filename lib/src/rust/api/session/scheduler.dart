@@ -0,0 +1,46 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../session.dart';
+import '../tensor.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'scheduler.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `run`, `start`, `work`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `drop`
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BatchScheduler>>
+abstract class BatchScheduler implements RustOpaqueInterface {
+  /// Creates a scheduler that takes ownership of `session` and runs it on a background thread.
+  factory BatchScheduler({
+    required SessionImpl session,
+    required BatchSchedulerOptions options,
+  }) => RustLib.instance.api.crateApiSessionSchedulerBatchSchedulerNew(
+    session: session,
+    options: options,
+  );
+
+  /// Queues a single set of inputs and waits until the batch it ends up in has run.
+  Future<Map<String, TensorImpl>> run({
+    required Map<String, TensorImpl> inputValues,
+  });
+}
+
+/// Options for a [`BatchScheduler`].
+@freezed
+sealed class BatchSchedulerOptions with _$BatchSchedulerOptions {
+  const BatchSchedulerOptions._();
+  const factory BatchSchedulerOptions({
+    /// The most requests that are run together in one batch. Defaults to 16.
+    int? maxBatchSize,
+
+    /// How long, in milliseconds, to wait for more requests after the first request of a batch arrives. Defaults to 5.
+    int? maxDelayMs,
+  }) = _BatchSchedulerOptions;
+  static Future<BatchSchedulerOptions> default_() => RustLib.instance.api
+      .crateApiSessionSchedulerBatchSchedulerOptionsDefault();
+}
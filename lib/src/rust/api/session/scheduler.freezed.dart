@@ -0,0 +1,272 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'scheduler.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$BatchSchedulerOptions {
+
+/// The most requests that are run together in one batch. Defaults to 16.
+ int? get maxBatchSize;/// How long, in milliseconds, to wait for more requests after the first request of a batch arrives. Defaults to 5.
+ int? get maxDelayMs;
+/// Create a copy of BatchSchedulerOptions
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$BatchSchedulerOptionsCopyWith<BatchSchedulerOptions> get copyWith => _$BatchSchedulerOptionsCopyWithImpl<BatchSchedulerOptions>(this as BatchSchedulerOptions, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is BatchSchedulerOptions&&(identical(other.maxBatchSize, maxBatchSize) || other.maxBatchSize == maxBatchSize)&&(identical(other.maxDelayMs, maxDelayMs) || other.maxDelayMs == maxDelayMs));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,maxBatchSize,maxDelayMs);
+
+@override
+String toString() {
+  return 'BatchSchedulerOptions(maxBatchSize: $maxBatchSize, maxDelayMs: $maxDelayMs)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $BatchSchedulerOptionsCopyWith<$Res>  {
+  factory $BatchSchedulerOptionsCopyWith(BatchSchedulerOptions value, $Res Function(BatchSchedulerOptions) _then) = _$BatchSchedulerOptionsCopyWithImpl;
+@useResult
+$Res call({
+ int? maxBatchSize, int? maxDelayMs
+});
+
+
+
+
+}
+/// @nodoc
+class _$BatchSchedulerOptionsCopyWithImpl<$Res>
+    implements $BatchSchedulerOptionsCopyWith<$Res> {
+  _$BatchSchedulerOptionsCopyWithImpl(this._self, this._then);
+
+  final BatchSchedulerOptions _self;
+  final $Res Function(BatchSchedulerOptions) _then;
+
+/// Create a copy of BatchSchedulerOptions
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? maxBatchSize = freezed,Object? maxDelayMs = freezed,}) {
+  return _then(_self.copyWith(
+maxBatchSize: freezed == maxBatchSize ? _self.maxBatchSize : maxBatchSize // ignore: cast_nullable_to_non_nullable
+as int?,maxDelayMs: freezed == maxDelayMs ? _self.maxDelayMs : maxDelayMs // ignore: cast_nullable_to_non_nullable
+as int?,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [BatchSchedulerOptions].
+extension BatchSchedulerOptionsPatterns on BatchSchedulerOptions {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _BatchSchedulerOptions value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _BatchSchedulerOptions() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _BatchSchedulerOptions value)  $default,){
+final _that = this;
+switch (_that) {
+case _BatchSchedulerOptions():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _BatchSchedulerOptions value)?  $default,){
+final _that = this;
+switch (_that) {
+case _BatchSchedulerOptions() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( int? maxBatchSize,  int? maxDelayMs)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _BatchSchedulerOptions() when $default != null:
+return $default(_that.maxBatchSize,_that.maxDelayMs);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( int? maxBatchSize,  int? maxDelayMs)  $default,) {final _that = this;
+switch (_that) {
+case _BatchSchedulerOptions():
+return $default(_that.maxBatchSize,_that.maxDelayMs);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( int? maxBatchSize,  int? maxDelayMs)?  $default,) {final _that = this;
+switch (_that) {
+case _BatchSchedulerOptions() when $default != null:
+return $default(_that.maxBatchSize,_that.maxDelayMs);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _BatchSchedulerOptions extends BatchSchedulerOptions {
+  const _BatchSchedulerOptions({this.maxBatchSize, this.maxDelayMs}): super._();
+  
+
+/// The most requests that are run together in one batch. Defaults to 16.
+@override final  int? maxBatchSize;
+/// How long, in milliseconds, to wait for more requests after the first request of a batch arrives. Defaults to 5.
+@override final  int? maxDelayMs;
+
+/// Create a copy of BatchSchedulerOptions
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$BatchSchedulerOptionsCopyWith<_BatchSchedulerOptions> get copyWith => __$BatchSchedulerOptionsCopyWithImpl<_BatchSchedulerOptions>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _BatchSchedulerOptions&&(identical(other.maxBatchSize, maxBatchSize) || other.maxBatchSize == maxBatchSize)&&(identical(other.maxDelayMs, maxDelayMs) || other.maxDelayMs == maxDelayMs));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,maxBatchSize,maxDelayMs);
+
+@override
+String toString() {
+  return 'BatchSchedulerOptions(maxBatchSize: $maxBatchSize, maxDelayMs: $maxDelayMs)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$BatchSchedulerOptionsCopyWith<$Res> implements $BatchSchedulerOptionsCopyWith<$Res> {
+  factory _$BatchSchedulerOptionsCopyWith(_BatchSchedulerOptions value, $Res Function(_BatchSchedulerOptions) _then) = __$BatchSchedulerOptionsCopyWithImpl;
+@override @useResult
+$Res call({
+ int? maxBatchSize, int? maxDelayMs
+});
+
+
+
+
+}
+/// @nodoc
+class __$BatchSchedulerOptionsCopyWithImpl<$Res>
+    implements _$BatchSchedulerOptionsCopyWith<$Res> {
+  __$BatchSchedulerOptionsCopyWithImpl(this._self, this._then);
+
+  final _BatchSchedulerOptions _self;
+  final $Res Function(_BatchSchedulerOptions) _then;
+
+/// Create a copy of BatchSchedulerOptions
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? maxBatchSize = freezed,Object? maxDelayMs = freezed,}) {
+  return _then(_BatchSchedulerOptions(
+maxBatchSize: freezed == maxBatchSize ? _self.maxBatchSize : maxBatchSize // ignore: cast_nullable_to_non_nullable
+as int?,maxDelayMs: freezed == maxDelayMs ? _self.maxDelayMs : maxDelayMs // ignore: cast_nullable_to_non_nullable
+as int?,
+  ));
+}
+
+
+}
+
+// dart format on
@@ -6,54 +6,250 @@
 import '../frb_generated.dart';
 import 'memory.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'tensor/cast.dart';
+import 'tensor/compare.dart';
+import 'tensor/index.dart';
+import 'tensor/ops.dart';
+import 'tensor/stats.dart';
 
-// These functions are ignored because they are not marked as `pub`: `create_tensor`, `from_value_ref`, `parse_shape`
+// These functions are ignored because they are not marked as `pub`: `allocate`, `budget_bytes`, `charge_budget`, `check_byte_len`, `concat_batch`, `create_tensor`, `create_tensor_from_vec`, `element_byte_len`, `ensure_mutable`, `from_borrowed_data`, `from_output`, `from_raw_data`, `from_value_ref`, `parse_shape`, `parse_shape_len`, `raw_byte_len`, `raw_data`, `reserve_budget`, `reserve_bytes`, `shape_for_byte_len`, `shared_tensor`, `split_batch`, `with_charge`, `with_reserved`
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `eq`, `fmt`, `fmt`
 
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<TensorDataView>>
+abstract class TensorDataView implements RustOpaqueInterface {
+  /// The number of bytes the elements take up.
+  int byteLen();
+
+  /// The data type of the elements.
+  TensorElementType dtype();
+
+  /// If the view has no elements.
+  bool isEmpty();
+
+  /// The number of elements in the view.
+  int len();
+
+  /// The address of the first element. May be `0` for tensors without any elements.
+  int ptr();
+
+  /// The shape of the viewed tensor.
+  List<int> shape();
+}
+
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<TensorImpl>>
 abstract class TensorImpl implements RustOpaqueInterface {
-  /// Creates a copy of this tensor and its data on the same device it resides on.
+  /// Adds two tensors of the same numeric type element-wise, broadcasting their shapes following NumPy's rules.
+  /// Integers wrap around on overflow.
+  TensorImpl add({required TensorImpl other});
+
+  /// Adds `value` to every element. Integer tensors require `value` to be a whole number in the range of their type.
+  TensorImpl addScalar({required double value});
+
+  /// If this tensor has the same type and shape as `expected` and every element is close to it, see
+  /// [`TensorImpl::diff`].
+  bool allclose({required TensorImpl expected, double? rtol, double? atol});
+
+  /// Returns the indices of the largest elements along `axis` as an `int64` tensor. The first occurrence wins ties,
+  /// and NaN is treated as larger than any number.
+  ///
+  /// With `keep_dims` the reduced axis is kept with size 1, otherwise it is removed.
+  TensorImpl argmax({required int axis, required bool keepDims});
+
+  /// Returns the indices of the smallest elements along `axis` as an `int64` tensor. The first occurrence wins ties,
+  /// and NaN is picked over any number, like in [`TensorImpl::argmax`].
+  TensorImpl argmin({required int axis, required bool keepDims});
+
+  /// Converts the tensor to another element type, creating a new tensor of the same shape.
+  ///
+  /// Any numeric type, `bool`, `float16`, `bfloat16` and `string` can be cast to any other. Floats are truncated
+  /// towards zero when cast to integers, anything non-zero (including NaN) becomes `true`, and `true`/`false` become
+  /// 1 and 0. Strings are parsed as integers or floats, also accepting `true`, `false`, `NaN` and `inf`, and numbers
+  /// are formatted in their shortest exact form.
+  ///
+  /// The 8-bit float types, `int4`/`uint4` and the complex types are not supported: casting from or to them fails.
+  ///
+  /// `mode` decides what happens to values the target type can't represent and defaults to
+  /// [`CastMode::Saturating`].
+  TensorImpl cast({required TensorElementType target, CastMode? mode});
+
+  /// Limits every element to the range `[min, max]`. Either bound may be left out, and NaN elements are kept as they
+  /// are. Integer tensors require the bounds to be whole numbers in the range of their type.
+  TensorImpl clamp({double? min, double? max});
+
+  /// Creates a copy of this tensor and its data on the same device it resides on. The copy is mutable, and counts
+  /// against the memory budget even if it doesn't fit.
   TensorImpl clone();
 
+  /// Joins tensors along an existing axis. All tensors must have the same element type and the same size in every
+  /// other dimension.
+  static TensorImpl concat({
+    required List<TensorImpl> tensors,
+    required int axis,
+  }) => RustLib.instance.api.crateApiTensorShapeTensorImplConcat(
+    tensors: tensors,
+    axis: axis,
+  );
+
+  /// Creates a zero-copy, read-only view of this tensor's data that keeps the data alive until the view is disposed.
+  /// Only tensors with fixed-width elements in CPU accessible memory can be viewed; use `extract_string` for strings.
+  TensorDataView dataView();
+
+  /// Describes the device and memory the Tensor's data resides in, like [`TensorImpl::memory_info`] but infallible.
+  MemoryInfo device();
+
+  /// Compares this tensor element by element with an `expected` tensor of the same shape and reports how they differ.
+  ///
+  /// Numeric elements are close if `|actual - expected| <= atol + rtol * |expected|`, as in NumPy's `allclose`;
+  /// `rtol` defaults to `1e-5` and `atol` to `1e-8`. NaN is never close to anything, and infinities are only close to
+  /// the same infinity. Numeric tensors of different types are compared by value but never reported as close.
+  /// Elements of any other type, including strings, must be exactly equal.
+  TensorDiff diff({required TensorImpl expected, double? rtol, double? atol});
+
+  /// Divides this tensor by `other` element-wise, broadcasting as in [`TensorImpl::add`]. Integer division rounds
+  /// towards zero and fails on division by zero.
+  TensorImpl div({required TensorImpl other});
+
+  /// Divides every element by `value`, which must fit the element type as in [`TensorImpl::add_scalar`].
+  TensorImpl divScalar({required double value});
+
   /// Get the data type of the Tensor
   TensorElementType dtype();
 
+  /// Copies the tensor's data into a new `Vec`, converting every element to `f32`.
+  Float32List extractBf16();
+
+  /// Copies the raw bit patterns of the tensor's elements into a new `Vec`.
+  Uint16List extractBf16Bits();
+
+  /// Copies the tensor's data into a new `Vec`.
+  List<bool> extractBool();
+
+  /// Copies the tensor's data into a new `Vec`, converting every element to `f32`.
+  Float32List extractF16();
+
+  /// Copies the raw bit patterns of the tensor's elements into a new `Vec`.
+  Uint16List extractF16Bits();
+
+  /// Copies the tensor's data into a new `Vec`.
+  Float32List extractF32();
+
+  /// Copies the tensor's data into a new `Vec`.
+  Float64List extractF64();
+
+  /// Copies the tensor's data into a new `Vec`.
+  Int16List extractI16();
+
+  /// Copies the tensor's data into a new `Vec`.
+  Int32List extractI32();
+
+  /// Copies the tensor's data into a new `Vec`.
+  List<int> extractI64();
+
+  /// Copies the tensor's data into a new `Vec`.
+  Int8List extractI8();
+
+  /// Copies the tensor's strings into a new `Vec`.
+  List<String> extractString();
+
+  /// Copies the tensor's data into a new `Vec`.
+  Uint16List extractU16();
+
+  /// Copies the tensor's data into a new `Vec`.
+  Uint32List extractU32();
+
+  /// Copies the tensor's data into a new `Vec`.
+  List<int> extractU64();
+
+  /// Copies the tensor's data into a new `Vec`.
+  Uint8List extractU8();
+
+  /// Releases a pointer returned by the matching `get_data_*_pointer`. The data belongs to the tensor, so there
+  /// is nothing to free.
+  static void freeBf16Pointer({required ArrayPointer arr}) =>
+      RustLib.instance.api.crateApiTensorTensorImplFreeBf16Pointer(arr: arr);
+
+  /// Releases a pointer returned by the matching `get_data_*_pointer`. The data belongs to the tensor, so there
+  /// is nothing to free.
   static void freeBoolPointer({required ArrayPointer arr}) =>
       RustLib.instance.api.crateApiTensorTensorImplFreeBoolPointer(arr: arr);
 
+  /// Releases a pointer returned by the matching `get_data_*_pointer`. The data belongs to the tensor, so there
+  /// is nothing to free.
+  static void freeF16Pointer({required ArrayPointer arr}) =>
+      RustLib.instance.api.crateApiTensorTensorImplFreeF16Pointer(arr: arr);
+
+  /// Releases a pointer returned by the matching `get_data_*_pointer`. The data belongs to the tensor, so there
+  /// is nothing to free.
   static void freeF32Pointer({required ArrayPointer arr}) =>
       RustLib.instance.api.crateApiTensorTensorImplFreeF32Pointer(arr: arr);
 
+  /// Releases a pointer returned by the matching `get_data_*_pointer`. The data belongs to the tensor, so there
+  /// is nothing to free.
   static void freeF64Pointer({required ArrayPointer arr}) =>
       RustLib.instance.api.crateApiTensorTensorImplFreeF64Pointer(arr: arr);
 
+  /// Releases a pointer returned by the matching `get_data_*_pointer`. The data belongs to the tensor, so there
+  /// is nothing to free.
   static void freeI16Pointer({required ArrayPointer arr}) =>
       RustLib.instance.api.crateApiTensorTensorImplFreeI16Pointer(arr: arr);
 
+  /// Releases a pointer returned by the matching `get_data_*_pointer`. The data belongs to the tensor, so there
+  /// is nothing to free.
   static void freeI32Pointer({required ArrayPointer arr}) =>
       RustLib.instance.api.crateApiTensorTensorImplFreeI32Pointer(arr: arr);
 
+  /// Releases a pointer returned by the matching `get_data_*_pointer`. The data belongs to the tensor, so there
+  /// is nothing to free.
   static void freeI64Pointer({required ArrayPointer arr}) =>
       RustLib.instance.api.crateApiTensorTensorImplFreeI64Pointer(arr: arr);
 
+  /// Releases a pointer returned by the matching `get_data_*_pointer`. The data belongs to the tensor, so there
+  /// is nothing to free.
   static void freeI8Pointer({required ArrayPointer arr}) =>
       RustLib.instance.api.crateApiTensorTensorImplFreeI8Pointer(arr: arr);
 
+  /// Frees the strings and the array returned by [`TensorImpl::get_data_string_pointer`].
   static void freeStringPointer({required ArrayPointer arr}) =>
       RustLib.instance.api.crateApiTensorTensorImplFreeStringPointer(arr: arr);
 
+  /// Releases a pointer returned by the matching `get_data_*_pointer`. The data belongs to the tensor, so there
+  /// is nothing to free.
   static void freeU16Pointer({required ArrayPointer arr}) =>
       RustLib.instance.api.crateApiTensorTensorImplFreeU16Pointer(arr: arr);
 
+  /// Releases a pointer returned by the matching `get_data_*_pointer`. The data belongs to the tensor, so there
+  /// is nothing to free.
   static void freeU32Pointer({required ArrayPointer arr}) =>
       RustLib.instance.api.crateApiTensorTensorImplFreeU32Pointer(arr: arr);
 
+  /// Releases a pointer returned by the matching `get_data_*_pointer`. The data belongs to the tensor, so there
+  /// is nothing to free.
   static void freeU64Pointer({required ArrayPointer arr}) =>
       RustLib.instance.api.crateApiTensorTensorImplFreeU64Pointer(arr: arr);
 
+  /// Releases a pointer returned by the matching `get_data_*_pointer`. The data belongs to the tensor, so there
+  /// is nothing to free.
   static void freeU8Pointer({required ArrayPointer arr}) =>
       RustLib.instance.api.crateApiTensorTensorImplFreeU8Pointer(arr: arr);
 
+  /// Creates a tensor by converting every `f32` in `data` to the nearest representable value.
+  static TensorImpl fromArrayBf16({
+    List<int>? shape,
+    required List<double> data,
+  }) => RustLib.instance.api.crateApiTensorTensorImplFromArrayBf16(
+    shape: shape,
+    data: data,
+  );
+
+  /// Creates a tensor from the raw bit patterns of its elements.
+  static TensorImpl fromArrayBf16Bits({
+    List<int>? shape,
+    required List<int> data,
+  }) => RustLib.instance.api.crateApiTensorTensorImplFromArrayBf16Bits(
+    shape: shape,
+    data: data,
+  );
+
   static TensorImpl fromArrayBool({
     List<int>? shape,
     required List<bool> data,
@@ -62,6 +258,24 @@ abstract class TensorImpl implements RustOpaqueInterface {
     data: data,
   );
 
+  /// Creates a tensor by converting every `f32` in `data` to the nearest representable value.
+  static TensorImpl fromArrayF16({
+    List<int>? shape,
+    required List<double> data,
+  }) => RustLib.instance.api.crateApiTensorTensorImplFromArrayF16(
+    shape: shape,
+    data: data,
+  );
+
+  /// Creates a tensor from the raw bit patterns of its elements.
+  static TensorImpl fromArrayF16Bits({
+    List<int>? shape,
+    required List<int> data,
+  }) => RustLib.instance.api.crateApiTensorTensorImplFromArrayF16Bits(
+    shape: shape,
+    data: data,
+  );
+
   static TensorImpl fromArrayF32({
     List<int>? shape,
     required List<double> data,
@@ -134,37 +348,335 @@ abstract class TensorImpl implements RustOpaqueInterface {
         data: data,
       );
 
+  /// Creates a tensor of any fixed-width element type from the raw, native-endian bytes of its elements.
+  ///
+  /// This covers every type in [`TensorElementType`] except [`TensorElementType::String`], including the 8-bit float
+  /// types. [`TensorElementType::Complex64`] and [`TensorElementType::Complex128`] elements are stored as a real part
+  /// followed by an imaginary part, and [`TensorElementType::Int4`]/[`TensorElementType::Uint4`] elements are packed
+  /// two to a byte with the first element in the low nibble, as in ONNX.
+  ///
+  /// As with the `from_array_*` functions, one dimension of `shape` may be `-1` to have it inferred from the length of
+  /// `bytes`, and leaving `shape` out creates a 1-D tensor.
+  static TensorImpl fromBytes({
+    required TensorElementType dtype,
+    List<int>? shape,
+    required List<int> bytes,
+  }) => RustLib.instance.api.crateApiTensorTensorImplFromBytes(
+    dtype: dtype,
+    shape: shape,
+    bytes: bytes,
+  );
+
+  /// Wraps `byte_len` bytes of memory at `ptr`, e.g. a buffer allocated from Dart with `malloc`, as a CPU tensor
+  /// without copying it.
+  ///
+  /// The data must be laid out as described in [`TensorImpl::from_bytes`] and aligned to the size of the element
+  /// type. `release` is the address of an `extern "C" fn(*mut c_void)`, such as `free` or the `nativeFree` of Dart's
+  /// `malloc`, which is called with `ptr` exactly once, when the tensor and every view of it are gone; leave it out if
+  /// the memory outlives the tensor anyway.
+  ///
+  /// Ownership passes to the tensor as soon as this is called, so `release` is also called if an error is returned.
+  /// With `immutable` set, the tensor is marked as not mutable, see [`TensorImpl::is_mutable`].
+  ///
+  /// The caller is responsible for the pointers, which can't be checked:
+  /// - `ptr` must point to `byte_len` bytes that are valid for reads, and for writes unless `immutable` is set, until
+  ///   `release` is called. Nothing else may write to them or free them meanwhile, so Dart must not free the buffer
+  ///   itself or let a finalizer free it.
+  /// - `release`, if given, must be the address of a function with that signature that may be called from any thread,
+  ///   e.g. a `Pointer.fromFunction` or `NativeCallable.listener` address, or a native function's address.
+  ///
+  /// Rust code should use [`TensorImpl::from_external_data_unchecked`], which states these requirements in its
+  /// signature.
+  static TensorImpl fromExternalData({
+    required TensorElementType dtype,
+    List<int>? shape,
+    required int ptr,
+    required int byteLen,
+    int? release,
+    required bool immutable,
+  }) => RustLib.instance.api.crateApiTensorExternalTensorImplFromExternalData(
+    dtype: dtype,
+    shape: shape,
+    ptr: ptr,
+    byteLen: byteLen,
+    release: release,
+    immutable: immutable,
+  );
+
+  /// Loads a tensor from the contents of a NumPy `.npy` file.
+  ///
+  /// All numeric NumPy dtypes, `bool`, `complex64`/`complex128` and fixed-length `str`/`bytes` arrays are supported,
+  /// in either byte order. Fortran-ordered arrays are converted to row-major order.
+  static TensorImpl fromNpy({required List<int> bytes}) =>
+      RustLib.instance.api.crateApiTensorNpyTensorImplFromNpy(bytes: bytes);
+
+  /// Decodes a serialized ONNX `TensorProto`, such as the `input_0.pb` and `output_0.pb` files of the ONNX test data
+  /// layout.
+  ///
+  /// Data stored in `raw_data`, in the typed fields (`float_data`, `int32_data`, ...) or in `string_data` is
+  /// supported, as is external data, whose location is resolved relative to `base_dir`.
+  static TensorImpl fromTensorProto({
+    required List<int> bytes,
+    String? baseDir,
+  }) => RustLib.instance.api.crateApiTensorProtoTensorImplFromTensorProto(
+    bytes: bytes,
+    baseDir: baseDir,
+  );
+
+  /// Reads the element at `index`, which has one entry per dimension. Negative entries count from the end of their
+  /// dimension.
+  TensorElement get({required List<int> index});
+
+  /// Returns the address and length of the tensor's data, which Dart can access as a `Uint16List`. The pointer is
+  /// only valid while this tensor is alive; prefer [`TensorImpl::data_view`], which keeps the data alive on its
+  /// own. Fails if the tensor isn't mutable, see [`TensorImpl::make_mutable`].
+  ArrayPointer getDataBf16Pointer();
+
+  /// Returns the address and length of the tensor's data, which may be written to. The pointer is only valid
+  /// while this tensor is alive; prefer [`TensorImpl::data_view`], which keeps the data alive on its own. Fails if
+  /// the tensor isn't mutable, see [`TensorImpl::make_mutable`].
   ArrayPointer getDataBoolPointer();
 
+  /// Returns the address and length of the tensor's data, which Dart can access as a `Uint16List`. The pointer is
+  /// only valid while this tensor is alive; prefer [`TensorImpl::data_view`], which keeps the data alive on its
+  /// own. Fails if the tensor isn't mutable, see [`TensorImpl::make_mutable`].
+  ArrayPointer getDataF16Pointer();
+
+  /// Returns the address and length of the tensor's data, which may be written to. The pointer is only valid
+  /// while this tensor is alive; prefer [`TensorImpl::data_view`], which keeps the data alive on its own. Fails if
+  /// the tensor isn't mutable, see [`TensorImpl::make_mutable`].
   ArrayPointer getDataF32Pointer();
 
+  /// Returns the address and length of the tensor's data, which may be written to. The pointer is only valid
+  /// while this tensor is alive; prefer [`TensorImpl::data_view`], which keeps the data alive on its own. Fails if
+  /// the tensor isn't mutable, see [`TensorImpl::make_mutable`].
   ArrayPointer getDataF64Pointer();
 
+  /// Returns the address and length of the tensor's data, which may be written to. The pointer is only valid
+  /// while this tensor is alive; prefer [`TensorImpl::data_view`], which keeps the data alive on its own. Fails if
+  /// the tensor isn't mutable, see [`TensorImpl::make_mutable`].
   ArrayPointer getDataI16Pointer();
 
+  /// Returns the address and length of the tensor's data, which may be written to. The pointer is only valid
+  /// while this tensor is alive; prefer [`TensorImpl::data_view`], which keeps the data alive on its own. Fails if
+  /// the tensor isn't mutable, see [`TensorImpl::make_mutable`].
   ArrayPointer getDataI32Pointer();
 
+  /// Returns the address and length of the tensor's data, which may be written to. The pointer is only valid
+  /// while this tensor is alive; prefer [`TensorImpl::data_view`], which keeps the data alive on its own. Fails if
+  /// the tensor isn't mutable, see [`TensorImpl::make_mutable`].
   ArrayPointer getDataI64Pointer();
 
+  /// Returns the address and length of the tensor's data, which may be written to. The pointer is only valid
+  /// while this tensor is alive; prefer [`TensorImpl::data_view`], which keeps the data alive on its own. Fails if
+  /// the tensor isn't mutable, see [`TensorImpl::make_mutable`].
   ArrayPointer getDataI8Pointer();
 
+  /// Returns an array of pointers to nul-terminated copies of the tensor's strings. The copies are owned by the caller
+  /// and must be released with [`TensorImpl::free_string_pointer`]. Since they are copies, this works for immutable
+  /// tensors as well.
   ArrayPointer getDataStringPointer();
 
+  /// Returns the address and length of the tensor's data, which may be written to. The pointer is only valid
+  /// while this tensor is alive; prefer [`TensorImpl::data_view`], which keeps the data alive on its own. Fails if
+  /// the tensor isn't mutable, see [`TensorImpl::make_mutable`].
   ArrayPointer getDataU16Pointer();
 
+  /// Returns the address and length of the tensor's data, which may be written to. The pointer is only valid
+  /// while this tensor is alive; prefer [`TensorImpl::data_view`], which keeps the data alive on its own. Fails if
+  /// the tensor isn't mutable, see [`TensorImpl::make_mutable`].
   ArrayPointer getDataU32Pointer();
 
+  /// Returns the address and length of the tensor's data, which may be written to. The pointer is only valid
+  /// while this tensor is alive; prefer [`TensorImpl::data_view`], which keeps the data alive on its own. Fails if
+  /// the tensor isn't mutable, see [`TensorImpl::make_mutable`].
   ArrayPointer getDataU64Pointer();
 
+  /// Returns the address and length of the tensor's data, which may be written to. The pointer is only valid
+  /// while this tensor is alive; prefer [`TensorImpl::data_view`], which keeps the data alive on its own. Fails if
+  /// the tensor isn't mutable, see [`TensorImpl::make_mutable`].
   ArrayPointer getDataU8Pointer();
 
   /// If this Tensor's underlying data is mutable
   bool isMutable();
 
+  /// Loads a tensor from a `.npy` file, see [`TensorImpl::from_npy`].
+  static Future<TensorImpl> loadNpy({required String path}) =>
+      RustLib.instance.api.crateApiTensorNpyTensorImplLoadNpy(path: path);
+
+  /// Loads a tensor from a `TensorProto` file, resolving external data relative to the file's directory.
+  static Future<TensorImpl> loadTensorProto({required String path}) =>
+      RustLib.instance.api.crateApiTensorProtoTensorImplLoadTensorProto(
+        path: path,
+      );
+
+  /// Computes the logarithm of the softmax of the tensor along `axis`, more accurately than taking the logarithm of
+  /// [`TensorImpl::softmax`].
+  TensorImpl logSoftmax({required int axis});
+
+  /// Makes the tensor mutable, copying its data first if it is immutable, so that writes never reach data that is
+  /// shared with an immutable tensor. Returns whether the data was copied.
+  bool makeMutable();
+
+  /// Finds the largest elements along `axes`, or of the whole tensor if `axes` is left out. NaN is propagated.
+  TensorImpl max({List<int>? axes, required bool keepDims});
+
+  /// Averages the elements along `axes`, or over the whole tensor if `axes` is left out. Floating point tensors keep
+  /// their type; other numeric tensors produce a `float32` tensor.
+  TensorImpl mean({List<int>? axes, required bool keepDims});
+
+  /// Describes the device and memory the Tensor's data resides in.
   MemoryInfo memoryInfo();
 
+  /// Finds the smallest elements along `axes`, or of the whole tensor if `axes` is left out. NaN is propagated.
+  TensorImpl min({List<int>? axes, required bool keepDims});
+
+  /// Multiplies two tensors element-wise, broadcasting as in [`TensorImpl::add`].
+  TensorImpl mul({required TensorImpl other});
+
+  /// Multiplies every element by `value`, which must fit the element type as in [`TensorImpl::add_scalar`].
+  TensorImpl mulScalar({required double value});
+
+  /// Creates a tensor of the given type and shape with its data allocated by `allocator`, e.g. on the device of an
+  /// execution provider. The data of tensors in CPU accessible memory is zeroed; strings are empty.
+  static TensorImpl newWithAllocator({
+    required Allocator allocator,
+    required TensorElementType dtype,
+    required List<int> shape,
+  }) => RustLib.instance.api.crateApiTensorTensorImplNewWithAllocator(
+    allocator: allocator,
+    dtype: dtype,
+    shape: shape,
+  );
+
+  /// The total number of elements in the Tensor
+  int numElements();
+
+  /// Returns a copy of this tensor with its dimensions reordered, so that dimension `i` of the result is dimension
+  /// `axes[i]` of this tensor. For example `[0, 3, 1, 2]` turns an NHWC tensor into an NCHW one.
+  TensorImpl permute({required List<int> axes});
+
+  /// Raises this tensor to the power of `other` element-wise, broadcasting as in [`TensorImpl::add`]. Integer powers
+  /// are computed exactly and fail on overflow or a negative exponent.
+  TensorImpl pow({required TensorImpl other});
+
+  /// Raises every element to the power of `value`. Integer tensors require `value` to be a whole number in the range
+  /// of their type, and fail like [`TensorImpl::pow`].
+  TensorImpl powScalar({required double value});
+
+  /// The number of dimensions of the Tensor
+  int rank();
+
+  /// Returns a view of this tensor with a new shape and the same number of elements. One dimension may be `-1` to
+  /// have it inferred from the others.
+  ///
+  /// The view shares this tensor's data, so writes through either one are visible in both.
+  TensorImpl reshape({required List<int> shape});
+
+  /// Saves the tensor to a `.npy` file, see [`TensorImpl::to_npy`].
+  Future<void> saveNpy({required String path});
+
+  /// Saves the tensor to a `TensorProto` file, see [`TensorImpl::to_tensor_proto`].
+  Future<void> saveTensorProto({required String path, String? name});
+
+  /// Writes the element at `index`, converting `value` to the tensor's element type like [`TensorImpl::cast`] does
+  /// with [`CastMode::Checked`]. Fails if the tensor isn't mutable.
+  void set({required List<int> index, required TensorElement value});
+
   /// Get the shape of the Tensor
   List<int> shape();
+
+  /// Computes the logistic sigmoid `1 / (1 + e^-x)` of every element.
+  TensorImpl sigmoid();
+
+  /// The size of the Tensor's data in bytes. For string tensors this is the combined length of the strings in UTF-8,
+  /// which leaves out the overhead of storing each string.
+  int sizeInBytes();
+
+  /// Returns a copy of part of this tensor, following the semantics of the ONNX `Slice` operator.
+  ///
+  /// For each of `axes` (all axes in order if left out) the elements from `starts[i]` up to but excluding `ends[i]`
+  /// are taken, every `steps[i]` elements (1 if left out). Negative indices count from the end of the dimension,
+  /// out of range indices are clamped, and a negative step walks the dimension backwards.
+  TensorImpl slice({
+    required List<int> starts,
+    required List<int> ends,
+    List<int>? axes,
+    List<int>? steps,
+  });
+
+  /// Returns a view of part of this tensor, selected like in [`TensorImpl::slice`], without copying any data.
+  ///
+  /// The view shares this tensor's data, including its strings, so writes through either one are visible in both. This
+  /// makes it cheap to read a few elements out of a large output, e.g. the first rows of a list of detections.
+  TensorSlice sliceView({
+    required List<int> starts,
+    required List<int> ends,
+    List<int>? axes,
+    List<int>? steps,
+  });
+
+  /// Computes the softmax of the tensor along `axis` (`-1` for the last one), so that every slice along it sums to 1.
+  ///
+  /// Floating point tensors keep their type; other numeric tensors produce a `float32` tensor.
+  TensorImpl softmax({required int axis});
+
+  /// Returns a view of this tensor without the given dimensions of size 1, or without every dimension of size 1 if
+  /// `axes` is left out.
+  TensorImpl squeeze({List<int>? axes});
+
+  /// Joins tensors of the same type and shape along a new axis, inserted at `axis` in the result.
+  static TensorImpl stack({
+    required List<TensorImpl> tensors,
+    required int axis,
+  }) => RustLib.instance.api.crateApiTensorShapeTensorImplStack(
+    tensors: tensors,
+    axis: axis,
+  );
+
+  /// Computes summary statistics over the elements of a numeric tensor.
+  ///
+  /// NaN and infinite elements are counted but left out of `min`, `max`, `mean` and `std`, so a handful of bad values
+  /// doesn't hide the distribution of the rest.
+  TensorStats stats();
+
+  /// The number of elements between neighbouring elements of each dimension, in the row-major order tensors are
+  /// always stored in.
+  List<int> strides();
+
+  /// Subtracts `other` from this tensor element-wise, broadcasting as in [`TensorImpl::add`].
+  TensorImpl sub({required TensorImpl other});
+
+  /// Subtracts `value` from every element, which must fit the element type as in [`TensorImpl::add_scalar`].
+  TensorImpl subScalar({required double value});
+
+  /// Sums the elements along `axes`, or over the whole tensor if `axes` is left out. The result has the same type as
+  /// the tensor, and integers wrap around on overflow.
+  ///
+  /// With `keep_dims` the reduced axes are kept with size 1, otherwise they are removed.
+  TensorImpl sum({List<int>? axes, required bool keepDims});
+
+  /// Copies the raw, native-endian bytes of this tensor's elements, laid out as described in
+  /// [`TensorImpl::from_bytes`].
+  Uint8List toBytes();
+
+  /// Serializes the tensor in NumPy's `.npy` format. String tensors are written as fixed-length `str` arrays.
+  Uint8List toNpy();
+
+  /// Serializes the tensor as an ONNX `TensorProto` with the given name. Fixed-width data is written to `raw_data` and
+  /// strings to `string_data`.
+  Uint8List toTensorProto({String? name});
+
+  /// Picks the `k` largest (or with `largest` set to `false`, smallest) elements along `axis` (`-1` if left out),
+  /// sorted from first to last picked. Equal elements are picked in order of their index, as in ONNX's `TopK`.
+  TopK topK({required int k, int? axis, bool? largest});
+
+  /// Returns a copy of this tensor with the order of its dimensions reversed.
+  TensorImpl transpose();
+
+  /// Returns a view of this tensor with dimensions of size 1 inserted, so that they are at `axes` in the result.
+  /// Negative axes count from the end of the result, as in ONNX.
+  TensorImpl unsqueeze({required List<int> axes});
 }
 
 class ArrayPointer {
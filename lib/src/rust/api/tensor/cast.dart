@@ -0,0 +1,20 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `out_of_range`, `parse`, `to_scalars`, `to_strings`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `eq`, `fmt`
+// These functions are ignored (category: IgnoreBecauseNotAllowedOwner): `default`
+
+/// How [`TensorImpl::cast`] handles values that the target type can't represent.
+enum CastMode {
+  /// Out of range values are clamped to the closest value of the target type, and NaN becomes 0 for integer types.
+  saturating,
+
+  /// The cast fails on the first out of range value, or on NaN when casting to an integer type.
+  checked,
+}
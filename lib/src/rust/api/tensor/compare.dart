@@ -0,0 +1,47 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'compare.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `is_numeric`, `unravel`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+/// How two tensors differ, see [`TensorImpl::diff`].
+@freezed
+sealed class TensorDiff with _$TensorDiff {
+  const TensorDiff._();
+  const factory TensorDiff({
+    /// If every element is within tolerance and the shapes and element types match.
+    required bool isClose,
+    required bool dtypeMismatch,
+    required bool shapeMismatch,
+
+    /// The number of elements that aren't within tolerance.
+    required int mismatchCount,
+
+    /// The largest absolute difference between two numeric elements.
+    required double maxAbsError,
+
+    /// The largest difference between two numeric elements relative to the expected element.
+    required double maxRelError,
+
+    /// The index of the element with the largest absolute difference, or of the first mismatching element for
+    /// non-numeric tensors. `None` if there is no mismatch or the shapes differ.
+    List<int>? worstIndex,
+
+    /// The element of this tensor at `worst_index`, formatted as text.
+    String? actual,
+
+    /// The element of the expected tensor at `worst_index`, formatted as text.
+    String? expected,
+  }) = _TensorDiff;
+
+  /// Describes the difference in a single line, for test failure messages and logs.
+  String summary() =>
+      RustLib.instance.api.crateApiTensorCompareTensorDiffSummary(that: this);
+}
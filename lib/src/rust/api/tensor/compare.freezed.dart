@@ -0,0 +1,313 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'compare.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$TensorDiff {
+
+/// If every element is within tolerance and the shapes and element types match.
+ bool get isClose; bool get dtypeMismatch; bool get shapeMismatch;/// The number of elements that aren't within tolerance.
+ int get mismatchCount;/// The largest absolute difference between two numeric elements.
+ double get maxAbsError;/// The largest difference between two numeric elements relative to the expected element.
+ double get maxRelError;/// The index of the element with the largest absolute difference, or of the first mismatching element for
+/// non-numeric tensors. `None` if there is no mismatch or the shapes differ.
+ List<int>? get worstIndex;/// The element of this tensor at `worst_index`, formatted as text.
+ String? get actual;/// The element of the expected tensor at `worst_index`, formatted as text.
+ String? get expected;
+/// Create a copy of TensorDiff
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$TensorDiffCopyWith<TensorDiff> get copyWith => _$TensorDiffCopyWithImpl<TensorDiff>(this as TensorDiff, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is TensorDiff&&(identical(other.isClose, isClose) || other.isClose == isClose)&&(identical(other.dtypeMismatch, dtypeMismatch) || other.dtypeMismatch == dtypeMismatch)&&(identical(other.shapeMismatch, shapeMismatch) || other.shapeMismatch == shapeMismatch)&&(identical(other.mismatchCount, mismatchCount) || other.mismatchCount == mismatchCount)&&(identical(other.maxAbsError, maxAbsError) || other.maxAbsError == maxAbsError)&&(identical(other.maxRelError, maxRelError) || other.maxRelError == maxRelError)&&const DeepCollectionEquality().equals(other.worstIndex, worstIndex)&&(identical(other.actual, actual) || other.actual == actual)&&(identical(other.expected, expected) || other.expected == expected));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,isClose,dtypeMismatch,shapeMismatch,mismatchCount,maxAbsError,maxRelError,const DeepCollectionEquality().hash(worstIndex),actual,expected);
+
+@override
+String toString() {
+  return 'TensorDiff(isClose: $isClose, dtypeMismatch: $dtypeMismatch, shapeMismatch: $shapeMismatch, mismatchCount: $mismatchCount, maxAbsError: $maxAbsError, maxRelError: $maxRelError, worstIndex: $worstIndex, actual: $actual, expected: $expected)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $TensorDiffCopyWith<$Res>  {
+  factory $TensorDiffCopyWith(TensorDiff value, $Res Function(TensorDiff) _then) = _$TensorDiffCopyWithImpl;
+@useResult
+$Res call({
+ bool isClose, bool dtypeMismatch, bool shapeMismatch, int mismatchCount, double maxAbsError, double maxRelError, List<int>? worstIndex, String? actual, String? expected
+});
+
+
+
+
+}
+/// @nodoc
+class _$TensorDiffCopyWithImpl<$Res>
+    implements $TensorDiffCopyWith<$Res> {
+  _$TensorDiffCopyWithImpl(this._self, this._then);
+
+  final TensorDiff _self;
+  final $Res Function(TensorDiff) _then;
+
+/// Create a copy of TensorDiff
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? isClose = null,Object? dtypeMismatch = null,Object? shapeMismatch = null,Object? mismatchCount = null,Object? maxAbsError = null,Object? maxRelError = null,Object? worstIndex = freezed,Object? actual = freezed,Object? expected = freezed,}) {
+  return _then(_self.copyWith(
+isClose: null == isClose ? _self.isClose : isClose // ignore: cast_nullable_to_non_nullable
+as bool,dtypeMismatch: null == dtypeMismatch ? _self.dtypeMismatch : dtypeMismatch // ignore: cast_nullable_to_non_nullable
+as bool,shapeMismatch: null == shapeMismatch ? _self.shapeMismatch : shapeMismatch // ignore: cast_nullable_to_non_nullable
+as bool,mismatchCount: null == mismatchCount ? _self.mismatchCount : mismatchCount // ignore: cast_nullable_to_non_nullable
+as int,maxAbsError: null == maxAbsError ? _self.maxAbsError : maxAbsError // ignore: cast_nullable_to_non_nullable
+as double,maxRelError: null == maxRelError ? _self.maxRelError : maxRelError // ignore: cast_nullable_to_non_nullable
+as double,worstIndex: freezed == worstIndex ? _self.worstIndex : worstIndex // ignore: cast_nullable_to_non_nullable
+as List<int>?,actual: freezed == actual ? _self.actual : actual // ignore: cast_nullable_to_non_nullable
+as String?,expected: freezed == expected ? _self.expected : expected // ignore: cast_nullable_to_non_nullable
+as String?,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [TensorDiff].
+extension TensorDiffPatterns on TensorDiff {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _TensorDiff value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _TensorDiff() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _TensorDiff value)  $default,){
+final _that = this;
+switch (_that) {
+case _TensorDiff():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _TensorDiff value)?  $default,){
+final _that = this;
+switch (_that) {
+case _TensorDiff() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( bool isClose,  bool dtypeMismatch,  bool shapeMismatch,  int mismatchCount,  double maxAbsError,  double maxRelError,  List<int>? worstIndex,  String? actual,  String? expected)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _TensorDiff() when $default != null:
+return $default(_that.isClose,_that.dtypeMismatch,_that.shapeMismatch,_that.mismatchCount,_that.maxAbsError,_that.maxRelError,_that.worstIndex,_that.actual,_that.expected);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( bool isClose,  bool dtypeMismatch,  bool shapeMismatch,  int mismatchCount,  double maxAbsError,  double maxRelError,  List<int>? worstIndex,  String? actual,  String? expected)  $default,) {final _that = this;
+switch (_that) {
+case _TensorDiff():
+return $default(_that.isClose,_that.dtypeMismatch,_that.shapeMismatch,_that.mismatchCount,_that.maxAbsError,_that.maxRelError,_that.worstIndex,_that.actual,_that.expected);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( bool isClose,  bool dtypeMismatch,  bool shapeMismatch,  int mismatchCount,  double maxAbsError,  double maxRelError,  List<int>? worstIndex,  String? actual,  String? expected)?  $default,) {final _that = this;
+switch (_that) {
+case _TensorDiff() when $default != null:
+return $default(_that.isClose,_that.dtypeMismatch,_that.shapeMismatch,_that.mismatchCount,_that.maxAbsError,_that.maxRelError,_that.worstIndex,_that.actual,_that.expected);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _TensorDiff extends TensorDiff {
+  const _TensorDiff({required this.isClose, required this.dtypeMismatch, required this.shapeMismatch, required this.mismatchCount, required this.maxAbsError, required this.maxRelError, final  List<int>? worstIndex, this.actual, this.expected}): _worstIndex = worstIndex,super._();
+  
+
+/// If every element is within tolerance and the shapes and element types match.
+@override final  bool isClose;
+@override final  bool dtypeMismatch;
+@override final  bool shapeMismatch;
+/// The number of elements that aren't within tolerance.
+@override final  int mismatchCount;
+/// The largest absolute difference between two numeric elements.
+@override final  double maxAbsError;
+/// The largest difference between two numeric elements relative to the expected element.
+@override final  double maxRelError;
+/// The index of the element with the largest absolute difference, or of the first mismatching element for
+/// non-numeric tensors. `None` if there is no mismatch or the shapes differ.
+ final  List<int>? _worstIndex;
+@override List<int>? get worstIndex {
+  final value = _worstIndex;
+  if (value == null) return null;
+  if (_worstIndex is EqualUnmodifiableListView) return _worstIndex;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(value);
+}
+
+/// The element of this tensor at `worst_index`, formatted as text.
+@override final  String? actual;
+/// The element of the expected tensor at `worst_index`, formatted as text.
+@override final  String? expected;
+
+/// Create a copy of TensorDiff
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$TensorDiffCopyWith<_TensorDiff> get copyWith => __$TensorDiffCopyWithImpl<_TensorDiff>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _TensorDiff&&(identical(other.isClose, isClose) || other.isClose == isClose)&&(identical(other.dtypeMismatch, dtypeMismatch) || other.dtypeMismatch == dtypeMismatch)&&(identical(other.shapeMismatch, shapeMismatch) || other.shapeMismatch == shapeMismatch)&&(identical(other.mismatchCount, mismatchCount) || other.mismatchCount == mismatchCount)&&(identical(other.maxAbsError, maxAbsError) || other.maxAbsError == maxAbsError)&&(identical(other.maxRelError, maxRelError) || other.maxRelError == maxRelError)&&const DeepCollectionEquality().equals(other._worstIndex, _worstIndex)&&(identical(other.actual, actual) || other.actual == actual)&&(identical(other.expected, expected) || other.expected == expected));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,isClose,dtypeMismatch,shapeMismatch,mismatchCount,maxAbsError,maxRelError,const DeepCollectionEquality().hash(_worstIndex),actual,expected);
+
+@override
+String toString() {
+  return 'TensorDiff(isClose: $isClose, dtypeMismatch: $dtypeMismatch, shapeMismatch: $shapeMismatch, mismatchCount: $mismatchCount, maxAbsError: $maxAbsError, maxRelError: $maxRelError, worstIndex: $worstIndex, actual: $actual, expected: $expected)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$TensorDiffCopyWith<$Res> implements $TensorDiffCopyWith<$Res> {
+  factory _$TensorDiffCopyWith(_TensorDiff value, $Res Function(_TensorDiff) _then) = __$TensorDiffCopyWithImpl;
+@override @useResult
+$Res call({
+ bool isClose, bool dtypeMismatch, bool shapeMismatch, int mismatchCount, double maxAbsError, double maxRelError, List<int>? worstIndex, String? actual, String? expected
+});
+
+
+
+
+}
+/// @nodoc
+class __$TensorDiffCopyWithImpl<$Res>
+    implements _$TensorDiffCopyWith<$Res> {
+  __$TensorDiffCopyWithImpl(this._self, this._then);
+
+  final _TensorDiff _self;
+  final $Res Function(_TensorDiff) _then;
+
+/// Create a copy of TensorDiff
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? isClose = null,Object? dtypeMismatch = null,Object? shapeMismatch = null,Object? mismatchCount = null,Object? maxAbsError = null,Object? maxRelError = null,Object? worstIndex = freezed,Object? actual = freezed,Object? expected = freezed,}) {
+  return _then(_TensorDiff(
+isClose: null == isClose ? _self.isClose : isClose // ignore: cast_nullable_to_non_nullable
+as bool,dtypeMismatch: null == dtypeMismatch ? _self.dtypeMismatch : dtypeMismatch // ignore: cast_nullable_to_non_nullable
+as bool,shapeMismatch: null == shapeMismatch ? _self.shapeMismatch : shapeMismatch // ignore: cast_nullable_to_non_nullable
+as bool,mismatchCount: null == mismatchCount ? _self.mismatchCount : mismatchCount // ignore: cast_nullable_to_non_nullable
+as int,maxAbsError: null == maxAbsError ? _self.maxAbsError : maxAbsError // ignore: cast_nullable_to_non_nullable
+as double,maxRelError: null == maxRelError ? _self.maxRelError : maxRelError // ignore: cast_nullable_to_non_nullable
+as double,worstIndex: freezed == worstIndex ? _self._worstIndex : worstIndex // ignore: cast_nullable_to_non_nullable
+as List<int>?,actual: freezed == actual ? _self.actual : actual // ignore: cast_nullable_to_non_nullable
+as String?,expected: freezed == expected ? _self.expected : expected // ignore: cast_nullable_to_non_nullable
+as String?,
+  ));
+}
+
+
+}
+
+// dart format on
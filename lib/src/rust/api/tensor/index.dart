@@ -0,0 +1,55 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../tensor.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'index.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `element_at`, `flat_index`, `from_scalar`, `set_element_at`, `shape_and_strides`, `to_scalar`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`, `fmt`
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<TensorSlice>>
+abstract class TensorSlice implements RustOpaqueInterface {
+  /// The data type of the elements.
+  TensorElementType dtype();
+
+  /// Reads the element at `index` of the view, see [`TensorImpl::get`].
+  TensorElement get({required List<int> index});
+
+  /// If the viewed tensor is mutable, and so elements can be written through this view.
+  bool isMutable();
+
+  /// Writes the element at `index` of the view, and so of the viewed tensor, see [`TensorImpl::set`].
+  void set({required List<int> index, required TensorElement value});
+
+  /// The shape of the view.
+  List<int> shape();
+
+  /// The distance, in elements, between neighbouring elements of each dimension in the viewed tensor.
+  List<int> strides();
+
+  /// Copies the elements of the view into a new, contiguous tensor.
+  TensorImpl toTensor();
+}
+
+/// A single element of a tensor, see [`TensorImpl::get`].
+@freezed
+sealed class TensorElement with _$TensorElement {
+  const TensorElement._();
+
+  const factory TensorElement.bool(bool field0) = TensorElement_Bool;
+
+  /// An element of a signed integer type.
+  const factory TensorElement.int(int field0) = TensorElement_Int;
+
+  /// An element of an unsigned integer type.
+  const factory TensorElement.uint(int field0) = TensorElement_Uint;
+
+  /// An element of a floating point type, including `float16` and `bfloat16`.
+  const factory TensorElement.float(double field0) = TensorElement_Float;
+  const factory TensorElement.string(String field0) = TensorElement_String;
+}
@@ -0,0 +1,522 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'index.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$TensorElement {
+
+ Object get field0;
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is TensorElement&&const DeepCollectionEquality().equals(other.field0, field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(field0));
+
+@override
+String toString() {
+  return 'TensorElement(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+class $TensorElementCopyWith<$Res>  {
+$TensorElementCopyWith(TensorElement _, $Res Function(TensorElement) __);
+}
+
+
+/// Adds pattern-matching-related methods to [TensorElement].
+extension TensorElementPatterns on TensorElement {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( TensorElement_Bool value)?  bool,TResult Function( TensorElement_Int value)?  int,TResult Function( TensorElement_Uint value)?  uint,TResult Function( TensorElement_Float value)?  float,TResult Function( TensorElement_String value)?  string,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case TensorElement_Bool() when bool != null:
+return bool(_that);case TensorElement_Int() when int != null:
+return int(_that);case TensorElement_Uint() when uint != null:
+return uint(_that);case TensorElement_Float() when float != null:
+return float(_that);case TensorElement_String() when string != null:
+return string(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( TensorElement_Bool value)  bool,required TResult Function( TensorElement_Int value)  int,required TResult Function( TensorElement_Uint value)  uint,required TResult Function( TensorElement_Float value)  float,required TResult Function( TensorElement_String value)  string,}){
+final _that = this;
+switch (_that) {
+case TensorElement_Bool():
+return bool(_that);case TensorElement_Int():
+return int(_that);case TensorElement_Uint():
+return uint(_that);case TensorElement_Float():
+return float(_that);case TensorElement_String():
+return string(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( TensorElement_Bool value)?  bool,TResult? Function( TensorElement_Int value)?  int,TResult? Function( TensorElement_Uint value)?  uint,TResult? Function( TensorElement_Float value)?  float,TResult? Function( TensorElement_String value)?  string,}){
+final _that = this;
+switch (_that) {
+case TensorElement_Bool() when bool != null:
+return bool(_that);case TensorElement_Int() when int != null:
+return int(_that);case TensorElement_Uint() when uint != null:
+return uint(_that);case TensorElement_Float() when float != null:
+return float(_that);case TensorElement_String() when string != null:
+return string(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( bool field0)?  bool,TResult Function( int field0)?  int,TResult Function( int field0)?  uint,TResult Function( double field0)?  float,TResult Function( String field0)?  string,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case TensorElement_Bool() when bool != null:
+return bool(_that.field0);case TensorElement_Int() when int != null:
+return int(_that.field0);case TensorElement_Uint() when uint != null:
+return uint(_that.field0);case TensorElement_Float() when float != null:
+return float(_that.field0);case TensorElement_String() when string != null:
+return string(_that.field0);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( bool field0)  bool,required TResult Function( int field0)  int,required TResult Function( int field0)  uint,required TResult Function( double field0)  float,required TResult Function( String field0)  string,}) {final _that = this;
+switch (_that) {
+case TensorElement_Bool():
+return bool(_that.field0);case TensorElement_Int():
+return int(_that.field0);case TensorElement_Uint():
+return uint(_that.field0);case TensorElement_Float():
+return float(_that.field0);case TensorElement_String():
+return string(_that.field0);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( bool field0)?  bool,TResult? Function( int field0)?  int,TResult? Function( int field0)?  uint,TResult? Function( double field0)?  float,TResult? Function( String field0)?  string,}) {final _that = this;
+switch (_that) {
+case TensorElement_Bool() when bool != null:
+return bool(_that.field0);case TensorElement_Int() when int != null:
+return int(_that.field0);case TensorElement_Uint() when uint != null:
+return uint(_that.field0);case TensorElement_Float() when float != null:
+return float(_that.field0);case TensorElement_String() when string != null:
+return string(_that.field0);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class TensorElement_Bool extends TensorElement {
+  const TensorElement_Bool(this.field0): super._();
+  
+
+@override final  bool field0;
+
+/// Create a copy of TensorElement
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$TensorElement_BoolCopyWith<TensorElement_Bool> get copyWith => _$TensorElement_BoolCopyWithImpl<TensorElement_Bool>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is TensorElement_Bool&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'TensorElement.bool(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $TensorElement_BoolCopyWith<$Res> implements $TensorElementCopyWith<$Res> {
+  factory $TensorElement_BoolCopyWith(TensorElement_Bool value, $Res Function(TensorElement_Bool) _then) = _$TensorElement_BoolCopyWithImpl;
+@useResult
+$Res call({
+ bool field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$TensorElement_BoolCopyWithImpl<$Res>
+    implements $TensorElement_BoolCopyWith<$Res> {
+  _$TensorElement_BoolCopyWithImpl(this._self, this._then);
+
+  final TensorElement_Bool _self;
+  final $Res Function(TensorElement_Bool) _then;
+
+/// Create a copy of TensorElement
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(TensorElement_Bool(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class TensorElement_Int extends TensorElement {
+  const TensorElement_Int(this.field0): super._();
+  
+
+@override final  int field0;
+
+/// Create a copy of TensorElement
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$TensorElement_IntCopyWith<TensorElement_Int> get copyWith => _$TensorElement_IntCopyWithImpl<TensorElement_Int>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is TensorElement_Int&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'TensorElement.int(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $TensorElement_IntCopyWith<$Res> implements $TensorElementCopyWith<$Res> {
+  factory $TensorElement_IntCopyWith(TensorElement_Int value, $Res Function(TensorElement_Int) _then) = _$TensorElement_IntCopyWithImpl;
+@useResult
+$Res call({
+ int field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$TensorElement_IntCopyWithImpl<$Res>
+    implements $TensorElement_IntCopyWith<$Res> {
+  _$TensorElement_IntCopyWithImpl(this._self, this._then);
+
+  final TensorElement_Int _self;
+  final $Res Function(TensorElement_Int) _then;
+
+/// Create a copy of TensorElement
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(TensorElement_Int(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class TensorElement_Uint extends TensorElement {
+  const TensorElement_Uint(this.field0): super._();
+  
+
+@override final  int field0;
+
+/// Create a copy of TensorElement
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$TensorElement_UintCopyWith<TensorElement_Uint> get copyWith => _$TensorElement_UintCopyWithImpl<TensorElement_Uint>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is TensorElement_Uint&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'TensorElement.uint(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $TensorElement_UintCopyWith<$Res> implements $TensorElementCopyWith<$Res> {
+  factory $TensorElement_UintCopyWith(TensorElement_Uint value, $Res Function(TensorElement_Uint) _then) = _$TensorElement_UintCopyWithImpl;
+@useResult
+$Res call({
+ int field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$TensorElement_UintCopyWithImpl<$Res>
+    implements $TensorElement_UintCopyWith<$Res> {
+  _$TensorElement_UintCopyWithImpl(this._self, this._then);
+
+  final TensorElement_Uint _self;
+  final $Res Function(TensorElement_Uint) _then;
+
+/// Create a copy of TensorElement
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(TensorElement_Uint(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class TensorElement_Float extends TensorElement {
+  const TensorElement_Float(this.field0): super._();
+  
+
+@override final  double field0;
+
+/// Create a copy of TensorElement
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$TensorElement_FloatCopyWith<TensorElement_Float> get copyWith => _$TensorElement_FloatCopyWithImpl<TensorElement_Float>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is TensorElement_Float&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'TensorElement.float(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $TensorElement_FloatCopyWith<$Res> implements $TensorElementCopyWith<$Res> {
+  factory $TensorElement_FloatCopyWith(TensorElement_Float value, $Res Function(TensorElement_Float) _then) = _$TensorElement_FloatCopyWithImpl;
+@useResult
+$Res call({
+ double field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$TensorElement_FloatCopyWithImpl<$Res>
+    implements $TensorElement_FloatCopyWith<$Res> {
+  _$TensorElement_FloatCopyWithImpl(this._self, this._then);
+
+  final TensorElement_Float _self;
+  final $Res Function(TensorElement_Float) _then;
+
+/// Create a copy of TensorElement
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(TensorElement_Float(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as double,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class TensorElement_String extends TensorElement {
+  const TensorElement_String(this.field0): super._();
+  
+
+@override final  String field0;
+
+/// Create a copy of TensorElement
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$TensorElement_StringCopyWith<TensorElement_String> get copyWith => _$TensorElement_StringCopyWithImpl<TensorElement_String>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is TensorElement_String&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'TensorElement.string(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $TensorElement_StringCopyWith<$Res> implements $TensorElementCopyWith<$Res> {
+  factory $TensorElement_StringCopyWith(TensorElement_String value, $Res Function(TensorElement_String) _then) = _$TensorElement_StringCopyWithImpl;
+@useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$TensorElement_StringCopyWithImpl<$Res>
+    implements $TensorElement_StringCopyWith<$Res> {
+  _$TensorElement_StringCopyWithImpl(this._self, this._then);
+
+  final TensorElement_String _self;
+  final $Res Function(TensorElement_String) _then;
+
+/// Create a copy of TensorElement
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(TensorElement_String(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+// dart format on
@@ -0,0 +1,39 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../tensor.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `element_data`, `for_dtype`, `header_value`, `parse`, `parse_shape`, `swap_bytes`
+
+/// Loads the tensors of a `.npz` file, see [`read_npz`].
+Future<Map<String, TensorImpl>> loadNpz({required String path}) =>
+    RustLib.instance.api.crateApiTensorNpyLoadNpz(path: path);
+
+/// Reads the tensors of a NumPy `.npz` archive, compressed or not, keyed by their array names. The result can be
+/// passed straight to `SessionImpl::run`.
+Map<String, TensorImpl> readNpz({required List<int> bytes}) =>
+    RustLib.instance.api.crateApiTensorNpyReadNpz(bytes: bytes);
+
+/// Saves tensors to a `.npz` file, see [`write_npz`].
+Future<void> saveNpz({
+  required Map<String, TensorImpl> tensors,
+  required String path,
+  required bool compressed,
+}) => RustLib.instance.api.crateApiTensorNpySaveNpz(
+  tensors: tensors,
+  path: path,
+  compressed: compressed,
+);
+
+/// Writes tensors to a NumPy `.npz` archive, as `numpy.savez` or, with `compressed`, `numpy.savez_compressed` would.
+Uint8List writeNpz({
+  required Map<String, TensorImpl> tensors,
+  required bool compressed,
+}) => RustLib.instance.api.crateApiTensorNpyWriteNpz(
+  tensors: tensors,
+  compressed: compressed,
+);
@@ -0,0 +1,32 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../tensor.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `arg_select`, `compare_nan_first`, `float_dtype`, `from_f64_data`, `map_along_axis`, `numeric_data`, `reduction`, `select_reduce`, `selects`, `split_at_axis`, `to_f64_data`
+
+/// The elements and indices picked by [`TensorImpl::top_k`].
+class TopK {
+  /// The picked elements, of the same type as the input tensor.
+  final TensorImpl values;
+
+  /// The `int64` indices of the picked elements along the axis.
+  final TensorImpl indices;
+
+  const TopK({required this.values, required this.indices});
+
+  @override
+  int get hashCode => values.hashCode ^ indices.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TopK &&
+          runtimeType == other.runtimeType &&
+          values == other.values &&
+          indices == other.indices;
+}
@@ -0,0 +1,16 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../tensor.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `bytes`, `bytes_field`, `decode`, `element_type`, `into_tensor`, `length_delimited`, `onnx_data_type`, `read_external_data`, `repeated`, `scalar`, `skip`, `swap_to_little_endian`, `tag`, `tag`, `truncated`, `varint`, `varint`, `varint_field`
+
+/// Loads several `TensorProto` files, e.g. all `input_*.pb` files of an ONNX test data set, keyed by the names stored
+/// in them so they can be passed straight to `SessionImpl::run`.
+Future<Map<String, TensorImpl>> loadTensorProtos({
+  required List<String> paths,
+}) => RustLib.instance.api.crateApiTensorProtoLoadTensorProtos(paths: paths);
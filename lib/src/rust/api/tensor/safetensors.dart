@@ -0,0 +1,43 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../tensor.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `copy_tensor`, `element_type`, `invalid`, `safetensors_dtype`, `shape_of`, `to_native_order`, `views`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `data`, `data_len`, `dtype`, `shape`
+
+/// Loads the tensors of a `.safetensors` file, keyed by name.
+///
+/// The file is memory-mapped and, where the data is suitably aligned, the tensors read it directly without copying.
+/// Such tensors are immutable and keep the file mapped until the last of them is dropped; the file must not be
+/// modified in the meantime.
+Future<Map<String, TensorImpl>> loadSafetensors({required String path}) =>
+    RustLib.instance.api.crateApiTensorSafetensorsLoadSafetensors(path: path);
+
+/// Reads the tensors of a `.safetensors` file held in memory, keyed by name. The data is copied into new tensors.
+Map<String, TensorImpl> readSafetensors({required List<int> bytes}) =>
+    RustLib.instance.api.crateApiTensorSafetensorsReadSafetensors(bytes: bytes);
+
+/// Saves tensors to a `.safetensors` file, see [`write_safetensors`].
+Future<void> saveSafetensors({
+  required Map<String, TensorImpl> tensors,
+  required String path,
+  Map<String, String>? metadata,
+}) => RustLib.instance.api.crateApiTensorSafetensorsSaveSafetensors(
+  tensors: tensors,
+  path: path,
+  metadata: metadata,
+);
+
+/// Serializes tensors in the `.safetensors` format, with optional string metadata stored in the header.
+Uint8List writeSafetensors({
+  required Map<String, TensorImpl> tensors,
+  Map<String, String>? metadata,
+}) => RustLib.instance.api.crateApiTensorSafetensorsWriteSafetensors(
+  tensors: tensors,
+  metadata: metadata,
+);
@@ -0,0 +1,42 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'stats.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `non_finite_stats`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+/// Summary statistics of a numeric tensor, see [`TensorImpl::stats`].
+@freezed
+sealed class TensorStats with _$TensorStats {
+  const TensorStats._();
+  const factory TensorStats({
+    /// The total number of elements.
+    required int count,
+
+    /// The smallest finite element, or NaN if there are none.
+    required double min,
+
+    /// The largest finite element, or NaN if there are none.
+    required double max,
+
+    /// The mean of the finite elements, or NaN if there are none.
+    required double mean,
+
+    /// The population standard deviation of the finite elements, or NaN if there are none.
+    required double std,
+    required int nanCount,
+    required int positiveInfinityCount,
+    required int negativeInfinityCount,
+    required int zeroCount,
+  }) = _TensorStats;
+
+  /// If any element is NaN or infinite.
+  bool hasNonFinite() => RustLib.instance.api
+      .crateApiTensorStatsTensorStatsHasNonFinite(that: this);
+}
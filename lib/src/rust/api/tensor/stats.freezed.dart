@@ -0,0 +1,299 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'stats.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$TensorStats {
+
+/// The total number of elements.
+ int get count;/// The smallest finite element, or NaN if there are none.
+ double get min;/// The largest finite element, or NaN if there are none.
+ double get max;/// The mean of the finite elements, or NaN if there are none.
+ double get mean;/// The population standard deviation of the finite elements, or NaN if there are none.
+ double get std; int get nanCount; int get positiveInfinityCount; int get negativeInfinityCount; int get zeroCount;
+/// Create a copy of TensorStats
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$TensorStatsCopyWith<TensorStats> get copyWith => _$TensorStatsCopyWithImpl<TensorStats>(this as TensorStats, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is TensorStats&&(identical(other.count, count) || other.count == count)&&(identical(other.min, min) || other.min == min)&&(identical(other.max, max) || other.max == max)&&(identical(other.mean, mean) || other.mean == mean)&&(identical(other.std, std) || other.std == std)&&(identical(other.nanCount, nanCount) || other.nanCount == nanCount)&&(identical(other.positiveInfinityCount, positiveInfinityCount) || other.positiveInfinityCount == positiveInfinityCount)&&(identical(other.negativeInfinityCount, negativeInfinityCount) || other.negativeInfinityCount == negativeInfinityCount)&&(identical(other.zeroCount, zeroCount) || other.zeroCount == zeroCount));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,count,min,max,mean,std,nanCount,positiveInfinityCount,negativeInfinityCount,zeroCount);
+
+@override
+String toString() {
+  return 'TensorStats(count: $count, min: $min, max: $max, mean: $mean, std: $std, nanCount: $nanCount, positiveInfinityCount: $positiveInfinityCount, negativeInfinityCount: $negativeInfinityCount, zeroCount: $zeroCount)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $TensorStatsCopyWith<$Res>  {
+  factory $TensorStatsCopyWith(TensorStats value, $Res Function(TensorStats) _then) = _$TensorStatsCopyWithImpl;
+@useResult
+$Res call({
+ int count, double min, double max, double mean, double std, int nanCount, int positiveInfinityCount, int negativeInfinityCount, int zeroCount
+});
+
+
+
+
+}
+/// @nodoc
+class _$TensorStatsCopyWithImpl<$Res>
+    implements $TensorStatsCopyWith<$Res> {
+  _$TensorStatsCopyWithImpl(this._self, this._then);
+
+  final TensorStats _self;
+  final $Res Function(TensorStats) _then;
+
+/// Create a copy of TensorStats
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? count = null,Object? min = null,Object? max = null,Object? mean = null,Object? std = null,Object? nanCount = null,Object? positiveInfinityCount = null,Object? negativeInfinityCount = null,Object? zeroCount = null,}) {
+  return _then(_self.copyWith(
+count: null == count ? _self.count : count // ignore: cast_nullable_to_non_nullable
+as int,min: null == min ? _self.min : min // ignore: cast_nullable_to_non_nullable
+as double,max: null == max ? _self.max : max // ignore: cast_nullable_to_non_nullable
+as double,mean: null == mean ? _self.mean : mean // ignore: cast_nullable_to_non_nullable
+as double,std: null == std ? _self.std : std // ignore: cast_nullable_to_non_nullable
+as double,nanCount: null == nanCount ? _self.nanCount : nanCount // ignore: cast_nullable_to_non_nullable
+as int,positiveInfinityCount: null == positiveInfinityCount ? _self.positiveInfinityCount : positiveInfinityCount // ignore: cast_nullable_to_non_nullable
+as int,negativeInfinityCount: null == negativeInfinityCount ? _self.negativeInfinityCount : negativeInfinityCount // ignore: cast_nullable_to_non_nullable
+as int,zeroCount: null == zeroCount ? _self.zeroCount : zeroCount // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [TensorStats].
+extension TensorStatsPatterns on TensorStats {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _TensorStats value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _TensorStats() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _TensorStats value)  $default,){
+final _that = this;
+switch (_that) {
+case _TensorStats():
+return $default(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _TensorStats value)?  $default,){
+final _that = this;
+switch (_that) {
+case _TensorStats() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function( int count,  double min,  double max,  double mean,  double std,  int nanCount,  int positiveInfinityCount,  int negativeInfinityCount,  int zeroCount)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _TensorStats() when $default != null:
+return $default(_that.count,_that.min,_that.max,_that.mean,_that.std,_that.nanCount,_that.positiveInfinityCount,_that.negativeInfinityCount,_that.zeroCount);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function( int count,  double min,  double max,  double mean,  double std,  int nanCount,  int positiveInfinityCount,  int negativeInfinityCount,  int zeroCount)  $default,) {final _that = this;
+switch (_that) {
+case _TensorStats():
+return $default(_that.count,_that.min,_that.max,_that.mean,_that.std,_that.nanCount,_that.positiveInfinityCount,_that.negativeInfinityCount,_that.zeroCount);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function( int count,  double min,  double max,  double mean,  double std,  int nanCount,  int positiveInfinityCount,  int negativeInfinityCount,  int zeroCount)?  $default,) {final _that = this;
+switch (_that) {
+case _TensorStats() when $default != null:
+return $default(_that.count,_that.min,_that.max,_that.mean,_that.std,_that.nanCount,_that.positiveInfinityCount,_that.negativeInfinityCount,_that.zeroCount);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class _TensorStats extends TensorStats {
+  const _TensorStats({required this.count, required this.min, required this.max, required this.mean, required this.std, required this.nanCount, required this.positiveInfinityCount, required this.negativeInfinityCount, required this.zeroCount}): super._();
+  
+
+/// The total number of elements.
+@override final  int count;
+/// The smallest finite element, or NaN if there are none.
+@override final  double min;
+/// The largest finite element, or NaN if there are none.
+@override final  double max;
+/// The mean of the finite elements, or NaN if there are none.
+@override final  double mean;
+/// The population standard deviation of the finite elements, or NaN if there are none.
+@override final  double std;
+@override final  int nanCount;
+@override final  int positiveInfinityCount;
+@override final  int negativeInfinityCount;
+@override final  int zeroCount;
+
+/// Create a copy of TensorStats
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$TensorStatsCopyWith<_TensorStats> get copyWith => __$TensorStatsCopyWithImpl<_TensorStats>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _TensorStats&&(identical(other.count, count) || other.count == count)&&(identical(other.min, min) || other.min == min)&&(identical(other.max, max) || other.max == max)&&(identical(other.mean, mean) || other.mean == mean)&&(identical(other.std, std) || other.std == std)&&(identical(other.nanCount, nanCount) || other.nanCount == nanCount)&&(identical(other.positiveInfinityCount, positiveInfinityCount) || other.positiveInfinityCount == positiveInfinityCount)&&(identical(other.negativeInfinityCount, negativeInfinityCount) || other.negativeInfinityCount == negativeInfinityCount)&&(identical(other.zeroCount, zeroCount) || other.zeroCount == zeroCount));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,count,min,max,mean,std,nanCount,positiveInfinityCount,negativeInfinityCount,zeroCount);
+
+@override
+String toString() {
+  return 'TensorStats(count: $count, min: $min, max: $max, mean: $mean, std: $std, nanCount: $nanCount, positiveInfinityCount: $positiveInfinityCount, negativeInfinityCount: $negativeInfinityCount, zeroCount: $zeroCount)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$TensorStatsCopyWith<$Res> implements $TensorStatsCopyWith<$Res> {
+  factory _$TensorStatsCopyWith(_TensorStats value, $Res Function(_TensorStats) _then) = __$TensorStatsCopyWithImpl;
+@override @useResult
+$Res call({
+ int count, double min, double max, double mean, double std, int nanCount, int positiveInfinityCount, int negativeInfinityCount, int zeroCount
+});
+
+
+
+
+}
+/// @nodoc
+class __$TensorStatsCopyWithImpl<$Res>
+    implements _$TensorStatsCopyWith<$Res> {
+  __$TensorStatsCopyWithImpl(this._self, this._then);
+
+  final _TensorStats _self;
+  final $Res Function(_TensorStats) _then;
+
+/// Create a copy of TensorStats
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? count = null,Object? min = null,Object? max = null,Object? mean = null,Object? std = null,Object? nanCount = null,Object? positiveInfinityCount = null,Object? negativeInfinityCount = null,Object? zeroCount = null,}) {
+  return _then(_TensorStats(
+count: null == count ? _self.count : count // ignore: cast_nullable_to_non_nullable
+as int,min: null == min ? _self.min : min // ignore: cast_nullable_to_non_nullable
+as double,max: null == max ? _self.max : max // ignore: cast_nullable_to_non_nullable
+as double,mean: null == mean ? _self.mean : mean // ignore: cast_nullable_to_non_nullable
+as double,std: null == std ? _self.std : std // ignore: cast_nullable_to_non_nullable
+as double,nanCount: null == nanCount ? _self.nanCount : nanCount // ignore: cast_nullable_to_non_nullable
+as int,positiveInfinityCount: null == positiveInfinityCount ? _self.positiveInfinityCount : positiveInfinityCount // ignore: cast_nullable_to_non_nullable
+as int,negativeInfinityCount: null == negativeInfinityCount ? _self.negativeInfinityCount : negativeInfinityCount // ignore: cast_nullable_to_non_nullable
+as int,zeroCount: null == zeroCount ? _self.zeroCount : zeroCount // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+// dart format on
@@ -0,0 +1,62 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../tensor.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'value.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `downcast_values`, `from_value`, `into_tensor`, `into_value`, `into_value`, `len`, `map_component`
+
+/// An ONNX map, i.e. the `map(string, float)` entries produced by sklearn-onnx's `ZipMap`.
+///
+/// The value of the key at index `i` of [`MapImpl::keys`] is the element at index `i` of the 1-D [`MapImpl::values`]
+/// tensor.
+class MapImpl {
+  final MapKeys keys;
+  final TensorImpl values;
+
+  const MapImpl({required this.keys, required this.values});
+
+  @override
+  int get hashCode => keys.hashCode ^ values.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MapImpl &&
+          runtimeType == other.runtimeType &&
+          keys == other.keys &&
+          values == other.values;
+}
+
+/// The keys of a [`MapImpl`].
+@freezed
+sealed class MapKeys with _$MapKeys {
+  const MapKeys._();
+
+  const factory MapKeys.int64(List<int> field0) = MapKeys_Int64;
+  const factory MapKeys.string(List<String> field0) = MapKeys_String;
+}
+
+/// Any value that can be passed to or returned from a session: a tensor, or one of the non-tensor types supported by
+/// ONNX.
+@freezed
+sealed class ValueImpl with _$ValueImpl {
+  const ValueImpl._();
+
+  /// A single tensor.
+  const factory ValueImpl.tensor(TensorImpl field0) = ValueImpl_Tensor;
+
+  /// An ordered list of tensors or maps, all of the same type.
+  const factory ValueImpl.sequence(List<ValueImpl> field0) = ValueImpl_Sequence;
+
+  /// A map from `int64` or `string` keys to scalar values.
+  const factory ValueImpl.map(MapImpl field0) = ValueImpl_Map;
+
+  /// A value that may be absent. An absent optional input is simply not passed to the session.
+  const factory ValueImpl.optional(ValueImpl? field0) = ValueImpl_Optional;
+}
@@ -0,0 +1,760 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'value.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$MapKeys {
+
+ List<Object> get field0;
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MapKeys&&const DeepCollectionEquality().equals(other.field0, field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(field0));
+
+@override
+String toString() {
+  return 'MapKeys(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+class $MapKeysCopyWith<$Res>  {
+$MapKeysCopyWith(MapKeys _, $Res Function(MapKeys) __);
+}
+
+
+/// Adds pattern-matching-related methods to [MapKeys].
+extension MapKeysPatterns on MapKeys {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( MapKeys_Int64 value)?  int64,TResult Function( MapKeys_String value)?  string,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case MapKeys_Int64() when int64 != null:
+return int64(_that);case MapKeys_String() when string != null:
+return string(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( MapKeys_Int64 value)  int64,required TResult Function( MapKeys_String value)  string,}){
+final _that = this;
+switch (_that) {
+case MapKeys_Int64():
+return int64(_that);case MapKeys_String():
+return string(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( MapKeys_Int64 value)?  int64,TResult? Function( MapKeys_String value)?  string,}){
+final _that = this;
+switch (_that) {
+case MapKeys_Int64() when int64 != null:
+return int64(_that);case MapKeys_String() when string != null:
+return string(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( List<int> field0)?  int64,TResult Function( List<String> field0)?  string,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case MapKeys_Int64() when int64 != null:
+return int64(_that.field0);case MapKeys_String() when string != null:
+return string(_that.field0);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( List<int> field0)  int64,required TResult Function( List<String> field0)  string,}) {final _that = this;
+switch (_that) {
+case MapKeys_Int64():
+return int64(_that.field0);case MapKeys_String():
+return string(_that.field0);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( List<int> field0)?  int64,TResult? Function( List<String> field0)?  string,}) {final _that = this;
+switch (_that) {
+case MapKeys_Int64() when int64 != null:
+return int64(_that.field0);case MapKeys_String() when string != null:
+return string(_that.field0);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class MapKeys_Int64 extends MapKeys {
+  const MapKeys_Int64(final  List<int> field0): _field0 = field0,super._();
+  
+
+ final  List<int> _field0;
+@override List<int> get field0 {
+  if (_field0 is EqualUnmodifiableListView) return _field0;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_field0);
+}
+
+
+/// Create a copy of MapKeys
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$MapKeys_Int64CopyWith<MapKeys_Int64> get copyWith => _$MapKeys_Int64CopyWithImpl<MapKeys_Int64>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MapKeys_Int64&&const DeepCollectionEquality().equals(other._field0, _field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_field0));
+
+@override
+String toString() {
+  return 'MapKeys.int64(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $MapKeys_Int64CopyWith<$Res> implements $MapKeysCopyWith<$Res> {
+  factory $MapKeys_Int64CopyWith(MapKeys_Int64 value, $Res Function(MapKeys_Int64) _then) = _$MapKeys_Int64CopyWithImpl;
+@useResult
+$Res call({
+ List<int> field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$MapKeys_Int64CopyWithImpl<$Res>
+    implements $MapKeys_Int64CopyWith<$Res> {
+  _$MapKeys_Int64CopyWithImpl(this._self, this._then);
+
+  final MapKeys_Int64 _self;
+  final $Res Function(MapKeys_Int64) _then;
+
+/// Create a copy of MapKeys
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(MapKeys_Int64(
+null == field0 ? _self._field0 : field0 // ignore: cast_nullable_to_non_nullable
+as List<int>,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class MapKeys_String extends MapKeys {
+  const MapKeys_String(final  List<String> field0): _field0 = field0,super._();
+  
+
+ final  List<String> _field0;
+@override List<String> get field0 {
+  if (_field0 is EqualUnmodifiableListView) return _field0;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_field0);
+}
+
+
+/// Create a copy of MapKeys
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$MapKeys_StringCopyWith<MapKeys_String> get copyWith => _$MapKeys_StringCopyWithImpl<MapKeys_String>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MapKeys_String&&const DeepCollectionEquality().equals(other._field0, _field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_field0));
+
+@override
+String toString() {
+  return 'MapKeys.string(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $MapKeys_StringCopyWith<$Res> implements $MapKeysCopyWith<$Res> {
+  factory $MapKeys_StringCopyWith(MapKeys_String value, $Res Function(MapKeys_String) _then) = _$MapKeys_StringCopyWithImpl;
+@useResult
+$Res call({
+ List<String> field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$MapKeys_StringCopyWithImpl<$Res>
+    implements $MapKeys_StringCopyWith<$Res> {
+  _$MapKeys_StringCopyWithImpl(this._self, this._then);
+
+  final MapKeys_String _self;
+  final $Res Function(MapKeys_String) _then;
+
+/// Create a copy of MapKeys
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(MapKeys_String(
+null == field0 ? _self._field0 : field0 // ignore: cast_nullable_to_non_nullable
+as List<String>,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ValueImpl {
+
+ Object? get field0;
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ValueImpl&&const DeepCollectionEquality().equals(other.field0, field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(field0));
+
+@override
+String toString() {
+  return 'ValueImpl(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+class $ValueImplCopyWith<$Res>  {
+$ValueImplCopyWith(ValueImpl _, $Res Function(ValueImpl) __);
+}
+
+
+/// Adds pattern-matching-related methods to [ValueImpl].
+extension ValueImplPatterns on ValueImpl {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( ValueImpl_Tensor value)?  tensor,TResult Function( ValueImpl_Sequence value)?  sequence,TResult Function( ValueImpl_Map value)?  map,TResult Function( ValueImpl_Optional value)?  optional,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case ValueImpl_Tensor() when tensor != null:
+return tensor(_that);case ValueImpl_Sequence() when sequence != null:
+return sequence(_that);case ValueImpl_Map() when map != null:
+return map(_that);case ValueImpl_Optional() when optional != null:
+return optional(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( ValueImpl_Tensor value)  tensor,required TResult Function( ValueImpl_Sequence value)  sequence,required TResult Function( ValueImpl_Map value)  map,required TResult Function( ValueImpl_Optional value)  optional,}){
+final _that = this;
+switch (_that) {
+case ValueImpl_Tensor():
+return tensor(_that);case ValueImpl_Sequence():
+return sequence(_that);case ValueImpl_Map():
+return map(_that);case ValueImpl_Optional():
+return optional(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( ValueImpl_Tensor value)?  tensor,TResult? Function( ValueImpl_Sequence value)?  sequence,TResult? Function( ValueImpl_Map value)?  map,TResult? Function( ValueImpl_Optional value)?  optional,}){
+final _that = this;
+switch (_that) {
+case ValueImpl_Tensor() when tensor != null:
+return tensor(_that);case ValueImpl_Sequence() when sequence != null:
+return sequence(_that);case ValueImpl_Map() when map != null:
+return map(_that);case ValueImpl_Optional() when optional != null:
+return optional(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( TensorImpl field0)?  tensor,TResult Function( List<ValueImpl> field0)?  sequence,TResult Function( MapImpl field0)?  map,TResult Function( ValueImpl? field0)?  optional,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case ValueImpl_Tensor() when tensor != null:
+return tensor(_that.field0);case ValueImpl_Sequence() when sequence != null:
+return sequence(_that.field0);case ValueImpl_Map() when map != null:
+return map(_that.field0);case ValueImpl_Optional() when optional != null:
+return optional(_that.field0);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( TensorImpl field0)  tensor,required TResult Function( List<ValueImpl> field0)  sequence,required TResult Function( MapImpl field0)  map,required TResult Function( ValueImpl? field0)  optional,}) {final _that = this;
+switch (_that) {
+case ValueImpl_Tensor():
+return tensor(_that.field0);case ValueImpl_Sequence():
+return sequence(_that.field0);case ValueImpl_Map():
+return map(_that.field0);case ValueImpl_Optional():
+return optional(_that.field0);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( TensorImpl field0)?  tensor,TResult? Function( List<ValueImpl> field0)?  sequence,TResult? Function( MapImpl field0)?  map,TResult? Function( ValueImpl? field0)?  optional,}) {final _that = this;
+switch (_that) {
+case ValueImpl_Tensor() when tensor != null:
+return tensor(_that.field0);case ValueImpl_Sequence() when sequence != null:
+return sequence(_that.field0);case ValueImpl_Map() when map != null:
+return map(_that.field0);case ValueImpl_Optional() when optional != null:
+return optional(_that.field0);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class ValueImpl_Tensor extends ValueImpl {
+  const ValueImpl_Tensor(this.field0): super._();
+  
+
+@override final  TensorImpl field0;
+
+/// Create a copy of ValueImpl
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ValueImpl_TensorCopyWith<ValueImpl_Tensor> get copyWith => _$ValueImpl_TensorCopyWithImpl<ValueImpl_Tensor>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ValueImpl_Tensor&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'ValueImpl.tensor(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ValueImpl_TensorCopyWith<$Res> implements $ValueImplCopyWith<$Res> {
+  factory $ValueImpl_TensorCopyWith(ValueImpl_Tensor value, $Res Function(ValueImpl_Tensor) _then) = _$ValueImpl_TensorCopyWithImpl;
+@useResult
+$Res call({
+ TensorImpl field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ValueImpl_TensorCopyWithImpl<$Res>
+    implements $ValueImpl_TensorCopyWith<$Res> {
+  _$ValueImpl_TensorCopyWithImpl(this._self, this._then);
+
+  final ValueImpl_Tensor _self;
+  final $Res Function(ValueImpl_Tensor) _then;
+
+/// Create a copy of ValueImpl
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(ValueImpl_Tensor(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as TensorImpl,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ValueImpl_Sequence extends ValueImpl {
+  const ValueImpl_Sequence(final  List<ValueImpl> field0): _field0 = field0,super._();
+  
+
+ final  List<ValueImpl> _field0;
+@override List<ValueImpl> get field0 {
+  if (_field0 is EqualUnmodifiableListView) return _field0;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_field0);
+}
+
+
+/// Create a copy of ValueImpl
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ValueImpl_SequenceCopyWith<ValueImpl_Sequence> get copyWith => _$ValueImpl_SequenceCopyWithImpl<ValueImpl_Sequence>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ValueImpl_Sequence&&const DeepCollectionEquality().equals(other._field0, _field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_field0));
+
+@override
+String toString() {
+  return 'ValueImpl.sequence(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ValueImpl_SequenceCopyWith<$Res> implements $ValueImplCopyWith<$Res> {
+  factory $ValueImpl_SequenceCopyWith(ValueImpl_Sequence value, $Res Function(ValueImpl_Sequence) _then) = _$ValueImpl_SequenceCopyWithImpl;
+@useResult
+$Res call({
+ List<ValueImpl> field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ValueImpl_SequenceCopyWithImpl<$Res>
+    implements $ValueImpl_SequenceCopyWith<$Res> {
+  _$ValueImpl_SequenceCopyWithImpl(this._self, this._then);
+
+  final ValueImpl_Sequence _self;
+  final $Res Function(ValueImpl_Sequence) _then;
+
+/// Create a copy of ValueImpl
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(ValueImpl_Sequence(
+null == field0 ? _self._field0 : field0 // ignore: cast_nullable_to_non_nullable
+as List<ValueImpl>,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ValueImpl_Map extends ValueImpl {
+  const ValueImpl_Map(this.field0): super._();
+  
+
+@override final  MapImpl field0;
+
+/// Create a copy of ValueImpl
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ValueImpl_MapCopyWith<ValueImpl_Map> get copyWith => _$ValueImpl_MapCopyWithImpl<ValueImpl_Map>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ValueImpl_Map&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'ValueImpl.map(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ValueImpl_MapCopyWith<$Res> implements $ValueImplCopyWith<$Res> {
+  factory $ValueImpl_MapCopyWith(ValueImpl_Map value, $Res Function(ValueImpl_Map) _then) = _$ValueImpl_MapCopyWithImpl;
+@useResult
+$Res call({
+ MapImpl field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ValueImpl_MapCopyWithImpl<$Res>
+    implements $ValueImpl_MapCopyWith<$Res> {
+  _$ValueImpl_MapCopyWithImpl(this._self, this._then);
+
+  final ValueImpl_Map _self;
+  final $Res Function(ValueImpl_Map) _then;
+
+/// Create a copy of ValueImpl
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(ValueImpl_Map(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as MapImpl,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ValueImpl_Optional extends ValueImpl {
+  const ValueImpl_Optional(this.field0): super._();
+  
+
+@override final  ValueImpl? field0;
+
+/// Create a copy of ValueImpl
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ValueImpl_OptionalCopyWith<ValueImpl_Optional> get copyWith => _$ValueImpl_OptionalCopyWithImpl<ValueImpl_Optional>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ValueImpl_Optional&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'ValueImpl.optional(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ValueImpl_OptionalCopyWith<$Res> implements $ValueImplCopyWith<$Res> {
+  factory $ValueImpl_OptionalCopyWith(ValueImpl_Optional value, $Res Function(ValueImpl_Optional) _then) = _$ValueImpl_OptionalCopyWithImpl;
+@useResult
+$Res call({
+ ValueImpl? field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ValueImpl_OptionalCopyWithImpl<$Res>
+    implements $ValueImpl_OptionalCopyWith<$Res> {
+  _$ValueImpl_OptionalCopyWithImpl(this._self, this._then);
+
+  final ValueImpl_Optional _self;
+  final $Res Function(ValueImpl_Optional) _then;
+
+/// Create a copy of ValueImpl
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = freezed,}) {
+  return _then(ValueImpl_Optional(
+freezed == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as ValueImpl?,
+  ));
+}
+
+
+}
+
+// dart format on
@@ -15,9 +15,23 @@ import 'api/execution_providers/tensorrt.dart';
 import 'api/execution_providers/xnnpack.dart';
 import 'api/logging.dart';
 import 'api/memory.dart';
+import 'api/memory/arena.dart';
+import 'api/memory/budget.dart';
+import 'api/memory/stats.dart';
+import 'api/memory/trim.dart';
 import 'api/session.dart';
 import 'api/session/builder/impl_options.dart';
+import 'api/session/scheduler.dart';
 import 'api/tensor.dart';
+import 'api/tensor/cast.dart';
+import 'api/tensor/compare.dart';
+import 'api/tensor/index.dart';
+import 'api/tensor/npy.dart';
+import 'api/tensor/ops.dart';
+import 'api/tensor/proto.dart';
+import 'api/tensor/safetensors.dart';
+import 'api/tensor/stats.dart';
+import 'api/tensor/value.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
@@ -441,335 +455,4957 @@ abstract class RustLibApi extends BaseApi {
     required XNNPACKExecutionProvider that,
   });
 
+  Future<List<Map<String, TensorImpl>>> crateApiSessionSessionImplRunBatch({
+    required SessionImpl that,
+    required List<Map<String, TensorImpl>> batch,
+  });
+
+  BatchScheduler crateApiSessionSchedulerBatchSchedulerNew({
+    required SessionImpl session,
+    required BatchSchedulerOptions options,
+  });
+
+  Future<Map<String, TensorImpl>> crateApiSessionSchedulerBatchSchedulerRun({
+    required BatchScheduler that,
+    required Map<String, TensorImpl> inputValues,
+  });
+
+  Future<BatchSchedulerOptions>
+  crateApiSessionSchedulerBatchSchedulerOptionsDefault();
+
+  Future<Map<String, ValueImpl>> crateApiSessionSessionImplRunValues({
+    required SessionImpl that,
+    required Map<String, ValueImpl> inputValues,
+  });
+
+  Float64List crateApiTensorTensorImplExtractF64({required TensorImpl that});
+
+  List<int> crateApiTensorTensorImplExtractI64({required TensorImpl that});
+
+  List<int> crateApiTensorTensorImplExtractU64({required TensorImpl that});
+
+  Float32List crateApiTensorTensorImplExtractF32({required TensorImpl that});
+
+  Uint32List crateApiTensorTensorImplExtractU32({required TensorImpl that});
+
+  Int32List crateApiTensorTensorImplExtractI32({required TensorImpl that});
+
+  Uint16List crateApiTensorTensorImplExtractU16({required TensorImpl that});
+
+  Int16List crateApiTensorTensorImplExtractI16({required TensorImpl that});
+
+  Uint8List crateApiTensorTensorImplExtractU8({required TensorImpl that});
+
+  Int8List crateApiTensorTensorImplExtractI8({required TensorImpl that});
+
+  List<bool> crateApiTensorTensorImplExtractBool({required TensorImpl that});
+
+  List<String> crateApiTensorTensorImplExtractString({
+    required TensorImpl that,
+  });
+
+  TensorDataView crateApiTensorTensorImplDataView({required TensorImpl that});
+
+  int crateApiTensorTensorDataViewPtr({required TensorDataView that});
+
+  int crateApiTensorTensorDataViewLen({required TensorDataView that});
+
+  bool crateApiTensorTensorDataViewIsEmpty({required TensorDataView that});
+
+  int crateApiTensorTensorDataViewByteLen({required TensorDataView that});
+
+  TensorElementType crateApiTensorTensorDataViewDtype({
+    required TensorDataView that,
+  });
+
+  List<int> crateApiTensorTensorDataViewShape({required TensorDataView that});
+
+  TensorImpl crateApiTensorTensorImplFromArrayF16({
+    List<int>? shape,
+    required List<double> data,
+  });
+
+  TensorImpl crateApiTensorTensorImplFromArrayF16Bits({
+    List<int>? shape,
+    required List<int> data,
+  });
+
+  Float32List crateApiTensorTensorImplExtractF16({required TensorImpl that});
+
+  Uint16List crateApiTensorTensorImplExtractF16Bits({required TensorImpl that});
+
+  TensorImpl crateApiTensorTensorImplFromArrayBf16({
+    List<int>? shape,
+    required List<double> data,
+  });
+
+  TensorImpl crateApiTensorTensorImplFromArrayBf16Bits({
+    List<int>? shape,
+    required List<int> data,
+  });
+
+  Float32List crateApiTensorTensorImplExtractBf16({required TensorImpl that});
+
+  Uint16List crateApiTensorTensorImplExtractBf16Bits({
+    required TensorImpl that,
+  });
+
+  TensorImpl crateApiTensorTensorImplFromBytes({
+    required TensorElementType dtype,
+    List<int>? shape,
+    required List<int> bytes,
+  });
+
+  Uint8List crateApiTensorTensorImplToBytes({required TensorImpl that});
+
+  TensorImpl crateApiTensorShapeTensorImplReshape({
+    required TensorImpl that,
+    required List<int> shape,
+  });
+
+  TensorImpl crateApiTensorShapeTensorImplPermute({
+    required TensorImpl that,
+    required List<int> axes,
+  });
+
+  TensorImpl crateApiTensorShapeTensorImplTranspose({required TensorImpl that});
+
+  TensorImpl crateApiTensorShapeTensorImplSqueeze({
+    required TensorImpl that,
+    List<int>? axes,
+  });
+
+  TensorImpl crateApiTensorShapeTensorImplUnsqueeze({
+    required TensorImpl that,
+    required List<int> axes,
+  });
+
+  TensorImpl crateApiTensorShapeTensorImplSlice({
+    required TensorImpl that,
+    required List<int> starts,
+    required List<int> ends,
+    List<int>? axes,
+    List<int>? steps,
+  });
+
+  TensorImpl crateApiTensorShapeTensorImplConcat({
+    required List<TensorImpl> tensors,
+    required int axis,
+  });
+
+  TensorImpl crateApiTensorShapeTensorImplStack({
+    required List<TensorImpl> tensors,
+    required int axis,
+  });
+
+  TensorImpl crateApiTensorOpsTensorImplSoftmax({
+    required TensorImpl that,
+    required int axis,
+  });
+
+  TensorImpl crateApiTensorOpsTensorImplLogSoftmax({
+    required TensorImpl that,
+    required int axis,
+  });
+
+  TensorImpl crateApiTensorOpsTensorImplSigmoid({required TensorImpl that});
+
+  TensorImpl crateApiTensorOpsTensorImplArgmax({
+    required TensorImpl that,
+    required int axis,
+    required bool keepDims,
+  });
+
+  TensorImpl crateApiTensorOpsTensorImplArgmin({
+    required TensorImpl that,
+    required int axis,
+    required bool keepDims,
+  });
+
+  TopK crateApiTensorOpsTensorImplTopK({
+    required TensorImpl that,
+    required int k,
+    int? axis,
+    bool? largest,
+  });
+
+  TensorImpl crateApiTensorOpsTensorImplSum({
+    required TensorImpl that,
+    List<int>? axes,
+    required bool keepDims,
+  });
+
+  TensorImpl crateApiTensorOpsTensorImplMean({
+    required TensorImpl that,
+    List<int>? axes,
+    required bool keepDims,
+  });
+
+  TensorImpl crateApiTensorOpsTensorImplMax({
+    required TensorImpl that,
+    List<int>? axes,
+    required bool keepDims,
+  });
+
+  TensorImpl crateApiTensorOpsTensorImplMin({
+    required TensorImpl that,
+    List<int>? axes,
+    required bool keepDims,
+  });
+
+  TensorImpl crateApiTensorArithmeticTensorImplAdd({
+    required TensorImpl that,
+    required TensorImpl other,
+  });
+
+  TensorImpl crateApiTensorArithmeticTensorImplSub({
+    required TensorImpl that,
+    required TensorImpl other,
+  });
+
+  TensorImpl crateApiTensorArithmeticTensorImplMul({
+    required TensorImpl that,
+    required TensorImpl other,
+  });
+
+  TensorImpl crateApiTensorArithmeticTensorImplDiv({
+    required TensorImpl that,
+    required TensorImpl other,
+  });
+
+  TensorImpl crateApiTensorArithmeticTensorImplPow({
+    required TensorImpl that,
+    required TensorImpl other,
+  });
+
+  TensorImpl crateApiTensorArithmeticTensorImplAddScalar({
+    required TensorImpl that,
+    required double value,
+  });
+
+  TensorImpl crateApiTensorArithmeticTensorImplSubScalar({
+    required TensorImpl that,
+    required double value,
+  });
+
+  TensorImpl crateApiTensorArithmeticTensorImplMulScalar({
+    required TensorImpl that,
+    required double value,
+  });
+
+  TensorImpl crateApiTensorArithmeticTensorImplDivScalar({
+    required TensorImpl that,
+    required double value,
+  });
+
+  TensorImpl crateApiTensorArithmeticTensorImplPowScalar({
+    required TensorImpl that,
+    required double value,
+  });
+
+  TensorImpl crateApiTensorArithmeticTensorImplClamp({
+    required TensorImpl that,
+    double? min,
+    double? max,
+  });
+
+  TensorImpl crateApiTensorCastTensorImplCast({
+    required TensorImpl that,
+    required TensorElementType target,
+    CastMode? mode,
+  });
+
+  TensorImpl crateApiTensorNpyTensorImplFromNpy({required List<int> bytes});
+
+  Uint8List crateApiTensorNpyTensorImplToNpy({required TensorImpl that});
+
+  Future<TensorImpl> crateApiTensorNpyTensorImplLoadNpy({required String path});
+
+  Future<void> crateApiTensorNpyTensorImplSaveNpy({
+    required TensorImpl that,
+    required String path,
+  });
+
+  Map<String, TensorImpl> crateApiTensorNpyReadNpz({required List<int> bytes});
+
+  Uint8List crateApiTensorNpyWriteNpz({
+    required Map<String, TensorImpl> tensors,
+    required bool compressed,
+  });
+
+  Future<Map<String, TensorImpl>> crateApiTensorNpyLoadNpz({
+    required String path,
+  });
+
+  Future<void> crateApiTensorNpySaveNpz({
+    required Map<String, TensorImpl> tensors,
+    required String path,
+    required bool compressed,
+  });
+
+  TensorImpl crateApiTensorProtoTensorImplFromTensorProto({
+    required List<int> bytes,
+    String? baseDir,
+  });
+
+  Uint8List crateApiTensorProtoTensorImplToTensorProto({
+    required TensorImpl that,
+    String? name,
+  });
+
+  Future<TensorImpl> crateApiTensorProtoTensorImplLoadTensorProto({
+    required String path,
+  });
+
+  Future<void> crateApiTensorProtoTensorImplSaveTensorProto({
+    required TensorImpl that,
+    required String path,
+    String? name,
+  });
+
+  Future<Map<String, TensorImpl>> crateApiTensorProtoLoadTensorProtos({
+    required List<String> paths,
+  });
+
+  Map<String, TensorImpl> crateApiTensorSafetensorsReadSafetensors({
+    required List<int> bytes,
+  });
+
+  Future<Map<String, TensorImpl>> crateApiTensorSafetensorsLoadSafetensors({
+    required String path,
+  });
+
+  Uint8List crateApiTensorSafetensorsWriteSafetensors({
+    required Map<String, TensorImpl> tensors,
+    Map<String, String>? metadata,
+  });
+
+  Future<void> crateApiTensorSafetensorsSaveSafetensors({
+    required Map<String, TensorImpl> tensors,
+    required String path,
+    Map<String, String>? metadata,
+  });
+
+  bool crateApiTensorStatsTensorStatsHasNonFinite({required TensorStats that});
+
+  TensorStats crateApiTensorStatsTensorImplStats({required TensorImpl that});
+
+  void crateApiSessionSessionImplSetNonFiniteCheck({
+    required SessionImpl that,
+    required NonFiniteCheck check,
+  });
+
+  List<String> crateApiSessionSessionImplNonFiniteOutputs({
+    required SessionImpl that,
+  });
+
+  String crateApiTensorCompareTensorDiffSummary({required TensorDiff that});
+
+  TensorDiff crateApiTensorCompareTensorImplDiff({
+    required TensorImpl that,
+    required TensorImpl expected,
+    double? rtol,
+    double? atol,
+  });
+
+  bool crateApiTensorCompareTensorImplAllclose({
+    required TensorImpl that,
+    required TensorImpl expected,
+    double? rtol,
+    double? atol,
+  });
+
+  TensorImpl crateApiTensorExternalTensorImplFromExternalData({
+    required TensorElementType dtype,
+    List<int>? shape,
+    required int ptr,
+    required int byteLen,
+    int? release,
+    required bool immutable,
+  });
+
+  bool crateApiTensorTensorImplMakeMutable({required TensorImpl that});
+
+  ArrayPointer crateApiTensorTensorImplGetDataF16Pointer({
+    required TensorImpl that,
+  });
+
+  void crateApiTensorTensorImplFreeF16Pointer({required ArrayPointer arr});
+
+  ArrayPointer crateApiTensorTensorImplGetDataBf16Pointer({
+    required TensorImpl that,
+  });
+
+  void crateApiTensorTensorImplFreeBf16Pointer({required ArrayPointer arr});
+
+  TensorElement crateApiTensorIndexTensorImplGet({
+    required TensorImpl that,
+    required List<int> index,
+  });
+
+  void crateApiTensorIndexTensorImplSet({
+    required TensorImpl that,
+    required List<int> index,
+    required TensorElement value,
+  });
+
+  TensorSlice crateApiTensorIndexTensorImplSliceView({
+    required TensorImpl that,
+    required List<int> starts,
+    required List<int> ends,
+    List<int>? axes,
+    List<int>? steps,
+  });
+
+  TensorElementType crateApiTensorIndexTensorSliceDtype({
+    required TensorSlice that,
+  });
+
+  List<int> crateApiTensorIndexTensorSliceShape({required TensorSlice that});
+
+  List<int> crateApiTensorIndexTensorSliceStrides({required TensorSlice that});
+
+  bool crateApiTensorIndexTensorSliceIsMutable({required TensorSlice that});
+
+  TensorElement crateApiTensorIndexTensorSliceGet({
+    required TensorSlice that,
+    required List<int> index,
+  });
+
+  void crateApiTensorIndexTensorSliceSet({
+    required TensorSlice that,
+    required List<int> index,
+    required TensorElement value,
+  });
+
+  TensorImpl crateApiTensorIndexTensorSliceToTensor({
+    required TensorSlice that,
+  });
+
+  int crateApiTensorTensorImplRank({required TensorImpl that});
+
+  int crateApiTensorTensorImplNumElements({required TensorImpl that});
+
+  List<int> crateApiTensorTensorImplStrides({required TensorImpl that});
+
+  int crateApiTensorTensorImplSizeInBytes({required TensorImpl that});
+
+  MemoryInfo crateApiTensorTensorImplDevice({required TensorImpl that});
+
+  MemoryInfo crateApiMemoryMemoryInfoNew({
+    required AllocationDevice allocationDevice,
+    required int deviceId,
+    required AllocatorType allocatorType,
+    required MemoryType memoryType,
+  });
+
+  Allocator crateApiMemoryAllocatorCpu();
+
+  MemoryInfo crateApiMemoryAllocatorMemoryInfo({required Allocator that});
+
+  Allocator crateApiSessionSessionImplAllocator({
+    required SessionImpl that,
+    MemoryInfo? memoryInfo,
+  });
+
+  TensorImpl crateApiTensorTensorImplNewWithAllocator({
+    required Allocator allocator,
+    required TensorElementType dtype,
+    required List<int> shape,
+  });
+
+  void crateApiMemoryStatsEnableAllocatorTracking();
+
+  bool crateApiMemoryStatsIsAllocatorTrackingEnabled();
+
+  AllocatorStats crateApiMemoryStatsAllocatorStats();
+
+  void crateApiMemoryStatsResetPeakAllocatorStats();
+
+  Future<AllocatorStats> crateApiMemoryStatsAllocatorStatsDefault();
+
+  Future<ArenaStats> crateApiMemoryStatsArenaStatsDefault();
+
+  ArenaStats crateApiSessionSessionImplArenaStats({
+    required SessionImpl that,
+    MemoryInfo? memoryInfo,
+  });
+
+  RunMemoryUsage? crateApiSessionSessionImplLastRunMemory({
+    required SessionImpl that,
+  });
+
+  void crateApiMemoryArenaConfigureEnvArena({required ArenaConfig config});
+
+  Future<ArenaConfig> crateApiMemoryArenaArenaConfigDefault();
+
+  MemoryTrimReport crateApiMemoryTrimTrimMemory();
+
+  void crateApiSessionSessionImplSuspend({required SessionImpl that});
+
+  Future<void> crateApiSessionSessionImplResume({required SessionImpl that});
+
+  bool crateApiSessionSessionImplIsSuspended({required SessionImpl that});
+
+  Future<SessionImpl>
+  crateApiSessionSessionBuilderOptionsCommitFromMemorySuspendable({
+    required SessionBuilderOptions that,
+    required List<int> modelBytes,
+  });
+
+  void crateApiMemoryBudgetSetMemoryBudget({
+    int? limitBytes,
+    required EvictionPolicy policy,
+  });
+
+  MemoryBudgetUsage crateApiMemoryBudgetMemoryBudgetUsage();
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_Allocator;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_Allocator;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_AllocatorPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_BatchScheduler;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_BatchScheduler;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_BatchSchedulerPtr;
+
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_MemoryInfo;
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_MemoryInfo;
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_MemoryInfo;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_MemoryInfoPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_SessionImpl;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_SessionImpl;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SessionImplPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_TensorDataView;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_TensorDataView;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_TensorDataViewPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_TensorImpl;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_TensorImpl;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_TensorImplPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_TensorSlice;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_TensorSlice;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_TensorSlicePtr;
+}
+
+class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+  RustLibApiImpl({
+    required super.handler,
+    required super.wire,
+    required super.generalizedFrbRustBinding,
+    required super.portManager,
+  });
+
+  @override
+  AllocationDevice crateApiMemoryMemoryInfoAllocationDevice({
+    required MemoryInfo that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_allocation_device,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMemoryMemoryInfoAllocationDeviceConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMemoryMemoryInfoAllocationDeviceConstMeta =>
+      const TaskConstMeta(
+        debugName: "MemoryInfo_allocation_device",
+        argNames: ["that"],
+      );
+
+  @override
+  AllocatorType crateApiMemoryMemoryInfoAllocatorType({
+    required MemoryInfo that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_allocator_type,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMemoryMemoryInfoAllocatorTypeConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMemoryMemoryInfoAllocatorTypeConstMeta =>
+      const TaskConstMeta(
+        debugName: "MemoryInfo_allocator_type",
+        argNames: ["that"],
+      );
+
+  @override
+  int crateApiMemoryMemoryInfoDeviceId({required MemoryInfo that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_i_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMemoryMemoryInfoDeviceIdConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMemoryMemoryInfoDeviceIdConstMeta =>
+      const TaskConstMeta(
+        debugName: "MemoryInfo_device_id",
+        argNames: ["that"],
+      );
+
+  @override
+  DeviceType crateApiMemoryMemoryInfoDeviceType({required MemoryInfo that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_device_type,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMemoryMemoryInfoDeviceTypeConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMemoryMemoryInfoDeviceTypeConstMeta =>
+      const TaskConstMeta(
+        debugName: "MemoryInfo_device_type",
+        argNames: ["that"],
+      );
+
+  @override
+  bool crateApiMemoryMemoryInfoIsCpuAccessible({required MemoryInfo that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMemoryMemoryInfoIsCpuAccessibleConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMemoryMemoryInfoIsCpuAccessibleConstMeta =>
+      const TaskConstMeta(
+        debugName: "MemoryInfo_is_cpu_accessible",
+        argNames: ["that"],
+      );
+
+  @override
+  MemoryType crateApiMemoryMemoryInfoMemoryType({required MemoryInfo that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_memory_type,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMemoryMemoryInfoMemoryTypeConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMemoryMemoryInfoMemoryTypeConstMeta =>
+      const TaskConstMeta(
+        debugName: "MemoryInfo_memory_type",
+        argNames: ["that"],
+      );
+
+  @override
+  SessionBuilderOptions crateApiSessionSessionImplBuilder() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_session_builder_options,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSessionSessionImplBuilderConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSessionSessionImplBuilderConstMeta =>
+      const TaskConstMeta(debugName: "SessionImpl_builder", argNames: []);
+
+  @override
+  List<Input> crateApiSessionSessionImplInputs({required SessionImpl that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_input,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSessionSessionImplInputsConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSessionSessionImplInputsConstMeta =>
+      const TaskConstMeta(debugName: "SessionImpl_inputs", argNames: ["that"]);
+
+  @override
+  List<Output> crateApiSessionSessionImplOutputs({required SessionImpl that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_output,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSessionSessionImplOutputsConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSessionSessionImplOutputsConstMeta =>
+      const TaskConstMeta(debugName: "SessionImpl_outputs", argNames: ["that"]);
+
+  @override
+  Future<Map<String, TensorImpl>> crateApiSessionSessionImplRun({
+    required SessionImpl that,
+    required Map<String, TensorImpl> inputValues,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl(
+            that,
+            serializer,
+          );
+          sse_encode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None(
+            inputValues,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 10,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSessionSessionImplRunConstMeta,
+        argValues: [that, inputValues],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSessionSessionImplRunConstMeta =>
+      const TaskConstMeta(
+        debugName: "SessionImpl_run",
+        argNames: ["that", "inputValues"],
+      );
+
+  @override
+  TensorImpl crateApiTensorTensorImplClone({required TensorImpl that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTensorTensorImplCloneConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplCloneConstMeta =>
+      const TaskConstMeta(debugName: "TensorImpl_clone", argNames: ["that"]);
+
+  @override
+  TensorElementType crateApiTensorTensorImplDtype({required TensorImpl that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_tensor_element_type,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTensorTensorImplDtypeConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplDtypeConstMeta =>
+      const TaskConstMeta(debugName: "TensorImpl_dtype", argNames: ["that"]);
+
+  @override
+  void crateApiTensorTensorImplFreeBoolPointer({required ArrayPointer arr}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_array_pointer(arr, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTensorTensorImplFreeBoolPointerConstMeta,
+        argValues: [arr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplFreeBoolPointerConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_free_bool_pointer",
+        argNames: ["arr"],
+      );
+
+  @override
+  void crateApiTensorTensorImplFreeF32Pointer({required ArrayPointer arr}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_array_pointer(arr, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTensorTensorImplFreeF32PointerConstMeta,
+        argValues: [arr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplFreeF32PointerConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_free_f32_pointer",
+        argNames: ["arr"],
+      );
+
+  @override
+  void crateApiTensorTensorImplFreeF64Pointer({required ArrayPointer arr}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_array_pointer(arr, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTensorTensorImplFreeF64PointerConstMeta,
+        argValues: [arr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplFreeF64PointerConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_free_f64_pointer",
+        argNames: ["arr"],
+      );
+
+  @override
+  void crateApiTensorTensorImplFreeI16Pointer({required ArrayPointer arr}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_array_pointer(arr, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTensorTensorImplFreeI16PointerConstMeta,
+        argValues: [arr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplFreeI16PointerConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_free_i16_pointer",
+        argNames: ["arr"],
+      );
+
+  @override
+  void crateApiTensorTensorImplFreeI32Pointer({required ArrayPointer arr}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_array_pointer(arr, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTensorTensorImplFreeI32PointerConstMeta,
+        argValues: [arr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplFreeI32PointerConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_free_i32_pointer",
+        argNames: ["arr"],
+      );
+
+  @override
+  void crateApiTensorTensorImplFreeI64Pointer({required ArrayPointer arr}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_array_pointer(arr, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTensorTensorImplFreeI64PointerConstMeta,
+        argValues: [arr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplFreeI64PointerConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_free_i64_pointer",
+        argNames: ["arr"],
+      );
+
+  @override
+  void crateApiTensorTensorImplFreeI8Pointer({required ArrayPointer arr}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_array_pointer(arr, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTensorTensorImplFreeI8PointerConstMeta,
+        argValues: [arr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplFreeI8PointerConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_free_i8_pointer",
+        argNames: ["arr"],
+      );
+
+  @override
+  void crateApiTensorTensorImplFreeStringPointer({required ArrayPointer arr}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_array_pointer(arr, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTensorTensorImplFreeStringPointerConstMeta,
+        argValues: [arr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplFreeStringPointerConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_free_string_pointer",
+        argNames: ["arr"],
+      );
+
+  @override
+  void crateApiTensorTensorImplFreeU16Pointer({required ArrayPointer arr}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_array_pointer(arr, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTensorTensorImplFreeU16PointerConstMeta,
+        argValues: [arr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplFreeU16PointerConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_free_u16_pointer",
+        argNames: ["arr"],
+      );
+
+  @override
+  void crateApiTensorTensorImplFreeU32Pointer({required ArrayPointer arr}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_array_pointer(arr, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTensorTensorImplFreeU32PointerConstMeta,
+        argValues: [arr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplFreeU32PointerConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_free_u32_pointer",
+        argNames: ["arr"],
+      );
+
+  @override
+  void crateApiTensorTensorImplFreeU64Pointer({required ArrayPointer arr}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_array_pointer(arr, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTensorTensorImplFreeU64PointerConstMeta,
+        argValues: [arr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplFreeU64PointerConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_free_u64_pointer",
+        argNames: ["arr"],
+      );
+
+  @override
+  void crateApiTensorTensorImplFreeU8Pointer({required ArrayPointer arr}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_array_pointer(arr, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTensorTensorImplFreeU8PointerConstMeta,
+        argValues: [arr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplFreeU8PointerConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_free_u8_pointer",
+        argNames: ["arr"],
+      );
+
+  @override
+  TensorImpl crateApiTensorTensorImplFromArrayBool({
+    List<int>? shape,
+    required List<bool> data,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
+          sse_encode_list_bool(data, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplFromArrayBoolConstMeta,
+        argValues: [shape, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplFromArrayBoolConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_from_array_bool",
+        argNames: ["shape", "data"],
+      );
+
+  @override
+  TensorImpl crateApiTensorTensorImplFromArrayF32({
+    List<int>? shape,
+    required List<double> data,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
+          sse_encode_list_prim_f_32_loose(data, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplFromArrayF32ConstMeta,
+        argValues: [shape, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplFromArrayF32ConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_from_array_f32",
+        argNames: ["shape", "data"],
+      );
+
+  @override
+  TensorImpl crateApiTensorTensorImplFromArrayF64({
+    List<int>? shape,
+    required List<double> data,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
+          sse_encode_list_prim_f_64_loose(data, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplFromArrayF64ConstMeta,
+        argValues: [shape, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplFromArrayF64ConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_from_array_f64",
+        argNames: ["shape", "data"],
+      );
+
+  @override
+  TensorImpl crateApiTensorTensorImplFromArrayI16({
+    List<int>? shape,
+    required List<int> data,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
+          sse_encode_list_prim_i_16_loose(data, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplFromArrayI16ConstMeta,
+        argValues: [shape, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplFromArrayI16ConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_from_array_i16",
+        argNames: ["shape", "data"],
+      );
+
+  @override
+  TensorImpl crateApiTensorTensorImplFromArrayI32({
+    List<int>? shape,
+    required List<int> data,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
+          sse_encode_list_prim_i_32_loose(data, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplFromArrayI32ConstMeta,
+        argValues: [shape, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplFromArrayI32ConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_from_array_i32",
+        argNames: ["shape", "data"],
+      );
+
+  @override
+  TensorImpl crateApiTensorTensorImplFromArrayI64({
+    List<int>? shape,
+    required List<int> data,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
+          sse_encode_list_CastedPrimitive_i_64(data, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplFromArrayI64ConstMeta,
+        argValues: [shape, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplFromArrayI64ConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_from_array_i64",
+        argNames: ["shape", "data"],
+      );
+
+  @override
+  TensorImpl crateApiTensorTensorImplFromArrayI8({
+    List<int>? shape,
+    required List<int> data,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
+          sse_encode_list_prim_i_8_loose(data, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplFromArrayI8ConstMeta,
+        argValues: [shape, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplFromArrayI8ConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_from_array_i8",
+        argNames: ["shape", "data"],
+      );
+
+  @override
+  TensorImpl crateApiTensorTensorImplFromArrayString({
+    List<int>? shape,
+    required List<String> data,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
+          sse_encode_list_String(data, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplFromArrayStringConstMeta,
+        argValues: [shape, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplFromArrayStringConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_from_array_string",
+        argNames: ["shape", "data"],
+      );
+
+  @override
+  TensorImpl crateApiTensorTensorImplFromArrayU16({
+    List<int>? shape,
+    required List<int> data,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
+          sse_encode_list_prim_u_16_loose(data, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplFromArrayU16ConstMeta,
+        argValues: [shape, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplFromArrayU16ConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_from_array_u16",
+        argNames: ["shape", "data"],
+      );
+
+  @override
+  TensorImpl crateApiTensorTensorImplFromArrayU32({
+    List<int>? shape,
+    required List<int> data,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
+          sse_encode_list_prim_u_32_loose(data, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplFromArrayU32ConstMeta,
+        argValues: [shape, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplFromArrayU32ConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_from_array_u32",
+        argNames: ["shape", "data"],
+      );
+
+  @override
+  TensorImpl crateApiTensorTensorImplFromArrayU64({
+    List<int>? shape,
+    required List<int> data,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
+          sse_encode_list_CastedPrimitive_u_64(data, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplFromArrayU64ConstMeta,
+        argValues: [shape, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplFromArrayU64ConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_from_array_u64",
+        argNames: ["shape", "data"],
+      );
+
+  @override
+  TensorImpl crateApiTensorTensorImplFromArrayU8({
+    List<int>? shape,
+    required List<int> data,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplFromArrayU8ConstMeta,
+        argValues: [shape, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplFromArrayU8ConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_from_array_u8",
+        argNames: ["shape", "data"],
+      );
+
+  @override
+  ArrayPointer crateApiTensorTensorImplGetDataBoolPointer({
+    required TensorImpl that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_array_pointer,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplGetDataBoolPointerConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplGetDataBoolPointerConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_get_data_bool_pointer",
+        argNames: ["that"],
+      );
+
+  @override
+  ArrayPointer crateApiTensorTensorImplGetDataF32Pointer({
+    required TensorImpl that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_array_pointer,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplGetDataF32PointerConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplGetDataF32PointerConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_get_data_f32_pointer",
+        argNames: ["that"],
+      );
+
+  @override
+  ArrayPointer crateApiTensorTensorImplGetDataF64Pointer({
+    required TensorImpl that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_array_pointer,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplGetDataF64PointerConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplGetDataF64PointerConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_get_data_f64_pointer",
+        argNames: ["that"],
+      );
+
+  @override
+  ArrayPointer crateApiTensorTensorImplGetDataI16Pointer({
+    required TensorImpl that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_array_pointer,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplGetDataI16PointerConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplGetDataI16PointerConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_get_data_i16_pointer",
+        argNames: ["that"],
+      );
+
+  @override
+  ArrayPointer crateApiTensorTensorImplGetDataI32Pointer({
+    required TensorImpl that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_array_pointer,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplGetDataI32PointerConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplGetDataI32PointerConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_get_data_i32_pointer",
+        argNames: ["that"],
+      );
+
+  @override
+  ArrayPointer crateApiTensorTensorImplGetDataI64Pointer({
+    required TensorImpl that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_array_pointer,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplGetDataI64PointerConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplGetDataI64PointerConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_get_data_i64_pointer",
+        argNames: ["that"],
+      );
+
+  @override
+  ArrayPointer crateApiTensorTensorImplGetDataI8Pointer({
+    required TensorImpl that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_array_pointer,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplGetDataI8PointerConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplGetDataI8PointerConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_get_data_i8_pointer",
+        argNames: ["that"],
+      );
+
+  @override
+  ArrayPointer crateApiTensorTensorImplGetDataStringPointer({
+    required TensorImpl that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_array_pointer,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplGetDataStringPointerConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplGetDataStringPointerConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_get_data_string_pointer",
+        argNames: ["that"],
+      );
+
+  @override
+  ArrayPointer crateApiTensorTensorImplGetDataU16Pointer({
+    required TensorImpl that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_array_pointer,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplGetDataU16PointerConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplGetDataU16PointerConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_get_data_u16_pointer",
+        argNames: ["that"],
+      );
+
+  @override
+  ArrayPointer crateApiTensorTensorImplGetDataU32Pointer({
+    required TensorImpl that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_array_pointer,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplGetDataU32PointerConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplGetDataU32PointerConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_get_data_u32_pointer",
+        argNames: ["that"],
+      );
+
+  @override
+  ArrayPointer crateApiTensorTensorImplGetDataU64Pointer({
+    required TensorImpl that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_array_pointer,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplGetDataU64PointerConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplGetDataU64PointerConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_get_data_u64_pointer",
+        argNames: ["that"],
+      );
+
+  @override
+  ArrayPointer crateApiTensorTensorImplGetDataU8Pointer({
+    required TensorImpl that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_array_pointer,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplGetDataU8PointerConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplGetDataU8PointerConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_get_data_u8_pointer",
+        argNames: ["that"],
+      );
+
+  @override
+  bool crateApiTensorTensorImplIsMutable({required TensorImpl that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTensorTensorImplIsMutableConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplIsMutableConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_is_mutable",
+        argNames: ["that"],
+      );
+
+  @override
+  MemoryInfo crateApiTensorTensorImplMemoryInfo({required TensorImpl that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplMemoryInfoConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplMemoryInfoConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_memory_info",
+        argNames: ["that"],
+      );
+
+  @override
+  List<int> crateApiTensorTensorImplShape({required TensorImpl that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_CastedPrimitive_i_64,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTensorTensorImplShapeConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplShapeConstMeta =>
+      const TaskConstMeta(debugName: "TensorImpl_shape", argNames: ["that"]);
+
+  @override
+  Future<CoreMLExecutionProvider>
+  crateApiExecutionProvidersCoremlCoreMlExecutionProviderDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 55,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_core_ml_execution_provider,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersCoremlCoreMlExecutionProviderDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersCoremlCoreMlExecutionProviderDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "core_ml_execution_provider_default",
+        argNames: [],
+      );
+
+  @override
+  bool crateApiExecutionProvidersCoremlCoreMlExecutionProviderIsAvailable({
+    required CoreMLExecutionProvider that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_core_ml_execution_provider(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersCoremlCoreMlExecutionProviderIsAvailableConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersCoremlCoreMlExecutionProviderIsAvailableConstMeta =>
+      const TaskConstMeta(
+        debugName: "core_ml_execution_provider_is_available",
+        argNames: ["that"],
+      );
+
+  @override
+  String crateApiExecutionProvidersCoremlCoreMlExecutionProviderName({
+    required CoreMLExecutionProvider that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_core_ml_execution_provider(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersCoremlCoreMlExecutionProviderNameConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersCoremlCoreMlExecutionProviderNameConstMeta =>
+      const TaskConstMeta(
+        debugName: "core_ml_execution_provider_name",
+        argNames: ["that"],
+      );
+
+  @override
+  CoreMLExecutionProvider
+  crateApiExecutionProvidersCoremlCoreMlExecutionProviderNew() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_core_ml_execution_provider,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersCoremlCoreMlExecutionProviderNewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersCoremlCoreMlExecutionProviderNewConstMeta =>
+      const TaskConstMeta(
+        debugName: "core_ml_execution_provider_new",
+        argNames: [],
+      );
+
+  @override
+  bool
+  crateApiExecutionProvidersCoremlCoreMlExecutionProviderSupportedByPlatform({
+    required CoreMLExecutionProvider that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_core_ml_execution_provider(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersCoremlCoreMlExecutionProviderSupportedByPlatformConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersCoremlCoreMlExecutionProviderSupportedByPlatformConstMeta =>
+      const TaskConstMeta(
+        debugName: "core_ml_execution_provider_supported_by_platform",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<CPUExecutionProvider>
+  crateApiExecutionProvidersCpuCpuExecutionProviderDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 60,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_cpu_execution_provider,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersCpuCpuExecutionProviderDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersCpuCpuExecutionProviderDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "cpu_execution_provider_default",
+        argNames: [],
+      );
+
+  @override
+  bool crateApiExecutionProvidersCpuCpuExecutionProviderIsAvailable({
+    required CPUExecutionProvider that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_cpu_execution_provider(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersCpuCpuExecutionProviderIsAvailableConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersCpuCpuExecutionProviderIsAvailableConstMeta =>
+      const TaskConstMeta(
+        debugName: "cpu_execution_provider_is_available",
+        argNames: ["that"],
+      );
+
+  @override
+  String crateApiExecutionProvidersCpuCpuExecutionProviderName({
+    required CPUExecutionProvider that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_cpu_execution_provider(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersCpuCpuExecutionProviderNameConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersCpuCpuExecutionProviderNameConstMeta =>
+      const TaskConstMeta(
+        debugName: "cpu_execution_provider_name",
+        argNames: ["that"],
+      );
+
+  @override
+  CPUExecutionProvider crateApiExecutionProvidersCpuCpuExecutionProviderNew() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_cpu_execution_provider,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersCpuCpuExecutionProviderNewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersCpuCpuExecutionProviderNewConstMeta =>
+      const TaskConstMeta(
+        debugName: "cpu_execution_provider_new",
+        argNames: [],
+      );
+
+  @override
+  bool crateApiExecutionProvidersCpuCpuExecutionProviderSupportedByPlatform({
+    required CPUExecutionProvider that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_cpu_execution_provider(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersCpuCpuExecutionProviderSupportedByPlatformConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersCpuCpuExecutionProviderSupportedByPlatformConstMeta =>
+      const TaskConstMeta(
+        debugName: "cpu_execution_provider_supported_by_platform",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<CUDAExecutionProvider>
+  crateApiExecutionProvidersCudaCudaExecutionProviderDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 65,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_cuda_execution_provider,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersCudaCudaExecutionProviderDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersCudaCudaExecutionProviderDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "cuda_execution_provider_default",
+        argNames: [],
+      );
+
+  @override
+  bool crateApiExecutionProvidersCudaCudaExecutionProviderIsAvailable({
+    required CUDAExecutionProvider that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_cuda_execution_provider(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersCudaCudaExecutionProviderIsAvailableConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersCudaCudaExecutionProviderIsAvailableConstMeta =>
+      const TaskConstMeta(
+        debugName: "cuda_execution_provider_is_available",
+        argNames: ["that"],
+      );
+
+  @override
+  String crateApiExecutionProvidersCudaCudaExecutionProviderName({
+    required CUDAExecutionProvider that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_cuda_execution_provider(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersCudaCudaExecutionProviderNameConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersCudaCudaExecutionProviderNameConstMeta =>
+      const TaskConstMeta(
+        debugName: "cuda_execution_provider_name",
+        argNames: ["that"],
+      );
+
+  @override
+  CUDAExecutionProvider
+  crateApiExecutionProvidersCudaCudaExecutionProviderNew() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_cuda_execution_provider,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersCudaCudaExecutionProviderNewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersCudaCudaExecutionProviderNewConstMeta =>
+      const TaskConstMeta(
+        debugName: "cuda_execution_provider_new",
+        argNames: [],
+      );
+
+  @override
+  bool crateApiExecutionProvidersCudaCudaExecutionProviderSupportedByPlatform({
+    required CUDAExecutionProvider that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_cuda_execution_provider(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersCudaCudaExecutionProviderSupportedByPlatformConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersCudaCudaExecutionProviderSupportedByPlatformConstMeta =>
+      const TaskConstMeta(
+        debugName: "cuda_execution_provider_supported_by_platform",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<DirectMLExecutionProvider>
+  crateApiExecutionProvidersDirectmlDirectMlExecutionProviderDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 70,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_direct_ml_execution_provider,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersDirectmlDirectMlExecutionProviderDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersDirectmlDirectMlExecutionProviderDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "direct_ml_execution_provider_default",
+        argNames: [],
+      );
+
+  @override
+  bool crateApiExecutionProvidersDirectmlDirectMlExecutionProviderIsAvailable({
+    required DirectMLExecutionProvider that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_direct_ml_execution_provider(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersDirectmlDirectMlExecutionProviderIsAvailableConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersDirectmlDirectMlExecutionProviderIsAvailableConstMeta =>
+      const TaskConstMeta(
+        debugName: "direct_ml_execution_provider_is_available",
+        argNames: ["that"],
+      );
+
+  @override
+  String crateApiExecutionProvidersDirectmlDirectMlExecutionProviderName({
+    required DirectMLExecutionProvider that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_direct_ml_execution_provider(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersDirectmlDirectMlExecutionProviderNameConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersDirectmlDirectMlExecutionProviderNameConstMeta =>
+      const TaskConstMeta(
+        debugName: "direct_ml_execution_provider_name",
+        argNames: ["that"],
+      );
+
+  @override
+  DirectMLExecutionProvider
+  crateApiExecutionProvidersDirectmlDirectMlExecutionProviderNew() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_direct_ml_execution_provider,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersDirectmlDirectMlExecutionProviderNewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersDirectmlDirectMlExecutionProviderNewConstMeta =>
+      const TaskConstMeta(
+        debugName: "direct_ml_execution_provider_new",
+        argNames: [],
+      );
+
+  @override
+  bool
+  crateApiExecutionProvidersDirectmlDirectMlExecutionProviderSupportedByPlatform({
+    required DirectMLExecutionProvider that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_direct_ml_execution_provider(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersDirectmlDirectMlExecutionProviderSupportedByPlatformConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersDirectmlDirectMlExecutionProviderSupportedByPlatformConstMeta =>
+      const TaskConstMeta(
+        debugName: "direct_ml_execution_provider_supported_by_platform",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<void> crateApiInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 75,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
+
+  @override
+  Future<NNAPIExecutionProvider>
+  crateApiExecutionProvidersNnapiNnapiExecutionProviderDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 76,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_nnapi_execution_provider,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersNnapiNnapiExecutionProviderDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersNnapiNnapiExecutionProviderDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "nnapi_execution_provider_default",
+        argNames: [],
+      );
+
+  @override
+  bool crateApiExecutionProvidersNnapiNnapiExecutionProviderIsAvailable({
+    required NNAPIExecutionProvider that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_nnapi_execution_provider(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersNnapiNnapiExecutionProviderIsAvailableConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersNnapiNnapiExecutionProviderIsAvailableConstMeta =>
+      const TaskConstMeta(
+        debugName: "nnapi_execution_provider_is_available",
+        argNames: ["that"],
+      );
+
+  @override
+  String crateApiExecutionProvidersNnapiNnapiExecutionProviderName({
+    required NNAPIExecutionProvider that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_nnapi_execution_provider(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersNnapiNnapiExecutionProviderNameConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersNnapiNnapiExecutionProviderNameConstMeta =>
+      const TaskConstMeta(
+        debugName: "nnapi_execution_provider_name",
+        argNames: ["that"],
+      );
+
+  @override
+  NNAPIExecutionProvider
+  crateApiExecutionProvidersNnapiNnapiExecutionProviderNew() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_nnapi_execution_provider,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersNnapiNnapiExecutionProviderNewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersNnapiNnapiExecutionProviderNewConstMeta =>
+      const TaskConstMeta(
+        debugName: "nnapi_execution_provider_new",
+        argNames: [],
+      );
+
+  @override
+  bool
+  crateApiExecutionProvidersNnapiNnapiExecutionProviderSupportedByPlatform({
+    required NNAPIExecutionProvider that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_nnapi_execution_provider(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersNnapiNnapiExecutionProviderSupportedByPlatformConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersNnapiNnapiExecutionProviderSupportedByPlatformConstMeta =>
+      const TaskConstMeta(
+        debugName: "nnapi_execution_provider_supported_by_platform",
+        argNames: ["that"],
+      );
+
+  @override
+  void crateApiLoggingOrtDebugMessages({OrtDebugLevel? level}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_ort_debug_level(level, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLoggingOrtDebugMessagesConstMeta,
+        argValues: [level],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoggingOrtDebugMessagesConstMeta =>
+      const TaskConstMeta(debugName: "ort_debug_messages", argNames: ["level"]);
+
+  @override
+  Future<QNNExecutionProvider>
+  crateApiExecutionProvidersQnnQnnExecutionProviderDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 82,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_qnn_execution_provider,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersQnnQnnExecutionProviderDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersQnnQnnExecutionProviderDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "qnn_execution_provider_default",
+        argNames: [],
+      );
+
+  @override
+  bool crateApiExecutionProvidersQnnQnnExecutionProviderIsAvailable({
+    required QNNExecutionProvider that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_qnn_execution_provider(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersQnnQnnExecutionProviderIsAvailableConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersQnnQnnExecutionProviderIsAvailableConstMeta =>
+      const TaskConstMeta(
+        debugName: "qnn_execution_provider_is_available",
+        argNames: ["that"],
+      );
+
+  @override
+  String crateApiExecutionProvidersQnnQnnExecutionProviderName({
+    required QNNExecutionProvider that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_qnn_execution_provider(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersQnnQnnExecutionProviderNameConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersQnnQnnExecutionProviderNameConstMeta =>
+      const TaskConstMeta(
+        debugName: "qnn_execution_provider_name",
+        argNames: ["that"],
+      );
+
+  @override
+  QNNExecutionProvider crateApiExecutionProvidersQnnQnnExecutionProviderNew() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_qnn_execution_provider,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersQnnQnnExecutionProviderNewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersQnnQnnExecutionProviderNewConstMeta =>
+      const TaskConstMeta(
+        debugName: "qnn_execution_provider_new",
+        argNames: [],
+      );
+
+  @override
+  bool crateApiExecutionProvidersQnnQnnExecutionProviderSupportedByPlatform({
+    required QNNExecutionProvider that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_qnn_execution_provider(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersQnnQnnExecutionProviderSupportedByPlatformConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersQnnQnnExecutionProviderSupportedByPlatformConstMeta =>
+      const TaskConstMeta(
+        debugName: "qnn_execution_provider_supported_by_platform",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<ROCmExecutionProvider>
+  crateApiExecutionProvidersRocmRoCmExecutionProviderDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 87,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_ro_cm_execution_provider,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersRocmRoCmExecutionProviderDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersRocmRoCmExecutionProviderDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "ro_cm_execution_provider_default",
+        argNames: [],
+      );
+
+  @override
+  bool crateApiExecutionProvidersRocmRoCmExecutionProviderIsAvailable({
+    required ROCmExecutionProvider that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_ro_cm_execution_provider(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersRocmRoCmExecutionProviderIsAvailableConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersRocmRoCmExecutionProviderIsAvailableConstMeta =>
+      const TaskConstMeta(
+        debugName: "ro_cm_execution_provider_is_available",
+        argNames: ["that"],
+      );
+
+  @override
+  String crateApiExecutionProvidersRocmRoCmExecutionProviderName({
+    required ROCmExecutionProvider that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_ro_cm_execution_provider(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersRocmRoCmExecutionProviderNameConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersRocmRoCmExecutionProviderNameConstMeta =>
+      const TaskConstMeta(
+        debugName: "ro_cm_execution_provider_name",
+        argNames: ["that"],
+      );
+
+  @override
+  ROCmExecutionProvider
+  crateApiExecutionProvidersRocmRoCmExecutionProviderNew() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_ro_cm_execution_provider,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersRocmRoCmExecutionProviderNewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersRocmRoCmExecutionProviderNewConstMeta =>
+      const TaskConstMeta(
+        debugName: "ro_cm_execution_provider_new",
+        argNames: [],
+      );
+
+  @override
+  bool crateApiExecutionProvidersRocmRoCmExecutionProviderSupportedByPlatform({
+    required ROCmExecutionProvider that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_ro_cm_execution_provider(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersRocmRoCmExecutionProviderSupportedByPlatformConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersRocmRoCmExecutionProviderSupportedByPlatformConstMeta =>
+      const TaskConstMeta(
+        debugName: "ro_cm_execution_provider_supported_by_platform",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<SessionImpl> crateApiSessionSessionBuilderOptionsCommitFromFile({
+    required SessionBuilderOptions that,
+    required String modelFilepath,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_session_builder_options(that, serializer);
+          sse_encode_String(modelFilepath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 92,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSessionSessionBuilderOptionsCommitFromFileConstMeta,
+        argValues: [that, modelFilepath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiSessionSessionBuilderOptionsCommitFromFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "session_builder_options_commit_from_file",
+        argNames: ["that", "modelFilepath"],
+      );
+
+  @override
+  Future<SessionImpl> crateApiSessionSessionBuilderOptionsCommitFromMemory({
+    required SessionBuilderOptions that,
+    required List<int> modelBytes,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_session_builder_options(that, serializer);
+          sse_encode_list_prim_u_8_loose(modelBytes, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 93,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta:
+            kCrateApiSessionSessionBuilderOptionsCommitFromMemoryConstMeta,
+        argValues: [that, modelBytes],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiSessionSessionBuilderOptionsCommitFromMemoryConstMeta =>
+      const TaskConstMeta(
+        debugName: "session_builder_options_commit_from_memory",
+        argNames: ["that", "modelBytes"],
+      );
+
+  @override
+  Future<SessionBuilderOptions> crateApiSessionSessionBuilderOptionsDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 94,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_session_builder_options,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSessionSessionBuilderOptionsDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSessionSessionBuilderOptionsDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "session_builder_options_default",
+        argNames: [],
+      );
+
+  @override
+  Future<TensorRTExecutionProvider>
+  crateApiExecutionProvidersTensorrtTensorRtExecutionProviderDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 95,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_tensor_rt_execution_provider,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersTensorrtTensorRtExecutionProviderDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersTensorrtTensorRtExecutionProviderDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "tensor_rt_execution_provider_default",
+        argNames: [],
+      );
+
+  @override
+  bool crateApiExecutionProvidersTensorrtTensorRtExecutionProviderIsAvailable({
+    required TensorRTExecutionProvider that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_tensor_rt_execution_provider(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersTensorrtTensorRtExecutionProviderIsAvailableConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersTensorrtTensorRtExecutionProviderIsAvailableConstMeta =>
+      const TaskConstMeta(
+        debugName: "tensor_rt_execution_provider_is_available",
+        argNames: ["that"],
+      );
+
+  @override
+  String crateApiExecutionProvidersTensorrtTensorRtExecutionProviderName({
+    required TensorRTExecutionProvider that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_tensor_rt_execution_provider(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersTensorrtTensorRtExecutionProviderNameConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersTensorrtTensorRtExecutionProviderNameConstMeta =>
+      const TaskConstMeta(
+        debugName: "tensor_rt_execution_provider_name",
+        argNames: ["that"],
+      );
+
+  @override
+  TensorRTExecutionProvider
+  crateApiExecutionProvidersTensorrtTensorRtExecutionProviderNew() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_tensor_rt_execution_provider,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersTensorrtTensorRtExecutionProviderNewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersTensorrtTensorRtExecutionProviderNewConstMeta =>
+      const TaskConstMeta(
+        debugName: "tensor_rt_execution_provider_new",
+        argNames: [],
+      );
+
+  @override
+  bool
+  crateApiExecutionProvidersTensorrtTensorRtExecutionProviderSupportedByPlatform({
+    required TensorRTExecutionProvider that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_tensor_rt_execution_provider(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersTensorrtTensorRtExecutionProviderSupportedByPlatformConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersTensorrtTensorRtExecutionProviderSupportedByPlatformConstMeta =>
+      const TaskConstMeta(
+        debugName: "tensor_rt_execution_provider_supported_by_platform",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<XNNPACKExecutionProvider>
+  crateApiExecutionProvidersXnnpackXnnpackExecutionProviderDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 100,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_xnnpack_execution_provider,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersXnnpackXnnpackExecutionProviderDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersXnnpackXnnpackExecutionProviderDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "xnnpack_execution_provider_default",
+        argNames: [],
+      );
+
+  @override
+  bool crateApiExecutionProvidersXnnpackXnnpackExecutionProviderIsAvailable({
+    required XNNPACKExecutionProvider that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_xnnpack_execution_provider(that, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 101,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersXnnpackXnnpackExecutionProviderIsAvailableConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersXnnpackXnnpackExecutionProviderIsAvailableConstMeta =>
+      const TaskConstMeta(
+        debugName: "xnnpack_execution_provider_is_available",
+        argNames: ["that"],
+      );
+
+  @override
+  String crateApiExecutionProvidersXnnpackXnnpackExecutionProviderName({
+    required XNNPACKExecutionProvider that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_xnnpack_execution_provider(that, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 102,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersXnnpackXnnpackExecutionProviderNameConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersXnnpackXnnpackExecutionProviderNameConstMeta =>
+      const TaskConstMeta(
+        debugName: "xnnpack_execution_provider_name",
+        argNames: ["that"],
+      );
+
+  @override
+  XNNPACKExecutionProvider
+  crateApiExecutionProvidersXnnpackXnnpackExecutionProviderNew() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 103,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_xnnpack_execution_provider,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersXnnpackXnnpackExecutionProviderNewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersXnnpackXnnpackExecutionProviderNewConstMeta =>
+      const TaskConstMeta(
+        debugName: "xnnpack_execution_provider_new",
+        argNames: [],
+      );
+
+  @override
+  bool
+  crateApiExecutionProvidersXnnpackXnnpackExecutionProviderSupportedByPlatform({
+    required XNNPACKExecutionProvider that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_xnnpack_execution_provider(that, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 104,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiExecutionProvidersXnnpackXnnpackExecutionProviderSupportedByPlatformConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiExecutionProvidersXnnpackXnnpackExecutionProviderSupportedByPlatformConstMeta =>
+      const TaskConstMeta(
+        debugName: "xnnpack_execution_provider_supported_by_platform",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<List<Map<String, TensorImpl>>> crateApiSessionSessionImplRunBatch({
+    required SessionImpl that,
+    required List<Map<String, TensorImpl>> batch,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl(
+            that,
+            serializer,
+          );
+          sse_encode_list_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None(
+            batch,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 105,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_list_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSessionSessionImplRunBatchConstMeta,
+        argValues: [that, batch],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSessionSessionImplRunBatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "SessionImpl_run_batch",
+        argNames: ["that", "batch"],
+      );
+
+  @override
+  BatchScheduler crateApiSessionSchedulerBatchSchedulerNew({
+    required SessionImpl session,
+    required BatchSchedulerOptions options,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl(
+            session,
+            serializer,
+          );
+          sse_encode_box_autoadd_batch_scheduler_options(options, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 106,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSessionSchedulerBatchSchedulerNewConstMeta,
+        argValues: [session, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSessionSchedulerBatchSchedulerNewConstMeta =>
+      const TaskConstMeta(
+        debugName: "BatchScheduler_new",
+        argNames: ["session", "options"],
+      );
+
+  @override
+  Future<Map<String, TensorImpl>> crateApiSessionSchedulerBatchSchedulerRun({
+    required BatchScheduler that,
+    required Map<String, TensorImpl> inputValues,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler(
+            that,
+            serializer,
+          );
+          sse_encode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None(
+            inputValues,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 107,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSessionSchedulerBatchSchedulerRunConstMeta,
+        argValues: [that, inputValues],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSessionSchedulerBatchSchedulerRunConstMeta =>
+      const TaskConstMeta(
+        debugName: "BatchScheduler_run",
+        argNames: ["that", "inputValues"],
+      );
+
+  @override
+  Future<BatchSchedulerOptions>
+  crateApiSessionSchedulerBatchSchedulerOptionsDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 108,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_batch_scheduler_options,
+          decodeErrorData: null,
+        ),
+        constMeta:
+            kCrateApiSessionSchedulerBatchSchedulerOptionsDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiSessionSchedulerBatchSchedulerOptionsDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "batch_scheduler_options_default",
+        argNames: [],
+      );
+
+  @override
+  Future<Map<String, ValueImpl>> crateApiSessionSessionImplRunValues({
+    required SessionImpl that,
+    required Map<String, ValueImpl> inputValues,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl(
+            that,
+            serializer,
+          );
+          sse_encode_Map_String_value_impl_None(inputValues, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 109,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_Map_String_value_impl_None,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSessionSessionImplRunValuesConstMeta,
+        argValues: [that, inputValues],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSessionSessionImplRunValuesConstMeta =>
+      const TaskConstMeta(
+        debugName: "SessionImpl_run_values",
+        argNames: ["that", "inputValues"],
+      );
+
+  @override
+  Float64List crateApiTensorTensorImplExtractF64({required TensorImpl that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 110,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_f_64_strict,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplExtractF64ConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplExtractF64ConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_extract_f64",
+        argNames: ["that"],
+      );
+
+  @override
+  List<int> crateApiTensorTensorImplExtractI64({required TensorImpl that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 111,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_CastedPrimitive_i_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplExtractI64ConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplExtractI64ConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_extract_i64",
+        argNames: ["that"],
+      );
+
+  @override
+  List<int> crateApiTensorTensorImplExtractU64({required TensorImpl that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 112,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_CastedPrimitive_u_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplExtractU64ConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplExtractU64ConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_extract_u64",
+        argNames: ["that"],
+      );
+
+  @override
+  Float32List crateApiTensorTensorImplExtractF32({required TensorImpl that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 113,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_f_32_strict,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplExtractF32ConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplExtractF32ConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_extract_f32",
+        argNames: ["that"],
+      );
+
+  @override
+  Uint32List crateApiTensorTensorImplExtractU32({required TensorImpl that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 114,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_32_strict,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplExtractU32ConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplExtractU32ConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_extract_u32",
+        argNames: ["that"],
+      );
+
+  @override
+  Int32List crateApiTensorTensorImplExtractI32({required TensorImpl that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 115,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_i_32_strict,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplExtractI32ConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplExtractI32ConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_extract_i32",
+        argNames: ["that"],
+      );
+
+  @override
+  Uint16List crateApiTensorTensorImplExtractU16({required TensorImpl that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 116,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_16_strict,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplExtractU16ConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplExtractU16ConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_extract_u16",
+        argNames: ["that"],
+      );
+
+  @override
+  Int16List crateApiTensorTensorImplExtractI16({required TensorImpl that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 117,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_i_16_strict,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplExtractI16ConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplExtractI16ConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_extract_i16",
+        argNames: ["that"],
+      );
+
+  @override
+  Uint8List crateApiTensorTensorImplExtractU8({required TensorImpl that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 118,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplExtractU8ConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplExtractU8ConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_extract_u8",
+        argNames: ["that"],
+      );
+
+  @override
+  Int8List crateApiTensorTensorImplExtractI8({required TensorImpl that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 119,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_i_8_strict,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplExtractI8ConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplExtractI8ConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_extract_i8",
+        argNames: ["that"],
+      );
+
+  @override
+  List<bool> crateApiTensorTensorImplExtractBool({required TensorImpl that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 120,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplExtractBoolConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplExtractBoolConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_extract_bool",
+        argNames: ["that"],
+      );
+
+  @override
+  List<String> crateApiTensorTensorImplExtractString({
+    required TensorImpl that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 121,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplExtractStringConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplExtractStringConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_extract_string",
+        argNames: ["that"],
+      );
+
+  @override
+  TensorDataView crateApiTensorTensorImplDataView({required TensorImpl that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 122,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplDataViewConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplDataViewConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_data_view",
+        argNames: ["that"],
+      );
+
+  @override
+  int crateApiTensorTensorDataViewPtr({required TensorDataView that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 123,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_CastedPrimitive_usize,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTensorTensorDataViewPtrConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorDataViewPtrConstMeta =>
+      const TaskConstMeta(debugName: "TensorDataView_ptr", argNames: ["that"]);
+
+  @override
+  int crateApiTensorTensorDataViewLen({required TensorDataView that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 124,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_CastedPrimitive_usize,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTensorTensorDataViewLenConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorDataViewLenConstMeta =>
+      const TaskConstMeta(debugName: "TensorDataView_len", argNames: ["that"]);
+
+  @override
+  bool crateApiTensorTensorDataViewIsEmpty({required TensorDataView that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 125,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTensorTensorDataViewIsEmptyConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorDataViewIsEmptyConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorDataView_is_empty",
+        argNames: ["that"],
+      );
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_MemoryInfoPtr;
+  @override
+  int crateApiTensorTensorDataViewByteLen({required TensorDataView that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 126,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_CastedPrimitive_usize,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTensorTensorDataViewByteLenConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_SessionImpl;
+  TaskConstMeta get kCrateApiTensorTensorDataViewByteLenConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorDataView_byte_len",
+        argNames: ["that"],
+      );
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_SessionImpl;
+  @override
+  TensorElementType crateApiTensorTensorDataViewDtype({
+    required TensorDataView that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 127,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_tensor_element_type,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTensorTensorDataViewDtypeConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SessionImplPtr;
+  TaskConstMeta get kCrateApiTensorTensorDataViewDtypeConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorDataView_dtype",
+        argNames: ["that"],
+      );
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_TensorImpl;
+  @override
+  List<int> crateApiTensorTensorDataViewShape({required TensorDataView that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 128,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_CastedPrimitive_i_64,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTensorTensorDataViewShapeConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_TensorImpl;
+  TaskConstMeta get kCrateApiTensorTensorDataViewShapeConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorDataView_shape",
+        argNames: ["that"],
+      );
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_TensorImplPtr;
-}
+  @override
+  TensorImpl crateApiTensorTensorImplFromArrayF16({
+    List<int>? shape,
+    required List<double> data,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
+          sse_encode_list_prim_f_32_loose(data, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 129,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplFromArrayF16ConstMeta,
+        argValues: [shape, data],
+        apiImpl: this,
+      ),
+    );
+  }
 
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
+  TaskConstMeta get kCrateApiTensorTensorImplFromArrayF16ConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_from_array_f16",
+        argNames: ["shape", "data"],
+      );
 
   @override
-  AllocationDevice crateApiMemoryMemoryInfoAllocationDevice({
-    required MemoryInfo that,
+  TensorImpl crateApiTensorTensorImplFromArrayF16Bits({
+    List<int>? shape,
+    required List<int> data,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
+          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
+          sse_encode_list_prim_u_16_loose(data, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 130,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplFromArrayF16BitsConstMeta,
+        argValues: [shape, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplFromArrayF16BitsConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_from_array_f16_bits",
+        argNames: ["shape", "data"],
+      );
+
+  @override
+  Float32List crateApiTensorTensorImplExtractF16({required TensorImpl that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 131,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_allocation_device,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_list_prim_f_32_strict,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMemoryMemoryInfoAllocationDeviceConstMeta,
+        constMeta: kCrateApiTensorTensorImplExtractF16ConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplExtractF16ConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_extract_f16",
+        argNames: ["that"],
+      );
+
+  @override
+  Uint16List crateApiTensorTensorImplExtractF16Bits({
+    required TensorImpl that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 132,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_16_strict,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplExtractF16BitsConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMemoryMemoryInfoAllocationDeviceConstMeta =>
+  TaskConstMeta get kCrateApiTensorTensorImplExtractF16BitsConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_extract_f16_bits",
+        argNames: ["that"],
+      );
+
+  @override
+  TensorImpl crateApiTensorTensorImplFromArrayBf16({
+    List<int>? shape,
+    required List<double> data,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
+          sse_encode_list_prim_f_32_loose(data, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 133,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplFromArrayBf16ConstMeta,
+        argValues: [shape, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplFromArrayBf16ConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_from_array_bf16",
+        argNames: ["shape", "data"],
+      );
+
+  @override
+  TensorImpl crateApiTensorTensorImplFromArrayBf16Bits({
+    List<int>? shape,
+    required List<int> data,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
+          sse_encode_list_prim_u_16_loose(data, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 134,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorTensorImplFromArrayBf16BitsConstMeta,
+        argValues: [shape, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorTensorImplFromArrayBf16BitsConstMeta =>
       const TaskConstMeta(
-        debugName: "MemoryInfo_allocation_device",
-        argNames: ["that"],
+        debugName: "TensorImpl_from_array_bf16_bits",
+        argNames: ["shape", "data"],
       );
 
   @override
-  AllocatorType crateApiMemoryMemoryInfoAllocatorType({
-    required MemoryInfo that,
-  }) {
+  Float32List crateApiTensorTensorImplExtractBf16({required TensorImpl that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 135,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_allocator_type,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_list_prim_f_32_strict,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMemoryMemoryInfoAllocatorTypeConstMeta,
+        constMeta: kCrateApiTensorTensorImplExtractBf16ConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMemoryMemoryInfoAllocatorTypeConstMeta =>
+  TaskConstMeta get kCrateApiTensorTensorImplExtractBf16ConstMeta =>
       const TaskConstMeta(
-        debugName: "MemoryInfo_allocator_type",
+        debugName: "TensorImpl_extract_bf16",
         argNames: ["that"],
       );
 
   @override
-  int crateApiMemoryMemoryInfoDeviceId({required MemoryInfo that}) {
+  Uint16List crateApiTensorTensorImplExtractBf16Bits({
+    required TensorImpl that,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 136,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_i_32,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_list_prim_u_16_strict,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMemoryMemoryInfoDeviceIdConstMeta,
+        constMeta: kCrateApiTensorTensorImplExtractBf16BitsConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMemoryMemoryInfoDeviceIdConstMeta =>
+  TaskConstMeta get kCrateApiTensorTensorImplExtractBf16BitsConstMeta =>
       const TaskConstMeta(
-        debugName: "MemoryInfo_device_id",
+        debugName: "TensorImpl_extract_bf16_bits",
         argNames: ["that"],
       );
 
   @override
-  DeviceType crateApiMemoryMemoryInfoDeviceType({required MemoryInfo that}) {
+  TensorImpl crateApiTensorTensorImplFromBytes({
+    required TensorElementType dtype,
+    List<int>? shape,
+    required List<int> bytes,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
-            that,
+          sse_encode_box_autoadd_tensor_element_type(dtype, serializer);
+          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
+          sse_encode_list_prim_u_8_loose(bytes, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
             serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+            funcId: 137,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_device_type,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMemoryMemoryInfoDeviceTypeConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiTensorTensorImplFromBytesConstMeta,
+        argValues: [dtype, shape, bytes],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMemoryMemoryInfoDeviceTypeConstMeta =>
+  TaskConstMeta get kCrateApiTensorTensorImplFromBytesConstMeta =>
       const TaskConstMeta(
-        debugName: "MemoryInfo_device_type",
-        argNames: ["that"],
+        debugName: "TensorImpl_from_bytes",
+        argNames: ["dtype", "shape", "bytes"],
       );
 
   @override
-  bool crateApiMemoryMemoryInfoIsCpuAccessible({required MemoryInfo that}) {
+  Uint8List crateApiTensorTensorImplToBytes({required TensorImpl that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 138,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMemoryMemoryInfoIsCpuAccessibleConstMeta,
+        constMeta: kCrateApiTensorTensorImplToBytesConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMemoryMemoryInfoIsCpuAccessibleConstMeta =>
-      const TaskConstMeta(
-        debugName: "MemoryInfo_is_cpu_accessible",
-        argNames: ["that"],
-      );
+  TaskConstMeta get kCrateApiTensorTensorImplToBytesConstMeta =>
+      const TaskConstMeta(debugName: "TensorImpl_to_bytes", argNames: ["that"]);
 
   @override
-  MemoryType crateApiMemoryMemoryInfoMemoryType({required MemoryInfo that}) {
+  TensorImpl crateApiTensorShapeTensorImplReshape({
+    required TensorImpl that,
+    required List<int> shape,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+          sse_encode_list_CastedPrimitive_i_64(shape, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 139,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_memory_type,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMemoryMemoryInfoMemoryTypeConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiTensorShapeTensorImplReshapeConstMeta,
+        argValues: [that, shape],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMemoryMemoryInfoMemoryTypeConstMeta =>
+  TaskConstMeta get kCrateApiTensorShapeTensorImplReshapeConstMeta =>
       const TaskConstMeta(
-        debugName: "MemoryInfo_memory_type",
-        argNames: ["that"],
+        debugName: "TensorImpl_reshape",
+        argNames: ["that", "shape"],
       );
 
   @override
-  SessionBuilderOptions crateApiSessionSessionImplBuilder() {
+  TensorImpl crateApiTensorShapeTensorImplPermute({
+    required TensorImpl that,
+    required List<int> axes,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          sse_encode_list_CastedPrimitive_i_64(axes, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 140,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_session_builder_options,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSessionSessionImplBuilderConstMeta,
-        argValues: [],
+        constMeta: kCrateApiTensorShapeTensorImplPermuteConstMeta,
+        argValues: [that, axes],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSessionSessionImplBuilderConstMeta =>
-      const TaskConstMeta(debugName: "SessionImpl_builder", argNames: []);
+  TaskConstMeta get kCrateApiTensorShapeTensorImplPermuteConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_permute",
+        argNames: ["that", "axes"],
+      );
 
   @override
-  List<Input> crateApiSessionSessionImplInputs({required SessionImpl that}) {
+  TensorImpl crateApiTensorShapeTensorImplTranspose({
+    required TensorImpl that,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 141,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_input,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSessionSessionImplInputsConstMeta,
+        constMeta: kCrateApiTensorShapeTensorImplTransposeConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSessionSessionImplInputsConstMeta =>
-      const TaskConstMeta(debugName: "SessionImpl_inputs", argNames: ["that"]);
+  TaskConstMeta get kCrateApiTensorShapeTensorImplTransposeConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_transpose",
+        argNames: ["that"],
+      );
 
   @override
-  List<Output> crateApiSessionSessionImplOutputs({required SessionImpl that}) {
+  TensorImpl crateApiTensorShapeTensorImplSqueeze({
+    required TensorImpl that,
+    List<int>? axes,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+          sse_encode_opt_list_CastedPrimitive_i_64(axes, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 142,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_output,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSessionSessionImplOutputsConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiTensorShapeTensorImplSqueezeConstMeta,
+        argValues: [that, axes],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSessionSessionImplOutputsConstMeta =>
-      const TaskConstMeta(debugName: "SessionImpl_outputs", argNames: ["that"]);
+  TaskConstMeta get kCrateApiTensorShapeTensorImplSqueezeConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_squeeze",
+        argNames: ["that", "axes"],
+      );
 
   @override
-  Future<Map<String, TensorImpl>> crateApiSessionSessionImplRun({
-    required SessionImpl that,
-    required Map<String, TensorImpl> inputValues,
+  TensorImpl crateApiTensorShapeTensorImplUnsqueeze({
+    required TensorImpl that,
+    required List<int> axes,
   }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
             that,
             serializer,
           );
-          sse_encode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None(
-            inputValues,
-            serializer,
-          );
-          pdeCallFfi(
+          sse_encode_list_CastedPrimitive_i_64(axes, serializer);
+          return pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 10,
-            port: port_,
-          );
+            funcId: 143,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None,
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSessionSessionImplRunConstMeta,
-        argValues: [that, inputValues],
+        constMeta: kCrateApiTensorShapeTensorImplUnsqueezeConstMeta,
+        argValues: [that, axes],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSessionSessionImplRunConstMeta =>
+  TaskConstMeta get kCrateApiTensorShapeTensorImplUnsqueezeConstMeta =>
       const TaskConstMeta(
-        debugName: "SessionImpl_run",
-        argNames: ["that", "inputValues"],
+        debugName: "TensorImpl_unsqueeze",
+        argNames: ["that", "axes"],
       );
 
   @override
-  TensorImpl crateApiTensorTensorImplClone({required TensorImpl that}) {
+  TensorImpl crateApiTensorShapeTensorImplSlice({
+    required TensorImpl that,
+    required List<int> starts,
+    required List<int> ends,
+    List<int>? axes,
+    List<int>? steps,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
@@ -778,2722 +5414,3241 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
+          sse_encode_list_CastedPrimitive_i_64(starts, serializer);
+          sse_encode_list_CastedPrimitive_i_64(ends, serializer);
+          sse_encode_opt_list_CastedPrimitive_i_64(axes, serializer);
+          sse_encode_opt_list_CastedPrimitive_i_64(steps, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 144,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplCloneConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiTensorShapeTensorImplSliceConstMeta,
+        argValues: [that, starts, ends, axes, steps],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplCloneConstMeta =>
-      const TaskConstMeta(debugName: "TensorImpl_clone", argNames: ["that"]);
+  TaskConstMeta get kCrateApiTensorShapeTensorImplSliceConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_slice",
+        argNames: ["that", "starts", "ends", "axes", "steps"],
+      );
 
   @override
-  TensorElementType crateApiTensorTensorImplDtype({required TensorImpl that}) {
+  TensorImpl crateApiTensorShapeTensorImplConcat({
+    required List<TensorImpl> tensors,
+    required int axis,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
-            that,
+          sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            tensors,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
+          sse_encode_CastedPrimitive_i_64(axis, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 145,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_tensor_element_type,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplDtypeConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiTensorShapeTensorImplConcatConstMeta,
+        argValues: [tensors, axis],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplDtypeConstMeta =>
-      const TaskConstMeta(debugName: "TensorImpl_dtype", argNames: ["that"]);
+  TaskConstMeta get kCrateApiTensorShapeTensorImplConcatConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_concat",
+        argNames: ["tensors", "axis"],
+      );
 
   @override
-  void crateApiTensorTensorImplFreeBoolPointer({required ArrayPointer arr}) {
+  TensorImpl crateApiTensorShapeTensorImplStack({
+    required List<TensorImpl> tensors,
+    required int axis,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_array_pointer(arr, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+          sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            tensors,
+            serializer,
+          );
+          sse_encode_CastedPrimitive_i_64(axis, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 146,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplFreeBoolPointerConstMeta,
-        argValues: [arr],
+        constMeta: kCrateApiTensorShapeTensorImplStackConstMeta,
+        argValues: [tensors, axis],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplFreeBoolPointerConstMeta =>
+  TaskConstMeta get kCrateApiTensorShapeTensorImplStackConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_free_bool_pointer",
-        argNames: ["arr"],
+        debugName: "TensorImpl_stack",
+        argNames: ["tensors", "axis"],
       );
 
   @override
-  void crateApiTensorTensorImplFreeF32Pointer({required ArrayPointer arr}) {
+  TensorImpl crateApiTensorOpsTensorImplSoftmax({
+    required TensorImpl that,
+    required int axis,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_array_pointer(arr, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          sse_encode_CastedPrimitive_i_64(axis, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 147,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplFreeF32PointerConstMeta,
-        argValues: [arr],
+        constMeta: kCrateApiTensorOpsTensorImplSoftmaxConstMeta,
+        argValues: [that, axis],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplFreeF32PointerConstMeta =>
+  TaskConstMeta get kCrateApiTensorOpsTensorImplSoftmaxConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_free_f32_pointer",
-        argNames: ["arr"],
+        debugName: "TensorImpl_softmax",
+        argNames: ["that", "axis"],
       );
 
   @override
-  void crateApiTensorTensorImplFreeF64Pointer({required ArrayPointer arr}) {
+  TensorImpl crateApiTensorOpsTensorImplLogSoftmax({
+    required TensorImpl that,
+    required int axis,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_array_pointer(arr, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          sse_encode_CastedPrimitive_i_64(axis, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 148,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplFreeF64PointerConstMeta,
-        argValues: [arr],
+        constMeta: kCrateApiTensorOpsTensorImplLogSoftmaxConstMeta,
+        argValues: [that, axis],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplFreeF64PointerConstMeta =>
+  TaskConstMeta get kCrateApiTensorOpsTensorImplLogSoftmaxConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_free_f64_pointer",
-        argNames: ["arr"],
+        debugName: "TensorImpl_log_softmax",
+        argNames: ["that", "axis"],
       );
 
   @override
-  void crateApiTensorTensorImplFreeI16Pointer({required ArrayPointer arr}) {
+  TensorImpl crateApiTensorOpsTensorImplSigmoid({required TensorImpl that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_array_pointer(arr, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 149,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplFreeI16PointerConstMeta,
-        argValues: [arr],
+        constMeta: kCrateApiTensorOpsTensorImplSigmoidConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplFreeI16PointerConstMeta =>
-      const TaskConstMeta(
-        debugName: "TensorImpl_free_i16_pointer",
-        argNames: ["arr"],
-      );
+  TaskConstMeta get kCrateApiTensorOpsTensorImplSigmoidConstMeta =>
+      const TaskConstMeta(debugName: "TensorImpl_sigmoid", argNames: ["that"]);
 
   @override
-  void crateApiTensorTensorImplFreeI32Pointer({required ArrayPointer arr}) {
+  TensorImpl crateApiTensorOpsTensorImplArgmax({
+    required TensorImpl that,
+    required int axis,
+    required bool keepDims,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_array_pointer(arr, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          sse_encode_CastedPrimitive_i_64(axis, serializer);
+          sse_encode_bool(keepDims, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 150,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplFreeI32PointerConstMeta,
-        argValues: [arr],
+        constMeta: kCrateApiTensorOpsTensorImplArgmaxConstMeta,
+        argValues: [that, axis, keepDims],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplFreeI32PointerConstMeta =>
+  TaskConstMeta get kCrateApiTensorOpsTensorImplArgmaxConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_free_i32_pointer",
-        argNames: ["arr"],
+        debugName: "TensorImpl_argmax",
+        argNames: ["that", "axis", "keepDims"],
       );
 
   @override
-  void crateApiTensorTensorImplFreeI64Pointer({required ArrayPointer arr}) {
+  TensorImpl crateApiTensorOpsTensorImplArgmin({
+    required TensorImpl that,
+    required int axis,
+    required bool keepDims,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_array_pointer(arr, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          sse_encode_CastedPrimitive_i_64(axis, serializer);
+          sse_encode_bool(keepDims, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 151,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplFreeI64PointerConstMeta,
-        argValues: [arr],
+        constMeta: kCrateApiTensorOpsTensorImplArgminConstMeta,
+        argValues: [that, axis, keepDims],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplFreeI64PointerConstMeta =>
+  TaskConstMeta get kCrateApiTensorOpsTensorImplArgminConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_free_i64_pointer",
-        argNames: ["arr"],
+        debugName: "TensorImpl_argmin",
+        argNames: ["that", "axis", "keepDims"],
       );
 
   @override
-  void crateApiTensorTensorImplFreeI8Pointer({required ArrayPointer arr}) {
+  TopK crateApiTensorOpsTensorImplTopK({
+    required TensorImpl that,
+    required int k,
+    int? axis,
+    bool? largest,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_array_pointer(arr, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          sse_encode_CastedPrimitive_usize(k, serializer);
+          sse_encode_opt_CastedPrimitive_i_64(axis, serializer);
+          sse_encode_opt_box_autoadd_bool(largest, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 152,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_top_k,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplFreeI8PointerConstMeta,
-        argValues: [arr],
+        constMeta: kCrateApiTensorOpsTensorImplTopKConstMeta,
+        argValues: [that, k, axis, largest],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplFreeI8PointerConstMeta =>
+  TaskConstMeta get kCrateApiTensorOpsTensorImplTopKConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_free_i8_pointer",
-        argNames: ["arr"],
+        debugName: "TensorImpl_top_k",
+        argNames: ["that", "k", "axis", "largest"],
       );
 
   @override
-  void crateApiTensorTensorImplFreeStringPointer({required ArrayPointer arr}) {
+  TensorImpl crateApiTensorOpsTensorImplSum({
+    required TensorImpl that,
+    List<int>? axes,
+    required bool keepDims,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_array_pointer(arr, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          sse_encode_opt_list_CastedPrimitive_i_64(axes, serializer);
+          sse_encode_bool(keepDims, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 153,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplFreeStringPointerConstMeta,
-        argValues: [arr],
+        constMeta: kCrateApiTensorOpsTensorImplSumConstMeta,
+        argValues: [that, axes, keepDims],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplFreeStringPointerConstMeta =>
+  TaskConstMeta get kCrateApiTensorOpsTensorImplSumConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_free_string_pointer",
-        argNames: ["arr"],
+        debugName: "TensorImpl_sum",
+        argNames: ["that", "axes", "keepDims"],
       );
 
   @override
-  void crateApiTensorTensorImplFreeU16Pointer({required ArrayPointer arr}) {
+  TensorImpl crateApiTensorOpsTensorImplMean({
+    required TensorImpl that,
+    List<int>? axes,
+    required bool keepDims,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_array_pointer(arr, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          sse_encode_opt_list_CastedPrimitive_i_64(axes, serializer);
+          sse_encode_bool(keepDims, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 154,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplFreeU16PointerConstMeta,
-        argValues: [arr],
+        constMeta: kCrateApiTensorOpsTensorImplMeanConstMeta,
+        argValues: [that, axes, keepDims],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplFreeU16PointerConstMeta =>
+  TaskConstMeta get kCrateApiTensorOpsTensorImplMeanConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_free_u16_pointer",
-        argNames: ["arr"],
+        debugName: "TensorImpl_mean",
+        argNames: ["that", "axes", "keepDims"],
       );
 
   @override
-  void crateApiTensorTensorImplFreeU32Pointer({required ArrayPointer arr}) {
+  TensorImpl crateApiTensorOpsTensorImplMax({
+    required TensorImpl that,
+    List<int>? axes,
+    required bool keepDims,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_array_pointer(arr, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          sse_encode_opt_list_CastedPrimitive_i_64(axes, serializer);
+          sse_encode_bool(keepDims, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 155,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplFreeU32PointerConstMeta,
-        argValues: [arr],
+        constMeta: kCrateApiTensorOpsTensorImplMaxConstMeta,
+        argValues: [that, axes, keepDims],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplFreeU32PointerConstMeta =>
+  TaskConstMeta get kCrateApiTensorOpsTensorImplMaxConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_free_u32_pointer",
-        argNames: ["arr"],
+        debugName: "TensorImpl_max",
+        argNames: ["that", "axes", "keepDims"],
       );
 
   @override
-  void crateApiTensorTensorImplFreeU64Pointer({required ArrayPointer arr}) {
+  TensorImpl crateApiTensorOpsTensorImplMin({
+    required TensorImpl that,
+    List<int>? axes,
+    required bool keepDims,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_array_pointer(arr, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          sse_encode_opt_list_CastedPrimitive_i_64(axes, serializer);
+          sse_encode_bool(keepDims, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 156,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplFreeU64PointerConstMeta,
-        argValues: [arr],
+        constMeta: kCrateApiTensorOpsTensorImplMinConstMeta,
+        argValues: [that, axes, keepDims],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplFreeU64PointerConstMeta =>
+  TaskConstMeta get kCrateApiTensorOpsTensorImplMinConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_free_u64_pointer",
-        argNames: ["arr"],
+        debugName: "TensorImpl_min",
+        argNames: ["that", "axes", "keepDims"],
       );
 
   @override
-  void crateApiTensorTensorImplFreeU8Pointer({required ArrayPointer arr}) {
+  TensorImpl crateApiTensorArithmeticTensorImplAdd({
+    required TensorImpl that,
+    required TensorImpl other,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_array_pointer(arr, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            other,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 157,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplFreeU8PointerConstMeta,
-        argValues: [arr],
+        constMeta: kCrateApiTensorArithmeticTensorImplAddConstMeta,
+        argValues: [that, other],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplFreeU8PointerConstMeta =>
+  TaskConstMeta get kCrateApiTensorArithmeticTensorImplAddConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_free_u8_pointer",
-        argNames: ["arr"],
+        debugName: "TensorImpl_add",
+        argNames: ["that", "other"],
       );
 
   @override
-  TensorImpl crateApiTensorTensorImplFromArrayBool({
-    List<int>? shape,
-    required List<bool> data,
+  TensorImpl crateApiTensorArithmeticTensorImplSub({
+    required TensorImpl that,
+    required TensorImpl other,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
-          sse_encode_list_bool(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            other,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 158,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplFromArrayBoolConstMeta,
-        argValues: [shape, data],
+        constMeta: kCrateApiTensorArithmeticTensorImplSubConstMeta,
+        argValues: [that, other],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplFromArrayBoolConstMeta =>
+  TaskConstMeta get kCrateApiTensorArithmeticTensorImplSubConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_from_array_bool",
-        argNames: ["shape", "data"],
+        debugName: "TensorImpl_sub",
+        argNames: ["that", "other"],
       );
 
   @override
-  TensorImpl crateApiTensorTensorImplFromArrayF32({
-    List<int>? shape,
-    required List<double> data,
+  TensorImpl crateApiTensorArithmeticTensorImplMul({
+    required TensorImpl that,
+    required TensorImpl other,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
-          sse_encode_list_prim_f_32_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            other,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 159,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplFromArrayF32ConstMeta,
-        argValues: [shape, data],
+        constMeta: kCrateApiTensorArithmeticTensorImplMulConstMeta,
+        argValues: [that, other],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplFromArrayF32ConstMeta =>
+  TaskConstMeta get kCrateApiTensorArithmeticTensorImplMulConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_from_array_f32",
-        argNames: ["shape", "data"],
+        debugName: "TensorImpl_mul",
+        argNames: ["that", "other"],
       );
 
   @override
-  TensorImpl crateApiTensorTensorImplFromArrayF64({
-    List<int>? shape,
-    required List<double> data,
+  TensorImpl crateApiTensorArithmeticTensorImplDiv({
+    required TensorImpl that,
+    required TensorImpl other,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
-          sse_encode_list_prim_f_64_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            other,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 160,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplFromArrayF64ConstMeta,
-        argValues: [shape, data],
+        constMeta: kCrateApiTensorArithmeticTensorImplDivConstMeta,
+        argValues: [that, other],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplFromArrayF64ConstMeta =>
+  TaskConstMeta get kCrateApiTensorArithmeticTensorImplDivConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_from_array_f64",
-        argNames: ["shape", "data"],
+        debugName: "TensorImpl_div",
+        argNames: ["that", "other"],
       );
 
   @override
-  TensorImpl crateApiTensorTensorImplFromArrayI16({
-    List<int>? shape,
-    required List<int> data,
+  TensorImpl crateApiTensorArithmeticTensorImplPow({
+    required TensorImpl that,
+    required TensorImpl other,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
-          sse_encode_list_prim_i_16_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            other,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 161,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplFromArrayI16ConstMeta,
-        argValues: [shape, data],
+        constMeta: kCrateApiTensorArithmeticTensorImplPowConstMeta,
+        argValues: [that, other],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplFromArrayI16ConstMeta =>
+  TaskConstMeta get kCrateApiTensorArithmeticTensorImplPowConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_from_array_i16",
-        argNames: ["shape", "data"],
+        debugName: "TensorImpl_pow",
+        argNames: ["that", "other"],
       );
 
   @override
-  TensorImpl crateApiTensorTensorImplFromArrayI32({
-    List<int>? shape,
-    required List<int> data,
+  TensorImpl crateApiTensorArithmeticTensorImplAddScalar({
+    required TensorImpl that,
+    required double value,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
-          sse_encode_list_prim_i_32_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          sse_encode_f_64(value, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 162,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplFromArrayI32ConstMeta,
-        argValues: [shape, data],
+        constMeta: kCrateApiTensorArithmeticTensorImplAddScalarConstMeta,
+        argValues: [that, value],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplFromArrayI32ConstMeta =>
+  TaskConstMeta get kCrateApiTensorArithmeticTensorImplAddScalarConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_from_array_i32",
-        argNames: ["shape", "data"],
+        debugName: "TensorImpl_add_scalar",
+        argNames: ["that", "value"],
       );
 
   @override
-  TensorImpl crateApiTensorTensorImplFromArrayI64({
-    List<int>? shape,
-    required List<int> data,
+  TensorImpl crateApiTensorArithmeticTensorImplSubScalar({
+    required TensorImpl that,
+    required double value,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
-          sse_encode_list_CastedPrimitive_i_64(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          sse_encode_f_64(value, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 163,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplFromArrayI64ConstMeta,
-        argValues: [shape, data],
+        constMeta: kCrateApiTensorArithmeticTensorImplSubScalarConstMeta,
+        argValues: [that, value],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplFromArrayI64ConstMeta =>
+  TaskConstMeta get kCrateApiTensorArithmeticTensorImplSubScalarConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_from_array_i64",
-        argNames: ["shape", "data"],
+        debugName: "TensorImpl_sub_scalar",
+        argNames: ["that", "value"],
       );
 
   @override
-  TensorImpl crateApiTensorTensorImplFromArrayI8({
-    List<int>? shape,
-    required List<int> data,
+  TensorImpl crateApiTensorArithmeticTensorImplMulScalar({
+    required TensorImpl that,
+    required double value,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
-          sse_encode_list_prim_i_8_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          sse_encode_f_64(value, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 164,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplFromArrayI8ConstMeta,
-        argValues: [shape, data],
+        constMeta: kCrateApiTensorArithmeticTensorImplMulScalarConstMeta,
+        argValues: [that, value],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplFromArrayI8ConstMeta =>
+  TaskConstMeta get kCrateApiTensorArithmeticTensorImplMulScalarConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_from_array_i8",
-        argNames: ["shape", "data"],
+        debugName: "TensorImpl_mul_scalar",
+        argNames: ["that", "value"],
       );
 
   @override
-  TensorImpl crateApiTensorTensorImplFromArrayString({
-    List<int>? shape,
-    required List<String> data,
+  TensorImpl crateApiTensorArithmeticTensorImplDivScalar({
+    required TensorImpl that,
+    required double value,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
-          sse_encode_list_String(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          sse_encode_f_64(value, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 165,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplFromArrayStringConstMeta,
-        argValues: [shape, data],
+        constMeta: kCrateApiTensorArithmeticTensorImplDivScalarConstMeta,
+        argValues: [that, value],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplFromArrayStringConstMeta =>
+  TaskConstMeta get kCrateApiTensorArithmeticTensorImplDivScalarConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_from_array_string",
-        argNames: ["shape", "data"],
+        debugName: "TensorImpl_div_scalar",
+        argNames: ["that", "value"],
       );
 
   @override
-  TensorImpl crateApiTensorTensorImplFromArrayU16({
-    List<int>? shape,
-    required List<int> data,
+  TensorImpl crateApiTensorArithmeticTensorImplPowScalar({
+    required TensorImpl that,
+    required double value,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
-          sse_encode_list_prim_u_16_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          sse_encode_f_64(value, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 166,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplFromArrayU16ConstMeta,
-        argValues: [shape, data],
+        constMeta: kCrateApiTensorArithmeticTensorImplPowScalarConstMeta,
+        argValues: [that, value],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplFromArrayU16ConstMeta =>
+  TaskConstMeta get kCrateApiTensorArithmeticTensorImplPowScalarConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_from_array_u16",
-        argNames: ["shape", "data"],
+        debugName: "TensorImpl_pow_scalar",
+        argNames: ["that", "value"],
       );
 
   @override
-  TensorImpl crateApiTensorTensorImplFromArrayU32({
-    List<int>? shape,
-    required List<int> data,
+  TensorImpl crateApiTensorArithmeticTensorImplClamp({
+    required TensorImpl that,
+    double? min,
+    double? max,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
-          sse_encode_list_prim_u_32_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          sse_encode_opt_box_autoadd_f_64(min, serializer);
+          sse_encode_opt_box_autoadd_f_64(max, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 167,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplFromArrayU32ConstMeta,
-        argValues: [shape, data],
+        constMeta: kCrateApiTensorArithmeticTensorImplClampConstMeta,
+        argValues: [that, min, max],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplFromArrayU32ConstMeta =>
+  TaskConstMeta get kCrateApiTensorArithmeticTensorImplClampConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_from_array_u32",
-        argNames: ["shape", "data"],
+        debugName: "TensorImpl_clamp",
+        argNames: ["that", "min", "max"],
       );
 
   @override
-  TensorImpl crateApiTensorTensorImplFromArrayU64({
-    List<int>? shape,
-    required List<int> data,
+  TensorImpl crateApiTensorCastTensorImplCast({
+    required TensorImpl that,
+    required TensorElementType target,
+    CastMode? mode,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
-          sse_encode_list_CastedPrimitive_u_64(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          sse_encode_box_autoadd_tensor_element_type(target, serializer);
+          sse_encode_opt_box_autoadd_cast_mode(mode, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 168,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplFromArrayU64ConstMeta,
-        argValues: [shape, data],
+        constMeta: kCrateApiTensorCastTensorImplCastConstMeta,
+        argValues: [that, target, mode],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplFromArrayU64ConstMeta =>
+  TaskConstMeta get kCrateApiTensorCastTensorImplCastConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_from_array_u64",
-        argNames: ["shape", "data"],
+        debugName: "TensorImpl_cast",
+        argNames: ["that", "target", "mode"],
       );
 
   @override
-  TensorImpl crateApiTensorTensorImplFromArrayU8({
-    List<int>? shape,
-    required List<int> data,
-  }) {
+  TensorImpl crateApiTensorNpyTensorImplFromNpy({required List<int> bytes}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+          sse_encode_list_prim_u_8_loose(bytes, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 169,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplFromArrayU8ConstMeta,
-        argValues: [shape, data],
+        constMeta: kCrateApiTensorNpyTensorImplFromNpyConstMeta,
+        argValues: [bytes],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplFromArrayU8ConstMeta =>
+  TaskConstMeta get kCrateApiTensorNpyTensorImplFromNpyConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_from_array_u8",
-        argNames: ["shape", "data"],
+        debugName: "TensorImpl_from_npy",
+        argNames: ["bytes"],
       );
 
   @override
-  ArrayPointer crateApiTensorTensorImplGetDataBoolPointer({
-    required TensorImpl that,
-  }) {
+  Uint8List crateApiTensorNpyTensorImplToNpy({required TensorImpl that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 170,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_array_pointer,
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplGetDataBoolPointerConstMeta,
+        constMeta: kCrateApiTensorNpyTensorImplToNpyConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplGetDataBoolPointerConstMeta =>
-      const TaskConstMeta(
-        debugName: "TensorImpl_get_data_bool_pointer",
-        argNames: ["that"],
-      );
+  TaskConstMeta get kCrateApiTensorNpyTensorImplToNpyConstMeta =>
+      const TaskConstMeta(debugName: "TensorImpl_to_npy", argNames: ["that"]);
 
   @override
-  ArrayPointer crateApiTensorTensorImplGetDataF32Pointer({
-    required TensorImpl that,
+  Future<TensorImpl> crateApiTensorNpyTensorImplLoadNpy({
+    required String path,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
-            that,
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
             serializer,
+            funcId: 171,
+            port: port_,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_array_pointer,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplGetDataF32PointerConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiTensorNpyTensorImplLoadNpyConstMeta,
+        argValues: [path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplGetDataF32PointerConstMeta =>
-      const TaskConstMeta(
-        debugName: "TensorImpl_get_data_f32_pointer",
-        argNames: ["that"],
-      );
+  TaskConstMeta get kCrateApiTensorNpyTensorImplLoadNpyConstMeta =>
+      const TaskConstMeta(debugName: "TensorImpl_load_npy", argNames: ["path"]);
 
   @override
-  ArrayPointer crateApiTensorTensorImplGetDataF64Pointer({
+  Future<void> crateApiTensorNpyTensorImplSaveNpy({
     required TensorImpl that,
+    required String path,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 172,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_array_pointer,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplGetDataF64PointerConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiTensorNpyTensorImplSaveNpyConstMeta,
+        argValues: [that, path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplGetDataF64PointerConstMeta =>
+  TaskConstMeta get kCrateApiTensorNpyTensorImplSaveNpyConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_get_data_f64_pointer",
-        argNames: ["that"],
+        debugName: "TensorImpl_save_npy",
+        argNames: ["that", "path"],
       );
 
   @override
-  ArrayPointer crateApiTensorTensorImplGetDataI16Pointer({
-    required TensorImpl that,
-  }) {
+  Map<String, TensorImpl> crateApiTensorNpyReadNpz({required List<int> bytes}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
-            that,
+          sse_encode_list_prim_u_8_loose(bytes, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
             serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+            funcId: 173,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_array_pointer,
+          decodeSuccessData:
+              sse_decode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplGetDataI16PointerConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiTensorNpyReadNpzConstMeta,
+        argValues: [bytes],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplGetDataI16PointerConstMeta =>
-      const TaskConstMeta(
-        debugName: "TensorImpl_get_data_i16_pointer",
-        argNames: ["that"],
-      );
+  TaskConstMeta get kCrateApiTensorNpyReadNpzConstMeta =>
+      const TaskConstMeta(debugName: "read_npz", argNames: ["bytes"]);
 
   @override
-  ArrayPointer crateApiTensorTensorImplGetDataI32Pointer({
-    required TensorImpl that,
+  Uint8List crateApiTensorNpyWriteNpz({
+    required Map<String, TensorImpl> tensors,
+    required bool compressed,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
-            that,
+          sse_encode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None(
+            tensors,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
+          sse_encode_bool(compressed, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 174,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_array_pointer,
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplGetDataI32PointerConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiTensorNpyWriteNpzConstMeta,
+        argValues: [tensors, compressed],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplGetDataI32PointerConstMeta =>
+  TaskConstMeta get kCrateApiTensorNpyWriteNpzConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_get_data_i32_pointer",
-        argNames: ["that"],
+        debugName: "write_npz",
+        argNames: ["tensors", "compressed"],
       );
 
   @override
-  ArrayPointer crateApiTensorTensorImplGetDataI64Pointer({
-    required TensorImpl that,
+  Future<Map<String, TensorImpl>> crateApiTensorNpyLoadNpz({
+    required String path,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
-            that,
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
             serializer,
+            funcId: 175,
+            port: port_,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_array_pointer,
+          decodeSuccessData:
+              sse_decode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplGetDataI64PointerConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiTensorNpyLoadNpzConstMeta,
+        argValues: [path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplGetDataI64PointerConstMeta =>
-      const TaskConstMeta(
-        debugName: "TensorImpl_get_data_i64_pointer",
-        argNames: ["that"],
-      );
+  TaskConstMeta get kCrateApiTensorNpyLoadNpzConstMeta =>
+      const TaskConstMeta(debugName: "load_npz", argNames: ["path"]);
 
   @override
-  ArrayPointer crateApiTensorTensorImplGetDataI8Pointer({
-    required TensorImpl that,
+  Future<void> crateApiTensorNpySaveNpz({
+    required Map<String, TensorImpl> tensors,
+    required String path,
+    required bool compressed,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
-            that,
+          sse_encode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None(
+            tensors,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
+          sse_encode_String(path, serializer);
+          sse_encode_bool(compressed, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 176,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_array_pointer,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplGetDataI8PointerConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiTensorNpySaveNpzConstMeta,
+        argValues: [tensors, path, compressed],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplGetDataI8PointerConstMeta =>
+  TaskConstMeta get kCrateApiTensorNpySaveNpzConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_get_data_i8_pointer",
-        argNames: ["that"],
+        debugName: "save_npz",
+        argNames: ["tensors", "path", "compressed"],
       );
 
   @override
-  ArrayPointer crateApiTensorTensorImplGetDataStringPointer({
-    required TensorImpl that,
+  TensorImpl crateApiTensorProtoTensorImplFromTensorProto({
+    required List<int> bytes,
+    String? baseDir,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
-            that,
+          sse_encode_list_prim_u_8_loose(bytes, serializer);
+          sse_encode_opt_String(baseDir, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
             serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
+            funcId: 177,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_array_pointer,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplGetDataStringPointerConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiTensorProtoTensorImplFromTensorProtoConstMeta,
+        argValues: [bytes, baseDir],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplGetDataStringPointerConstMeta =>
+  TaskConstMeta get kCrateApiTensorProtoTensorImplFromTensorProtoConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_get_data_string_pointer",
-        argNames: ["that"],
+        debugName: "TensorImpl_from_tensor_proto",
+        argNames: ["bytes", "baseDir"],
       );
 
   @override
-  ArrayPointer crateApiTensorTensorImplGetDataU16Pointer({
+  Uint8List crateApiTensorProtoTensorImplToTensorProto({
     required TensorImpl that,
+    String? name,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
+          sse_encode_opt_String(name, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 178,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_array_pointer,
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplGetDataU16PointerConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiTensorProtoTensorImplToTensorProtoConstMeta,
+        argValues: [that, name],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplGetDataU16PointerConstMeta =>
+  TaskConstMeta get kCrateApiTensorProtoTensorImplToTensorProtoConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_get_data_u16_pointer",
-        argNames: ["that"],
+        debugName: "TensorImpl_to_tensor_proto",
+        argNames: ["that", "name"],
       );
 
   @override
-  ArrayPointer crateApiTensorTensorImplGetDataU32Pointer({
-    required TensorImpl that,
+  Future<TensorImpl> crateApiTensorProtoTensorImplLoadTensorProto({
+    required String path,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
-            that,
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
             serializer,
+            funcId: 179,
+            port: port_,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_array_pointer,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplGetDataU32PointerConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiTensorProtoTensorImplLoadTensorProtoConstMeta,
+        argValues: [path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplGetDataU32PointerConstMeta =>
+  TaskConstMeta get kCrateApiTensorProtoTensorImplLoadTensorProtoConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_get_data_u32_pointer",
-        argNames: ["that"],
+        debugName: "TensorImpl_load_tensor_proto",
+        argNames: ["path"],
       );
 
   @override
-  ArrayPointer crateApiTensorTensorImplGetDataU64Pointer({
+  Future<void> crateApiTensorProtoTensorImplSaveTensorProto({
     required TensorImpl that,
+    required String path,
+    String? name,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
+          sse_encode_String(path, serializer);
+          sse_encode_opt_String(name, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 180,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_array_pointer,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplGetDataU64PointerConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiTensorProtoTensorImplSaveTensorProtoConstMeta,
+        argValues: [that, path, name],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplGetDataU64PointerConstMeta =>
+  TaskConstMeta get kCrateApiTensorProtoTensorImplSaveTensorProtoConstMeta =>
       const TaskConstMeta(
-        debugName: "TensorImpl_get_data_u64_pointer",
-        argNames: ["that"],
+        debugName: "TensorImpl_save_tensor_proto",
+        argNames: ["that", "path", "name"],
       );
 
   @override
-  ArrayPointer crateApiTensorTensorImplGetDataU8Pointer({
-    required TensorImpl that,
+  Future<Map<String, TensorImpl>> crateApiTensorProtoLoadTensorProtos({
+    required List<String> paths,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
-            that,
+          sse_encode_list_String(paths, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
             serializer,
+            funcId: 181,
+            port: port_,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_array_pointer,
+          decodeSuccessData:
+              sse_decode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplGetDataU8PointerConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiTensorProtoLoadTensorProtosConstMeta,
+        argValues: [paths],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplGetDataU8PointerConstMeta =>
-      const TaskConstMeta(
-        debugName: "TensorImpl_get_data_u8_pointer",
-        argNames: ["that"],
-      );
+  TaskConstMeta get kCrateApiTensorProtoLoadTensorProtosConstMeta =>
+      const TaskConstMeta(debugName: "load_tensor_protos", argNames: ["paths"]);
 
   @override
-  bool crateApiTensorTensorImplIsMutable({required TensorImpl that}) {
+  Map<String, TensorImpl> crateApiTensorSafetensorsReadSafetensors({
+    required List<int> bytes,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
-            that,
+          sse_encode_list_prim_u_8_loose(bytes, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
             serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
+            funcId: 182,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplIsMutableConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiTensorSafetensorsReadSafetensorsConstMeta,
+        argValues: [bytes],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplIsMutableConstMeta =>
-      const TaskConstMeta(
-        debugName: "TensorImpl_is_mutable",
-        argNames: ["that"],
-      );
+  TaskConstMeta get kCrateApiTensorSafetensorsReadSafetensorsConstMeta =>
+      const TaskConstMeta(debugName: "read_safetensors", argNames: ["bytes"]);
 
   @override
-  MemoryInfo crateApiTensorTensorImplMemoryInfo({required TensorImpl that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<Map<String, TensorImpl>> crateApiTensorSafetensorsLoadSafetensors({
+    required String path,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
-            that,
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
             serializer,
+            funcId: 183,
+            port: port_,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo,
+              sse_decode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplMemoryInfoConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiTensorSafetensorsLoadSafetensorsConstMeta,
+        argValues: [path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplMemoryInfoConstMeta =>
-      const TaskConstMeta(
-        debugName: "TensorImpl_memory_info",
-        argNames: ["that"],
-      );
+  TaskConstMeta get kCrateApiTensorSafetensorsLoadSafetensorsConstMeta =>
+      const TaskConstMeta(debugName: "load_safetensors", argNames: ["path"]);
 
   @override
-  List<int> crateApiTensorTensorImplShape({required TensorImpl that}) {
+  Uint8List crateApiTensorSafetensorsWriteSafetensors({
+    required Map<String, TensorImpl> tensors,
+    Map<String, String>? metadata,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
-            that,
+          sse_encode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None(
+            tensors,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
+          sse_encode_opt_Map_String_String_None(metadata, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 184,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_CastedPrimitive_i_64,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiTensorTensorImplShapeConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiTensorSafetensorsWriteSafetensorsConstMeta,
+        argValues: [tensors, metadata],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTensorTensorImplShapeConstMeta =>
-      const TaskConstMeta(debugName: "TensorImpl_shape", argNames: ["that"]);
+  TaskConstMeta get kCrateApiTensorSafetensorsWriteSafetensorsConstMeta =>
+      const TaskConstMeta(
+        debugName: "write_safetensors",
+        argNames: ["tensors", "metadata"],
+      );
 
   @override
-  Future<CoreMLExecutionProvider>
-  crateApiExecutionProvidersCoremlCoreMlExecutionProviderDefault() {
+  Future<void> crateApiTensorSafetensorsSaveSafetensors({
+    required Map<String, TensorImpl> tensors,
+    required String path,
+    Map<String, String>? metadata,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None(
+            tensors,
+            serializer,
+          );
+          sse_encode_String(path, serializer);
+          sse_encode_opt_Map_String_String_None(metadata, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 55,
+            funcId: 185,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_core_ml_execution_provider,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersCoremlCoreMlExecutionProviderDefaultConstMeta,
-        argValues: [],
+        constMeta: kCrateApiTensorSafetensorsSaveSafetensorsConstMeta,
+        argValues: [tensors, path, metadata],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersCoremlCoreMlExecutionProviderDefaultConstMeta =>
+  TaskConstMeta get kCrateApiTensorSafetensorsSaveSafetensorsConstMeta =>
       const TaskConstMeta(
-        debugName: "core_ml_execution_provider_default",
-        argNames: [],
+        debugName: "save_safetensors",
+        argNames: ["tensors", "path", "metadata"],
       );
 
   @override
-  bool crateApiExecutionProvidersCoremlCoreMlExecutionProviderIsAvailable({
-    required CoreMLExecutionProvider that,
-  }) {
+  bool crateApiTensorStatsTensorStatsHasNonFinite({required TensorStats that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_core_ml_execution_provider(that, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
+          sse_encode_box_autoadd_tensor_stats(that, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 186,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_bool,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersCoremlCoreMlExecutionProviderIsAvailableConstMeta,
+        constMeta: kCrateApiTensorStatsTensorStatsHasNonFiniteConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersCoremlCoreMlExecutionProviderIsAvailableConstMeta =>
+  TaskConstMeta get kCrateApiTensorStatsTensorStatsHasNonFiniteConstMeta =>
       const TaskConstMeta(
-        debugName: "core_ml_execution_provider_is_available",
+        debugName: "tensor_stats_has_non_finite",
         argNames: ["that"],
       );
 
   @override
-  String crateApiExecutionProvidersCoremlCoreMlExecutionProviderName({
-    required CoreMLExecutionProvider that,
-  }) {
+  TensorStats crateApiTensorStatsTensorImplStats({required TensorImpl that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_core_ml_execution_provider(that, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 187,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_tensor_stats,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersCoremlCoreMlExecutionProviderNameConstMeta,
+        constMeta: kCrateApiTensorStatsTensorImplStatsConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersCoremlCoreMlExecutionProviderNameConstMeta =>
-      const TaskConstMeta(
-        debugName: "core_ml_execution_provider_name",
-        argNames: ["that"],
-      );
+  TaskConstMeta get kCrateApiTensorStatsTensorImplStatsConstMeta =>
+      const TaskConstMeta(debugName: "TensorImpl_stats", argNames: ["that"]);
 
   @override
-  CoreMLExecutionProvider
-  crateApiExecutionProvidersCoremlCoreMlExecutionProviderNew() {
+  void crateApiSessionSessionImplSetNonFiniteCheck({
+    required SessionImpl that,
+    required NonFiniteCheck check,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl(
+            that,
+            serializer,
+          );
+          sse_encode_box_autoadd_non_finite_check(check, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 188,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_core_ml_execution_provider,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersCoremlCoreMlExecutionProviderNewConstMeta,
-        argValues: [],
+        constMeta: kCrateApiSessionSessionImplSetNonFiniteCheckConstMeta,
+        argValues: [that, check],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersCoremlCoreMlExecutionProviderNewConstMeta =>
+  TaskConstMeta get kCrateApiSessionSessionImplSetNonFiniteCheckConstMeta =>
       const TaskConstMeta(
-        debugName: "core_ml_execution_provider_new",
-        argNames: [],
+        debugName: "SessionImpl_set_non_finite_check",
+        argNames: ["that", "check"],
       );
 
   @override
-  bool
-  crateApiExecutionProvidersCoremlCoreMlExecutionProviderSupportedByPlatform({
-    required CoreMLExecutionProvider that,
+  List<String> crateApiSessionSessionImplNonFiniteOutputs({
+    required SessionImpl that,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_core_ml_execution_provider(that, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 189,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_list_String,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersCoremlCoreMlExecutionProviderSupportedByPlatformConstMeta,
+        constMeta: kCrateApiSessionSessionImplNonFiniteOutputsConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersCoremlCoreMlExecutionProviderSupportedByPlatformConstMeta =>
+  TaskConstMeta get kCrateApiSessionSessionImplNonFiniteOutputsConstMeta =>
       const TaskConstMeta(
-        debugName: "core_ml_execution_provider_supported_by_platform",
+        debugName: "SessionImpl_non_finite_outputs",
         argNames: ["that"],
       );
 
   @override
-  Future<CPUExecutionProvider>
-  crateApiExecutionProvidersCpuCpuExecutionProviderDefault() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  String crateApiTensorCompareTensorDiffSummary({required TensorDiff that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
+          sse_encode_box_autoadd_tensor_diff(that, serializer);
+          return pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 60,
-            port: port_,
-          );
+            funcId: 190,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_cpu_execution_provider,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersCpuCpuExecutionProviderDefaultConstMeta,
-        argValues: [],
+        constMeta: kCrateApiTensorCompareTensorDiffSummaryConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersCpuCpuExecutionProviderDefaultConstMeta =>
+  TaskConstMeta get kCrateApiTensorCompareTensorDiffSummaryConstMeta =>
+      const TaskConstMeta(debugName: "tensor_diff_summary", argNames: ["that"]);
+
+  @override
+  TensorDiff crateApiTensorCompareTensorImplDiff({
+    required TensorImpl that,
+    required TensorImpl expected,
+    double? rtol,
+    double? atol,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            expected,
+            serializer,
+          );
+          sse_encode_opt_box_autoadd_f_64(rtol, serializer);
+          sse_encode_opt_box_autoadd_f_64(atol, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 191,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_tensor_diff,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTensorCompareTensorImplDiffConstMeta,
+        argValues: [that, expected, rtol, atol],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTensorCompareTensorImplDiffConstMeta =>
       const TaskConstMeta(
-        debugName: "cpu_execution_provider_default",
-        argNames: [],
+        debugName: "TensorImpl_diff",
+        argNames: ["that", "expected", "rtol", "atol"],
       );
 
   @override
-  bool crateApiExecutionProvidersCpuCpuExecutionProviderIsAvailable({
-    required CPUExecutionProvider that,
+  bool crateApiTensorCompareTensorImplAllclose({
+    required TensorImpl that,
+    required TensorImpl expected,
+    double? rtol,
+    double? atol,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_cpu_execution_provider(that, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            expected,
+            serializer,
+          );
+          sse_encode_opt_box_autoadd_f_64(rtol, serializer);
+          sse_encode_opt_box_autoadd_f_64(atol, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 192,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersCpuCpuExecutionProviderIsAvailableConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiTensorCompareTensorImplAllcloseConstMeta,
+        argValues: [that, expected, rtol, atol],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersCpuCpuExecutionProviderIsAvailableConstMeta =>
+  TaskConstMeta get kCrateApiTensorCompareTensorImplAllcloseConstMeta =>
       const TaskConstMeta(
-        debugName: "cpu_execution_provider_is_available",
-        argNames: ["that"],
+        debugName: "TensorImpl_allclose",
+        argNames: ["that", "expected", "rtol", "atol"],
       );
 
   @override
-  String crateApiExecutionProvidersCpuCpuExecutionProviderName({
-    required CPUExecutionProvider that,
+  TensorImpl crateApiTensorExternalTensorImplFromExternalData({
+    required TensorElementType dtype,
+    List<int>? shape,
+    required int ptr,
+    required int byteLen,
+    int? release,
+    required bool immutable,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_cpu_execution_provider(that, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
+          sse_encode_box_autoadd_tensor_element_type(dtype, serializer);
+          sse_encode_opt_list_CastedPrimitive_i_64(shape, serializer);
+          sse_encode_CastedPrimitive_usize(ptr, serializer);
+          sse_encode_CastedPrimitive_usize(byteLen, serializer);
+          sse_encode_opt_CastedPrimitive_usize(release, serializer);
+          sse_encode_bool(immutable, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 193,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersCpuCpuExecutionProviderNameConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiTensorExternalTensorImplFromExternalDataConstMeta,
+        argValues: [dtype, shape, ptr, byteLen, release, immutable],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiExecutionProvidersCpuCpuExecutionProviderNameConstMeta =>
+  get kCrateApiTensorExternalTensorImplFromExternalDataConstMeta =>
       const TaskConstMeta(
-        debugName: "cpu_execution_provider_name",
-        argNames: ["that"],
+        debugName: "TensorImpl_from_external_data",
+        argNames: ["dtype", "shape", "ptr", "byteLen", "release", "immutable"],
       );
 
   @override
-  CPUExecutionProvider crateApiExecutionProvidersCpuCpuExecutionProviderNew() {
+  bool crateApiTensorTensorImplMakeMutable({required TensorImpl that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63)!;
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 194,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_cpu_execution_provider,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersCpuCpuExecutionProviderNewConstMeta,
-        argValues: [],
+        constMeta: kCrateApiTensorTensorImplMakeMutableConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersCpuCpuExecutionProviderNewConstMeta =>
+  TaskConstMeta get kCrateApiTensorTensorImplMakeMutableConstMeta =>
       const TaskConstMeta(
-        debugName: "cpu_execution_provider_new",
-        argNames: [],
+        debugName: "TensorImpl_make_mutable",
+        argNames: ["that"],
       );
 
   @override
-  bool crateApiExecutionProvidersCpuCpuExecutionProviderSupportedByPlatform({
-    required CPUExecutionProvider that,
+  ArrayPointer crateApiTensorTensorImplGetDataF16Pointer({
+    required TensorImpl that,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_cpu_execution_provider(that, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64)!;
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 195,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_array_pointer,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersCpuCpuExecutionProviderSupportedByPlatformConstMeta,
+        constMeta: kCrateApiTensorTensorImplGetDataF16PointerConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersCpuCpuExecutionProviderSupportedByPlatformConstMeta =>
+  TaskConstMeta get kCrateApiTensorTensorImplGetDataF16PointerConstMeta =>
       const TaskConstMeta(
-        debugName: "cpu_execution_provider_supported_by_platform",
+        debugName: "TensorImpl_get_data_f16_pointer",
         argNames: ["that"],
       );
 
   @override
-  Future<CUDAExecutionProvider>
-  crateApiExecutionProvidersCudaCudaExecutionProviderDefault() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  void crateApiTensorTensorImplFreeF16Pointer({required ArrayPointer arr}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
+          sse_encode_box_autoadd_array_pointer(arr, serializer);
+          return pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 65,
-            port: port_,
-          );
+            funcId: 196,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_cuda_execution_provider,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersCudaCudaExecutionProviderDefaultConstMeta,
-        argValues: [],
+        constMeta: kCrateApiTensorTensorImplFreeF16PointerConstMeta,
+        argValues: [arr],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersCudaCudaExecutionProviderDefaultConstMeta =>
+  TaskConstMeta get kCrateApiTensorTensorImplFreeF16PointerConstMeta =>
       const TaskConstMeta(
-        debugName: "cuda_execution_provider_default",
-        argNames: [],
+        debugName: "TensorImpl_free_f16_pointer",
+        argNames: ["arr"],
       );
 
   @override
-  bool crateApiExecutionProvidersCudaCudaExecutionProviderIsAvailable({
-    required CUDAExecutionProvider that,
+  ArrayPointer crateApiTensorTensorImplGetDataBf16Pointer({
+    required TensorImpl that,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_cuda_execution_provider(that, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 197,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_array_pointer,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersCudaCudaExecutionProviderIsAvailableConstMeta,
+        constMeta: kCrateApiTensorTensorImplGetDataBf16PointerConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersCudaCudaExecutionProviderIsAvailableConstMeta =>
+  TaskConstMeta get kCrateApiTensorTensorImplGetDataBf16PointerConstMeta =>
       const TaskConstMeta(
-        debugName: "cuda_execution_provider_is_available",
+        debugName: "TensorImpl_get_data_bf16_pointer",
         argNames: ["that"],
       );
 
   @override
-  String crateApiExecutionProvidersCudaCudaExecutionProviderName({
-    required CUDAExecutionProvider that,
-  }) {
+  void crateApiTensorTensorImplFreeBf16Pointer({required ArrayPointer arr}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_cuda_execution_provider(that, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
+          sse_encode_box_autoadd_array_pointer(arr, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 198,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersCudaCudaExecutionProviderNameConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiTensorTensorImplFreeBf16PointerConstMeta,
+        argValues: [arr],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersCudaCudaExecutionProviderNameConstMeta =>
+  TaskConstMeta get kCrateApiTensorTensorImplFreeBf16PointerConstMeta =>
       const TaskConstMeta(
-        debugName: "cuda_execution_provider_name",
-        argNames: ["that"],
+        debugName: "TensorImpl_free_bf16_pointer",
+        argNames: ["arr"],
       );
 
   @override
-  CUDAExecutionProvider
-  crateApiExecutionProvidersCudaCudaExecutionProviderNew() {
+  TensorElement crateApiTensorIndexTensorImplGet({
+    required TensorImpl that,
+    required List<int> index,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          sse_encode_list_CastedPrimitive_i_64(index, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 199,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_cuda_execution_provider,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_tensor_element,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersCudaCudaExecutionProviderNewConstMeta,
-        argValues: [],
+        constMeta: kCrateApiTensorIndexTensorImplGetConstMeta,
+        argValues: [that, index],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersCudaCudaExecutionProviderNewConstMeta =>
+  TaskConstMeta get kCrateApiTensorIndexTensorImplGetConstMeta =>
       const TaskConstMeta(
-        debugName: "cuda_execution_provider_new",
-        argNames: [],
+        debugName: "TensorImpl_get",
+        argNames: ["that", "index"],
       );
 
   @override
-  bool crateApiExecutionProvidersCudaCudaExecutionProviderSupportedByPlatform({
-    required CUDAExecutionProvider that,
+  void crateApiTensorIndexTensorImplSet({
+    required TensorImpl that,
+    required List<int> index,
+    required TensorElement value,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_cuda_execution_provider(that, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          sse_encode_list_CastedPrimitive_i_64(index, serializer);
+          sse_encode_box_autoadd_tensor_element(value, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 200,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersCudaCudaExecutionProviderSupportedByPlatformConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiTensorIndexTensorImplSetConstMeta,
+        argValues: [that, index, value],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersCudaCudaExecutionProviderSupportedByPlatformConstMeta =>
+  TaskConstMeta get kCrateApiTensorIndexTensorImplSetConstMeta =>
       const TaskConstMeta(
-        debugName: "cuda_execution_provider_supported_by_platform",
-        argNames: ["that"],
+        debugName: "TensorImpl_set",
+        argNames: ["that", "index", "value"],
       );
 
   @override
-  Future<DirectMLExecutionProvider>
-  crateApiExecutionProvidersDirectmlDirectMlExecutionProviderDefault() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  TensorSlice crateApiTensorIndexTensorImplSliceView({
+    required TensorImpl that,
+    required List<int> starts,
+    required List<int> ends,
+    List<int>? axes,
+    List<int>? steps,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
             serializer,
-            funcId: 70,
-            port: port_,
           );
+          sse_encode_list_CastedPrimitive_i_64(starts, serializer);
+          sse_encode_list_CastedPrimitive_i_64(ends, serializer);
+          sse_encode_opt_list_CastedPrimitive_i_64(axes, serializer);
+          sse_encode_opt_list_CastedPrimitive_i_64(steps, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 201,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_direct_ml_execution_provider,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersDirectmlDirectMlExecutionProviderDefaultConstMeta,
-        argValues: [],
+        constMeta: kCrateApiTensorIndexTensorImplSliceViewConstMeta,
+        argValues: [that, starts, ends, axes, steps],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersDirectmlDirectMlExecutionProviderDefaultConstMeta =>
+  TaskConstMeta get kCrateApiTensorIndexTensorImplSliceViewConstMeta =>
       const TaskConstMeta(
-        debugName: "direct_ml_execution_provider_default",
-        argNames: [],
+        debugName: "TensorImpl_slice_view",
+        argNames: ["that", "starts", "ends", "axes", "steps"],
       );
 
   @override
-  bool crateApiExecutionProvidersDirectmlDirectMlExecutionProviderIsAvailable({
-    required DirectMLExecutionProvider that,
+  TensorElementType crateApiTensorIndexTensorSliceDtype({
+    required TensorSlice that,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_direct_ml_execution_provider(that, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 202,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_tensor_element_type,
+          decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersDirectmlDirectMlExecutionProviderIsAvailableConstMeta,
+        constMeta: kCrateApiTensorIndexTensorSliceDtypeConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersDirectmlDirectMlExecutionProviderIsAvailableConstMeta =>
-      const TaskConstMeta(
-        debugName: "direct_ml_execution_provider_is_available",
-        argNames: ["that"],
-      );
+  TaskConstMeta get kCrateApiTensorIndexTensorSliceDtypeConstMeta =>
+      const TaskConstMeta(debugName: "TensorSlice_dtype", argNames: ["that"]);
 
   @override
-  String crateApiExecutionProvidersDirectmlDirectMlExecutionProviderName({
-    required DirectMLExecutionProvider that,
-  }) {
+  List<int> crateApiTensorIndexTensorSliceShape({required TensorSlice that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_direct_ml_execution_provider(that, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 203,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_list_CastedPrimitive_i_64,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersDirectmlDirectMlExecutionProviderNameConstMeta,
+        constMeta: kCrateApiTensorIndexTensorSliceShapeConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersDirectmlDirectMlExecutionProviderNameConstMeta =>
-      const TaskConstMeta(
-        debugName: "direct_ml_execution_provider_name",
-        argNames: ["that"],
-      );
+  TaskConstMeta get kCrateApiTensorIndexTensorSliceShapeConstMeta =>
+      const TaskConstMeta(debugName: "TensorSlice_shape", argNames: ["that"]);
 
   @override
-  DirectMLExecutionProvider
-  crateApiExecutionProvidersDirectmlDirectMlExecutionProviderNew() {
+  List<int> crateApiTensorIndexTensorSliceStrides({required TensorSlice that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 204,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_direct_ml_execution_provider,
+          decodeSuccessData: sse_decode_list_CastedPrimitive_i_64,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersDirectmlDirectMlExecutionProviderNewConstMeta,
-        argValues: [],
+        constMeta: kCrateApiTensorIndexTensorSliceStridesConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersDirectmlDirectMlExecutionProviderNewConstMeta =>
-      const TaskConstMeta(
-        debugName: "direct_ml_execution_provider_new",
-        argNames: [],
-      );
+  TaskConstMeta get kCrateApiTensorIndexTensorSliceStridesConstMeta =>
+      const TaskConstMeta(debugName: "TensorSlice_strides", argNames: ["that"]);
 
   @override
-  bool
-  crateApiExecutionProvidersDirectmlDirectMlExecutionProviderSupportedByPlatform({
-    required DirectMLExecutionProvider that,
-  }) {
+  bool crateApiTensorIndexTensorSliceIsMutable({required TensorSlice that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_direct_ml_execution_provider(that, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 205,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersDirectmlDirectMlExecutionProviderSupportedByPlatformConstMeta,
+        constMeta: kCrateApiTensorIndexTensorSliceIsMutableConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersDirectmlDirectMlExecutionProviderSupportedByPlatformConstMeta =>
+  TaskConstMeta get kCrateApiTensorIndexTensorSliceIsMutableConstMeta =>
       const TaskConstMeta(
-        debugName: "direct_ml_execution_provider_supported_by_platform",
+        debugName: "TensorSlice_is_mutable",
         argNames: ["that"],
       );
 
   @override
-  Future<void> crateApiInitApp() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  TensorElement crateApiTensorIndexTensorSliceGet({
+    required TensorSlice that,
+    required List<int> index,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+            that,
             serializer,
-            funcId: 75,
-            port: port_,
           );
+          sse_encode_list_CastedPrimitive_i_64(index, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 206,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_tensor_element,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiInitAppConstMeta,
-        argValues: [],
+        constMeta: kCrateApiTensorIndexTensorSliceGetConstMeta,
+        argValues: [that, index],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta get kCrateApiTensorIndexTensorSliceGetConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorSlice_get",
+        argNames: ["that", "index"],
+      );
 
   @override
-  Future<NNAPIExecutionProvider>
-  crateApiExecutionProvidersNnapiNnapiExecutionProviderDefault() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  void crateApiTensorIndexTensorSliceSet({
+    required TensorSlice that,
+    required List<int> index,
+    required TensorElement value,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+            that,
             serializer,
-            funcId: 76,
-            port: port_,
           );
+          sse_encode_list_CastedPrimitive_i_64(index, serializer);
+          sse_encode_box_autoadd_tensor_element(value, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 207,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_nnapi_execution_provider,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersNnapiNnapiExecutionProviderDefaultConstMeta,
-        argValues: [],
+        constMeta: kCrateApiTensorIndexTensorSliceSetConstMeta,
+        argValues: [that, index, value],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersNnapiNnapiExecutionProviderDefaultConstMeta =>
+  TaskConstMeta get kCrateApiTensorIndexTensorSliceSetConstMeta =>
       const TaskConstMeta(
-        debugName: "nnapi_execution_provider_default",
-        argNames: [],
+        debugName: "TensorSlice_set",
+        argNames: ["that", "index", "value"],
       );
 
   @override
-  bool crateApiExecutionProvidersNnapiNnapiExecutionProviderIsAvailable({
-    required NNAPIExecutionProvider that,
+  TensorImpl crateApiTensorIndexTensorSliceToTensor({
+    required TensorSlice that,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_nnapi_execution_provider(that, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 208,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersNnapiNnapiExecutionProviderIsAvailableConstMeta,
+        constMeta: kCrateApiTensorIndexTensorSliceToTensorConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersNnapiNnapiExecutionProviderIsAvailableConstMeta =>
+  TaskConstMeta get kCrateApiTensorIndexTensorSliceToTensorConstMeta =>
       const TaskConstMeta(
-        debugName: "nnapi_execution_provider_is_available",
+        debugName: "TensorSlice_to_tensor",
         argNames: ["that"],
       );
 
   @override
-  String crateApiExecutionProvidersNnapiNnapiExecutionProviderName({
-    required NNAPIExecutionProvider that,
-  }) {
+  int crateApiTensorTensorImplRank({required TensorImpl that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_nnapi_execution_provider(that, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 209,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_CastedPrimitive_usize,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersNnapiNnapiExecutionProviderNameConstMeta,
+        constMeta: kCrateApiTensorTensorImplRankConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersNnapiNnapiExecutionProviderNameConstMeta =>
-      const TaskConstMeta(
-        debugName: "nnapi_execution_provider_name",
-        argNames: ["that"],
-      );
+  TaskConstMeta get kCrateApiTensorTensorImplRankConstMeta =>
+      const TaskConstMeta(debugName: "TensorImpl_rank", argNames: ["that"]);
 
   @override
-  NNAPIExecutionProvider
-  crateApiExecutionProvidersNnapiNnapiExecutionProviderNew() {
+  int crateApiTensorTensorImplNumElements({required TensorImpl that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 210,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_nnapi_execution_provider,
+          decodeSuccessData: sse_decode_CastedPrimitive_usize,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersNnapiNnapiExecutionProviderNewConstMeta,
-        argValues: [],
+        constMeta: kCrateApiTensorTensorImplNumElementsConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersNnapiNnapiExecutionProviderNewConstMeta =>
+  TaskConstMeta get kCrateApiTensorTensorImplNumElementsConstMeta =>
       const TaskConstMeta(
-        debugName: "nnapi_execution_provider_new",
-        argNames: [],
+        debugName: "TensorImpl_num_elements",
+        argNames: ["that"],
       );
 
   @override
-  bool
-  crateApiExecutionProvidersNnapiNnapiExecutionProviderSupportedByPlatform({
-    required NNAPIExecutionProvider that,
-  }) {
+  List<int> crateApiTensorTensorImplStrides({required TensorImpl that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_nnapi_execution_provider(that, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 211,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_list_CastedPrimitive_i_64,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersNnapiNnapiExecutionProviderSupportedByPlatformConstMeta,
+        constMeta: kCrateApiTensorTensorImplStridesConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersNnapiNnapiExecutionProviderSupportedByPlatformConstMeta =>
-      const TaskConstMeta(
-        debugName: "nnapi_execution_provider_supported_by_platform",
-        argNames: ["that"],
-      );
+  TaskConstMeta get kCrateApiTensorTensorImplStridesConstMeta =>
+      const TaskConstMeta(debugName: "TensorImpl_strides", argNames: ["that"]);
 
   @override
-  void crateApiLoggingOrtDebugMessages({OrtDebugLevel? level}) {
+  int crateApiTensorTensorImplSizeInBytes({required TensorImpl that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_box_autoadd_ort_debug_level(level, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 212,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_CastedPrimitive_usize,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiLoggingOrtDebugMessagesConstMeta,
-        argValues: [level],
+        constMeta: kCrateApiTensorTensorImplSizeInBytesConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiLoggingOrtDebugMessagesConstMeta =>
-      const TaskConstMeta(debugName: "ort_debug_messages", argNames: ["level"]);
+  TaskConstMeta get kCrateApiTensorTensorImplSizeInBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "TensorImpl_size_in_bytes",
+        argNames: ["that"],
+      );
 
   @override
-  Future<QNNExecutionProvider>
-  crateApiExecutionProvidersQnnQnnExecutionProviderDefault() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  MemoryInfo crateApiTensorTensorImplDevice({required TensorImpl that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            that,
             serializer,
-            funcId: 82,
-            port: port_,
           );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 213,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_qnn_execution_provider,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersQnnQnnExecutionProviderDefaultConstMeta,
-        argValues: [],
+        constMeta: kCrateApiTensorTensorImplDeviceConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersQnnQnnExecutionProviderDefaultConstMeta =>
-      const TaskConstMeta(
-        debugName: "qnn_execution_provider_default",
-        argNames: [],
-      );
+  TaskConstMeta get kCrateApiTensorTensorImplDeviceConstMeta =>
+      const TaskConstMeta(debugName: "TensorImpl_device", argNames: ["that"]);
 
   @override
-  bool crateApiExecutionProvidersQnnQnnExecutionProviderIsAvailable({
-    required QNNExecutionProvider that,
+  MemoryInfo crateApiMemoryMemoryInfoNew({
+    required AllocationDevice allocationDevice,
+    required int deviceId,
+    required AllocatorType allocatorType,
+    required MemoryType memoryType,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_qnn_execution_provider(that, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83)!;
+          sse_encode_box_autoadd_allocation_device(
+            allocationDevice,
+            serializer,
+          );
+          sse_encode_i_32(deviceId, serializer);
+          sse_encode_box_autoadd_allocator_type(allocatorType, serializer);
+          sse_encode_box_autoadd_memory_type(memoryType, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 214,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersQnnQnnExecutionProviderIsAvailableConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiMemoryMemoryInfoNewConstMeta,
+        argValues: [allocationDevice, deviceId, allocatorType, memoryType],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersQnnQnnExecutionProviderIsAvailableConstMeta =>
+  TaskConstMeta get kCrateApiMemoryMemoryInfoNewConstMeta =>
       const TaskConstMeta(
-        debugName: "qnn_execution_provider_is_available",
-        argNames: ["that"],
+        debugName: "MemoryInfo_new",
+        argNames: [
+          "allocationDevice",
+          "deviceId",
+          "allocatorType",
+          "memoryType",
+        ],
       );
 
   @override
-  String crateApiExecutionProvidersQnnQnnExecutionProviderName({
-    required QNNExecutionProvider that,
-  }) {
+  Allocator crateApiMemoryAllocatorCpu() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_qnn_execution_provider(that, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84)!;
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 215,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersQnnQnnExecutionProviderNameConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiMemoryAllocatorCpuConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersQnnQnnExecutionProviderNameConstMeta =>
-      const TaskConstMeta(
-        debugName: "qnn_execution_provider_name",
-        argNames: ["that"],
-      );
+  TaskConstMeta get kCrateApiMemoryAllocatorCpuConstMeta =>
+      const TaskConstMeta(debugName: "Allocator_cpu", argNames: []);
 
   @override
-  QNNExecutionProvider crateApiExecutionProvidersQnnQnnExecutionProviderNew() {
+  MemoryInfo crateApiMemoryAllocatorMemoryInfo({required Allocator that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 216,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_qnn_execution_provider,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersQnnQnnExecutionProviderNewConstMeta,
-        argValues: [],
+        constMeta: kCrateApiMemoryAllocatorMemoryInfoConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersQnnQnnExecutionProviderNewConstMeta =>
+  TaskConstMeta get kCrateApiMemoryAllocatorMemoryInfoConstMeta =>
       const TaskConstMeta(
-        debugName: "qnn_execution_provider_new",
-        argNames: [],
+        debugName: "Allocator_memory_info",
+        argNames: ["that"],
       );
 
   @override
-  bool crateApiExecutionProvidersQnnQnnExecutionProviderSupportedByPlatform({
-    required QNNExecutionProvider that,
+  Allocator crateApiSessionSessionImplAllocator({
+    required SessionImpl that,
+    MemoryInfo? memoryInfo,
   }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_qnn_execution_provider(that, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl(
+            that,
+            serializer,
+          );
+          sse_encode_opt_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
+            memoryInfo,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 217,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersQnnQnnExecutionProviderSupportedByPlatformConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiSessionSessionImplAllocatorConstMeta,
+        argValues: [that, memoryInfo],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersQnnQnnExecutionProviderSupportedByPlatformConstMeta =>
+  TaskConstMeta get kCrateApiSessionSessionImplAllocatorConstMeta =>
       const TaskConstMeta(
-        debugName: "qnn_execution_provider_supported_by_platform",
-        argNames: ["that"],
+        debugName: "SessionImpl_allocator",
+        argNames: ["that", "memoryInfo"],
       );
 
   @override
-  Future<ROCmExecutionProvider>
-  crateApiExecutionProvidersRocmRoCmExecutionProviderDefault() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  TensorImpl crateApiTensorTensorImplNewWithAllocator({
+    required Allocator allocator,
+    required TensorElementType dtype,
+    required List<int> shape,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator(
+            allocator,
             serializer,
-            funcId: 87,
-            port: port_,
           );
+          sse_encode_box_autoadd_tensor_element_type(dtype, serializer);
+          sse_encode_list_CastedPrimitive_i_64(shape, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 218,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_ro_cm_execution_provider,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersRocmRoCmExecutionProviderDefaultConstMeta,
-        argValues: [],
+        constMeta: kCrateApiTensorTensorImplNewWithAllocatorConstMeta,
+        argValues: [allocator, dtype, shape],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersRocmRoCmExecutionProviderDefaultConstMeta =>
+  TaskConstMeta get kCrateApiTensorTensorImplNewWithAllocatorConstMeta =>
       const TaskConstMeta(
-        debugName: "ro_cm_execution_provider_default",
-        argNames: [],
+        debugName: "TensorImpl_new_with_allocator",
+        argNames: ["allocator", "dtype", "shape"],
       );
 
   @override
-  bool crateApiExecutionProvidersRocmRoCmExecutionProviderIsAvailable({
-    required ROCmExecutionProvider that,
-  }) {
+  void crateApiMemoryStatsEnableAllocatorTracking() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_ro_cm_execution_provider(that, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88)!;
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 219,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersRocmRoCmExecutionProviderIsAvailableConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiMemoryStatsEnableAllocatorTrackingConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersRocmRoCmExecutionProviderIsAvailableConstMeta =>
-      const TaskConstMeta(
-        debugName: "ro_cm_execution_provider_is_available",
-        argNames: ["that"],
-      );
+  TaskConstMeta get kCrateApiMemoryStatsEnableAllocatorTrackingConstMeta =>
+      const TaskConstMeta(debugName: "enable_allocator_tracking", argNames: []);
 
   @override
-  String crateApiExecutionProvidersRocmRoCmExecutionProviderName({
-    required ROCmExecutionProvider that,
-  }) {
+  bool crateApiMemoryStatsIsAllocatorTrackingEnabled() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_ro_cm_execution_provider(that, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 220,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersRocmRoCmExecutionProviderNameConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiMemoryStatsIsAllocatorTrackingEnabledConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersRocmRoCmExecutionProviderNameConstMeta =>
+  TaskConstMeta get kCrateApiMemoryStatsIsAllocatorTrackingEnabledConstMeta =>
       const TaskConstMeta(
-        debugName: "ro_cm_execution_provider_name",
-        argNames: ["that"],
+        debugName: "is_allocator_tracking_enabled",
+        argNames: [],
       );
 
   @override
-  ROCmExecutionProvider
-  crateApiExecutionProvidersRocmRoCmExecutionProviderNew() {
+  AllocatorStats crateApiMemoryStatsAllocatorStats() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90)!;
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 221,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_ro_cm_execution_provider,
+          decodeSuccessData: sse_decode_allocator_stats,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersRocmRoCmExecutionProviderNewConstMeta,
+        constMeta: kCrateApiMemoryStatsAllocatorStatsConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersRocmRoCmExecutionProviderNewConstMeta =>
-      const TaskConstMeta(
-        debugName: "ro_cm_execution_provider_new",
-        argNames: [],
-      );
+  TaskConstMeta get kCrateApiMemoryStatsAllocatorStatsConstMeta =>
+      const TaskConstMeta(debugName: "allocator_stats", argNames: []);
 
   @override
-  bool crateApiExecutionProvidersRocmRoCmExecutionProviderSupportedByPlatform({
-    required ROCmExecutionProvider that,
-  }) {
+  void crateApiMemoryStatsResetPeakAllocatorStats() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_ro_cm_execution_provider(that, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91)!;
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 222,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersRocmRoCmExecutionProviderSupportedByPlatformConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiMemoryStatsResetPeakAllocatorStatsConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersRocmRoCmExecutionProviderSupportedByPlatformConstMeta =>
+  TaskConstMeta get kCrateApiMemoryStatsResetPeakAllocatorStatsConstMeta =>
       const TaskConstMeta(
-        debugName: "ro_cm_execution_provider_supported_by_platform",
-        argNames: ["that"],
+        debugName: "reset_peak_allocator_stats",
+        argNames: [],
       );
 
   @override
-  Future<SessionImpl> crateApiSessionSessionBuilderOptionsCommitFromFile({
-    required SessionBuilderOptions that,
-    required String modelFilepath,
-  }) {
+  Future<AllocatorStats> crateApiMemoryStatsAllocatorStatsDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_session_builder_options(that, serializer);
-          sse_encode_String(modelFilepath, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 92,
+            funcId: 223,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_allocator_stats,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiSessionSessionBuilderOptionsCommitFromFileConstMeta,
-        argValues: [that, modelFilepath],
+        constMeta: kCrateApiMemoryStatsAllocatorStatsDefaultConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiSessionSessionBuilderOptionsCommitFromFileConstMeta =>
-      const TaskConstMeta(
-        debugName: "session_builder_options_commit_from_file",
-        argNames: ["that", "modelFilepath"],
-      );
+  TaskConstMeta get kCrateApiMemoryStatsAllocatorStatsDefaultConstMeta =>
+      const TaskConstMeta(debugName: "allocator_stats_default", argNames: []);
 
   @override
-  Future<SessionImpl> crateApiSessionSessionBuilderOptionsCommitFromMemory({
-    required SessionBuilderOptions that,
-    required List<int> modelBytes,
-  }) {
+  Future<ArenaStats> crateApiMemoryStatsArenaStatsDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_session_builder_options(that, serializer);
-          sse_encode_list_prim_u_8_loose(modelBytes, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 93,
+            funcId: 224,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_arena_stats,
+          decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiSessionSessionBuilderOptionsCommitFromMemoryConstMeta,
-        argValues: [that, modelBytes],
+        constMeta: kCrateApiMemoryStatsArenaStatsDefaultConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiSessionSessionBuilderOptionsCommitFromMemoryConstMeta =>
-      const TaskConstMeta(
-        debugName: "session_builder_options_commit_from_memory",
-        argNames: ["that", "modelBytes"],
-      );
+  TaskConstMeta get kCrateApiMemoryStatsArenaStatsDefaultConstMeta =>
+      const TaskConstMeta(debugName: "arena_stats_default", argNames: []);
 
   @override
-  Future<SessionBuilderOptions> crateApiSessionSessionBuilderOptionsDefault() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  ArenaStats crateApiSessionSessionImplArenaStats({
+    required SessionImpl that,
+    MemoryInfo? memoryInfo,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl(
+            that,
+            serializer,
+          );
+          sse_encode_opt_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
+            memoryInfo,
             serializer,
-            funcId: 94,
-            port: port_,
           );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 225,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_session_builder_options,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_arena_stats,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSessionSessionBuilderOptionsDefaultConstMeta,
-        argValues: [],
+        constMeta: kCrateApiSessionSessionImplArenaStatsConstMeta,
+        argValues: [that, memoryInfo],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSessionSessionBuilderOptionsDefaultConstMeta =>
+  TaskConstMeta get kCrateApiSessionSessionImplArenaStatsConstMeta =>
       const TaskConstMeta(
-        debugName: "session_builder_options_default",
-        argNames: [],
+        debugName: "SessionImpl_arena_stats",
+        argNames: ["that", "memoryInfo"],
       );
 
   @override
-  Future<TensorRTExecutionProvider>
-  crateApiExecutionProvidersTensorrtTensorRtExecutionProviderDefault() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  RunMemoryUsage? crateApiSessionSessionImplLastRunMemory({
+    required SessionImpl that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl(
+            that,
             serializer,
-            funcId: 95,
-            port: port_,
           );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 226,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_tensor_rt_execution_provider,
+          decodeSuccessData: sse_decode_opt_box_autoadd_run_memory_usage,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersTensorrtTensorRtExecutionProviderDefaultConstMeta,
-        argValues: [],
+        constMeta: kCrateApiSessionSessionImplLastRunMemoryConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersTensorrtTensorRtExecutionProviderDefaultConstMeta =>
+  TaskConstMeta get kCrateApiSessionSessionImplLastRunMemoryConstMeta =>
       const TaskConstMeta(
-        debugName: "tensor_rt_execution_provider_default",
-        argNames: [],
+        debugName: "SessionImpl_last_run_memory",
+        argNames: ["that"],
       );
 
   @override
-  bool crateApiExecutionProvidersTensorrtTensorRtExecutionProviderIsAvailable({
-    required TensorRTExecutionProvider that,
-  }) {
+  void crateApiMemoryArenaConfigureEnvArena({required ArenaConfig config}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_tensor_rt_execution_provider(that, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96)!;
+          sse_encode_box_autoadd_arena_config(config, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 227,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersTensorrtTensorRtExecutionProviderIsAvailableConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiMemoryArenaConfigureEnvArenaConstMeta,
+        argValues: [config],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersTensorrtTensorRtExecutionProviderIsAvailableConstMeta =>
+  TaskConstMeta get kCrateApiMemoryArenaConfigureEnvArenaConstMeta =>
       const TaskConstMeta(
-        debugName: "tensor_rt_execution_provider_is_available",
-        argNames: ["that"],
+        debugName: "configure_env_arena",
+        argNames: ["config"],
       );
 
   @override
-  String crateApiExecutionProvidersTensorrtTensorRtExecutionProviderName({
-    required TensorRTExecutionProvider that,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<ArenaConfig> crateApiMemoryArenaArenaConfigDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_tensor_rt_execution_provider(that, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97)!;
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 228,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_arena_config,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersTensorrtTensorRtExecutionProviderNameConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiMemoryArenaArenaConfigDefaultConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersTensorrtTensorRtExecutionProviderNameConstMeta =>
-      const TaskConstMeta(
-        debugName: "tensor_rt_execution_provider_name",
-        argNames: ["that"],
-      );
+  TaskConstMeta get kCrateApiMemoryArenaArenaConfigDefaultConstMeta =>
+      const TaskConstMeta(debugName: "arena_config_default", argNames: []);
 
   @override
-  TensorRTExecutionProvider
-  crateApiExecutionProvidersTensorrtTensorRtExecutionProviderNew() {
+  MemoryTrimReport crateApiMemoryTrimTrimMemory() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98)!;
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 229,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_tensor_rt_execution_provider,
+          decodeSuccessData: sse_decode_memory_trim_report,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersTensorrtTensorRtExecutionProviderNewConstMeta,
+        constMeta: kCrateApiMemoryTrimTrimMemoryConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersTensorrtTensorRtExecutionProviderNewConstMeta =>
-      const TaskConstMeta(
-        debugName: "tensor_rt_execution_provider_new",
-        argNames: [],
-      );
+  TaskConstMeta get kCrateApiMemoryTrimTrimMemoryConstMeta =>
+      const TaskConstMeta(debugName: "trim_memory", argNames: []);
 
   @override
-  bool
-  crateApiExecutionProvidersTensorrtTensorRtExecutionProviderSupportedByPlatform({
-    required TensorRTExecutionProvider that,
-  }) {
+  void crateApiSessionSessionImplSuspend({required SessionImpl that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_tensor_rt_execution_provider(that, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99)!;
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 230,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersTensorrtTensorRtExecutionProviderSupportedByPlatformConstMeta,
+        constMeta: kCrateApiSessionSessionImplSuspendConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersTensorrtTensorRtExecutionProviderSupportedByPlatformConstMeta =>
-      const TaskConstMeta(
-        debugName: "tensor_rt_execution_provider_supported_by_platform",
-        argNames: ["that"],
-      );
+  TaskConstMeta get kCrateApiSessionSessionImplSuspendConstMeta =>
+      const TaskConstMeta(debugName: "SessionImpl_suspend", argNames: ["that"]);
 
   @override
-  Future<XNNPACKExecutionProvider>
-  crateApiExecutionProvidersXnnpackXnnpackExecutionProviderDefault() {
+  Future<void> crateApiSessionSessionImplResume({required SessionImpl that}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl(
+            that,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 100,
+            funcId: 231,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_xnnpack_execution_provider,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersXnnpackXnnpackExecutionProviderDefaultConstMeta,
-        argValues: [],
+        constMeta: kCrateApiSessionSessionImplResumeConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersXnnpackXnnpackExecutionProviderDefaultConstMeta =>
-      const TaskConstMeta(
-        debugName: "xnnpack_execution_provider_default",
-        argNames: [],
-      );
+  TaskConstMeta get kCrateApiSessionSessionImplResumeConstMeta =>
+      const TaskConstMeta(debugName: "SessionImpl_resume", argNames: ["that"]);
 
   @override
-  bool crateApiExecutionProvidersXnnpackXnnpackExecutionProviderIsAvailable({
-    required XNNPACKExecutionProvider that,
-  }) {
+  bool crateApiSessionSessionImplIsSuspended({required SessionImpl that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_xnnpack_execution_provider(that, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl(
+            that,
+            serializer,
+          );
           return pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 101,
+            funcId: 232,
           )!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_bool,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersXnnpackXnnpackExecutionProviderIsAvailableConstMeta,
+        constMeta: kCrateApiSessionSessionImplIsSuspendedConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersXnnpackXnnpackExecutionProviderIsAvailableConstMeta =>
+  TaskConstMeta get kCrateApiSessionSessionImplIsSuspendedConstMeta =>
       const TaskConstMeta(
-        debugName: "xnnpack_execution_provider_is_available",
+        debugName: "SessionImpl_is_suspended",
         argNames: ["that"],
       );
 
   @override
-  String crateApiExecutionProvidersXnnpackXnnpackExecutionProviderName({
-    required XNNPACKExecutionProvider that,
+  Future<SessionImpl>
+  crateApiSessionSessionBuilderOptionsCommitFromMemorySuspendable({
+    required SessionBuilderOptions that,
+    required List<int> modelBytes,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_xnnpack_execution_provider(that, serializer);
-          return pdeCallFfi(
+          sse_encode_box_autoadd_session_builder_options(that, serializer);
+          sse_encode_list_prim_u_8_loose(modelBytes, serializer);
+          pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 102,
-          )!;
+            funcId: 233,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
         constMeta:
-            kCrateApiExecutionProvidersXnnpackXnnpackExecutionProviderNameConstMeta,
-        argValues: [that],
+            kCrateApiSessionSessionBuilderOptionsCommitFromMemorySuspendableConstMeta,
+        argValues: [that, modelBytes],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiExecutionProvidersXnnpackXnnpackExecutionProviderNameConstMeta =>
+  get kCrateApiSessionSessionBuilderOptionsCommitFromMemorySuspendableConstMeta =>
       const TaskConstMeta(
-        debugName: "xnnpack_execution_provider_name",
-        argNames: ["that"],
+        debugName: "session_builder_options_commit_from_memory_suspendable",
+        argNames: ["that", "modelBytes"],
       );
 
   @override
-  XNNPACKExecutionProvider
-  crateApiExecutionProvidersXnnpackXnnpackExecutionProviderNew() {
+  void crateApiMemoryBudgetSetMemoryBudget({
+    int? limitBytes,
+    required EvictionPolicy policy,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_CastedPrimitive_u_64(limitBytes, serializer);
+          sse_encode_box_autoadd_eviction_policy(policy, serializer);
           return pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 103,
+            funcId: 234,
           )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_xnnpack_execution_provider,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersXnnpackXnnpackExecutionProviderNewConstMeta,
-        argValues: [],
+        constMeta: kCrateApiMemoryBudgetSetMemoryBudgetConstMeta,
+        argValues: [limitBytes, policy],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersXnnpackXnnpackExecutionProviderNewConstMeta =>
+  TaskConstMeta get kCrateApiMemoryBudgetSetMemoryBudgetConstMeta =>
       const TaskConstMeta(
-        debugName: "xnnpack_execution_provider_new",
-        argNames: [],
+        debugName: "set_memory_budget",
+        argNames: ["limitBytes", "policy"],
       );
 
   @override
-  bool
-  crateApiExecutionProvidersXnnpackXnnpackExecutionProviderSupportedByPlatform({
-    required XNNPACKExecutionProvider that,
-  }) {
+  MemoryBudgetUsage crateApiMemoryBudgetMemoryBudgetUsage() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_xnnpack_execution_provider(that, serializer);
           return pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 104,
+            funcId: 235,
           )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_memory_budget_usage,
           decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiExecutionProvidersXnnpackXnnpackExecutionProviderSupportedByPlatformConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiMemoryBudgetMemoryBudgetUsageConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiExecutionProvidersXnnpackXnnpackExecutionProviderSupportedByPlatformConstMeta =>
-      const TaskConstMeta(
-        debugName: "xnnpack_execution_provider_supported_by_platform",
-        argNames: ["that"],
-      );
+  TaskConstMeta get kCrateApiMemoryBudgetMemoryBudgetUsageConstMeta =>
+      const TaskConstMeta(debugName: "memory_budget_usage", argNames: []);
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_Allocator => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_Allocator => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_BatchScheduler => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_BatchScheduler => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler;
 
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_MemoryInfo => wire
@@ -3511,6 +8666,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   get rust_arc_decrement_strong_count_SessionImpl => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl;
 
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_TensorDataView => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_TensorDataView => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView;
+
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_TensorImpl => wire
       .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl;
@@ -3519,12 +8682,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   get rust_arc_decrement_strong_count_TensorImpl => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl;
 
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_TensorSlice => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_TensorSlice => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice;
+
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return AnyhowException(raw as String);
   }
 
+  @protected
+  Allocator
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AllocatorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  BatchScheduler
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return BatchSchedulerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
   @protected
   MemoryInfo
   dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
@@ -3543,6 +8732,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return SessionImplImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
+  @protected
+  TensorDataView
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TensorDataViewImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
   @protected
   TensorImpl
   dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
@@ -3552,6 +8750,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return TensorImplImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
+  @protected
+  TensorSlice
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TensorSliceImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
   @protected
   SessionImpl
   dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl(
@@ -3570,6 +8777,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return TensorImplImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
+  @protected
+  TensorSlice
+  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TensorSliceImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  Allocator
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AllocatorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  BatchScheduler
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return BatchSchedulerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
   @protected
   MemoryInfo
   dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
@@ -3588,6 +8822,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return SessionImplImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
+  @protected
+  TensorDataView
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TensorDataViewImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
   @protected
   TensorImpl
   dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
@@ -3597,6 +8840,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return TensorImplImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
+  @protected
+  TensorSlice
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TensorSliceImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
   @protected
   int dco_decode_CastedPrimitive_i_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3606,32 +8858,66 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  int dco_decode_CastedPrimitive_u_64(dynamic raw) {
+  int dco_decode_CastedPrimitive_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError(
+      'Not implemented in this codec, please use the other one',
+    );
+  }
+
+  @protected
+  int dco_decode_CastedPrimitive_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError(
+      'Not implemented in this codec, please use the other one',
+    );
+  }
+
+  @protected
+  Map<String, TensorImpl>
+  dco_decode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(
+      dco_decode_list_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_tensor_impl(
+        raw,
+      ).map((e) => MapEntry(e.$1, e.$2)),
+    );
+  }
+
+  @protected
+  Map<String, String> dco_decode_Map_String_String_None(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(
+      dco_decode_list_record_string_string(raw).map((e) => MapEntry(e.$1, e.$2)),
+    );
+  }
+
+  @protected
+  Map<String, ValueImpl> dco_decode_Map_String_value_impl_None(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError(
-      'Not implemented in this codec, please use the other one',
+    return Map.fromEntries(
+      dco_decode_list_record_string_value_impl(raw).map((e) => MapEntry(e.$1, e.$2)),
     );
   }
 
   @protected
-  int dco_decode_CastedPrimitive_usize(dynamic raw) {
+  Allocator
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator(
+    dynamic raw,
+  ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError(
-      'Not implemented in this codec, please use the other one',
-    );
+    return AllocatorImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
   @protected
-  Map<String, TensorImpl>
-  dco_decode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None(
+  BatchScheduler
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler(
     dynamic raw,
   ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-      dco_decode_list_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_tensor_impl(
-        raw,
-      ).map((e) => MapEntry(e.$1, e.$2)),
-    );
+    return BatchSchedulerImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
   @protected
@@ -3652,6 +8938,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return SessionImplImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
+  @protected
+  TensorDataView
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TensorDataViewImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
   @protected
   TensorImpl
   dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
@@ -3661,6 +8956,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return TensorImplImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
+  @protected
+  TensorSlice
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TensorSliceImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
   @protected
   String dco_decode_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3708,18 +9012,69 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  AllocatorStats dco_decode_allocator_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return AllocatorStats(
+      bytesInUse: dco_decode_CastedPrimitive_u_64(arr[0]),
+      peakBytesInUse: dco_decode_CastedPrimitive_u_64(arr[1]),
+      totalAllocatedBytes: dco_decode_CastedPrimitive_u_64(arr[2]),
+      numAllocs: dco_decode_CastedPrimitive_u_64(arr[3]),
+      numFrees: dco_decode_CastedPrimitive_u_64(arr[4]),
+      largestBlock: dco_decode_CastedPrimitive_u_64(arr[5]),
+      reservedBytes: dco_decode_CastedPrimitive_u_64(arr[6]),
+    );
+  }
+
   @protected
   AllocatorType dco_decode_allocator_type(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return AllocatorType.values[raw as int];
   }
 
+  @protected
+  ArenaConfig dco_decode_arena_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ArenaConfig(
+      maxMemory: dco_decode_opt_CastedPrimitive_usize(arr[0]),
+      arenaExtendStrategy: dco_decode_opt_box_autoadd_arena_extend_strategy(
+        arr[1],
+      ),
+      initialChunkSizeBytes: dco_decode_opt_CastedPrimitive_usize(arr[2]),
+    );
+  }
+
   @protected
   ArenaExtendStrategy dco_decode_arena_extend_strategy(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return ArenaExtendStrategy.values[raw as int];
   }
 
+  @protected
+  ArenaStats dco_decode_arena_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return ArenaStats(
+      limitBytes: dco_decode_CastedPrimitive_u_64(arr[0]),
+      bytesInUse: dco_decode_CastedPrimitive_u_64(arr[1]),
+      peakBytesInUse: dco_decode_CastedPrimitive_u_64(arr[2]),
+      totalAllocatedBytes: dco_decode_CastedPrimitive_u_64(arr[3]),
+      numAllocs: dco_decode_CastedPrimitive_u_64(arr[4]),
+      numReserves: dco_decode_CastedPrimitive_u_64(arr[5]),
+      numArenaExtensions: dco_decode_CastedPrimitive_u_64(arr[6]),
+      numArenaShrinkages: dco_decode_CastedPrimitive_u_64(arr[7]),
+      largestAllocBytes: dco_decode_CastedPrimitive_u_64(arr[8]),
+    );
+  }
+
   @protected
   ArrayPointer dco_decode_array_pointer(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3732,12 +9087,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  BatchSchedulerOptions dco_decode_batch_scheduler_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return BatchSchedulerOptions(
+      maxBatchSize: dco_decode_opt_CastedPrimitive_usize(arr[0]),
+      maxDelayMs: dco_decode_opt_CastedPrimitive_u_64(arr[1]),
+    );
+  }
+
   @protected
   bool dco_decode_bool(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as bool;
   }
 
+  @protected
+  AllocationDevice dco_decode_box_autoadd_allocation_device(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_allocation_device(raw);
+  }
+
+  @protected
+  AllocatorType dco_decode_box_autoadd_allocator_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_allocator_type(raw);
+  }
+
+  @protected
+  ArenaConfig dco_decode_box_autoadd_arena_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_arena_config(raw);
+  }
+
   @protected
   ArenaExtendStrategy dco_decode_box_autoadd_arena_extend_strategy(
     dynamic raw,
@@ -3752,12 +9137,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_array_pointer(raw);
   }
 
+  @protected
+  BatchSchedulerOptions dco_decode_box_autoadd_batch_scheduler_options(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_batch_scheduler_options(raw);
+  }
+
   @protected
   bool dco_decode_box_autoadd_bool(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as bool;
   }
 
+  @protected
+  CastMode dco_decode_box_autoadd_cast_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_cast_mode(raw);
+  }
+
   @protected
   CoreMLComputeUnits dco_decode_box_autoadd_core_ml_compute_units(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3825,6 +9224,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_direct_ml_execution_provider(raw);
   }
 
+  @protected
+  EvictionPolicy dco_decode_box_autoadd_eviction_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_eviction_policy(raw);
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
   @protected
   GraphOptimizationLevel dco_decode_box_autoadd_graph_optimization_level(
     dynamic raw,
@@ -3845,6 +9256,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as int;
   }
 
+  @protected
+  MapImpl dco_decode_box_autoadd_map_impl(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_map_impl(raw);
+  }
+
+  @protected
+  MemoryType dco_decode_box_autoadd_memory_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_memory_type(raw);
+  }
+
   @protected
   NNAPIExecutionProvider dco_decode_box_autoadd_nnapi_execution_provider(
     dynamic raw,
@@ -3853,6 +9276,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_nnapi_execution_provider(raw);
   }
 
+  @protected
+  NonFiniteCheck dco_decode_box_autoadd_non_finite_check(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_non_finite_check(raw);
+  }
+
   @protected
   OrtDebugLevel dco_decode_box_autoadd_ort_debug_level(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3893,6 +9322,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_ro_cm_execution_provider(raw);
   }
 
+  @protected
+  RunMemoryUsage dco_decode_box_autoadd_run_memory_usage(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_run_memory_usage(raw);
+  }
+
   @protected
   SessionBuilderOptions dco_decode_box_autoadd_session_builder_options(
     dynamic raw,
@@ -3901,6 +9336,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_session_builder_options(raw);
   }
 
+  @protected
+  TensorDiff dco_decode_box_autoadd_tensor_diff(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_tensor_diff(raw);
+  }
+
+  @protected
+  TensorElement dco_decode_box_autoadd_tensor_element(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_tensor_element(raw);
+  }
+
+  @protected
+  TensorElementType dco_decode_box_autoadd_tensor_element_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_tensor_element_type(raw);
+  }
+
   @protected
   TensorRTExecutionProvider dco_decode_box_autoadd_tensor_rt_execution_provider(
     dynamic raw,
@@ -3909,6 +9362,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_tensor_rt_execution_provider(raw);
   }
 
+  @protected
+  TensorStats dco_decode_box_autoadd_tensor_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_tensor_stats(raw);
+  }
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3929,6 +9388,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_xnnpack_execution_provider(raw);
   }
 
+  @protected
+  ValueImpl dco_decode_box_value_impl(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_value_impl(raw);
+  }
+
+  @protected
+  CastMode dco_decode_cast_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CastMode.values[raw as int];
+  }
+
   @protected
   CoreMLComputeUnits dco_decode_core_ml_compute_units(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4037,6 +9508,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  EvictionPolicy dco_decode_eviction_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return EvictionPolicy.values[raw as int];
+  }
+
   @protected
   ExecutionProvider dco_decode_execution_provider(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4133,6 +9610,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return Input(name: dco_decode_String(arr[0]));
   }
 
+  @protected
+  List<TensorImpl>
+  dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(
+          dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl,
+        )
+        .toList();
+  }
+
   @protected
   List<int> dco_decode_list_CastedPrimitive_i_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4145,6 +9635,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_CastedPrimitive_u_64).toList();
   }
 
+  @protected
+  List<Map<String, TensorImpl>>
+  dco_decode_list_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(
+          dco_decode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None,
+        )
+        .toList();
+  }
+
   @protected
   List<String> dco_decode_list_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4284,6 +9787,82 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         .toList();
   }
 
+  @protected
+  List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
+  }
+
+  @protected
+  List<(String, ValueImpl)> dco_decode_list_record_string_value_impl(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_record_string_value_impl)
+        .toList();
+  }
+
+  @protected
+  List<ValueImpl> dco_decode_list_value_impl(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_value_impl).toList();
+  }
+
+  @protected
+  MapImpl dco_decode_map_impl(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return MapImpl(
+      keys: dco_decode_map_keys(arr[0]),
+      values:
+          dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            arr[1],
+          ),
+    );
+  }
+
+  @protected
+  MapKeys dco_decode_map_keys(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return MapKeys_Int64(dco_decode_list_CastedPrimitive_i_64(raw[1]));
+      case 1:
+        return MapKeys_String(dco_decode_list_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  MemoryBudgetUsage dco_decode_memory_budget_usage(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return MemoryBudgetUsage(
+      limitBytes: dco_decode_opt_CastedPrimitive_u_64(arr[0]),
+      tensorBytes: dco_decode_CastedPrimitive_u_64(arr[1]),
+      sessionBytes: dco_decode_CastedPrimitive_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  MemoryTrimReport dco_decode_memory_trim_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return MemoryTrimReport(
+      sessionsSuspended: dco_decode_CastedPrimitive_u_64(arr[0]),
+      releasedBytes: dco_decode_CastedPrimitive_u_64(arr[1]),
+      sessionsScheduled: dco_decode_CastedPrimitive_u_64(arr[2]),
+    );
+  }
+
   @protected
   MemoryType dco_decode_memory_type(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4304,12 +9883,47 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  NonFiniteCheck dco_decode_non_finite_check(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return NonFiniteCheck.values[raw as int];
+  }
+
+  @protected
+  MemoryInfo?
+  dco_decode_opt_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_CastedPrimitive_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_CastedPrimitive_i_64(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_CastedPrimitive_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_CastedPrimitive_u_64(raw);
+  }
+
   @protected
   int? dco_decode_opt_CastedPrimitive_usize(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw == null ? null : dco_decode_CastedPrimitive_usize(raw);
   }
 
+  @protected
+  Map<String, String>? dco_decode_opt_Map_String_String_None(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_Map_String_String_None(raw);
+  }
+
   @protected
   String? dco_decode_opt_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4332,6 +9946,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_bool(raw);
   }
 
+  @protected
+  CastMode? dco_decode_opt_box_autoadd_cast_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_cast_mode(raw);
+  }
+
   @protected
   CoreMLComputeUnits? dco_decode_opt_box_autoadd_core_ml_compute_units(
     dynamic raw,
@@ -4380,6 +10000,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         : dco_decode_box_autoadd_cuda_attention_backend(raw);
   }
 
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+  }
+
   @protected
   GraphOptimizationLevel? dco_decode_opt_box_autoadd_graph_optimization_level(
     dynamic raw,
@@ -4436,6 +10062,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_qnn_profiling_level(raw);
   }
 
+  @protected
+  RunMemoryUsage? dco_decode_opt_box_autoadd_run_memory_usage(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_run_memory_usage(raw);
+  }
+
   @protected
   int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4448,6 +10080,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_u_8(raw);
   }
 
+  @protected
+  ValueImpl? dco_decode_opt_box_value_impl(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_value_impl(raw);
+  }
+
   @protected
   List<int>? dco_decode_opt_list_CastedPrimitive_i_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4537,6 +10175,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  (String, String) dco_decode_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_String(arr[1]));
+  }
+
+  @protected
+  (String, ValueImpl) dco_decode_record_string_value_impl(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_value_impl(arr[1]));
+  }
+
   @protected
   ROCmExecutionProvider dco_decode_ro_cm_execution_provider(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4559,12 +10217,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  RunMemoryUsage dco_decode_run_memory_usage(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return RunMemoryUsage(
+      peakBytesInUse: dco_decode_CastedPrimitive_u_64(arr[0]),
+      peakIncreaseBytes: dco_decode_CastedPrimitive_u_64(arr[1]),
+    );
+  }
+
   @protected
   SessionBuilderOptions dco_decode_session_builder_options(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
     return SessionBuilderOptions(
       executionProviders: dco_decode_opt_list_execution_provider(arr[0]),
       intraThreads: dco_decode_opt_CastedPrimitive_usize(arr[1]),
@@ -4574,9 +10244,49 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         arr[4],
       ),
       memoryPattern: dco_decode_opt_box_autoadd_bool(arr[5]),
+      envAllocators: dco_decode_opt_box_autoadd_bool(arr[6]),
+      cpuMemoryArena: dco_decode_opt_box_autoadd_bool(arr[7]),
+    );
+  }
+
+  @protected
+  TensorDiff dco_decode_tensor_diff(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return TensorDiff(
+      isClose: dco_decode_bool(arr[0]),
+      dtypeMismatch: dco_decode_bool(arr[1]),
+      shapeMismatch: dco_decode_bool(arr[2]),
+      mismatchCount: dco_decode_CastedPrimitive_u_64(arr[3]),
+      maxAbsError: dco_decode_f_64(arr[4]),
+      maxRelError: dco_decode_f_64(arr[5]),
+      worstIndex: dco_decode_opt_list_CastedPrimitive_i_64(arr[6]),
+      actual: dco_decode_opt_String(arr[7]),
+      expected: dco_decode_opt_String(arr[8]),
     );
   }
 
+  @protected
+  TensorElement dco_decode_tensor_element(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return TensorElement_Bool(dco_decode_bool(raw[1]));
+      case 1:
+        return TensorElement_Int(dco_decode_CastedPrimitive_i_64(raw[1]));
+      case 2:
+        return TensorElement_Uint(dco_decode_CastedPrimitive_u_64(raw[1]));
+      case 3:
+        return TensorElement_Float(dco_decode_f_64(raw[1]));
+      case 4:
+        return TensorElement_String(dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
   @protected
   TensorElementType dco_decode_tensor_element_type(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4634,6 +10344,43 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  TensorStats dco_decode_tensor_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return TensorStats(
+      count: dco_decode_CastedPrimitive_u_64(arr[0]),
+      min: dco_decode_f_64(arr[1]),
+      max: dco_decode_f_64(arr[2]),
+      mean: dco_decode_f_64(arr[3]),
+      std: dco_decode_f_64(arr[4]),
+      nanCount: dco_decode_CastedPrimitive_u_64(arr[5]),
+      positiveInfinityCount: dco_decode_CastedPrimitive_u_64(arr[6]),
+      negativeInfinityCount: dco_decode_CastedPrimitive_u_64(arr[7]),
+      zeroCount: dco_decode_CastedPrimitive_u_64(arr[8]),
+    );
+  }
+
+  @protected
+  TopK dco_decode_top_k(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TopK(
+      values:
+          dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            arr[0],
+          ),
+      indices:
+          dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            arr[1],
+          ),
+    );
+  }
+
   @protected
   int dco_decode_u_16(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4670,6 +10417,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dcoDecodeU64(raw);
   }
 
+  @protected
+  ValueImpl dco_decode_value_impl(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return ValueImpl_Tensor(
+          dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+            raw[1],
+          ),
+        );
+      case 1:
+        return ValueImpl_Sequence(dco_decode_list_value_impl(raw[1]));
+      case 2:
+        return ValueImpl_Map(dco_decode_box_autoadd_map_impl(raw[1]));
+      case 3:
+        return ValueImpl_Optional(dco_decode_opt_box_value_impl(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
   @protected
   XNNPACKExecutionProvider dco_decode_xnnpack_execution_provider(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4688,6 +10456,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return AnyhowException(inner);
   }
 
+  @protected
+  Allocator
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return AllocatorImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  BatchScheduler
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return BatchSchedulerImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
   @protected
   MemoryInfo
   sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
@@ -4712,6 +10504,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  TensorDataView
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return TensorDataViewImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
   @protected
   TensorImpl
   sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
@@ -4724,6 +10528,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  TensorSlice
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return TensorSliceImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
   @protected
   SessionImpl
   sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl(
@@ -4748,6 +10564,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  TensorSlice
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return TensorSliceImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  Allocator
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return AllocatorImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  BatchScheduler
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return BatchSchedulerImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
   @protected
   MemoryInfo
   sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
@@ -4772,6 +10624,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  TensorDataView
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return TensorDataViewImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
   @protected
   TensorImpl
   sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
@@ -4784,6 +10648,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  TensorSlice
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return TensorSliceImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
   @protected
   int sse_decode_CastedPrimitive_i_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4818,6 +10694,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
   }
 
+  @protected
+  Map<String, String> sse_decode_Map_String_String_None(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_string(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  Map<String, ValueImpl> sse_decode_Map_String_value_impl_None(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_value_impl(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  Allocator
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return AllocatorImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  BatchScheduler
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return BatchSchedulerImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
   @protected
   MemoryInfo
   sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
@@ -4842,6 +10760,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  TensorDataView
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return TensorDataViewImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
   @protected
   TensorImpl
   sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
@@ -4854,6 +10784,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  TensorSlice
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return TensorSliceImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
   @protected
   String sse_decode_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4899,6 +10841,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  AllocatorStats sse_decode_allocator_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_bytesInUse = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_peakBytesInUse = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_totalAllocatedBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_numAllocs = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_numFrees = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_largestBlock = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_reservedBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+    return AllocatorStats(
+      bytesInUse: var_bytesInUse,
+      peakBytesInUse: var_peakBytesInUse,
+      totalAllocatedBytes: var_totalAllocatedBytes,
+      numAllocs: var_numAllocs,
+      numFrees: var_numFrees,
+      largestBlock: var_largestBlock,
+      reservedBytes: var_reservedBytes,
+    );
+  }
+
   @protected
   AllocatorType sse_decode_allocator_type(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4906,6 +10869,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return AllocatorType.values[inner];
   }
 
+  @protected
+  ArenaConfig sse_decode_arena_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxMemory = sse_decode_opt_CastedPrimitive_usize(deserializer);
+    var var_arenaExtendStrategy =
+        sse_decode_opt_box_autoadd_arena_extend_strategy(deserializer);
+    var var_initialChunkSizeBytes = sse_decode_opt_CastedPrimitive_usize(
+      deserializer,
+    );
+    return ArenaConfig(
+      maxMemory: var_maxMemory,
+      arenaExtendStrategy: var_arenaExtendStrategy,
+      initialChunkSizeBytes: var_initialChunkSizeBytes,
+    );
+  }
+
   @protected
   ArenaExtendStrategy sse_decode_arena_extend_strategy(
     SseDeserializer deserializer,
@@ -4915,6 +10894,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ArenaExtendStrategy.values[inner];
   }
 
+  @protected
+  ArenaStats sse_decode_arena_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_limitBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_bytesInUse = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_peakBytesInUse = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_totalAllocatedBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_numAllocs = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_numReserves = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_numArenaExtensions = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_numArenaShrinkages = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_largestAllocBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+    return ArenaStats(
+      limitBytes: var_limitBytes,
+      bytesInUse: var_bytesInUse,
+      peakBytesInUse: var_peakBytesInUse,
+      totalAllocatedBytes: var_totalAllocatedBytes,
+      numAllocs: var_numAllocs,
+      numReserves: var_numReserves,
+      numArenaExtensions: var_numArenaExtensions,
+      numArenaShrinkages: var_numArenaShrinkages,
+      largestAllocBytes: var_largestAllocBytes,
+    );
+  }
+
   @protected
   ArrayPointer sse_decode_array_pointer(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4923,12 +10927,49 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ArrayPointer(ptr: var_ptr, len: var_len);
   }
 
+  @protected
+  BatchSchedulerOptions sse_decode_batch_scheduler_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxBatchSize = sse_decode_opt_CastedPrimitive_usize(deserializer);
+    var var_maxDelayMs = sse_decode_opt_CastedPrimitive_u_64(deserializer);
+    return BatchSchedulerOptions(
+      maxBatchSize: var_maxBatchSize,
+      maxDelayMs: var_maxDelayMs,
+    );
+  }
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return deserializer.buffer.getUint8() != 0;
   }
 
+  @protected
+  AllocationDevice sse_decode_box_autoadd_allocation_device(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_allocation_device(deserializer));
+  }
+
+  @protected
+  AllocatorType sse_decode_box_autoadd_allocator_type(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_allocator_type(deserializer));
+  }
+
+  @protected
+  ArenaConfig sse_decode_box_autoadd_arena_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_arena_config(deserializer));
+  }
+
   @protected
   ArenaExtendStrategy sse_decode_box_autoadd_arena_extend_strategy(
     SseDeserializer deserializer,
@@ -4945,12 +10986,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_array_pointer(deserializer));
   }
 
+  @protected
+  BatchSchedulerOptions sse_decode_box_autoadd_batch_scheduler_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_batch_scheduler_options(deserializer));
+  }
+
   @protected
   bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return (sse_decode_bool(deserializer));
   }
 
+  @protected
+  CastMode sse_decode_box_autoadd_cast_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_cast_mode(deserializer));
+  }
+
   @protected
   CoreMLComputeUnits sse_decode_box_autoadd_core_ml_compute_units(
     SseDeserializer deserializer,
@@ -5005,23 +11060,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_cuda_attention_backend(deserializer));
+    return (sse_decode_cuda_attention_backend(deserializer));
+  }
+
+  @protected
+  CUDAExecutionProvider sse_decode_box_autoadd_cuda_execution_provider(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_cuda_execution_provider(deserializer));
+  }
+
+  @protected
+  DirectMLExecutionProvider sse_decode_box_autoadd_direct_ml_execution_provider(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_direct_ml_execution_provider(deserializer));
   }
 
   @protected
-  CUDAExecutionProvider sse_decode_box_autoadd_cuda_execution_provider(
+  EvictionPolicy sse_decode_box_autoadd_eviction_policy(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_cuda_execution_provider(deserializer));
+    return (sse_decode_eviction_policy(deserializer));
   }
 
   @protected
-  DirectMLExecutionProvider sse_decode_box_autoadd_direct_ml_execution_provider(
-    SseDeserializer deserializer,
-  ) {
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_direct_ml_execution_provider(deserializer));
+    return (sse_decode_f_64(deserializer));
   }
 
   @protected
@@ -5044,6 +11113,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_i_8(deserializer));
   }
 
+  @protected
+  MapImpl sse_decode_box_autoadd_map_impl(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_map_impl(deserializer));
+  }
+
+  @protected
+  MemoryType sse_decode_box_autoadd_memory_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_memory_type(deserializer));
+  }
+
   @protected
   NNAPIExecutionProvider sse_decode_box_autoadd_nnapi_execution_provider(
     SseDeserializer deserializer,
@@ -5052,6 +11133,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_nnapi_execution_provider(deserializer));
   }
 
+  @protected
+  NonFiniteCheck sse_decode_box_autoadd_non_finite_check(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_non_finite_check(deserializer));
+  }
+
   @protected
   OrtDebugLevel sse_decode_box_autoadd_ort_debug_level(
     SseDeserializer deserializer,
@@ -5100,6 +11189,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_ro_cm_execution_provider(deserializer));
   }
 
+  @protected
+  RunMemoryUsage sse_decode_box_autoadd_run_memory_usage(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_run_memory_usage(deserializer));
+  }
+
   @protected
   SessionBuilderOptions sse_decode_box_autoadd_session_builder_options(
     SseDeserializer deserializer,
@@ -5108,6 +11205,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_session_builder_options(deserializer));
   }
 
+  @protected
+  TensorDiff sse_decode_box_autoadd_tensor_diff(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_tensor_diff(deserializer));
+  }
+
+  @protected
+  TensorElement sse_decode_box_autoadd_tensor_element(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_tensor_element(deserializer));
+  }
+
+  @protected
+  TensorElementType sse_decode_box_autoadd_tensor_element_type(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_tensor_element_type(deserializer));
+  }
+
   @protected
   TensorRTExecutionProvider sse_decode_box_autoadd_tensor_rt_execution_provider(
     SseDeserializer deserializer,
@@ -5116,6 +11235,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_tensor_rt_execution_provider(deserializer));
   }
 
+  @protected
+  TensorStats sse_decode_box_autoadd_tensor_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_tensor_stats(deserializer));
+  }
+
   @protected
   int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5136,6 +11263,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_xnnpack_execution_provider(deserializer));
   }
 
+  @protected
+  ValueImpl sse_decode_box_value_impl(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_value_impl(deserializer));
+  }
+
+  @protected
+  CastMode sse_decode_cast_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return CastMode.values[inner];
+  }
+
   @protected
   CoreMLComputeUnits sse_decode_core_ml_compute_units(
     SseDeserializer deserializer,
@@ -5277,6 +11417,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return DirectMLExecutionProvider.raw(deviceId: var_deviceId);
   }
 
+  @protected
+  EvictionPolicy sse_decode_eviction_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return EvictionPolicy.values[inner];
+  }
+
   @protected
   ExecutionProvider sse_decode_execution_provider(
     SseDeserializer deserializer,
@@ -5387,6 +11534,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return Input(name: var_name);
   }
 
+  @protected
+  List<TensorImpl>
+  sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TensorImpl>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+          deserializer,
+        ),
+      );
+    }
+    return ans_;
+  }
+
   @protected
   List<int> sse_decode_list_CastedPrimitive_i_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5411,6 +11577,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<Map<String, TensorImpl>>
+  sse_decode_list_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Map<String, TensorImpl>>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(
+        sse_decode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None(
+          deserializer,
+        ),
+      );
+    }
+    return ans_;
+  }
+
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5604,6 +11789,102 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<(String, String)> sse_decode_list_record_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, String)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_string(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<(String, ValueImpl)> sse_decode_list_record_string_value_impl(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, ValueImpl)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_value_impl(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ValueImpl> sse_decode_list_value_impl(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ValueImpl>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_value_impl(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  MapImpl sse_decode_map_impl(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_keys = sse_decode_map_keys(deserializer);
+    var var_values =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+          deserializer,
+        );
+    return MapImpl(keys: var_keys, values: var_values);
+  }
+
+  @protected
+  MapKeys sse_decode_map_keys(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_list_CastedPrimitive_i_64(deserializer);
+        return MapKeys_Int64(var_field0);
+      case 1:
+        var var_field0 = sse_decode_list_String(deserializer);
+        return MapKeys_String(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  MemoryBudgetUsage sse_decode_memory_budget_usage(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_limitBytes = sse_decode_opt_CastedPrimitive_u_64(deserializer);
+    var var_tensorBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_sessionBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+    return MemoryBudgetUsage(
+      limitBytes: var_limitBytes,
+      tensorBytes: var_tensorBytes,
+      sessionBytes: var_sessionBytes,
+    );
+  }
+
+  @protected
+  MemoryTrimReport sse_decode_memory_trim_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sessionsSuspended = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_releasedBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_sessionsScheduled = sse_decode_CastedPrimitive_u_64(deserializer);
+    return MemoryTrimReport(
+      sessionsSuspended: var_sessionsSuspended,
+      releasedBytes: var_releasedBytes,
+      sessionsScheduled: var_sessionsScheduled,
+    );
+  }
+
   @protected
   MemoryType sse_decode_memory_type(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5628,6 +11909,51 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  NonFiniteCheck sse_decode_non_finite_check(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return NonFiniteCheck.values[inner];
+  }
+
+  @protected
+  MemoryInfo?
+  sse_decode_opt_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
+        deserializer,
+      ));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_CastedPrimitive_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_CastedPrimitive_i_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_CastedPrimitive_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_CastedPrimitive_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   int? sse_decode_opt_CastedPrimitive_usize(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5639,6 +11965,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  Map<String, String>? sse_decode_opt_Map_String_String_None(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_Map_String_String_None(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5674,6 +12013,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  CastMode? sse_decode_opt_box_autoadd_cast_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_cast_mode(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   CoreMLComputeUnits? sse_decode_opt_box_autoadd_core_ml_compute_units(
     SseDeserializer deserializer,
@@ -5745,6 +12095,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   GraphOptimizationLevel? sse_decode_opt_box_autoadd_graph_optimization_level(
     SseDeserializer deserializer,
@@ -5832,6 +12193,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  RunMemoryUsage? sse_decode_opt_box_autoadd_run_memory_usage(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_run_memory_usage(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5854,6 +12228,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  ValueImpl? sse_decode_opt_box_value_impl(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_value_impl(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   List<int>? sse_decode_opt_list_CastedPrimitive_i_64(
     SseDeserializer deserializer,
@@ -5982,6 +12367,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (var_field0, var_field1);
   }
 
+  @protected
+  (String, String) sse_decode_record_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_String(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (String, ValueImpl) sse_decode_record_string_value_impl(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_value_impl(deserializer);
+    return (var_field0, var_field1);
+  }
+
   @protected
   ROCmExecutionProvider sse_decode_ro_cm_execution_provider(
     SseDeserializer deserializer,
@@ -6014,6 +12419,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  RunMemoryUsage sse_decode_run_memory_usage(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_peakBytesInUse = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_peakIncreaseBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+    return RunMemoryUsage(
+      peakBytesInUse: var_peakBytesInUse,
+      peakIncreaseBytes: var_peakIncreaseBytes,
+    );
+  }
+
   @protected
   SessionBuilderOptions sse_decode_session_builder_options(
     SseDeserializer deserializer,
@@ -6028,6 +12444,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_optimizationLevel =
         sse_decode_opt_box_autoadd_graph_optimization_level(deserializer);
     var var_memoryPattern = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_envAllocators = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_cpuMemoryArena = sse_decode_opt_box_autoadd_bool(deserializer);
     return SessionBuilderOptions(
       executionProviders: var_executionProviders,
       intraThreads: var_intraThreads,
@@ -6035,9 +12453,62 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       parallelExecution: var_parallelExecution,
       optimizationLevel: var_optimizationLevel,
       memoryPattern: var_memoryPattern,
+      envAllocators: var_envAllocators,
+      cpuMemoryArena: var_cpuMemoryArena,
     );
   }
 
+  @protected
+  TensorDiff sse_decode_tensor_diff(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_isClose = sse_decode_bool(deserializer);
+    var var_dtypeMismatch = sse_decode_bool(deserializer);
+    var var_shapeMismatch = sse_decode_bool(deserializer);
+    var var_mismatchCount = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_maxAbsError = sse_decode_f_64(deserializer);
+    var var_maxRelError = sse_decode_f_64(deserializer);
+    var var_worstIndex = sse_decode_opt_list_CastedPrimitive_i_64(deserializer);
+    var var_actual = sse_decode_opt_String(deserializer);
+    var var_expected = sse_decode_opt_String(deserializer);
+    return TensorDiff(
+      isClose: var_isClose,
+      dtypeMismatch: var_dtypeMismatch,
+      shapeMismatch: var_shapeMismatch,
+      mismatchCount: var_mismatchCount,
+      maxAbsError: var_maxAbsError,
+      maxRelError: var_maxRelError,
+      worstIndex: var_worstIndex,
+      actual: var_actual,
+      expected: var_expected,
+    );
+  }
+
+  @protected
+  TensorElement sse_decode_tensor_element(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_bool(deserializer);
+        return TensorElement_Bool(var_field0);
+      case 1:
+        var var_field0 = sse_decode_CastedPrimitive_i_64(deserializer);
+        return TensorElement_Int(var_field0);
+      case 2:
+        var var_field0 = sse_decode_CastedPrimitive_u_64(deserializer);
+        return TensorElement_Uint(var_field0);
+      case 3:
+        var var_field0 = sse_decode_f_64(deserializer);
+        return TensorElement_Float(var_field0);
+      case 4:
+        var var_field0 = sse_decode_String(deserializer);
+        return TensorElement_String(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
   @protected
   TensorElementType sse_decode_tensor_element_type(
     SseDeserializer deserializer,
@@ -6152,6 +12623,49 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  TensorStats sse_decode_tensor_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_count = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_min = sse_decode_f_64(deserializer);
+    var var_max = sse_decode_f_64(deserializer);
+    var var_mean = sse_decode_f_64(deserializer);
+    var var_std = sse_decode_f_64(deserializer);
+    var var_nanCount = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_positiveInfinityCount = sse_decode_CastedPrimitive_u_64(
+      deserializer,
+    );
+    var var_negativeInfinityCount = sse_decode_CastedPrimitive_u_64(
+      deserializer,
+    );
+    var var_zeroCount = sse_decode_CastedPrimitive_u_64(deserializer);
+    return TensorStats(
+      count: var_count,
+      min: var_min,
+      max: var_max,
+      mean: var_mean,
+      std: var_std,
+      nanCount: var_nanCount,
+      positiveInfinityCount: var_positiveInfinityCount,
+      negativeInfinityCount: var_negativeInfinityCount,
+      zeroCount: var_zeroCount,
+    );
+  }
+
+  @protected
+  TopK sse_decode_top_k(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_values =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+          deserializer,
+        );
+    var var_indices =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+          deserializer,
+        );
+    return TopK(values: var_values, indices: var_indices);
+  }
+
   @protected
   int sse_decode_u_16(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6187,6 +12701,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getBigUint64();
   }
 
+  @protected
+  ValueImpl sse_decode_value_impl(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 =
+            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+              deserializer,
+            );
+        return ValueImpl_Tensor(var_field0);
+      case 1:
+        var var_field0 = sse_decode_list_value_impl(deserializer);
+        return ValueImpl_Sequence(var_field0);
+      case 2:
+        var var_field0 = sse_decode_box_autoadd_map_impl(deserializer);
+        return ValueImpl_Map(var_field0);
+      case 3:
+        var var_field0 = sse_decode_opt_box_value_impl(deserializer);
+        return ValueImpl_Optional(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
   @protected
   XNNPACKExecutionProvider sse_decode_xnnpack_execution_provider(
     SseDeserializer deserializer,
@@ -6209,6 +12749,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.message, serializer);
   }
 
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator(
+    Allocator self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as AllocatorImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler(
+    BatchScheduler self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as BatchSchedulerImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
   @protected
   void
   sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
@@ -6235,6 +12801,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+    TensorDataView self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as TensorDataViewImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
   @protected
   void
   sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
@@ -6248,6 +12827,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+    TensorSlice self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as TensorSliceImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
   @protected
   void
   sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl(
@@ -6274,6 +12866,45 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+    TensorSlice self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as TensorSliceImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator(
+    Allocator self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as AllocatorImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler(
+    BatchScheduler self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as BatchSchedulerImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
+
   @protected
   void
   sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
@@ -6300,6 +12931,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+    TensorDataView self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as TensorDataViewImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
+
   @protected
   void
   sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
@@ -6313,6 +12957,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+    TensorSlice self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as TensorSliceImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_CastedPrimitive_i_64(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6344,6 +13001,56 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_Map_String_String_None(
+    Map<String, String> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_string(
+      self.entries.map((e) => (e.key, e.value)).toList(),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_Map_String_value_impl_None(
+    Map<String, ValueImpl> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_value_impl(
+      self.entries.map((e) => (e.key, e.value)).toList(),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator(
+    Allocator self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as AllocatorImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler(
+    BatchScheduler self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as BatchSchedulerImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
   @protected
   void
   sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
@@ -6370,6 +13077,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+    TensorDataView self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as TensorDataViewImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
   @protected
   void
   sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
@@ -6383,6 +13103,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+    TensorSlice self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as TensorSliceImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6426,12 +13159,41 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_allocator_stats(
+    AllocatorStats self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_CastedPrimitive_u_64(self.bytesInUse, serializer);
+    sse_encode_CastedPrimitive_u_64(self.peakBytesInUse, serializer);
+    sse_encode_CastedPrimitive_u_64(self.totalAllocatedBytes, serializer);
+    sse_encode_CastedPrimitive_u_64(self.numAllocs, serializer);
+    sse_encode_CastedPrimitive_u_64(self.numFrees, serializer);
+    sse_encode_CastedPrimitive_u_64(self.largestBlock, serializer);
+    sse_encode_CastedPrimitive_u_64(self.reservedBytes, serializer);
+  }
+
   @protected
   void sse_encode_allocator_type(AllocatorType self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.index, serializer);
   }
 
+  @protected
+  void sse_encode_arena_config(ArenaConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_CastedPrimitive_usize(self.maxMemory, serializer);
+    sse_encode_opt_box_autoadd_arena_extend_strategy(
+      self.arenaExtendStrategy,
+      serializer,
+    );
+    sse_encode_opt_CastedPrimitive_usize(
+      self.initialChunkSizeBytes,
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_arena_extend_strategy(
     ArenaExtendStrategy self,
@@ -6442,16 +13204,67 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_array_pointer(ArrayPointer self, SseSerializer serializer) {
+  void sse_encode_arena_stats(ArenaStats self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_CastedPrimitive_u_64(self.limitBytes, serializer);
+    sse_encode_CastedPrimitive_u_64(self.bytesInUse, serializer);
+    sse_encode_CastedPrimitive_u_64(self.peakBytesInUse, serializer);
+    sse_encode_CastedPrimitive_u_64(self.totalAllocatedBytes, serializer);
+    sse_encode_CastedPrimitive_u_64(self.numAllocs, serializer);
+    sse_encode_CastedPrimitive_u_64(self.numReserves, serializer);
+    sse_encode_CastedPrimitive_u_64(self.numArenaExtensions, serializer);
+    sse_encode_CastedPrimitive_u_64(self.numArenaShrinkages, serializer);
+    sse_encode_CastedPrimitive_u_64(self.largestAllocBytes, serializer);
+  }
+
+  @protected
+  void sse_encode_array_pointer(ArrayPointer self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_CastedPrimitive_usize(self.ptr, serializer);
+    sse_encode_CastedPrimitive_usize(self.len, serializer);
+  }
+
+  @protected
+  void sse_encode_batch_scheduler_options(
+    BatchSchedulerOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_CastedPrimitive_usize(self.maxBatchSize, serializer);
+    sse_encode_opt_CastedPrimitive_u_64(self.maxDelayMs, serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_allocation_device(
+    AllocationDevice self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_allocation_device(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_allocator_type(
+    AllocatorType self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_CastedPrimitive_usize(self.ptr, serializer);
-    sse_encode_CastedPrimitive_usize(self.len, serializer);
+    sse_encode_allocator_type(self, serializer);
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_box_autoadd_arena_config(
+    ArenaConfig self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_arena_config(self, serializer);
   }
 
   @protected
@@ -6472,12 +13285,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_array_pointer(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_batch_scheduler_options(
+    BatchSchedulerOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_batch_scheduler_options(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_bool(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_cast_mode(
+    CastMode self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_cast_mode(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_core_ml_compute_units(
     CoreMLComputeUnits self,
@@ -6559,6 +13390,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_direct_ml_execution_provider(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_eviction_policy(
+    EvictionPolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_eviction_policy(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_graph_optimization_level(
     GraphOptimizationLevel self,
@@ -6580,6 +13426,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_8(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_map_impl(MapImpl self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_map_impl(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_memory_type(
+    MemoryType self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_memory_type(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_nnapi_execution_provider(
     NNAPIExecutionProvider self,
@@ -6589,6 +13450,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_nnapi_execution_provider(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_non_finite_check(
+    NonFiniteCheck self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_non_finite_check(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_ort_debug_level(
     OrtDebugLevel self,
@@ -6643,6 +13513,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_ro_cm_execution_provider(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_run_memory_usage(
+    RunMemoryUsage self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_run_memory_usage(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_session_builder_options(
     SessionBuilderOptions self,
@@ -6652,6 +13531,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_session_builder_options(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_tensor_diff(
+    TensorDiff self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_tensor_diff(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_tensor_element(
+    TensorElement self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_tensor_element(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_tensor_element_type(
+    TensorElementType self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_tensor_element_type(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_tensor_rt_execution_provider(
     TensorRTExecutionProvider self,
@@ -6661,6 +13567,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_tensor_rt_execution_provider(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_tensor_stats(
+    TensorStats self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_tensor_stats(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6682,6 +13597,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_xnnpack_execution_provider(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_value_impl(ValueImpl self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_value_impl(self, serializer);
+  }
+
+  @protected
+  void sse_encode_cast_mode(CastMode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_core_ml_compute_units(
     CoreMLComputeUnits self,
@@ -6807,6 +13734,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_i_32(self.deviceId, serializer);
   }
 
+  @protected
+  void sse_encode_eviction_policy(
+    EvictionPolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_execution_provider(
     ExecutionProvider self,
@@ -6895,6 +13831,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.name, serializer);
   }
 
+  @protected
+  void
+  sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+    List<TensorImpl> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+        item,
+        serializer,
+      );
+    }
+  }
+
   @protected
   void sse_encode_list_CastedPrimitive_i_64(
     List<int> self,
@@ -6919,6 +13871,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void
+  sse_encode_list_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None(
+    List<Map<String, TensorImpl>> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None(
+        item,
+        serializer,
+      );
+    }
+  }
+
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7159,6 +14127,87 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_record_string_string(
+    List<(String, String)> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_string(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_record_string_value_impl(
+    List<(String, ValueImpl)> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_value_impl(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_value_impl(
+    List<ValueImpl> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_value_impl(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_map_impl(MapImpl self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_map_keys(self.keys, serializer);
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+      self.values,
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_map_keys(MapKeys self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case MapKeys_Int64(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_list_CastedPrimitive_i_64(field0, serializer);
+      case MapKeys_String(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_list_String(field0, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_memory_budget_usage(
+    MemoryBudgetUsage self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_CastedPrimitive_u_64(self.limitBytes, serializer);
+    sse_encode_CastedPrimitive_u_64(self.tensorBytes, serializer);
+    sse_encode_CastedPrimitive_u_64(self.sessionBytes, serializer);
+  }
+
+  @protected
+  void sse_encode_memory_trim_report(
+    MemoryTrimReport self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_CastedPrimitive_u_64(self.sessionsSuspended, serializer);
+    sse_encode_CastedPrimitive_u_64(self.releasedBytes, serializer);
+    sse_encode_CastedPrimitive_u_64(self.sessionsScheduled, serializer);
+  }
+
   @protected
   void sse_encode_memory_type(MemoryType self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7177,6 +14226,58 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_bool(self.cpuOnly, serializer);
   }
 
+  @protected
+  void sse_encode_non_finite_check(
+    NonFiniteCheck self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void
+  sse_encode_opt_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
+    MemoryInfo? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
+        self,
+        serializer,
+      );
+    }
+  }
+
+  @protected
+  void sse_encode_opt_CastedPrimitive_i_64(
+    int? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_CastedPrimitive_i_64(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_CastedPrimitive_u_64(
+    int? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_CastedPrimitive_u_64(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_CastedPrimitive_usize(
     int? self,
@@ -7190,6 +14291,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_Map_String_String_None(
+    Map<String, String>? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_Map_String_String_None(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7223,6 +14337,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_cast_mode(
+    CastMode? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_cast_mode(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_core_ml_compute_units(
     CoreMLComputeUnits? self,
@@ -7288,6 +14415,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_64(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_graph_optimization_level(
     GraphOptimizationLevel? self,
@@ -7356,20 +14493,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_qnn_performance_mode(self, serializer);
+      sse_encode_box_autoadd_qnn_performance_mode(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_qnn_profiling_level(
+    QNNProfilingLevel? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_qnn_profiling_level(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_qnn_profiling_level(
-    QNNProfilingLevel? self,
+  void sse_encode_opt_box_autoadd_run_memory_usage(
+    RunMemoryUsage? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_qnn_profiling_level(self, serializer);
+      sse_encode_box_autoadd_run_memory_usage(self, serializer);
     }
   }
 
@@ -7393,6 +14543,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_value_impl(
+    ValueImpl? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_value_impl(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_list_CastedPrimitive_i_64(
     List<int>? self,
@@ -7509,6 +14672,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_record_string_string(
+    (String, String) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.$1, serializer);
+    sse_encode_String(self.$2, serializer);
+  }
+
+  @protected
+  void sse_encode_record_string_value_impl(
+    (String, ValueImpl) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.$1, serializer);
+    sse_encode_value_impl(self.$2, serializer);
+  }
+
   @protected
   void sse_encode_ro_cm_execution_provider(
     ROCmExecutionProvider self,
@@ -7530,6 +14713,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_i_32(self.maxTuningDuration, serializer);
   }
 
+  @protected
+  void sse_encode_run_memory_usage(
+    RunMemoryUsage self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_CastedPrimitive_u_64(self.peakBytesInUse, serializer);
+    sse_encode_CastedPrimitive_u_64(self.peakIncreaseBytes, serializer);
+  }
+
   @protected
   void sse_encode_session_builder_options(
     SessionBuilderOptions self,
@@ -7545,6 +14738,44 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       serializer,
     );
     sse_encode_opt_box_autoadd_bool(self.memoryPattern, serializer);
+    sse_encode_opt_box_autoadd_bool(self.envAllocators, serializer);
+    sse_encode_opt_box_autoadd_bool(self.cpuMemoryArena, serializer);
+  }
+
+  @protected
+  void sse_encode_tensor_diff(TensorDiff self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.isClose, serializer);
+    sse_encode_bool(self.dtypeMismatch, serializer);
+    sse_encode_bool(self.shapeMismatch, serializer);
+    sse_encode_CastedPrimitive_u_64(self.mismatchCount, serializer);
+    sse_encode_f_64(self.maxAbsError, serializer);
+    sse_encode_f_64(self.maxRelError, serializer);
+    sse_encode_opt_list_CastedPrimitive_i_64(self.worstIndex, serializer);
+    sse_encode_opt_String(self.actual, serializer);
+    sse_encode_opt_String(self.expected, serializer);
+  }
+
+  @protected
+  void sse_encode_tensor_element(TensorElement self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case TensorElement_Bool(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_bool(field0, serializer);
+      case TensorElement_Int(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_CastedPrimitive_i_64(field0, serializer);
+      case TensorElement_Uint(field0: final field0):
+        sse_encode_i_32(2, serializer);
+        sse_encode_CastedPrimitive_u_64(field0, serializer);
+      case TensorElement_Float(field0: final field0):
+        sse_encode_i_32(3, serializer);
+        sse_encode_f_64(field0, serializer);
+      case TensorElement_String(field0: final field0):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(field0, serializer);
+    }
   }
 
   @protected
@@ -7609,6 +14840,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_bool(self.engineHwCompatible, serializer);
   }
 
+  @protected
+  void sse_encode_tensor_stats(TensorStats self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_CastedPrimitive_u_64(self.count, serializer);
+    sse_encode_f_64(self.min, serializer);
+    sse_encode_f_64(self.max, serializer);
+    sse_encode_f_64(self.mean, serializer);
+    sse_encode_f_64(self.std, serializer);
+    sse_encode_CastedPrimitive_u_64(self.nanCount, serializer);
+    sse_encode_CastedPrimitive_u_64(self.positiveInfinityCount, serializer);
+    sse_encode_CastedPrimitive_u_64(self.negativeInfinityCount, serializer);
+    sse_encode_CastedPrimitive_u_64(self.zeroCount, serializer);
+  }
+
+  @protected
+  void sse_encode_top_k(TopK self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+      self.values,
+      serializer,
+    );
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+      self.indices,
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_u_16(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7644,6 +14902,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putBigUint64(self);
   }
 
+  @protected
+  void sse_encode_value_impl(ValueImpl self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case ValueImpl_Tensor(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+          field0,
+          serializer,
+        );
+      case ValueImpl_Sequence(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_list_value_impl(field0, serializer);
+      case ValueImpl_Map(field0: final field0):
+        sse_encode_i_32(2, serializer);
+        sse_encode_box_autoadd_map_impl(field0, serializer);
+      case ValueImpl_Optional(field0: final field0):
+        sse_encode_i_32(3, serializer);
+        sse_encode_opt_box_value_impl(field0, serializer);
+    }
+  }
+
   @protected
   void sse_encode_xnnpack_execution_provider(
     XNNPACKExecutionProvider self,
@@ -7654,6 +14934,58 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 }
 
+@sealed
+class AllocatorImpl extends RustOpaque implements Allocator {
+  // Not to be used by end users
+  AllocatorImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  AllocatorImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_Allocator,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_Allocator,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_AllocatorPtr,
+  );
+
+  /// Describes the memory this allocator allocates.
+  MemoryInfo memoryInfo() =>
+      RustLib.instance.api.crateApiMemoryAllocatorMemoryInfo(that: this);
+}
+
+@sealed
+class BatchSchedulerImpl extends RustOpaque implements BatchScheduler {
+  // Not to be used by end users
+  BatchSchedulerImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  BatchSchedulerImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_BatchScheduler,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_BatchScheduler,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_BatchSchedulerPtr,
+  );
+
+  /// Queues a single set of inputs and waits until the batch it ends up in has run.
+  Future<Map<String, TensorImpl>> run({
+    required Map<String, TensorImpl> inputValues,
+  }) => RustLib.instance.api.crateApiSessionSchedulerBatchSchedulerRun(
+    that: this,
+    inputValues: inputValues,
+  );
+}
+
 @sealed
 class MemoryInfoImpl extends RustOpaque implements MemoryInfo {
   // Not to be used by end users
@@ -7750,14 +15082,49 @@ class SessionImplImpl extends RustOpaque implements SessionImpl {
         RustLib.instance.api.rust_arc_decrement_strong_count_SessionImplPtr,
   );
 
+  /// Creates an [`Allocator`] for the device described by `memory_info`, which one of the session's execution
+  /// providers must support. Leaving `memory_info` out creates an allocator like the one the session uses for its
+  /// outputs. Fails while the session is suspended.
+  ///
+  /// The allocator, and every tensor allocated with it, keeps the session's memory alive, even after
+  /// [`SessionImpl::suspend`].
+  Allocator allocator({MemoryInfo? memoryInfo}) => RustLib.instance.api
+      .crateApiSessionSessionImplAllocator(that: this, memoryInfo: memoryInfo);
+
+  /// Returns the statistics ONNX Runtime keeps for the session's allocator for the device described by
+  /// `memory_info`, or the one it uses for its outputs if left out. With a memory arena these are the arena's, which
+  /// is shared with other sessions when it's the environment's, see [`SessionBuilderOptions::env_allocators`].
+  /// Allocators without statistics, like the CPU allocator of a session without an arena, report zeros.
+  ///
+  /// Requires ONNX Runtime 1.23 or newer, and fails while the session is suspended.
+  ArenaStats arenaStats({MemoryInfo? memoryInfo}) => RustLib.instance.api
+      .crateApiSessionSessionImplArenaStats(that: this, memoryInfo: memoryInfo);
+
   /// Information about the graph's inputs.
   List<Input> inputs() =>
       RustLib.instance.api.crateApiSessionSessionImplInputs(that: this);
 
+  /// If the session was suspended and hasn't been committed again since, see [`SessionImpl::suspend`].
+  bool isSuspended() =>
+      RustLib.instance.api.crateApiSessionSessionImplIsSuspended(that: this);
+
+  /// The memory used by the last successful run, if the session was created after [`stats::enable_allocator_tracking`].
+  RunMemoryUsage? lastRunMemory() =>
+      RustLib.instance.api.crateApiSessionSessionImplLastRunMemory(that: this);
+
+  /// The names of the outputs of the last checked run that contained NaN or infinite values.
+  List<String> nonFiniteOutputs() => RustLib.instance.api
+      .crateApiSessionSessionImplNonFiniteOutputs(that: this);
+
   /// Information about the graph's outputs.
   List<Output> outputs() =>
       RustLib.instance.api.crateApiSessionSessionImplOutputs(that: this);
 
+  /// Commits the session again if it's suspended, e.g. ahead of time to keep the first run after
+  /// [`SessionImpl::suspend`] fast. Fails if the model doesn't fit into the memory budget.
+  Future<void> resume() =>
+      RustLib.instance.api.crateApiSessionSessionImplResume(that: this);
+
   /// Run input data through the ONNX graph, performing inference.
   ///
   /// See [`crate::inputs!`] for a convenient macro which will help you create your session inputs from `ndarray`s or
@@ -7780,6 +15147,91 @@ class SessionImplImpl extends RustOpaque implements SessionImpl {
     that: this,
     inputValues: inputValues,
   );
+
+  /// Run several independent sets of input data through the ONNX graph.
+  ///
+  /// Compatible inputs (same names, element types and dimensions after the batch dimension) are stacked along the
+  /// batch dimension so the graph only runs once, and the outputs are split back up so that every entry of the
+  /// result belongs to the entry of `batch` at the same index. If the model's inputs or outputs don't have a dynamic
+  /// batch dimension, or the inputs can't be stacked, every entry is run on its own instead.
+  Future<List<Map<String, TensorImpl>>> runBatch({
+    required List<Map<String, TensorImpl>> batch,
+  }) => RustLib.instance.api.crateApiSessionSessionImplRunBatch(
+    that: this,
+    batch: batch,
+  );
+
+  /// Run input data through the ONNX graph like [`SessionImpl::run`], for models whose inputs or outputs are not
+  /// only tensors but also sequences, maps or optional values.
+  Future<Map<String, ValueImpl>> runValues({
+    required Map<String, ValueImpl> inputValues,
+  }) => RustLib.instance.api.crateApiSessionSessionImplRunValues(
+    that: this,
+    inputValues: inputValues,
+  );
+
+  /// Sets whether the floating point outputs of every run are checked for NaN and infinite values. Checking reads
+  /// every output once, so it's disabled by default.
+  void setNonFiniteCheck({required NonFiniteCheck check}) =>
+      RustLib.instance.api.crateApiSessionSessionImplSetNonFiniteCheck(
+        that: this,
+        check: check,
+      );
+
+  /// Drops the underlying session, and with it the memory of its weights and arena, while keeping everything needed
+  /// to commit it again. The next run commits it again first, which takes as long as committing it did originally.
+  /// Fails for sessions committed with [`SessionBuilderOptions::commit_from_memory`], which don't keep their model.
+  ///
+  /// Meant for apps that move to the background, so that they don't have to rebuild their session objects. Tensors
+  /// and allocators obtained from the session stay valid, but allocators from [`SessionImpl::allocator`] and the
+  /// tensors allocated with them keep the session's memory alive: it's only released, and its charge against the
+  /// memory budget only dropped, once they're dropped too. The memory budget suspends idle sessions without such
+  /// allocators itself with
+  /// [`EvictionPolicy::SuspendIdleSessions`](crate::api::memory::budget::EvictionPolicy::SuspendIdleSessions).
+  void suspend() =>
+      RustLib.instance.api.crateApiSessionSessionImplSuspend(that: this);
+}
+
+@sealed
+class TensorDataViewImpl extends RustOpaque implements TensorDataView {
+  // Not to be used by end users
+  TensorDataViewImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  TensorDataViewImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_TensorDataView,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_TensorDataView,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_TensorDataViewPtr,
+  );
+
+  /// The number of bytes the elements take up.
+  int byteLen() =>
+      RustLib.instance.api.crateApiTensorTensorDataViewByteLen(that: this);
+
+  /// The data type of the elements.
+  TensorElementType dtype() =>
+      RustLib.instance.api.crateApiTensorTensorDataViewDtype(that: this);
+
+  /// If the view has no elements.
+  bool isEmpty() =>
+      RustLib.instance.api.crateApiTensorTensorDataViewIsEmpty(that: this);
+
+  /// The number of elements in the view.
+  int len() => RustLib.instance.api.crateApiTensorTensorDataViewLen(that: this);
+
+  /// The address of the first element. May be `0` for tensors without any elements.
+  int ptr() => RustLib.instance.api.crateApiTensorTensorDataViewPtr(that: this);
+
+  /// The shape of the viewed tensor.
+  List<int> shape() =>
+      RustLib.instance.api.crateApiTensorTensorDataViewShape(that: this);
 }
 
 @sealed
@@ -7801,47 +15253,259 @@ class TensorImplImpl extends RustOpaque implements TensorImpl {
         RustLib.instance.api.rust_arc_decrement_strong_count_TensorImplPtr,
   );
 
-  /// Creates a copy of this tensor and its data on the same device it resides on.
+  /// Adds two tensors of the same numeric type element-wise, broadcasting their shapes following NumPy's rules.
+  /// Integers wrap around on overflow.
+  TensorImpl add({required TensorImpl other}) => RustLib.instance.api
+      .crateApiTensorArithmeticTensorImplAdd(that: this, other: other);
+
+  /// Adds `value` to every element. Integer tensors require `value` to be a whole number in the range of their type.
+  TensorImpl addScalar({required double value}) => RustLib.instance.api
+      .crateApiTensorArithmeticTensorImplAddScalar(that: this, value: value);
+
+  /// If this tensor has the same type and shape as `expected` and every element is close to it, see
+  /// [`TensorImpl::diff`].
+  bool allclose({required TensorImpl expected, double? rtol, double? atol}) =>
+      RustLib.instance.api.crateApiTensorCompareTensorImplAllclose(
+        that: this,
+        expected: expected,
+        rtol: rtol,
+        atol: atol,
+      );
+
+  /// Returns the indices of the largest elements along `axis` as an `int64` tensor. The first occurrence wins ties,
+  /// and NaN is treated as larger than any number.
+  ///
+  /// With `keep_dims` the reduced axis is kept with size 1, otherwise it is removed.
+  TensorImpl argmax({required int axis, required bool keepDims}) =>
+      RustLib.instance.api.crateApiTensorOpsTensorImplArgmax(
+        that: this,
+        axis: axis,
+        keepDims: keepDims,
+      );
+
+  /// Returns the indices of the smallest elements along `axis` as an `int64` tensor. The first occurrence wins ties,
+  /// and NaN is picked over any number, like in [`TensorImpl::argmax`].
+  TensorImpl argmin({required int axis, required bool keepDims}) =>
+      RustLib.instance.api.crateApiTensorOpsTensorImplArgmin(
+        that: this,
+        axis: axis,
+        keepDims: keepDims,
+      );
+
+  /// Converts the tensor to another element type, creating a new tensor of the same shape.
+  ///
+  /// Any numeric type, `bool`, `float16`, `bfloat16` and `string` can be cast to any other. Floats are truncated
+  /// towards zero when cast to integers, anything non-zero (including NaN) becomes `true`, and `true`/`false` become
+  /// 1 and 0. Strings are parsed as integers or floats, also accepting `true`, `false`, `NaN` and `inf`, and numbers
+  /// are formatted in their shortest exact form.
+  ///
+  /// The 8-bit float types, `int4`/`uint4` and the complex types are not supported: casting from or to them fails.
+  ///
+  /// `mode` decides what happens to values the target type can't represent and defaults to
+  /// [`CastMode::Saturating`].
+  TensorImpl cast({required TensorElementType target, CastMode? mode}) =>
+      RustLib.instance.api.crateApiTensorCastTensorImplCast(
+        that: this,
+        target: target,
+        mode: mode,
+      );
+
+  /// Limits every element to the range `[min, max]`. Either bound may be left out, and NaN elements are kept as they
+  /// are. Integer tensors require the bounds to be whole numbers in the range of their type.
+  TensorImpl clamp({double? min, double? max}) => RustLib.instance.api
+      .crateApiTensorArithmeticTensorImplClamp(that: this, min: min, max: max);
+
+  /// Creates a copy of this tensor and its data on the same device it resides on. The copy is mutable, and counts
+  /// against the memory budget even if it doesn't fit.
   TensorImpl clone() =>
       RustLib.instance.api.crateApiTensorTensorImplClone(that: this);
 
+  /// Creates a zero-copy, read-only view of this tensor's data that keeps the data alive until the view is disposed.
+  /// Only tensors with fixed-width elements in CPU accessible memory can be viewed; use `extract_string` for strings.
+  TensorDataView dataView() =>
+      RustLib.instance.api.crateApiTensorTensorImplDataView(that: this);
+
+  /// Describes the device and memory the Tensor's data resides in, like [`TensorImpl::memory_info`] but infallible.
+  MemoryInfo device() =>
+      RustLib.instance.api.crateApiTensorTensorImplDevice(that: this);
+
+  /// Compares this tensor element by element with an `expected` tensor of the same shape and reports how they differ.
+  ///
+  /// Numeric elements are close if `|actual - expected| <= atol + rtol * |expected|`, as in NumPy's `allclose`;
+  /// `rtol` defaults to `1e-5` and `atol` to `1e-8`. NaN is never close to anything, and infinities are only close to
+  /// the same infinity. Numeric tensors of different types are compared by value but never reported as close.
+  /// Elements of any other type, including strings, must be exactly equal.
+  TensorDiff diff({required TensorImpl expected, double? rtol, double? atol}) =>
+      RustLib.instance.api.crateApiTensorCompareTensorImplDiff(
+        that: this,
+        expected: expected,
+        rtol: rtol,
+        atol: atol,
+      );
+
+  /// Divides this tensor by `other` element-wise, broadcasting as in [`TensorImpl::add`]. Integer division rounds
+  /// towards zero and fails on division by zero.
+  TensorImpl div({required TensorImpl other}) => RustLib.instance.api
+      .crateApiTensorArithmeticTensorImplDiv(that: this, other: other);
+
+  /// Divides every element by `value`, which must fit the element type as in [`TensorImpl::add_scalar`].
+  TensorImpl divScalar({required double value}) => RustLib.instance.api
+      .crateApiTensorArithmeticTensorImplDivScalar(that: this, value: value);
+
   /// Get the data type of the Tensor
   TensorElementType dtype() =>
       RustLib.instance.api.crateApiTensorTensorImplDtype(that: this);
 
+  /// Copies the tensor's data into a new `Vec`, converting every element to `f32`.
+  Float32List extractBf16() =>
+      RustLib.instance.api.crateApiTensorTensorImplExtractBf16(that: this);
+
+  /// Copies the raw bit patterns of the tensor's elements into a new `Vec`.
+  Uint16List extractBf16Bits() =>
+      RustLib.instance.api.crateApiTensorTensorImplExtractBf16Bits(that: this);
+
+  /// Copies the tensor's data into a new `Vec`.
+  List<bool> extractBool() =>
+      RustLib.instance.api.crateApiTensorTensorImplExtractBool(that: this);
+
+  /// Copies the tensor's data into a new `Vec`, converting every element to `f32`.
+  Float32List extractF16() =>
+      RustLib.instance.api.crateApiTensorTensorImplExtractF16(that: this);
+
+  /// Copies the raw bit patterns of the tensor's elements into a new `Vec`.
+  Uint16List extractF16Bits() =>
+      RustLib.instance.api.crateApiTensorTensorImplExtractF16Bits(that: this);
+
+  /// Copies the tensor's data into a new `Vec`.
+  Float32List extractF32() =>
+      RustLib.instance.api.crateApiTensorTensorImplExtractF32(that: this);
+
+  /// Copies the tensor's data into a new `Vec`.
+  Float64List extractF64() =>
+      RustLib.instance.api.crateApiTensorTensorImplExtractF64(that: this);
+
+  /// Copies the tensor's data into a new `Vec`.
+  Int16List extractI16() =>
+      RustLib.instance.api.crateApiTensorTensorImplExtractI16(that: this);
+
+  /// Copies the tensor's data into a new `Vec`.
+  Int32List extractI32() =>
+      RustLib.instance.api.crateApiTensorTensorImplExtractI32(that: this);
+
+  /// Copies the tensor's data into a new `Vec`.
+  List<int> extractI64() =>
+      RustLib.instance.api.crateApiTensorTensorImplExtractI64(that: this);
+
+  /// Copies the tensor's data into a new `Vec`.
+  Int8List extractI8() =>
+      RustLib.instance.api.crateApiTensorTensorImplExtractI8(that: this);
+
+  /// Copies the tensor's strings into a new `Vec`.
+  List<String> extractString() =>
+      RustLib.instance.api.crateApiTensorTensorImplExtractString(that: this);
+
+  /// Copies the tensor's data into a new `Vec`.
+  Uint16List extractU16() =>
+      RustLib.instance.api.crateApiTensorTensorImplExtractU16(that: this);
+
+  /// Copies the tensor's data into a new `Vec`.
+  Uint32List extractU32() =>
+      RustLib.instance.api.crateApiTensorTensorImplExtractU32(that: this);
+
+  /// Copies the tensor's data into a new `Vec`.
+  List<int> extractU64() =>
+      RustLib.instance.api.crateApiTensorTensorImplExtractU64(that: this);
+
+  /// Copies the tensor's data into a new `Vec`.
+  Uint8List extractU8() =>
+      RustLib.instance.api.crateApiTensorTensorImplExtractU8(that: this);
+
+  /// Reads the element at `index`, which has one entry per dimension. Negative entries count from the end of their
+  /// dimension.
+  TensorElement get({required List<int> index}) => RustLib.instance.api
+      .crateApiTensorIndexTensorImplGet(that: this, index: index);
+
+  /// Returns the address and length of the tensor's data, which Dart can access as a `Uint16List`. The pointer is
+  /// only valid while this tensor is alive; prefer [`TensorImpl::data_view`], which keeps the data alive on its
+  /// own. Fails if the tensor isn't mutable, see [`TensorImpl::make_mutable`].
+  ArrayPointer getDataBf16Pointer() => RustLib.instance.api
+      .crateApiTensorTensorImplGetDataBf16Pointer(that: this);
+
+  /// Returns the address and length of the tensor's data, which may be written to. The pointer is only valid
+  /// while this tensor is alive; prefer [`TensorImpl::data_view`], which keeps the data alive on its own. Fails if
+  /// the tensor isn't mutable, see [`TensorImpl::make_mutable`].
   ArrayPointer getDataBoolPointer() => RustLib.instance.api
       .crateApiTensorTensorImplGetDataBoolPointer(that: this);
 
+  /// Returns the address and length of the tensor's data, which Dart can access as a `Uint16List`. The pointer is
+  /// only valid while this tensor is alive; prefer [`TensorImpl::data_view`], which keeps the data alive on its
+  /// own. Fails if the tensor isn't mutable, see [`TensorImpl::make_mutable`].
+  ArrayPointer getDataF16Pointer() => RustLib.instance.api
+      .crateApiTensorTensorImplGetDataF16Pointer(that: this);
+
+  /// Returns the address and length of the tensor's data, which may be written to. The pointer is only valid
+  /// while this tensor is alive; prefer [`TensorImpl::data_view`], which keeps the data alive on its own. Fails if
+  /// the tensor isn't mutable, see [`TensorImpl::make_mutable`].
   ArrayPointer getDataF32Pointer() => RustLib.instance.api
       .crateApiTensorTensorImplGetDataF32Pointer(that: this);
 
+  /// Returns the address and length of the tensor's data, which may be written to. The pointer is only valid
+  /// while this tensor is alive; prefer [`TensorImpl::data_view`], which keeps the data alive on its own. Fails if
+  /// the tensor isn't mutable, see [`TensorImpl::make_mutable`].
   ArrayPointer getDataF64Pointer() => RustLib.instance.api
       .crateApiTensorTensorImplGetDataF64Pointer(that: this);
 
+  /// Returns the address and length of the tensor's data, which may be written to. The pointer is only valid
+  /// while this tensor is alive; prefer [`TensorImpl::data_view`], which keeps the data alive on its own. Fails if
+  /// the tensor isn't mutable, see [`TensorImpl::make_mutable`].
   ArrayPointer getDataI16Pointer() => RustLib.instance.api
       .crateApiTensorTensorImplGetDataI16Pointer(that: this);
 
+  /// Returns the address and length of the tensor's data, which may be written to. The pointer is only valid
+  /// while this tensor is alive; prefer [`TensorImpl::data_view`], which keeps the data alive on its own. Fails if
+  /// the tensor isn't mutable, see [`TensorImpl::make_mutable`].
   ArrayPointer getDataI32Pointer() => RustLib.instance.api
       .crateApiTensorTensorImplGetDataI32Pointer(that: this);
 
+  /// Returns the address and length of the tensor's data, which may be written to. The pointer is only valid
+  /// while this tensor is alive; prefer [`TensorImpl::data_view`], which keeps the data alive on its own. Fails if
+  /// the tensor isn't mutable, see [`TensorImpl::make_mutable`].
   ArrayPointer getDataI64Pointer() => RustLib.instance.api
       .crateApiTensorTensorImplGetDataI64Pointer(that: this);
 
+  /// Returns the address and length of the tensor's data, which may be written to. The pointer is only valid
+  /// while this tensor is alive; prefer [`TensorImpl::data_view`], which keeps the data alive on its own. Fails if
+  /// the tensor isn't mutable, see [`TensorImpl::make_mutable`].
   ArrayPointer getDataI8Pointer() =>
       RustLib.instance.api.crateApiTensorTensorImplGetDataI8Pointer(that: this);
 
+  /// Returns an array of pointers to nul-terminated copies of the tensor's strings. The copies are owned by the caller
+  /// and must be released with [`TensorImpl::free_string_pointer`]. Since they are copies, this works for immutable
+  /// tensors as well.
   ArrayPointer getDataStringPointer() => RustLib.instance.api
       .crateApiTensorTensorImplGetDataStringPointer(that: this);
 
+  /// Returns the address and length of the tensor's data, which may be written to. The pointer is only valid
+  /// while this tensor is alive; prefer [`TensorImpl::data_view`], which keeps the data alive on its own. Fails if
+  /// the tensor isn't mutable, see [`TensorImpl::make_mutable`].
   ArrayPointer getDataU16Pointer() => RustLib.instance.api
       .crateApiTensorTensorImplGetDataU16Pointer(that: this);
 
+  /// Returns the address and length of the tensor's data, which may be written to. The pointer is only valid
+  /// while this tensor is alive; prefer [`TensorImpl::data_view`], which keeps the data alive on its own. Fails if
+  /// the tensor isn't mutable, see [`TensorImpl::make_mutable`].
   ArrayPointer getDataU32Pointer() => RustLib.instance.api
       .crateApiTensorTensorImplGetDataU32Pointer(that: this);
 
+  /// Returns the address and length of the tensor's data, which may be written to. The pointer is only valid
+  /// while this tensor is alive; prefer [`TensorImpl::data_view`], which keeps the data alive on its own. Fails if
+  /// the tensor isn't mutable, see [`TensorImpl::make_mutable`].
   ArrayPointer getDataU64Pointer() => RustLib.instance.api
       .crateApiTensorTensorImplGetDataU64Pointer(that: this);
 
+  /// Returns the address and length of the tensor's data, which may be written to. The pointer is only valid
+  /// while this tensor is alive; prefer [`TensorImpl::data_view`], which keeps the data alive on its own. Fails if
+  /// the tensor isn't mutable, see [`TensorImpl::make_mutable`].
   ArrayPointer getDataU8Pointer() =>
       RustLib.instance.api.crateApiTensorTensorImplGetDataU8Pointer(that: this);
 
@@ -7849,10 +15513,275 @@ class TensorImplImpl extends RustOpaque implements TensorImpl {
   bool isMutable() =>
       RustLib.instance.api.crateApiTensorTensorImplIsMutable(that: this);
 
+  /// Computes the logarithm of the softmax of the tensor along `axis`, more accurately than taking the logarithm of
+  /// [`TensorImpl::softmax`].
+  TensorImpl logSoftmax({required int axis}) => RustLib.instance.api
+      .crateApiTensorOpsTensorImplLogSoftmax(that: this, axis: axis);
+
+  /// Makes the tensor mutable, copying its data first if it is immutable, so that writes never reach data that is
+  /// shared with an immutable tensor. Returns whether the data was copied.
+  bool makeMutable() =>
+      RustLib.instance.api.crateApiTensorTensorImplMakeMutable(that: this);
+
+  /// Finds the largest elements along `axes`, or of the whole tensor if `axes` is left out. NaN is propagated.
+  TensorImpl max({List<int>? axes, required bool keepDims}) =>
+      RustLib.instance.api.crateApiTensorOpsTensorImplMax(
+        that: this,
+        axes: axes,
+        keepDims: keepDims,
+      );
+
+  /// Averages the elements along `axes`, or over the whole tensor if `axes` is left out. Floating point tensors keep
+  /// their type; other numeric tensors produce a `float32` tensor.
+  TensorImpl mean({List<int>? axes, required bool keepDims}) =>
+      RustLib.instance.api.crateApiTensorOpsTensorImplMean(
+        that: this,
+        axes: axes,
+        keepDims: keepDims,
+      );
+
+  /// Describes the device and memory the Tensor's data resides in.
   MemoryInfo memoryInfo() =>
       RustLib.instance.api.crateApiTensorTensorImplMemoryInfo(that: this);
 
+  /// Finds the smallest elements along `axes`, or of the whole tensor if `axes` is left out. NaN is propagated.
+  TensorImpl min({List<int>? axes, required bool keepDims}) =>
+      RustLib.instance.api.crateApiTensorOpsTensorImplMin(
+        that: this,
+        axes: axes,
+        keepDims: keepDims,
+      );
+
+  /// Multiplies two tensors element-wise, broadcasting as in [`TensorImpl::add`].
+  TensorImpl mul({required TensorImpl other}) => RustLib.instance.api
+      .crateApiTensorArithmeticTensorImplMul(that: this, other: other);
+
+  /// Multiplies every element by `value`, which must fit the element type as in [`TensorImpl::add_scalar`].
+  TensorImpl mulScalar({required double value}) => RustLib.instance.api
+      .crateApiTensorArithmeticTensorImplMulScalar(that: this, value: value);
+
+  /// The total number of elements in the Tensor
+  int numElements() =>
+      RustLib.instance.api.crateApiTensorTensorImplNumElements(that: this);
+
+  /// Returns a copy of this tensor with its dimensions reordered, so that dimension `i` of the result is dimension
+  /// `axes[i]` of this tensor. For example `[0, 3, 1, 2]` turns an NHWC tensor into an NCHW one.
+  TensorImpl permute({required List<int> axes}) => RustLib.instance.api
+      .crateApiTensorShapeTensorImplPermute(that: this, axes: axes);
+
+  /// Raises this tensor to the power of `other` element-wise, broadcasting as in [`TensorImpl::add`]. Integer powers
+  /// are computed exactly and fail on overflow or a negative exponent.
+  TensorImpl pow({required TensorImpl other}) => RustLib.instance.api
+      .crateApiTensorArithmeticTensorImplPow(that: this, other: other);
+
+  /// Raises every element to the power of `value`. Integer tensors require `value` to be a whole number in the range
+  /// of their type, and fail like [`TensorImpl::pow`].
+  TensorImpl powScalar({required double value}) => RustLib.instance.api
+      .crateApiTensorArithmeticTensorImplPowScalar(that: this, value: value);
+
+  /// The number of dimensions of the Tensor
+  int rank() => RustLib.instance.api.crateApiTensorTensorImplRank(that: this);
+
+  /// Returns a view of this tensor with a new shape and the same number of elements. One dimension may be `-1` to
+  /// have it inferred from the others.
+  ///
+  /// The view shares this tensor's data, so writes through either one are visible in both.
+  TensorImpl reshape({required List<int> shape}) => RustLib.instance.api
+      .crateApiTensorShapeTensorImplReshape(that: this, shape: shape);
+
+  /// Saves the tensor to a `.npy` file, see [`TensorImpl::to_npy`].
+  Future<void> saveNpy({required String path}) => RustLib.instance.api
+      .crateApiTensorNpyTensorImplSaveNpy(that: this, path: path);
+
+  /// Saves the tensor to a `TensorProto` file, see [`TensorImpl::to_tensor_proto`].
+  Future<void> saveTensorProto({required String path, String? name}) =>
+      RustLib.instance.api.crateApiTensorProtoTensorImplSaveTensorProto(
+        that: this,
+        path: path,
+        name: name,
+      );
+
+  /// Writes the element at `index`, converting `value` to the tensor's element type like [`TensorImpl::cast`] does
+  /// with [`CastMode::Checked`]. Fails if the tensor isn't mutable.
+  void set({required List<int> index, required TensorElement value}) =>
+      RustLib.instance.api.crateApiTensorIndexTensorImplSet(
+        that: this,
+        index: index,
+        value: value,
+      );
+
   /// Get the shape of the Tensor
   List<int> shape() =>
       RustLib.instance.api.crateApiTensorTensorImplShape(that: this);
+
+  /// Computes the logistic sigmoid `1 / (1 + e^-x)` of every element.
+  TensorImpl sigmoid() =>
+      RustLib.instance.api.crateApiTensorOpsTensorImplSigmoid(that: this);
+
+  /// The size of the Tensor's data in bytes. For string tensors this is the combined length of the strings in UTF-8,
+  /// which leaves out the overhead of storing each string.
+  int sizeInBytes() =>
+      RustLib.instance.api.crateApiTensorTensorImplSizeInBytes(that: this);
+
+  /// Returns a copy of part of this tensor, following the semantics of the ONNX `Slice` operator.
+  ///
+  /// For each of `axes` (all axes in order if left out) the elements from `starts[i]` up to but excluding `ends[i]`
+  /// are taken, every `steps[i]` elements (1 if left out). Negative indices count from the end of the dimension,
+  /// out of range indices are clamped, and a negative step walks the dimension backwards.
+  TensorImpl slice({
+    required List<int> starts,
+    required List<int> ends,
+    List<int>? axes,
+    List<int>? steps,
+  }) => RustLib.instance.api.crateApiTensorShapeTensorImplSlice(
+    that: this,
+    starts: starts,
+    ends: ends,
+    axes: axes,
+    steps: steps,
+  );
+
+  /// Returns a view of part of this tensor, selected like in [`TensorImpl::slice`], without copying any data.
+  ///
+  /// The view shares this tensor's data, including its strings, so writes through either one are visible in both. This
+  /// makes it cheap to read a few elements out of a large output, e.g. the first rows of a list of detections.
+  TensorSlice sliceView({
+    required List<int> starts,
+    required List<int> ends,
+    List<int>? axes,
+    List<int>? steps,
+  }) => RustLib.instance.api.crateApiTensorIndexTensorImplSliceView(
+    that: this,
+    starts: starts,
+    ends: ends,
+    axes: axes,
+    steps: steps,
+  );
+
+  /// Computes the softmax of the tensor along `axis` (`-1` for the last one), so that every slice along it sums to 1.
+  ///
+  /// Floating point tensors keep their type; other numeric tensors produce a `float32` tensor.
+  TensorImpl softmax({required int axis}) => RustLib.instance.api
+      .crateApiTensorOpsTensorImplSoftmax(that: this, axis: axis);
+
+  /// Returns a view of this tensor without the given dimensions of size 1, or without every dimension of size 1 if
+  /// `axes` is left out.
+  TensorImpl squeeze({List<int>? axes}) => RustLib.instance.api
+      .crateApiTensorShapeTensorImplSqueeze(that: this, axes: axes);
+
+  /// Computes summary statistics over the elements of a numeric tensor.
+  ///
+  /// NaN and infinite elements are counted but left out of `min`, `max`, `mean` and `std`, so a handful of bad values
+  /// doesn't hide the distribution of the rest.
+  TensorStats stats() =>
+      RustLib.instance.api.crateApiTensorStatsTensorImplStats(that: this);
+
+  /// The number of elements between neighbouring elements of each dimension, in the row-major order tensors are
+  /// always stored in.
+  List<int> strides() =>
+      RustLib.instance.api.crateApiTensorTensorImplStrides(that: this);
+
+  /// Subtracts `other` from this tensor element-wise, broadcasting as in [`TensorImpl::add`].
+  TensorImpl sub({required TensorImpl other}) => RustLib.instance.api
+      .crateApiTensorArithmeticTensorImplSub(that: this, other: other);
+
+  /// Subtracts `value` from every element, which must fit the element type as in [`TensorImpl::add_scalar`].
+  TensorImpl subScalar({required double value}) => RustLib.instance.api
+      .crateApiTensorArithmeticTensorImplSubScalar(that: this, value: value);
+
+  /// Sums the elements along `axes`, or over the whole tensor if `axes` is left out. The result has the same type as
+  /// the tensor, and integers wrap around on overflow.
+  ///
+  /// With `keep_dims` the reduced axes are kept with size 1, otherwise they are removed.
+  TensorImpl sum({List<int>? axes, required bool keepDims}) =>
+      RustLib.instance.api.crateApiTensorOpsTensorImplSum(
+        that: this,
+        axes: axes,
+        keepDims: keepDims,
+      );
+
+  /// Copies the raw, native-endian bytes of this tensor's elements, laid out as described in
+  /// [`TensorImpl::from_bytes`].
+  Uint8List toBytes() =>
+      RustLib.instance.api.crateApiTensorTensorImplToBytes(that: this);
+
+  /// Serializes the tensor in NumPy's `.npy` format. String tensors are written as fixed-length `str` arrays.
+  Uint8List toNpy() =>
+      RustLib.instance.api.crateApiTensorNpyTensorImplToNpy(that: this);
+
+  /// Serializes the tensor as an ONNX `TensorProto` with the given name. Fixed-width data is written to `raw_data` and
+  /// strings to `string_data`.
+  Uint8List toTensorProto({String? name}) => RustLib.instance.api
+      .crateApiTensorProtoTensorImplToTensorProto(that: this, name: name);
+
+  /// Picks the `k` largest (or with `largest` set to `false`, smallest) elements along `axis` (`-1` if left out),
+  /// sorted from first to last picked. Equal elements are picked in order of their index, as in ONNX's `TopK`.
+  TopK topK({required int k, int? axis, bool? largest}) =>
+      RustLib.instance.api.crateApiTensorOpsTensorImplTopK(
+        that: this,
+        k: k,
+        axis: axis,
+        largest: largest,
+      );
+
+  /// Returns a copy of this tensor with the order of its dimensions reversed.
+  TensorImpl transpose() =>
+      RustLib.instance.api.crateApiTensorShapeTensorImplTranspose(that: this);
+
+  /// Returns a view of this tensor with dimensions of size 1 inserted, so that they are at `axes` in the result.
+  /// Negative axes count from the end of the result, as in ONNX.
+  TensorImpl unsqueeze({required List<int> axes}) => RustLib.instance.api
+      .crateApiTensorShapeTensorImplUnsqueeze(that: this, axes: axes);
+}
+
+@sealed
+class TensorSliceImpl extends RustOpaque implements TensorSlice {
+  // Not to be used by end users
+  TensorSliceImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  TensorSliceImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_TensorSlice,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_TensorSlice,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_TensorSlicePtr,
+  );
+
+  /// The data type of the elements.
+  TensorElementType dtype() =>
+      RustLib.instance.api.crateApiTensorIndexTensorSliceDtype(that: this);
+
+  /// Reads the element at `index` of the view, see [`TensorImpl::get`].
+  TensorElement get({required List<int> index}) => RustLib.instance.api
+      .crateApiTensorIndexTensorSliceGet(that: this, index: index);
+
+  /// If the viewed tensor is mutable, and so elements can be written through this view.
+  bool isMutable() =>
+      RustLib.instance.api.crateApiTensorIndexTensorSliceIsMutable(that: this);
+
+  /// Writes the element at `index` of the view, and so of the viewed tensor, see [`TensorImpl::set`].
+  void set({required List<int> index, required TensorElement value}) =>
+      RustLib.instance.api.crateApiTensorIndexTensorSliceSet(
+        that: this,
+        index: index,
+        value: value,
+      );
+
+  /// The shape of the view.
+  List<int> shape() =>
+      RustLib.instance.api.crateApiTensorIndexTensorSliceShape(that: this);
+
+  /// The distance, in elements, between neighbouring elements of each dimension in the viewed tensor.
+  List<int> strides() =>
+      RustLib.instance.api.crateApiTensorIndexTensorSliceStrides(that: this);
+
+  /// Copies the elements of the view into a new, contiguous tensor.
+  TensorImpl toTensor() =>
+      RustLib.instance.api.crateApiTensorIndexTensorSliceToTensor(that: this);
 }
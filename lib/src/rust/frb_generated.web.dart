@@ -18,9 +18,23 @@ import 'api/execution_providers/tensorrt.dart';
 import 'api/execution_providers/xnnpack.dart';
 import 'api/logging.dart';
 import 'api/memory.dart';
+import 'api/memory/arena.dart';
+import 'api/memory/budget.dart';
+import 'api/memory/stats.dart';
+import 'api/memory/trim.dart';
 import 'api/session.dart';
 import 'api/session/builder/impl_options.dart';
+import 'api/session/scheduler.dart';
 import 'api/tensor.dart';
+import 'api/tensor/cast.dart';
+import 'api/tensor/compare.dart';
+import 'api/tensor/index.dart';
+import 'api/tensor/npy.dart';
+import 'api/tensor/ops.dart';
+import 'api/tensor/proto.dart';
+import 'api/tensor/safetensors.dart';
+import 'api/tensor/stats.dart';
+import 'api/tensor/value.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
@@ -34,6 +48,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     required super.portManager,
   });
 
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_AllocatorPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_BatchSchedulerPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler;
+
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_MemoryInfoPtr => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo;
@@ -42,13 +64,33 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   get rust_arc_decrement_strong_count_SessionImplPtr => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl;
 
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_TensorDataViewPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView;
+
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_TensorImplPtr => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl;
 
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_TensorSlicePtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice;
+
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw);
 
+  @protected
+  Allocator
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator(
+    dynamic raw,
+  );
+
+  @protected
+  BatchScheduler
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler(
+    dynamic raw,
+  );
+
   @protected
   MemoryInfo
   dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
@@ -61,12 +103,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  TensorDataView
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+    dynamic raw,
+  );
+
   @protected
   TensorImpl
   dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
     dynamic raw,
   );
 
+  @protected
+  TensorSlice
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+    dynamic raw,
+  );
+
   @protected
   SessionImpl
   dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl(
@@ -79,6 +133,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  TensorSlice
+  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+    dynamic raw,
+  );
+
+  @protected
+  Allocator
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator(
+    dynamic raw,
+  );
+
+  @protected
+  BatchScheduler
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler(
+    dynamic raw,
+  );
+
   @protected
   MemoryInfo
   dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
@@ -91,12 +163,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  TensorDataView
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+    dynamic raw,
+  );
+
   @protected
   TensorImpl
   dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
     dynamic raw,
   );
 
+  @protected
+  TensorSlice
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+    dynamic raw,
+  );
+
   @protected
   int dco_decode_CastedPrimitive_i_64(dynamic raw);
 
@@ -112,6 +196,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  Map<String, String> dco_decode_Map_String_String_None(dynamic raw);
+
+  @protected
+  Map<String, ValueImpl> dco_decode_Map_String_value_impl_None(dynamic raw);
+
+  @protected
+  Allocator
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator(
+    dynamic raw,
+  );
+
+  @protected
+  BatchScheduler
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler(
+    dynamic raw,
+  );
+
   @protected
   MemoryInfo
   dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
@@ -124,12 +226,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  TensorDataView
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+    dynamic raw,
+  );
+
   @protected
   TensorImpl
   dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
     dynamic raw,
   );
 
+  @protected
+  TensorSlice
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+    dynamic raw,
+  );
+
   @protected
   String dco_decode_String(dynamic raw);
 
@@ -139,27 +253,56 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AllocationDevice dco_decode_allocation_device(dynamic raw);
 
+  @protected
+  AllocatorStats dco_decode_allocator_stats(dynamic raw);
+
   @protected
   AllocatorType dco_decode_allocator_type(dynamic raw);
 
+  @protected
+  ArenaConfig dco_decode_arena_config(dynamic raw);
+
   @protected
   ArenaExtendStrategy dco_decode_arena_extend_strategy(dynamic raw);
 
+  @protected
+  ArenaStats dco_decode_arena_stats(dynamic raw);
+
   @protected
   ArrayPointer dco_decode_array_pointer(dynamic raw);
 
+  @protected
+  BatchSchedulerOptions dco_decode_batch_scheduler_options(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  AllocationDevice dco_decode_box_autoadd_allocation_device(dynamic raw);
+
+  @protected
+  AllocatorType dco_decode_box_autoadd_allocator_type(dynamic raw);
+
+  @protected
+  ArenaConfig dco_decode_box_autoadd_arena_config(dynamic raw);
+
   @protected
   ArenaExtendStrategy dco_decode_box_autoadd_arena_extend_strategy(dynamic raw);
 
   @protected
   ArrayPointer dco_decode_box_autoadd_array_pointer(dynamic raw);
 
+  @protected
+  BatchSchedulerOptions dco_decode_box_autoadd_batch_scheduler_options(
+    dynamic raw,
+  );
+
   @protected
   bool dco_decode_box_autoadd_bool(dynamic raw);
 
+  @protected
+  CastMode dco_decode_box_autoadd_cast_mode(dynamic raw);
+
   @protected
   CoreMLComputeUnits dco_decode_box_autoadd_core_ml_compute_units(dynamic raw);
 
@@ -200,6 +343,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  EvictionPolicy dco_decode_box_autoadd_eviction_policy(dynamic raw);
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw);
+
   @protected
   GraphOptimizationLevel dco_decode_box_autoadd_graph_optimization_level(
     dynamic raw,
@@ -211,11 +360,20 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   int dco_decode_box_autoadd_i_8(dynamic raw);
 
+  @protected
+  MapImpl dco_decode_box_autoadd_map_impl(dynamic raw);
+
+  @protected
+  MemoryType dco_decode_box_autoadd_memory_type(dynamic raw);
+
   @protected
   NNAPIExecutionProvider dco_decode_box_autoadd_nnapi_execution_provider(
     dynamic raw,
   );
 
+  @protected
+  NonFiniteCheck dco_decode_box_autoadd_non_finite_check(dynamic raw);
+
   @protected
   OrtDebugLevel dco_decode_box_autoadd_ort_debug_level(dynamic raw);
 
@@ -238,16 +396,31 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  RunMemoryUsage dco_decode_box_autoadd_run_memory_usage(dynamic raw);
+
   @protected
   SessionBuilderOptions dco_decode_box_autoadd_session_builder_options(
     dynamic raw,
   );
 
+  @protected
+  TensorDiff dco_decode_box_autoadd_tensor_diff(dynamic raw);
+
+  @protected
+  TensorElement dco_decode_box_autoadd_tensor_element(dynamic raw);
+
+  @protected
+  TensorElementType dco_decode_box_autoadd_tensor_element_type(dynamic raw);
+
   @protected
   TensorRTExecutionProvider dco_decode_box_autoadd_tensor_rt_execution_provider(
     dynamic raw,
   );
 
+  @protected
+  TensorStats dco_decode_box_autoadd_tensor_stats(dynamic raw);
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw);
 
@@ -259,6 +432,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  ValueImpl dco_decode_box_value_impl(dynamic raw);
+
+  @protected
+  CastMode dco_decode_cast_mode(dynamic raw);
+
   @protected
   CoreMLComputeUnits dco_decode_core_ml_compute_units(dynamic raw);
 
@@ -293,6 +472,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  EvictionPolicy dco_decode_eviction_policy(dynamic raw);
+
   @protected
   ExecutionProvider dco_decode_execution_provider(dynamic raw);
 
@@ -320,12 +502,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Input dco_decode_input(dynamic raw);
 
+  @protected
+  List<TensorImpl>
+  dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+    dynamic raw,
+  );
+
   @protected
   List<int> dco_decode_list_CastedPrimitive_i_64(dynamic raw);
 
   @protected
   List<int> dco_decode_list_CastedPrimitive_u_64(dynamic raw);
 
+  @protected
+  List<Map<String, TensorImpl>>
+  dco_decode_list_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None(
+    dynamic raw,
+  );
+
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
@@ -395,15 +589,56 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  List<(String, String)> dco_decode_list_record_string_string(dynamic raw);
+
+  @protected
+  List<(String, ValueImpl)> dco_decode_list_record_string_value_impl(
+    dynamic raw,
+  );
+
+  @protected
+  List<ValueImpl> dco_decode_list_value_impl(dynamic raw);
+
+  @protected
+  MapImpl dco_decode_map_impl(dynamic raw);
+
+  @protected
+  MapKeys dco_decode_map_keys(dynamic raw);
+
+  @protected
+  MemoryBudgetUsage dco_decode_memory_budget_usage(dynamic raw);
+
+  @protected
+  MemoryTrimReport dco_decode_memory_trim_report(dynamic raw);
+
   @protected
   MemoryType dco_decode_memory_type(dynamic raw);
 
   @protected
   NNAPIExecutionProvider dco_decode_nnapi_execution_provider(dynamic raw);
 
+  @protected
+  NonFiniteCheck dco_decode_non_finite_check(dynamic raw);
+
+  @protected
+  MemoryInfo?
+  dco_decode_opt_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
+    dynamic raw,
+  );
+
+  @protected
+  int? dco_decode_opt_CastedPrimitive_i_64(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_CastedPrimitive_u_64(dynamic raw);
+
   @protected
   int? dco_decode_opt_CastedPrimitive_usize(dynamic raw);
 
+  @protected
+  Map<String, String>? dco_decode_opt_Map_String_String_None(dynamic raw);
+
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
@@ -415,6 +650,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
 
+  @protected
+  CastMode? dco_decode_opt_box_autoadd_cast_mode(dynamic raw);
+
   @protected
   CoreMLComputeUnits? dco_decode_opt_box_autoadd_core_ml_compute_units(
     dynamic raw,
@@ -438,6 +676,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
   @protected
   GraphOptimizationLevel? dco_decode_opt_box_autoadd_graph_optimization_level(
     dynamic raw,
@@ -467,12 +708,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  RunMemoryUsage? dco_decode_opt_box_autoadd_run_memory_usage(dynamic raw);
+
   @protected
   int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
 
   @protected
   int? dco_decode_opt_box_autoadd_u_8(dynamic raw);
 
+  @protected
+  ValueImpl? dco_decode_opt_box_value_impl(dynamic raw);
+
   @protected
   List<int>? dco_decode_opt_list_CastedPrimitive_i_64(dynamic raw);
 
@@ -503,12 +750,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  (String, String) dco_decode_record_string_string(dynamic raw);
+
+  @protected
+  (String, ValueImpl) dco_decode_record_string_value_impl(dynamic raw);
+
   @protected
   ROCmExecutionProvider dco_decode_ro_cm_execution_provider(dynamic raw);
 
+  @protected
+  RunMemoryUsage dco_decode_run_memory_usage(dynamic raw);
+
   @protected
   SessionBuilderOptions dco_decode_session_builder_options(dynamic raw);
 
+  @protected
+  TensorDiff dco_decode_tensor_diff(dynamic raw);
+
+  @protected
+  TensorElement dco_decode_tensor_element(dynamic raw);
+
   @protected
   TensorElementType dco_decode_tensor_element_type(dynamic raw);
 
@@ -517,6 +779,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  TensorStats dco_decode_tensor_stats(dynamic raw);
+
+  @protected
+  TopK dco_decode_top_k(dynamic raw);
+
   @protected
   int dco_decode_u_16(dynamic raw);
 
@@ -535,12 +803,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt dco_decode_usize(dynamic raw);
 
+  @protected
+  ValueImpl dco_decode_value_impl(dynamic raw);
+
   @protected
   XNNPACKExecutionProvider dco_decode_xnnpack_execution_provider(dynamic raw);
 
   @protected
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
+  @protected
+  Allocator
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  BatchScheduler
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler(
+    SseDeserializer deserializer,
+  );
+
   @protected
   MemoryInfo
   sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
@@ -553,12 +836,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  TensorDataView
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+    SseDeserializer deserializer,
+  );
+
   @protected
   TensorImpl
   sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
     SseDeserializer deserializer,
   );
 
+  @protected
+  TensorSlice
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+    SseDeserializer deserializer,
+  );
+
   @protected
   SessionImpl
   sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl(
@@ -571,6 +866,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  TensorSlice
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Allocator
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  BatchScheduler
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler(
+    SseDeserializer deserializer,
+  );
+
   @protected
   MemoryInfo
   sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
@@ -583,12 +896,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  TensorDataView
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+    SseDeserializer deserializer,
+  );
+
   @protected
   TensorImpl
   sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
     SseDeserializer deserializer,
   );
 
+  @protected
+  TensorSlice
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+    SseDeserializer deserializer,
+  );
+
   @protected
   int sse_decode_CastedPrimitive_i_64(SseDeserializer deserializer);
 
@@ -604,6 +929,28 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  Map<String, String> sse_decode_Map_String_String_None(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Map<String, ValueImpl> sse_decode_Map_String_value_impl_None(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Allocator
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  BatchScheduler
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler(
+    SseDeserializer deserializer,
+  );
+
   @protected
   MemoryInfo
   sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
@@ -616,32 +963,71 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  TensorDataView
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+    SseDeserializer deserializer,
+  );
+
   @protected
   TensorImpl
   sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
     SseDeserializer deserializer,
   );
 
+  @protected
+  TensorSlice
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+    SseDeserializer deserializer,
+  );
+
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
   @protected
   AllocationDevice sse_decode_allocation_device(SseDeserializer deserializer);
 
+  @protected
+  AllocatorStats sse_decode_allocator_stats(SseDeserializer deserializer);
+
   @protected
   AllocatorType sse_decode_allocator_type(SseDeserializer deserializer);
 
+  @protected
+  ArenaConfig sse_decode_arena_config(SseDeserializer deserializer);
+
   @protected
   ArenaExtendStrategy sse_decode_arena_extend_strategy(
     SseDeserializer deserializer,
   );
 
+  @protected
+  ArenaStats sse_decode_arena_stats(SseDeserializer deserializer);
+
   @protected
   ArrayPointer sse_decode_array_pointer(SseDeserializer deserializer);
 
+  @protected
+  BatchSchedulerOptions sse_decode_batch_scheduler_options(
+    SseDeserializer deserializer,
+  );
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
+  @protected
+  AllocationDevice sse_decode_box_autoadd_allocation_device(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AllocatorType sse_decode_box_autoadd_allocator_type(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ArenaConfig sse_decode_box_autoadd_arena_config(SseDeserializer deserializer);
+
   @protected
   ArenaExtendStrategy sse_decode_box_autoadd_arena_extend_strategy(
     SseDeserializer deserializer,
@@ -652,9 +1038,17 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  BatchSchedulerOptions sse_decode_box_autoadd_batch_scheduler_options(
+    SseDeserializer deserializer,
+  );
+
   @protected
   bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
 
+  @protected
+  CastMode sse_decode_box_autoadd_cast_mode(SseDeserializer deserializer);
+
   @protected
   CoreMLComputeUnits sse_decode_box_autoadd_core_ml_compute_units(
     SseDeserializer deserializer,
@@ -701,6 +1095,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  EvictionPolicy sse_decode_box_autoadd_eviction_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
   @protected
   GraphOptimizationLevel sse_decode_box_autoadd_graph_optimization_level(
     SseDeserializer deserializer,
@@ -712,11 +1114,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   int sse_decode_box_autoadd_i_8(SseDeserializer deserializer);
 
+  @protected
+  MapImpl sse_decode_box_autoadd_map_impl(SseDeserializer deserializer);
+
+  @protected
+  MemoryType sse_decode_box_autoadd_memory_type(SseDeserializer deserializer);
+
   @protected
   NNAPIExecutionProvider sse_decode_box_autoadd_nnapi_execution_provider(
     SseDeserializer deserializer,
   );
 
+  @protected
+  NonFiniteCheck sse_decode_box_autoadd_non_finite_check(
+    SseDeserializer deserializer,
+  );
+
   @protected
   OrtDebugLevel sse_decode_box_autoadd_ort_debug_level(
     SseDeserializer deserializer,
@@ -747,16 +1160,37 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  RunMemoryUsage sse_decode_box_autoadd_run_memory_usage(
+    SseDeserializer deserializer,
+  );
+
   @protected
   SessionBuilderOptions sse_decode_box_autoadd_session_builder_options(
     SseDeserializer deserializer,
   );
 
+  @protected
+  TensorDiff sse_decode_box_autoadd_tensor_diff(SseDeserializer deserializer);
+
+  @protected
+  TensorElement sse_decode_box_autoadd_tensor_element(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TensorElementType sse_decode_box_autoadd_tensor_element_type(
+    SseDeserializer deserializer,
+  );
+
   @protected
   TensorRTExecutionProvider sse_decode_box_autoadd_tensor_rt_execution_provider(
     SseDeserializer deserializer,
   );
 
+  @protected
+  TensorStats sse_decode_box_autoadd_tensor_stats(SseDeserializer deserializer);
+
   @protected
   int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
 
@@ -768,6 +1202,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  ValueImpl sse_decode_box_value_impl(SseDeserializer deserializer);
+
+  @protected
+  CastMode sse_decode_cast_mode(SseDeserializer deserializer);
+
   @protected
   CoreMLComputeUnits sse_decode_core_ml_compute_units(
     SseDeserializer deserializer,
@@ -816,6 +1256,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  EvictionPolicy sse_decode_eviction_policy(SseDeserializer deserializer);
+
   @protected
   ExecutionProvider sse_decode_execution_provider(SseDeserializer deserializer);
 
@@ -845,12 +1288,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Input sse_decode_input(SseDeserializer deserializer);
 
+  @protected
+  List<TensorImpl>
+  sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<int> sse_decode_list_CastedPrimitive_i_64(SseDeserializer deserializer);
 
   @protected
   List<int> sse_decode_list_CastedPrimitive_u_64(SseDeserializer deserializer);
 
+  @protected
+  List<Map<String, TensorImpl>>
+  sse_decode_list_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer);
 
@@ -922,6 +1377,33 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<(String, String)> sse_decode_list_record_string_string(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<(String, ValueImpl)> sse_decode_list_record_string_value_impl(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ValueImpl> sse_decode_list_value_impl(SseDeserializer deserializer);
+
+  @protected
+  MapImpl sse_decode_map_impl(SseDeserializer deserializer);
+
+  @protected
+  MapKeys sse_decode_map_keys(SseDeserializer deserializer);
+
+  @protected
+  MemoryBudgetUsage sse_decode_memory_budget_usage(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MemoryTrimReport sse_decode_memory_trim_report(SseDeserializer deserializer);
+
   @protected
   MemoryType sse_decode_memory_type(SseDeserializer deserializer);
 
@@ -930,9 +1412,29 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  NonFiniteCheck sse_decode_non_finite_check(SseDeserializer deserializer);
+
+  @protected
+  MemoryInfo?
+  sse_decode_opt_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_CastedPrimitive_i_64(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_CastedPrimitive_u_64(SseDeserializer deserializer);
+
   @protected
   int? sse_decode_opt_CastedPrimitive_usize(SseDeserializer deserializer);
 
+  @protected
+  Map<String, String>? sse_decode_opt_Map_String_String_None(
+    SseDeserializer deserializer,
+  );
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer);
 
@@ -944,6 +1446,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
 
+  @protected
+  CastMode? sse_decode_opt_box_autoadd_cast_mode(SseDeserializer deserializer);
+
   @protected
   CoreMLComputeUnits? sse_decode_opt_box_autoadd_core_ml_compute_units(
     SseDeserializer deserializer,
@@ -971,6 +1476,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
   @protected
   GraphOptimizationLevel? sse_decode_opt_box_autoadd_graph_optimization_level(
     SseDeserializer deserializer,
@@ -1002,12 +1510,20 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  RunMemoryUsage? sse_decode_opt_box_autoadd_run_memory_usage(
+    SseDeserializer deserializer,
+  );
+
   @protected
   int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
 
   @protected
   int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer);
 
+  @protected
+  ValueImpl? sse_decode_opt_box_value_impl(SseDeserializer deserializer);
+
   @protected
   List<int>? sse_decode_opt_list_CastedPrimitive_i_64(
     SseDeserializer deserializer,
@@ -1050,16 +1566,35 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  (String, String) sse_decode_record_string_string(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (String, ValueImpl) sse_decode_record_string_value_impl(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ROCmExecutionProvider sse_decode_ro_cm_execution_provider(
     SseDeserializer deserializer,
   );
 
+  @protected
+  RunMemoryUsage sse_decode_run_memory_usage(SseDeserializer deserializer);
+
   @protected
   SessionBuilderOptions sse_decode_session_builder_options(
     SseDeserializer deserializer,
   );
 
+  @protected
+  TensorDiff sse_decode_tensor_diff(SseDeserializer deserializer);
+
+  @protected
+  TensorElement sse_decode_tensor_element(SseDeserializer deserializer);
+
   @protected
   TensorElementType sse_decode_tensor_element_type(
     SseDeserializer deserializer,
@@ -1070,6 +1605,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  TensorStats sse_decode_tensor_stats(SseDeserializer deserializer);
+
+  @protected
+  TopK sse_decode_top_k(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_16(SseDeserializer deserializer);
 
@@ -1088,6 +1629,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt sse_decode_usize(SseDeserializer deserializer);
 
+  @protected
+  ValueImpl sse_decode_value_impl(SseDeserializer deserializer);
+
   @protected
   XNNPACKExecutionProvider sse_decode_xnnpack_execution_provider(
     SseDeserializer deserializer,
@@ -1099,6 +1643,20 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator(
+    Allocator self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler(
+    BatchScheduler self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
@@ -1113,6 +1671,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+    TensorDataView self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
@@ -1120,6 +1685,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+    TensorSlice self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionImpl(
@@ -1134,6 +1706,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+    TensorSlice self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator(
+    Allocator self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler(
+    BatchScheduler self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
@@ -1148,6 +1741,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+    TensorDataView self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
@@ -1155,6 +1755,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+    TensorSlice self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_CastedPrimitive_i_64(int self, SseSerializer serializer);
 
@@ -1166,8 +1773,34 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
 
   @protected
   void
-  sse_encode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None(
-    Map<String, TensorImpl> self,
+  sse_encode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None(
+    Map<String, TensorImpl> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_Map_String_String_None(
+    Map<String, String> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_Map_String_value_impl_None(
+    Map<String, ValueImpl> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator(
+    Allocator self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler(
+    BatchScheduler self,
     SseSerializer serializer,
   );
 
@@ -1185,6 +1818,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+    TensorDataView self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
@@ -1192,6 +1832,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+    TensorSlice self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
@@ -1201,21 +1848,57 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_allocator_stats(
+    AllocatorStats self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_allocator_type(AllocatorType self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_arena_config(ArenaConfig self, SseSerializer serializer);
+
   @protected
   void sse_encode_arena_extend_strategy(
     ArenaExtendStrategy self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_arena_stats(ArenaStats self, SseSerializer serializer);
+
   @protected
   void sse_encode_array_pointer(ArrayPointer self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_batch_scheduler_options(
+    BatchSchedulerOptions self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_allocation_device(
+    AllocationDevice self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_allocator_type(
+    AllocatorType self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_arena_config(
+    ArenaConfig self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_arena_extend_strategy(
     ArenaExtendStrategy self,
@@ -1228,9 +1911,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_batch_scheduler_options(
+    BatchSchedulerOptions self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_cast_mode(
+    CastMode self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_core_ml_compute_units(
     CoreMLComputeUnits self,
@@ -1285,6 +1980,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_eviction_policy(
+    EvictionPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_graph_optimization_level(
     GraphOptimizationLevel self,
@@ -1297,12 +2001,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_i_8(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_map_impl(MapImpl self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_memory_type(
+    MemoryType self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_nnapi_execution_provider(
     NNAPIExecutionProvider self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_non_finite_check(
+    NonFiniteCheck self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_ort_debug_level(
     OrtDebugLevel self,
@@ -1339,18 +2058,48 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_run_memory_usage(
+    RunMemoryUsage self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_session_builder_options(
     SessionBuilderOptions self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_tensor_diff(
+    TensorDiff self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_tensor_element(
+    TensorElement self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_tensor_element_type(
+    TensorElementType self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_tensor_rt_execution_provider(
     TensorRTExecutionProvider self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_tensor_stats(
+    TensorStats self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
 
@@ -1363,6 +2112,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_value_impl(ValueImpl self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_cast_mode(CastMode self, SseSerializer serializer);
+
   @protected
   void sse_encode_core_ml_compute_units(
     CoreMLComputeUnits self,
@@ -1420,6 +2175,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_eviction_policy(
+    EvictionPolicy self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_execution_provider(
     ExecutionProvider self,
@@ -1453,6 +2214,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_input(Input self, SseSerializer serializer);
 
+  @protected
+  void
+  sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
+    List<TensorImpl> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_CastedPrimitive_i_64(
     List<int> self,
@@ -1465,6 +2233,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_list_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl_None(
+    List<Map<String, TensorImpl>> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
@@ -1577,6 +2352,42 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_record_string_string(
+    List<(String, String)> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_record_string_value_impl(
+    List<(String, ValueImpl)> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_value_impl(
+    List<ValueImpl> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_map_impl(MapImpl self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_map_keys(MapKeys self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_memory_budget_usage(
+    MemoryBudgetUsage self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_memory_trim_report(
+    MemoryTrimReport self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_memory_type(MemoryType self, SseSerializer serializer);
 
@@ -1586,12 +2397,37 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_non_finite_check(
+    NonFiniteCheck self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_opt_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
+    MemoryInfo? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_CastedPrimitive_i_64(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_CastedPrimitive_u_64(int? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_CastedPrimitive_usize(
     int? self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_Map_String_String_None(
+    Map<String, String>? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
@@ -1604,6 +2440,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_cast_mode(
+    CastMode? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_core_ml_compute_units(
     CoreMLComputeUnits? self,
@@ -1634,6 +2476,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_graph_optimization_level(
     GraphOptimizationLevel? self,
@@ -1670,12 +2515,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_run_memory_usage(
+    RunMemoryUsage? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
   @protected
   void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_value_impl(ValueImpl? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_list_CastedPrimitive_i_64(
     List<int>? self,
@@ -1725,18 +2579,42 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_record_string_string(
+    (String, String) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_record_string_value_impl(
+    (String, ValueImpl) self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_ro_cm_execution_provider(
     ROCmExecutionProvider self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_run_memory_usage(
+    RunMemoryUsage self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_session_builder_options(
     SessionBuilderOptions self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_tensor_diff(TensorDiff self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_tensor_element(TensorElement self, SseSerializer serializer);
+
   @protected
   void sse_encode_tensor_element_type(
     TensorElementType self,
@@ -1749,6 +2627,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_tensor_stats(TensorStats self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_top_k(TopK self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_16(int self, SseSerializer serializer);
 
@@ -1767,6 +2651,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_usize(BigInt self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_value_impl(ValueImpl self, SseSerializer serializer);
+
   @protected
   void sse_encode_xnnpack_execution_provider(
     XNNPACKExecutionProvider self,
@@ -1779,6 +2666,38 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
 class RustLibWire implements BaseWire {
   RustLibWire.fromExternalLibrary(ExternalLibrary lib);
 
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler(
+        ptr,
+      );
+
   void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
     int ptr,
@@ -1811,6 +2730,22 @@ class RustLibWire implements BaseWire {
         ptr,
       );
 
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+        ptr,
+      );
+
   void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
     int ptr,
@@ -1826,6 +2761,22 @@ class RustLibWire implements BaseWire {
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
         ptr,
       );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+        ptr,
+      );
 }
 
 @JS('wasm_bindgen')
@@ -1834,6 +2785,26 @@ external RustLibWasmModule get wasmModule;
 @JS()
 @anonymous
 extension type RustLibWasmModule._(JSObject _) implements JSObject {
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAllocator(
+    int ptr,
+  );
+
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBatchScheduler(
+    int ptr,
+  );
+
   external void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMemoryInfo(
     int ptr,
@@ -1854,6 +2825,16 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     int ptr,
   );
 
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorDataView(
+    int ptr,
+  );
+
   external void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
     int ptr,
@@ -1863,4 +2844,14 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
   rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorImpl(
     int ptr,
   );
+
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTensorSlice(
+    int ptr,
+  );
 }
@@ -70,6 +70,7 @@ pub trait ExecutionProviderBase: Send + Sync {
   fn build(&self) -> ExecutionProviderDispatch;
 }
 
+#[derive(Clone)]
 pub enum ExecutionProvider {
   CoreML(CoreMLExecutionProvider),
   CPU(CPUExecutionProvider),
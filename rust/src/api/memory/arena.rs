@@ -0,0 +1,120 @@
+use std::ffi::CString;
+use flutter_rust_bridge::frb;
+use ort::memory::{AllocationDevice, AllocatorType, MemoryInfo, MemoryType};
+use ort::{AsPointer, Error, Result};
+use crate::api::execution_providers::ArenaExtendStrategy;
+use crate::api::memory::{env_cpu_allocator, EnvCpuAllocator};
+
+/// How the CPU memory arena shared by sessions grows, see [`configure_env_arena`]. Options left out keep ONNX
+/// Runtime's defaults.
+#[derive(Debug, Clone, Default)]
+#[frb(dart_metadata=("freezed"))]
+pub struct ArenaConfig {
+  /// The most bytes the arena may hold. Allocations that would exceed it fail. Unlimited by default.
+  pub max_memory: Option<usize>,
+  /// How much the arena grows by when it runs out of memory.
+  pub arena_extend_strategy: Option<ArenaExtendStrategy>,
+  /// The size of the first block of memory the arena allocates.
+  pub initial_chunk_size_bytes: Option<usize>,
+}
+
+impl ArenaConfig {
+  fn entries(&self) -> Vec<(&'static str, usize)> {
+    let mut entries = Vec::new();
+    if let Some(max_memory) = self.max_memory {
+      entries.push(("max_mem", max_memory));
+    }
+    if let Some(strategy) = &self.arena_extend_strategy {
+      entries.push(("arena_extend_strategy", match strategy {
+        ArenaExtendStrategy::NextPowerOfTwo => 0,
+        ArenaExtendStrategy::SameAsRequested => 1,
+      }));
+    }
+    if let Some(initial_chunk_size_bytes) = self.initial_chunk_size_bytes {
+      entries.push(("initial_chunk_size_bytes", initial_chunk_size_bytes));
+    }
+    entries
+  }
+}
+
+fn arena_memory_info() -> Result<MemoryInfo> {
+  MemoryInfo::new(AllocationDevice::CPU, 0, AllocatorType::Arena, MemoryType::Default)
+}
+
+/// Registers a CPU memory arena with the ONNX Runtime environment, to be shared by every session created from now on
+/// with [`SessionBuilderOptions::env_allocators`] set, instead of each growing an arena of its own.
+///
+/// Calling this again replaces the arena for sessions created afterwards; existing sessions keep the one they were
+/// created with. Fails if allocator tracking was enabled, as both replace the environment's CPU allocator.
+///
+/// [`SessionBuilderOptions::env_allocators`]: crate::api::session::SessionBuilderOptions::env_allocators
+#[frb(sync)]
+pub fn configure_env_arena(config: ArenaConfig) -> Result<()> {
+  let mut registered = env_cpu_allocator();
+  if *registered == Some(EnvCpuAllocator::Tracking) {
+    return Err(Error::new("An environment arena can't be configured once allocator tracking is enabled"));
+  }
+
+  let entries = config.entries();
+  let keys = entries
+    .iter()
+    .map(|(key, _)| CString::new(*key).map_err(|e| Error::new(format!("Failed to create CString: {}", e))))
+    .collect::<Result<Vec<_>>>()?;
+  let key_ptrs = keys.iter().map(|key| key.as_ptr()).collect::<Vec<_>>();
+  let values = entries.iter().map(|(_, value)| *value).collect::<Vec<_>>();
+  let mut arena_cfg: *mut ort::sys::OrtArenaCfg = std::ptr::null_mut();
+  unsafe {
+    ort::error::status_to_result((ort::api().CreateArenaCfgV2)(
+      key_ptrs.as_ptr(),
+      values.as_ptr(),
+      entries.len(),
+      &mut arena_cfg,
+    ))?;
+  }
+
+  let result = register_arena(&mut registered, arena_cfg);
+  // ONNX Runtime copies the configuration into the arena
+  unsafe { (ort::api().ReleaseArenaCfg)(arena_cfg) };
+  result.map_err(|e| Error::new(format!("Failed to register the environment arena: {}", e)))
+}
+
+fn register_arena(registered: &mut Option<EnvCpuAllocator>, arena_cfg: *const ort::sys::OrtArenaCfg) -> Result<()> {
+  let memory_info = arena_memory_info()?;
+  let environment = ort::environment::get_environment()?;
+  if *registered == Some(EnvCpuAllocator::Arena) {
+    unsafe {
+      ort::error::status_to_result((ort::api().UnregisterAllocator)(environment.ptr().cast_mut(), memory_info.ptr()))?;
+    }
+    *registered = None;
+  }
+  unsafe {
+    ort::error::status_to_result((ort::api().CreateAndRegisterAllocator)(
+      environment.ptr().cast_mut(),
+      memory_info.ptr(),
+      arena_cfg,
+    ))?;
+  }
+  *registered = Some(EnvCpuAllocator::Arena);
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use ort::error::Result;
+  use crate::api::execution_providers::ArenaExtendStrategy;
+  use crate::api::memory::arena::{configure_env_arena, ArenaConfig};
+  use crate::api::memory::stats::enable_allocator_tracking;
+
+  #[test]
+  fn env_arena_excludes_tracking() -> Result<()> {
+    configure_env_arena(ArenaConfig {
+      max_memory: Some(64 << 20),
+      arena_extend_strategy: Some(ArenaExtendStrategy::SameAsRequested),
+      initial_chunk_size_bytes: Some(1 << 20),
+    })?;
+    // Reconfiguring replaces the arena
+    configure_env_arena(ArenaConfig::default())?;
+    assert!(enable_allocator_tracking().is_err());
+    Ok(())
+  }
+}
@@ -0,0 +1,441 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, Weak};
+use flutter_rust_bridge::frb;
+use ort::{Error, Result};
+use crate::api::memory::trim::Trimmed;
+
+/// What happens when a tensor or session doesn't fit into the memory budget, see [`set_memory_budget`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EvictionPolicy {
+  /// The allocation or commit fails.
+  #[default]
+  Fail,
+  /// Sessions that aren't running are suspended, least recently used first, until the request fits, see
+  /// [`SessionImpl::suspend`]. Fails if it still doesn't fit once every idle session is suspended.
+  ///
+  /// [`SessionImpl::suspend`]: crate::api::session::SessionImpl::suspend
+  SuspendIdleSessions,
+}
+
+/// Makes room in the memory budget in place of the [`EvictionPolicy`], e.g. by suspending sessions in an app specific
+/// order or dropping cached tensors, see [`set_eviction_handler`].
+pub trait EvictionHandler: Send + Sync {
+  /// Called when `needed_bytes` don't fit into the budget, with what is counted against it at that time. Returns
+  /// whether anything was released, in which case the request is tried again and the handler asked again if it
+  /// still doesn't fit.
+  ///
+  /// The budget isn't locked meanwhile, so the handler may drop tensors and suspend sessions. It must not return
+  /// `true` without releasing something, as it would be asked again forever.
+  fn evict(&self, needed_bytes: u64, usage: MemoryBudgetUsage) -> bool;
+}
+
+/// The memory counted against the budget, see [`memory_budget_usage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[frb(dart_metadata=("freezed"))]
+pub struct MemoryBudgetUsage {
+  /// The most bytes tensors and sessions may hold, or `None` if there's no budget.
+  pub limit_bytes: Option<u64>,
+  /// Bytes of data owned by live tensors.
+  pub tensor_bytes: u64,
+  /// The estimated footprint of committed sessions, i.e. the size of their models, see [`set_memory_budget`].
+  pub session_bytes: u64,
+}
+
+/// What a charge against the budget is for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ChargeKind {
+  Tensor,
+  Session,
+}
+
+impl ChargeKind {
+  fn name(self) -> &'static str {
+    match self {
+      ChargeKind::Tensor => "tensor",
+      ChargeKind::Session => "session",
+    }
+  }
+}
+
+struct Budget {
+  limit_bytes: Option<u64>,
+  policy: EvictionPolicy,
+  /// Asked to make room in place of the policy, see [`set_eviction_handler`].
+  handler: Option<Arc<dyn EvictionHandler>>,
+  tensor_bytes: u64,
+  session_bytes: u64,
+}
+
+impl Budget {
+  const fn new() -> Budget {
+    Budget {
+      limit_bytes: None,
+      policy: EvictionPolicy::Fail,
+      handler: None,
+      tensor_bytes: 0,
+      session_bytes: 0,
+    }
+  }
+
+  fn used_bytes(&self) -> u64 {
+    self.tensor_bytes + self.session_bytes
+  }
+
+  fn usage(&self) -> MemoryBudgetUsage {
+    MemoryBudgetUsage {
+      limit_bytes: self.limit_bytes,
+      tensor_bytes: self.tensor_bytes,
+      session_bytes: self.session_bytes,
+    }
+  }
+
+  fn add(&mut self, kind: ChargeKind, bytes: u64) {
+    match kind {
+      ChargeKind::Tensor => self.tensor_bytes += bytes,
+      ChargeKind::Session => self.session_bytes += bytes,
+    }
+  }
+
+  /// Counts `bytes` if they fit. Otherwise returns whether to evict something and try again, or the error to fail
+  /// with.
+  fn admit(&mut self, kind: ChargeKind, bytes: u64) -> std::result::Result<(), Option<Error>> {
+    let used = self.used_bytes();
+    match self.limit_bytes {
+      Some(limit) if used + bytes > limit => match (&self.handler, self.policy) {
+        (None, EvictionPolicy::Fail) => Err(Some(exceeded(kind, bytes, used, limit))),
+        (Some(_), _) | (None, EvictionPolicy::SuspendIdleSessions) => Err(None),
+      },
+      _ => {
+        self.add(kind, bytes);
+        Ok(())
+      },
+    }
+  }
+}
+
+/// The budget along with the candidates for eviction. There's a single one for the process, tests create their own.
+struct MemoryBudget {
+  budget: Mutex<Budget>,
+  evictable: Mutex<Vec<Weak<dyn Evictable>>>,
+}
+
+static MEMORY_BUDGET: MemoryBudget = MemoryBudget::new();
+
+impl MemoryBudget {
+  const fn new() -> MemoryBudget {
+    MemoryBudget {
+      budget: Mutex::new(Budget::new()),
+      evictable: Mutex::new(Vec::new()),
+    }
+  }
+
+  fn budget(&self) -> MutexGuard<'_, Budget> {
+    self.budget.lock().unwrap_or_else(|e| e.into_inner())
+  }
+
+  fn reserve(&'static self, kind: ChargeKind, bytes: u64) -> Result<MemoryCharge> {
+    loop {
+      match self.budget().admit(kind, bytes) {
+        Ok(()) => return Ok(MemoryCharge { budget: self, kind, bytes }),
+        Err(Some(e)) => return Err(e),
+        // The budget isn't locked while evicting, as releasing a session's charge locks it
+        Err(None) => {},
+      }
+      if !self.evict(bytes) {
+        let budget = self.budget();
+        let limit = budget.limit_bytes.unwrap_or(u64::MAX);
+        return Err(exceeded(kind, bytes, budget.used_bytes(), limit));
+      }
+    }
+  }
+
+  fn charge(&'static self, kind: ChargeKind, bytes: u64) -> MemoryCharge {
+    self.budget().add(kind, bytes);
+    MemoryCharge { budget: self, kind, bytes }
+  }
+
+  fn register_evictable(&self, evictable: &Arc<dyn Evictable>) {
+    let mut candidates = self.evictable.lock().unwrap_or_else(|e| e.into_inner());
+    candidates.retain(|candidate| candidate.strong_count() > 0);
+    candidates.push(Arc::downgrade(evictable));
+  }
+
+  fn evictables(&self) -> Vec<Arc<dyn Evictable>> {
+    self
+      .evictable
+      .lock()
+      .unwrap_or_else(|e| e.into_inner())
+      .iter()
+      .filter_map(Weak::upgrade)
+      .collect()
+  }
+
+  /// Makes room for `needed_bytes` with the eviction handler if there's one, or according to the [`EvictionPolicy`]
+  /// otherwise, returning whether anything was released.
+  fn evict(&self, needed_bytes: u64) -> bool {
+    let (policy, handler, usage) = {
+      let budget = self.budget();
+      // Cloned so that the handler can replace itself
+      (budget.policy, budget.handler.clone(), budget.usage())
+    };
+    match (handler, policy) {
+      (Some(handler), _) => handler.evict(needed_bytes, usage),
+      (None, EvictionPolicy::Fail) => false,
+      (None, EvictionPolicy::SuspendIdleSessions) => self.evict_one(),
+    }
+  }
+
+  /// Evicts the least recently used candidate that isn't in use, returning whether there was one.
+  fn evict_one(&self) -> bool {
+    let mut candidates = self.evictables();
+    candidates.sort_by_key(|candidate| candidate.last_used());
+    candidates.iter().any(|candidate| candidate.try_evict())
+  }
+}
+
+/// Sets the most bytes live tensors and committed sessions may hold together, or removes the limit with `None`, and
+/// what happens when a new tensor or session doesn't fit.
+///
+/// Tensors count the data they own; views and memory they don't own, like external buffers or memory-mapped files,
+/// aren't counted. Sessions count the size of their model while committed. That's only a rough estimate of their
+/// footprint: it leaves out the arena and the buffers of their execution providers, and optimizations can make the
+/// weights smaller or larger than the model, so leave some headroom below the device's real limit. Copies
+/// made with [`TensorImpl::clone`] and the outputs of a run are counted but never refused, as their memory is already
+/// allocated. Lowering the limit below what's in use doesn't release anything, it only refuses new requests.
+///
+/// [`TensorImpl::clone`]: crate::api::tensor::TensorImpl::clone
+#[frb(sync)]
+pub fn set_memory_budget(limit_bytes: Option<u64>, policy: EvictionPolicy) {
+  let mut budget = MEMORY_BUDGET.budget();
+  budget.limit_bytes = limit_bytes;
+  budget.policy = policy;
+}
+
+/// Sets a handler to ask to make room in place of the [`EvictionPolicy`], or removes it with `None`.
+///
+/// Only available from Rust: the handler is called synchronously from whatever allocates or commits, often Dart's own
+/// thread, which couldn't run a Dart callback while it waits.
+#[frb(ignore)]
+pub fn set_eviction_handler(handler: Option<Arc<dyn EvictionHandler>>) {
+  MEMORY_BUDGET.budget().handler = handler;
+}
+
+/// Returns the limit of the memory budget and what is counted against it.
+#[frb(sync)]
+pub fn memory_budget_usage() -> MemoryBudgetUsage {
+  MEMORY_BUDGET.budget().usage()
+}
+
+/// Bytes counted against the budget until dropped.
+pub(crate) struct MemoryCharge {
+  budget: &'static MemoryBudget,
+  kind: ChargeKind,
+  bytes: u64,
+}
+
+impl MemoryCharge {
+  /// Counts `bytes` against the budget if they fit, evicting according to the [`EvictionPolicy`] to make room.
+  pub(crate) fn reserve(kind: ChargeKind, bytes: u64) -> Result<MemoryCharge> {
+    MEMORY_BUDGET.reserve(kind, bytes)
+  }
+
+  /// Counts `bytes` that are already allocated against the budget, even if they don't fit.
+  pub(crate) fn charge(kind: ChargeKind, bytes: u64) -> MemoryCharge {
+    MEMORY_BUDGET.charge(kind, bytes)
+  }
+
+  pub(crate) fn bytes(&self) -> u64 {
+    self.bytes
+  }
+}
+
+impl std::fmt::Debug for MemoryCharge {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("MemoryCharge").field("kind", &self.kind).field("bytes", &self.bytes).finish()
+  }
+}
+
+impl Drop for MemoryCharge {
+  fn drop(&mut self) {
+    let mut budget = self.budget.budget();
+    match self.kind {
+      ChargeKind::Tensor => budget.tensor_bytes -= self.bytes,
+      ChargeKind::Session => budget.session_bytes -= self.bytes,
+    }
+  }
+}
+
+fn exceeded(kind: ChargeKind, bytes: u64, used: u64, limit: u64) -> Error {
+  Error::new(format!(
+    "Memory budget exceeded: a {} needs {} bytes, but {} of {} bytes are in use",
+    kind.name(),
+    bytes,
+    used,
+    limit
+  ))
+}
+
+/// Something that can give back memory counted against the budget, i.e. a committed session.
+pub(crate) trait Evictable: Send + Sync {
+  /// When it was last used, see [`next_use`]. Those used least recently are evicted first.
+  fn last_used(&self) -> u64;
+  /// Releases its memory unless it's in use, returning whether it did.
+  fn try_evict(&self) -> bool;
+  /// Releases what it can for [`trim_memory`](crate::api::memory::trim::trim_memory).
+  fn trim(&self) -> Trimmed;
+}
+
+static USE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Returns an increasing number to order uses by.
+pub(crate) fn next_use() -> u64 {
+  USE_COUNTER.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Makes `evictable` a candidate for [`EvictionPolicy::SuspendIdleSessions`] for as long as it's alive.
+pub(crate) fn register_evictable(evictable: &Arc<dyn Evictable>) {
+  MEMORY_BUDGET.register_evictable(evictable);
+}
+
+/// The registered candidates that are still alive.
+pub(crate) fn evictables() -> Vec<Arc<dyn Evictable>> {
+  MEMORY_BUDGET.evictables()
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::atomic::{AtomicU64, Ordering};
+  use std::sync::{Arc, Mutex};
+  use crate::api::memory::budget::{
+    Budget, ChargeKind, Evictable, EvictionHandler, EvictionPolicy, MemoryBudget, MemoryBudgetUsage, MemoryCharge,
+  };
+  use crate::api::memory::trim::Trimmed;
+
+  /// A budget of its own, as the global one is shared with every other test.
+  fn test_budget(limit_bytes: u64, policy: EvictionPolicy) -> &'static MemoryBudget {
+    let memory_budget: &'static MemoryBudget = Box::leak(Box::new(MemoryBudget::new()));
+    let mut budget = memory_budget.budget();
+    budget.limit_bytes = Some(limit_bytes);
+    budget.policy = policy;
+    drop(budget);
+    memory_budget
+  }
+
+  /// Holds a charge like a committed session, logging its name to `evicted` when it gives it back.
+  struct StubSession {
+    name: &'static str,
+    last_used: u64,
+    busy: bool,
+    charge: Mutex<Option<MemoryCharge>>,
+    evicted: Arc<Mutex<Vec<&'static str>>>,
+  }
+
+  impl Evictable for StubSession {
+    fn last_used(&self) -> u64 {
+      self.last_used
+    }
+
+    fn try_evict(&self) -> bool {
+      if self.busy {
+        return false;
+      }
+      let evicted = self.charge.lock().unwrap().take().is_some();
+      if evicted {
+        self.evicted.lock().unwrap().push(self.name);
+      }
+      evicted
+    }
+
+    fn trim(&self) -> Trimmed {
+      Trimmed::Untouched
+    }
+  }
+
+  /// Drops one of `charges` each time it's asked, until there are none left.
+  struct StubHandler {
+    calls: AtomicU64,
+    charges: Mutex<Vec<MemoryCharge>>,
+  }
+
+  impl EvictionHandler for StubHandler {
+    fn evict(&self, _needed_bytes: u64, _usage: MemoryBudgetUsage) -> bool {
+      self.calls.fetch_add(1, Ordering::Relaxed);
+      self.charges.lock().unwrap().pop().is_some()
+    }
+  }
+
+  #[test]
+  fn budget_admits_until_the_limit() {
+    let mut budget = Budget::new();
+    budget.limit_bytes = Some(1000);
+    assert!(budget.admit(ChargeKind::Session, 600).is_ok());
+    assert!(budget.admit(ChargeKind::Tensor, 400).is_ok());
+    let error = budget.admit(ChargeKind::Tensor, 1).unwrap_err().expect("the request is refused");
+    assert!(error.to_string().contains("1000 of 1000 bytes are in use"));
+
+    budget.policy = EvictionPolicy::SuspendIdleSessions;
+    assert!(budget.admit(ChargeKind::Tensor, 1).unwrap_err().is_none());
+    budget.policy = EvictionPolicy::Fail;
+    budget.handler = Some(Arc::new(StubHandler {
+      calls: AtomicU64::new(0),
+      charges: Mutex::new(Vec::new()),
+    }));
+    assert!(budget.admit(ChargeKind::Tensor, 1).unwrap_err().is_none());
+    assert_eq!((budget.tensor_bytes, budget.session_bytes), (400, 600));
+  }
+
+  #[test]
+  fn reserve_suspends_least_recently_used_sessions() {
+    let budget = test_budget(100, EvictionPolicy::SuspendIdleSessions);
+    let evicted = Arc::new(Mutex::new(Vec::new()));
+    let session = |name, last_used, busy| {
+      let session = Arc::new(StubSession {
+        name,
+        last_used,
+        busy,
+        charge: Mutex::new(Some(budget.reserve(ChargeKind::Session, 30).unwrap())),
+        evicted: evicted.clone(),
+      });
+      budget.register_evictable(&(session.clone() as Arc<dyn Evictable>));
+      session
+    };
+    let _sessions = [session("running", 0, true), session("recent", 3, false), session("old", 1, false)];
+
+    // Each eviction frees 30 bytes, so the reserve is retried until the two idle sessions are suspended
+    let charge = budget.reserve(ChargeKind::Tensor, 60).unwrap();
+    assert_eq!(*evicted.lock().unwrap(), ["old", "recent"]);
+    assert_eq!(budget.budget().used_bytes(), 90);
+
+    // Fails once only the running session is left
+    let error = budget.reserve(ChargeKind::Tensor, 20).unwrap_err();
+    assert!(error.to_string().contains("90 of 100 bytes are in use"));
+    drop(charge);
+    assert_eq!(budget.budget().used_bytes(), 30);
+  }
+
+  #[test]
+  fn reserve_asks_the_handler_until_it_gives_up() {
+    let budget = test_budget(100, EvictionPolicy::Fail);
+    let handler = Arc::new(StubHandler {
+      calls: AtomicU64::new(0),
+      charges: Mutex::new(vec![
+        budget.reserve(ChargeKind::Tensor, 40).unwrap(),
+        budget.reserve(ChargeKind::Tensor, 40).unwrap(),
+      ]),
+    });
+    budget.budget().handler = Some(handler.clone());
+
+    // Asked in place of the policy, and again while the request still doesn't fit
+    let charge = budget.reserve(ChargeKind::Tensor, 60).unwrap();
+    assert_eq!(handler.calls.load(Ordering::Relaxed), 1);
+    let _charge = budget.reserve(ChargeKind::Tensor, 40).unwrap();
+    assert_eq!(handler.calls.load(Ordering::Relaxed), 2);
+
+    // A handler that releases nothing ends the loop
+    let error = budget.reserve(ChargeKind::Tensor, 1).unwrap_err();
+    assert!(error.to_string().contains("100 of 100 bytes are in use"));
+    assert_eq!(handler.calls.load(Ordering::Relaxed), 3);
+    drop(charge);
+    assert!(budget.reserve(ChargeKind::Tensor, 1).is_ok());
+  }
+}
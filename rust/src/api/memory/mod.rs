@@ -1,8 +1,34 @@
+pub mod arena;
+pub mod budget;
+pub mod stats;
+pub mod trim;
+
 use flutter_rust_bridge::frb;
 use ort::{Error, Result};
 use ort::memory::{AllocationDevice as OrtAllocationDevice};
 pub use ort::memory::{AllocatorType, DeviceType, MemoryType};
-use crate::api::tensor::TensorImpl;
+use std::any::Any;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// The CPU allocator registered with the environment for sessions to share, see
+/// [`SessionBuilderOptions::env_allocators`]. Sessions look it up by device alone, so there can only be one.
+///
+/// [`SessionBuilderOptions::env_allocators`]: crate::api::session::SessionBuilderOptions::env_allocators
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum EnvCpuAllocator {
+  /// The tracked allocator, see [`stats::enable_allocator_tracking`].
+  Tracking,
+  /// An arena, see [`arena::configure_env_arena`].
+  Arena,
+}
+
+static ENV_CPU_ALLOCATOR: Mutex<Option<EnvCpuAllocator>> = Mutex::new(None);
+
+/// Locks the record of which CPU allocator is registered with the environment, for as long as one is registered or
+/// replaced.
+pub(crate) fn env_cpu_allocator() -> MutexGuard<'static, Option<EnvCpuAllocator>> {
+  ENV_CPU_ALLOCATOR.lock().unwrap_or_else(|e| e.into_inner())
+}
 
 /// Represents possible devices that have their own device allocator.
 pub enum AllocationDevice {
@@ -151,7 +177,11 @@ pub enum _MemoryType {
 ///
 /// [`Value`]: crate::value::Value
 pub struct MemoryInfo {
-  ptr: TensorImpl,
+  allocation_device: String,
+  device_id: i32,
+  allocator_type: AllocatorType,
+  memory_type: MemoryType,
+  device_type: DeviceType,
 }
 
 impl MemoryInfo {
@@ -160,29 +190,45 @@ impl MemoryInfo {
   /// # Examples
   /// `MemoryInfo` can be used to specify the device & memory type used by an [`Allocator`] to allocate tensors.
   /// See [`Allocator`] for more information & potential applications.
-  /// ```no_run
-  /// # use ort::{memory::{Allocator, MemoryInfo, MemoryType, AllocationDevice, AllocatorType}, session::Session, value::Tensor};
-  /// # fn main() -> ort::Result<()> {
-  /// # let session = Session::builder()?.commit_from_file("tests/data/upsample.onnx")?;
-  /// let allocator = Allocator::new(
-  /// 	&session,
-  /// 	MemoryInfo::new(AllocationDevice::CUDA, 0, AllocatorType::Device, MemoryType::Default)?
-  /// )?;
+  /// ```ignore
+  /// let allocator = session.allocator(Some(
+  /// 	MemoryInfo::new(AllocationDevice::Cuda, 0, AllocatorType::Device, MemoryType::Default)?
+  /// ))?;
   ///
-  /// let mut tensor = Tensor::<f32>::new(&allocator, [1_usize, 3, 224, 224])?;
-  /// # Ok(())
-  /// # }
+  /// let tensor = TensorImpl::new_with_allocator(&allocator, TensorElementType::Float32, vec![1, 3, 224, 224])?;
   /// ```
+  #[frb(sync)]
+  pub fn new(allocation_device: AllocationDevice, device_id: i32, allocator_type: AllocatorType, memory_type: MemoryType) -> Result<Self> {
+    Ok(Self::from_ort(&ort::memory::MemoryInfo::new(
+      allocation_device.to_ort_allocation_device()?,
+      device_id,
+      allocator_type,
+      memory_type,
+    )?))
+  }
+
+  /// Copies the properties of an `ort` [`ort::memory::MemoryInfo`], which can't be shared between threads itself.
   #[frb(ignore)]
-  pub fn new(tensor: TensorImpl) -> Result<Self> {
-    Ok(Self {
-      ptr: tensor,
-    })
+  pub(crate) fn from_ort(info: &ort::memory::MemoryInfo) -> Self {
+    Self {
+      allocation_device: info.allocation_device().as_str().to_string(),
+      device_id: info.device_id(),
+      allocator_type: info.allocator_type(),
+      memory_type: info.memory_type(),
+      device_type: info.device_type(),
+    }
   }
 
-  // All getter functions are (at least currently) infallible - they simply just dereference the corresponding fields,
-  // and always return `nullptr` for the status; so none of these have to return `Result`s.
-  // https://github.com/microsoft/onnxruntime/blob/v1.23.1/onnxruntime/core/framework/allocator.cc#L304
+  /// Creates the `ort` [`ort::memory::MemoryInfo`] this struct describes.
+  #[frb(ignore)]
+  pub(crate) fn to_ort(&self) -> Result<ort::memory::MemoryInfo> {
+    ort::memory::MemoryInfo::new(
+      self.allocation_device().to_ort_allocation_device()?,
+      self.device_id,
+      self.allocator_type,
+      self.memory_type,
+    )
+  }
 
   /// Returns the [`MemoryType`] described by this struct.
   /// ```
@@ -195,7 +241,7 @@ impl MemoryInfo {
   /// ```
   #[frb(sync)]
   pub fn memory_type(&self) -> MemoryType {
-    self.ptr.tensor.memory_info().memory_type()
+    self.memory_type
   }
 
   /// Returns the [`AllocatorType`] described by this struct.
@@ -209,7 +255,7 @@ impl MemoryInfo {
   /// ```
   #[frb(sync)]
   pub fn allocator_type(&self) -> AllocatorType {
-    self.ptr.tensor.memory_info().allocator_type()
+    self.allocator_type
   }
 
   /// Returns the [`AllocationDevice`] this struct was created with.
@@ -223,7 +269,7 @@ impl MemoryInfo {
   /// ```
   #[frb(sync)]
   pub fn allocation_device(&self) -> AllocationDevice {
-    AllocationDevice::from_str(self.ptr.tensor.memory_info().allocation_device().as_str())
+    AllocationDevice::from_str(&self.allocation_device)
   }
 
   /// Returns the ID of the [`AllocationDevice`] described by this struct.
@@ -237,19 +283,80 @@ impl MemoryInfo {
   /// ```
   #[frb(sync)]
   pub fn device_id(&self) -> i32 {
-    self.ptr.tensor.memory_info().device_id()
+    self.device_id
   }
 
   /// Returns the type of device (CPU/GPU) this memory is allocated on.
   #[frb(sync)]
   pub fn device_type(&self) -> DeviceType {
-    self.ptr.tensor.memory_info().device_type()
+    self.device_type
   }
 
   /// Returns `true` if this memory is accessible by the CPU; meaning that, if a value were allocated on this device,
   /// it could be extracted to an `ndarray` or slice.
   #[frb(sync)]
   pub fn is_cpu_accessible(&self) -> bool {
-    self.ptr.tensor.memory_info().is_cpu_accessible()
+    self.device_type == DeviceType::CPU
+  }
+}
+
+/// Allocates memory on the device described by its [`MemoryInfo`], most notably for the data of tensors created with
+/// [`TensorImpl::new_with_allocator`].
+///
+/// Allocators for devices other than the CPU are obtained from a session with [`SessionImpl::allocator`], as only a
+/// session knows about the devices of its execution providers. Tensors keep the allocator their data came from alive.
+///
+/// An allocator obtained from a session, and every tensor allocated with it, keeps that session's memory alive. A
+/// suspended session only releases its memory once they're dropped too, see [`SessionImpl::suspend`].
+///
+/// [`TensorImpl::new_with_allocator`]: crate::api::tensor::TensorImpl::new_with_allocator
+/// [`SessionImpl::allocator`]: crate::api::session::SessionImpl::allocator
+pub struct Allocator {
+  // `ort` allocators can't be shared between threads, so every use is serialized
+  inner: Arc<Mutex<ort::memory::Allocator>>,
+  /// What the allocator keeps alive besides itself, i.e. the memory budget charge of the session it came from.
+  pinned: Option<Arc<dyn Any + Send + Sync>>,
+}
+
+impl Allocator {
+  #[frb(ignore)]
+  pub(crate) fn from_ort(allocator: ort::memory::Allocator) -> Self {
+    Self {
+      inner: Arc::new(Mutex::new(allocator)),
+      pinned: None,
+    }
+  }
+
+  /// Keeps `pinned` alive for as long as the allocator or any tensor it allocated exists.
+  #[frb(ignore)]
+  pub(crate) fn pinning(mut self, pinned: Arc<dyn Any + Send + Sync>) -> Self {
+    self.pinned = Some(pinned);
+    self
+  }
+
+  /// Returns the default CPU allocator, which isn't tied to any session.
+  #[frb(sync)]
+  pub fn cpu() -> Allocator {
+    Allocator::from_ort(ort::memory::Allocator::default())
+  }
+
+  #[frb(ignore)]
+  pub(crate) fn lock(&self) -> MutexGuard<'_, ort::memory::Allocator> {
+    self.inner.lock().unwrap_or_else(|e| e.into_inner())
+  }
+
+  /// A handle that keeps the allocator alive, for values holding memory it allocated.
+  #[frb(ignore)]
+  pub(crate) fn keep_alive(&self) -> Arc<dyn Any + Send + Sync> {
+    match &self.pinned {
+      Some(pinned) => Arc::new((self.inner.clone(), pinned.clone())),
+      None => self.inner.clone(),
+    }
+  }
+
+  /// Describes the memory this allocator allocates.
+  #[frb(sync)]
+  pub fn memory_info(&self) -> MemoryInfo {
+    MemoryInfo::from_ort(&self.lock().memory_info())
   }
 }
@@ -0,0 +1,407 @@
+use std::alloc::{alloc, dealloc, Layout};
+use std::ffi::{c_char, c_void, CStr};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use flutter_rust_bridge::frb;
+use ort::{AsPointer, Error, Result};
+use crate::api::memory::{env_cpu_allocator, EnvCpuAllocator};
+
+/// Statistics ONNX Runtime keeps for one of a session's allocators, see [`SessionImpl::arena_stats`]. Statistics
+/// ONNX Runtime doesn't report for an allocator are 0.
+///
+/// [`SessionImpl::arena_stats`]: crate::api::session::SessionImpl::arena_stats
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[frb(dart_metadata=("freezed"))]
+pub struct ArenaStats {
+  /// The most bytes the arena may take from the system.
+  pub limit_bytes: u64,
+  /// Bytes handed out by the arena and not yet freed.
+  pub bytes_in_use: u64,
+  /// The most bytes that have been in use at once.
+  pub peak_bytes_in_use: u64,
+  /// Bytes the arena has taken from the system, whether they're in use or not.
+  pub total_allocated_bytes: u64,
+  pub num_allocs: u64,
+  /// Allocations made through the allocator's `Reserve` function, which bypass the arena's chunks.
+  pub num_reserves: u64,
+  /// How often the arena has grown.
+  pub num_arena_extensions: u64,
+  /// How often the arena has been shrunk, see [`trim_memory`](crate::api::memory::trim::trim_memory).
+  pub num_arena_shrinkages: u64,
+  /// The size of the largest single allocation.
+  pub largest_alloc_bytes: u64,
+}
+
+impl ArenaStats {
+  /// Reads the statistics from the keys and values `AllocatorGetStats` reports.
+  fn from_pairs<'a>(pairs: impl IntoIterator<Item = (&'a str, &'a str)>) -> ArenaStats {
+    let mut stats = ArenaStats::default();
+    for (key, value) in pairs {
+      let field = match key {
+        "Limit" => &mut stats.limit_bytes,
+        "InUse" => &mut stats.bytes_in_use,
+        "MaxInUse" => &mut stats.peak_bytes_in_use,
+        "TotalAllocated" => &mut stats.total_allocated_bytes,
+        "NumAllocs" => &mut stats.num_allocs,
+        "NumReserves" => &mut stats.num_reserves,
+        "NumArenaExtensions" => &mut stats.num_arena_extensions,
+        "NumArenaShrinkages" => &mut stats.num_arena_shrinkages,
+        "MaxAllocSize" => &mut stats.largest_alloc_bytes,
+        _ => continue,
+      };
+      *field = value.parse().unwrap_or_default();
+    }
+    stats
+  }
+}
+
+type AllocatorGetStats = unsafe extern "system" fn(
+  *const ort::sys::OrtAllocator,
+  *mut *mut ort::sys::OrtKeyValuePairs,
+) -> ort::sys::OrtStatusPtr;
+
+/// The function table of C API version 23, which starts with every function of the version `ort` is built for
+/// (22) and adds `AllocatorGetStats` right after `GetTensorSizeInBytes`.
+#[repr(C)]
+struct OrtApi23 {
+  base: ort::sys::OrtApi,
+  get_tensor_size_in_bytes: *const c_void,
+  allocator_get_stats: AllocatorGetStats,
+}
+
+/// `AllocatorGetStats`, if the ONNX Runtime library in use is version 1.23 or newer.
+fn allocator_get_stats() -> Option<AllocatorGetStats> {
+  static GET_STATS: OnceLock<Option<AllocatorGetStats>> = OnceLock::new();
+  *GET_STATS.get_or_init(|| unsafe {
+    let base = ort::sys::OrtGetApiBase();
+    if base.is_null() {
+      return None;
+    }
+    // Older libraries return null for API versions they don't know
+    let api = ((*base).GetApi)(23).cast::<OrtApi23>();
+    api.as_ref().map(|api| api.allocator_get_stats)
+  })
+}
+
+/// Returns the statistics ONNX Runtime keeps for `allocator`, which for a session's allocator are those of its arena.
+pub(crate) fn arena_stats(allocator: &ort::memory::Allocator) -> Result<ArenaStats> {
+  let get_stats = allocator_get_stats()
+    .ok_or_else(|| Error::new("Arena statistics require ONNX Runtime 1.23 or newer"))?;
+  let mut pairs: *mut ort::sys::OrtKeyValuePairs = std::ptr::null_mut();
+  unsafe {
+    ort::error::status_to_result(get_stats(allocator.ptr(), &mut pairs))?;
+  }
+  if pairs.is_null() {
+    return Ok(ArenaStats::default());
+  }
+
+  let mut keys: *const *const c_char = std::ptr::null();
+  let mut values: *const *const c_char = std::ptr::null();
+  let mut len = 0;
+  let entries: Vec<(String, String)> = unsafe {
+    (ort::api().GetKeyValuePairs)(pairs, &mut keys, &mut values, &mut len);
+    let entries = (0..len)
+      .map(|i| {
+        let text = |strings: *const *const c_char| CStr::from_ptr(*strings.add(i)).to_string_lossy().into_owned();
+        (text(keys), text(values))
+      })
+      .collect();
+    (ort::api().ReleaseKeyValuePairs)(pairs);
+    entries
+  };
+  Ok(ArenaStats::from_pairs(entries.iter().map(|(key, value)| (key.as_str(), value.as_str()))))
+}
+
+/// Usage statistics of the tracked allocator, see [`allocator_stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[frb(dart_metadata=("freezed"))]
+pub struct AllocatorStats {
+  /// Bytes currently allocated and not yet freed.
+  pub bytes_in_use: u64,
+  /// The most bytes that have been in use at once.
+  pub peak_bytes_in_use: u64,
+  /// Bytes allocated so far, including those that have been freed again.
+  pub total_allocated_bytes: u64,
+  pub num_allocs: u64,
+  pub num_frees: u64,
+  /// The size of the largest single allocation.
+  pub largest_block: u64,
+  /// Bytes of `bytes_in_use` that ONNX Runtime requested through the allocator's `Reserve` function rather than
+  /// `Alloc`. What it reserves that way is up to ONNX Runtime, so this is no measure of a session's fixed footprint.
+  pub reserved_bytes: u64,
+}
+
+/// Memory used by a single run of a session whose memory is tracked, see [`SessionImpl::last_run_memory`].
+///
+/// [`SessionImpl::last_run_memory`]: crate::api::session::SessionImpl::last_run_memory
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[frb(dart_metadata=("freezed"))]
+pub struct RunMemoryUsage {
+  /// The most bytes in use at once by the tracked allocator during the run, including memory held by other sessions.
+  pub peak_bytes_in_use: u64,
+  /// How far the bytes in use rose above what was in use when the run started, i.e. roughly what the run itself
+  /// needed.
+  pub peak_increase_bytes: u64,
+}
+
+static BYTES_IN_USE: AtomicU64 = AtomicU64::new(0);
+static PEAK_BYTES_IN_USE: AtomicU64 = AtomicU64::new(0);
+static TOTAL_ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+static NUM_ALLOCS: AtomicU64 = AtomicU64::new(0);
+static NUM_FREES: AtomicU64 = AtomicU64::new(0);
+static LARGEST_BLOCK: AtomicU64 = AtomicU64::new(0);
+static RESERVED_BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// The peaks of the runs currently in progress, so that each sees every allocation made while it runs.
+static RUN_PEAKS: Mutex<Vec<Arc<AtomicU64>>> = Mutex::new(Vec::new());
+static ACTIVE_RUNS: AtomicUsize = AtomicUsize::new(0);
+static TRACKING_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// A debug mode that routes the CPU memory of every session created from now on through a shared allocator that
+/// keeps the statistics returned by [`allocator_stats`], and records the peak memory use of each of their runs.
+///
+/// It changes the allocation behavior it measures, so prefer [`SessionImpl::arena_stats`], which reports what a
+/// session's own arena holds without replacing it, and only turn this on to see every allocation of a run.
+///
+/// ONNX Runtime only accepts custom allocators for the whole environment, so the statistics are process-wide: they
+/// add up every session that uses the tracked allocator, and can't be told apart per session.
+///
+/// The tracked allocator also replaces the CPU memory arena of each of these sessions. Every allocation of a run then
+/// goes to the system allocator, memory freed is returned rather than kept for the next run, and every allocation
+/// during a run takes a lock to record its peak, which makes runs slower. Sessions created before this call, or with
+/// [`SessionBuilderOptions::env_allocators`] set to `false`, keep their arenas and aren't tracked.
+///
+/// Fails if an arena was configured with [`configure_env_arena`], as both replace the environment's CPU allocator.
+///
+/// [`SessionBuilderOptions::env_allocators`]: crate::api::session::SessionBuilderOptions::env_allocators
+/// [`configure_env_arena`]: crate::api::memory::arena::configure_env_arena
+/// [`SessionImpl::arena_stats`]: crate::api::session::SessionImpl::arena_stats
+#[frb(sync)]
+pub fn enable_allocator_tracking() -> Result<()> {
+  register_tracking_allocator()?;
+  TRACKING_ENABLED.store(true, Ordering::Relaxed);
+  Ok(())
+}
+
+/// If sessions created now have their memory tracked, see [`enable_allocator_tracking`].
+#[frb(sync)]
+pub fn is_allocator_tracking_enabled() -> bool {
+  TRACKING_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Returns the usage statistics of the tracked allocator, which serves every session created after
+/// [`enable_allocator_tracking`]. All zeros if tracking was never enabled.
+///
+/// Only memory ONNX Runtime allocates on the CPU for those sessions is counted: their initializers, intermediate values
+/// and outputs, and tensors created through their allocators.
+#[frb(sync)]
+pub fn allocator_stats() -> AllocatorStats {
+  AllocatorStats {
+    bytes_in_use: BYTES_IN_USE.load(Ordering::Relaxed),
+    peak_bytes_in_use: PEAK_BYTES_IN_USE.load(Ordering::Relaxed),
+    total_allocated_bytes: TOTAL_ALLOCATED_BYTES.load(Ordering::Relaxed),
+    num_allocs: NUM_ALLOCS.load(Ordering::Relaxed),
+    num_frees: NUM_FREES.load(Ordering::Relaxed),
+    largest_block: LARGEST_BLOCK.load(Ordering::Relaxed),
+    reserved_bytes: RESERVED_BYTES.load(Ordering::Relaxed),
+  }
+}
+
+/// Resets [`AllocatorStats::peak_bytes_in_use`] to the bytes currently in use, e.g. before a phase of interest.
+#[frb(sync)]
+pub fn reset_peak_allocator_stats() {
+  PEAK_BYTES_IN_USE.store(BYTES_IN_USE.load(Ordering::Relaxed), Ordering::Relaxed);
+}
+
+/// Every block starts with a header holding its size and whether it was reserved, padded so that the memory handed
+/// out keeps the alignment ONNX Runtime expects.
+const HEADER: usize = 64;
+
+fn layout(size: usize) -> Option<Layout> {
+  Layout::from_size_align(size.checked_add(HEADER)?, HEADER).ok()
+}
+
+fn allocate(size: usize, reserved: bool) -> *mut c_void {
+  let Some(layout) = layout(size) else {
+    return std::ptr::null_mut();
+  };
+  let block = unsafe { alloc(layout) };
+  if block.is_null() {
+    return std::ptr::null_mut();
+  }
+  unsafe {
+    block.cast::<usize>().write(size);
+    block.add(size_of::<usize>()).write(reserved as u8);
+  }
+
+  let size = size as u64;
+  let in_use = BYTES_IN_USE.fetch_add(size, Ordering::Relaxed) + size;
+  PEAK_BYTES_IN_USE.fetch_max(in_use, Ordering::Relaxed);
+  TOTAL_ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+  NUM_ALLOCS.fetch_add(1, Ordering::Relaxed);
+  LARGEST_BLOCK.fetch_max(size, Ordering::Relaxed);
+  if reserved {
+    RESERVED_BYTES.fetch_add(size, Ordering::Relaxed);
+  }
+  if ACTIVE_RUNS.load(Ordering::Relaxed) > 0 {
+    for peak in RUN_PEAKS.lock().unwrap_or_else(|e| e.into_inner()).iter() {
+      peak.fetch_max(in_use, Ordering::Relaxed);
+    }
+  }
+
+  unsafe { block.add(HEADER).cast() }
+}
+
+unsafe extern "system" fn alloc_fn(_this: *mut ort::sys::OrtAllocator, size: usize) -> *mut c_void {
+  allocate(size, false)
+}
+
+unsafe extern "system" fn reserve_fn(_this: *const ort::sys::OrtAllocator, size: usize) -> *mut c_void {
+  allocate(size, true)
+}
+
+unsafe extern "system" fn free_fn(_this: *mut ort::sys::OrtAllocator, p: *mut c_void) {
+  if p.is_null() {
+    return;
+  }
+  let block = p.cast::<u8>().sub(HEADER);
+  let size = block.cast::<usize>().read();
+  let reserved = block.add(size_of::<usize>()).read() != 0;
+  // The layout was valid when the block was allocated
+  dealloc(block, layout(size).unwrap_or_else(|| unreachable!()));
+
+  BYTES_IN_USE.fetch_sub(size as u64, Ordering::Relaxed);
+  NUM_FREES.fetch_add(1, Ordering::Relaxed);
+  if reserved {
+    RESERVED_BYTES.fetch_sub(size as u64, Ordering::Relaxed);
+  }
+}
+
+unsafe extern "system" fn info_fn(this: *const ort::sys::OrtAllocator) -> *const ort::sys::OrtMemoryInfo {
+  (*this.cast::<TrackingAllocator>()).memory_info
+}
+
+/// A CPU allocator that counts what it allocates. The `OrtAllocator` must come first, so that ONNX Runtime can call
+/// it through a pointer to the whole struct.
+#[repr(C)]
+struct TrackingAllocator {
+  base: ort::sys::OrtAllocator,
+  memory_info: *const ort::sys::OrtMemoryInfo,
+}
+
+/// Registers the tracked allocator with the ONNX Runtime environment, where sessions that use the environment's
+/// allocators share it. Only the first call registers it.
+fn register_tracking_allocator() -> Result<()> {
+  let mut registered = env_cpu_allocator();
+  match *registered {
+    Some(EnvCpuAllocator::Tracking) => return Ok(()),
+    Some(EnvCpuAllocator::Arena) => {
+      return Err(Error::new("Allocator tracking can't be enabled once an environment arena is configured"));
+    },
+    None => {},
+  }
+
+  let mut memory_info: *mut ort::sys::OrtMemoryInfo = std::ptr::null_mut();
+  unsafe {
+    ort::error::status_to_result((ort::api().CreateCpuMemoryInfo)(
+      ort::sys::OrtAllocatorType::OrtDeviceAllocator,
+      ort::sys::OrtMemType::OrtMemTypeDefault,
+      &mut memory_info,
+    ))
+    .map_err(|e| Error::new(format!("Failed to register the tracked allocator: {}", e)))?;
+  }
+
+  // Both live for the rest of the process, as ONNX Runtime may use the allocator until it shuts down
+  let allocator = Box::leak(Box::new(TrackingAllocator {
+    base: ort::sys::OrtAllocator {
+      version: ort::sys::ORT_API_VERSION,
+      Alloc: Some(alloc_fn),
+      Free: Some(free_fn),
+      Info: Some(info_fn),
+      Reserve: Some(reserve_fn),
+    },
+    memory_info,
+  }));
+  let environment = ort::environment::get_environment()?;
+  unsafe {
+    ort::error::status_to_result((ort::api().RegisterAllocator)(
+      environment.ptr().cast_mut(),
+      std::ptr::from_mut(allocator).cast(),
+    ))
+    .map_err(|e| Error::new(format!("Failed to register the tracked allocator: {}", e)))?;
+  }
+  *registered = Some(EnvCpuAllocator::Tracking);
+  Ok(())
+}
+
+/// Records the peak memory use of the tracked allocator while a run is in progress.
+pub(crate) struct RunWatch {
+  baseline: u64,
+  peak: Arc<AtomicU64>,
+}
+
+impl RunWatch {
+  pub(crate) fn start() -> RunWatch {
+    let baseline = BYTES_IN_USE.load(Ordering::Relaxed);
+    let peak = Arc::new(AtomicU64::new(baseline));
+    RUN_PEAKS.lock().unwrap_or_else(|e| e.into_inner()).push(peak.clone());
+    ACTIVE_RUNS.fetch_add(1, Ordering::Relaxed);
+    RunWatch { baseline, peak }
+  }
+
+  pub(crate) fn finish(self) -> RunMemoryUsage {
+    let peak = self.peak.load(Ordering::Relaxed);
+    RunMemoryUsage {
+      peak_bytes_in_use: peak,
+      peak_increase_bytes: peak.saturating_sub(self.baseline),
+    }
+  }
+}
+
+impl Drop for RunWatch {
+  fn drop(&mut self) {
+    let mut peaks = RUN_PEAKS.lock().unwrap_or_else(|e| e.into_inner());
+    peaks.retain(|peak| !Arc::ptr_eq(peak, &self.peak));
+    ACTIVE_RUNS.fetch_sub(1, Ordering::Relaxed);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::api::memory::stats::{allocator_stats, alloc_fn, free_fn, ArenaStats, RunWatch};
+
+  #[test]
+  fn arena_stats_from_pairs() {
+    let stats = ArenaStats::from_pairs([
+      ("Limit", "1048576"),
+      ("InUse", "4096"),
+      ("MaxInUse", "8192"),
+      ("NumAllocs", "3"),
+      ("MaxAllocSize", "4096"),
+      ("Unknown", "1"),
+    ]);
+    assert_eq!(stats.limit_bytes, 1048576);
+    assert_eq!((stats.bytes_in_use, stats.peak_bytes_in_use), (4096, 8192));
+    assert_eq!((stats.num_allocs, stats.largest_alloc_bytes), (3, 4096));
+    // Keys that aren't reported stay 0
+    assert_eq!(stats.num_arena_extensions, 0);
+  }
+
+  #[test]
+  fn tracking_allocator_counts_blocks() {
+    let before = allocator_stats();
+    let watch = RunWatch::start();
+
+    let block = unsafe { alloc_fn(std::ptr::null_mut(), 1000) };
+    assert!(!block.is_null());
+    assert_eq!(block as usize % 64, 0);
+    let during = allocator_stats();
+    unsafe { free_fn(std::ptr::null_mut(), block) };
+
+    let usage = watch.finish();
+    assert!(usage.peak_increase_bytes >= 1000);
+    assert!(during.num_allocs > before.num_allocs);
+    assert!(during.largest_block >= 1000);
+    assert!(allocator_stats().num_frees > before.num_frees);
+  }
+}
@@ -0,0 +1,114 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use flutter_rust_bridge::frb;
+use ort::Result;
+use ort::session::run_options::RunOptions;
+use crate::api::memory::budget;
+
+/// What [`trim_memory`] did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[frb(dart_metadata=("freezed"))]
+pub struct MemoryTrimReport {
+  /// The idle sessions that were suspended, releasing all of their memory, see [`SessionImpl::suspend`].
+  pub sessions_suspended: u64,
+  /// An estimate of the bytes released by suspending sessions: the size of their models, as counted against the
+  /// memory budget, plus what their own CPU arenas had allocated if ONNX Runtime reports it, see
+  /// [`SessionImpl::arena_stats`].
+  pub released_bytes: u64,
+  /// The sessions that couldn't be suspended, as they were running, don't keep their model or have live allocators,
+  /// and whose CPU memory arenas are shrunk at the end of their next run instead. Sessions without an arena, see
+  /// [`SessionBuilderOptions::cpu_memory_arena`], aren't counted.
+  ///
+  /// [`SessionBuilderOptions::cpu_memory_arena`]: crate::api::session::SessionBuilderOptions::cpu_memory_arena
+  pub sessions_scheduled: u64,
+}
+
+/// What trimming a single session did, see [`trim_memory`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Trimmed {
+  /// Suspended, releasing about this many bytes.
+  Suspended(u64),
+  /// Its arena is shrunk at the end of its next run.
+  Scheduled,
+  /// Nothing to release, e.g. as it's suspended already.
+  Untouched,
+}
+
+static TRIM_EPOCH: AtomicU64 = AtomicU64::new(0);
+
+/// Releases the memory of live sessions, e.g. when the app is told that memory is low.
+///
+/// Idle sessions that keep their model are suspended, which releases their weights and arenas right away, and they're
+/// committed again on their next run, see [`SessionImpl::suspend`]. ONNX Runtime only shrinks the arenas of the other
+/// sessions at the end of a run, so those that allocate from a CPU memory arena release what it keeps cached after
+/// their next run, keeping memory still in use, like the outputs of that run.
+///
+/// [`SessionImpl::suspend`]: crate::api::session::SessionImpl::suspend
+#[frb(sync)]
+pub fn trim_memory() -> MemoryTrimReport {
+  TRIM_EPOCH.fetch_add(1, Ordering::Relaxed);
+  let mut report = MemoryTrimReport {
+    sessions_suspended: 0,
+    released_bytes: 0,
+    sessions_scheduled: 0,
+  };
+  for candidate in budget::evictables() {
+    match candidate.trim() {
+      Trimmed::Suspended(bytes) => {
+        report.sessions_suspended += 1;
+        report.released_bytes += bytes;
+      },
+      Trimmed::Scheduled => report.sessions_scheduled += 1,
+      Trimmed::Untouched => {},
+    }
+  }
+  report
+}
+
+/// Tracks whether a live session has shrunk its arena since the last call to [`trim_memory`].
+pub(crate) struct TrimState {
+  epoch: u64,
+}
+
+impl TrimState {
+  pub(crate) fn new() -> TrimState {
+    TrimState {
+      epoch: TRIM_EPOCH.load(Ordering::Relaxed),
+    }
+  }
+
+  /// Returns the options for the next run to shrink the session's arena, if a trim was requested since the last.
+  /// ONNX Runtime fails runs that ask to shrink an arena the session doesn't have, hence `uses_arena`.
+  pub(crate) fn take_shrink(&mut self, uses_arena: bool) -> Result<Option<RunOptions>> {
+    let epoch = TRIM_EPOCH.load(Ordering::Relaxed);
+    if epoch == self.epoch {
+      return Ok(None);
+    }
+    self.epoch = epoch;
+    if !uses_arena {
+      return Ok(None);
+    }
+    let mut options = RunOptions::new()?;
+    options.add_config_entry("memory.enable_memory_arena_shrinkage", "cpu:0")?;
+    Ok(Some(options))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ort::error::Result;
+  use crate::api::memory::trim::{trim_memory, TrimState};
+
+  #[test]
+  fn trim_schedules_one_shrink_per_session() -> Result<()> {
+    let mut state = TrimState::new();
+    let mut without_arena = TrimState::new();
+    assert!(state.take_shrink(true)?.is_none());
+
+    trim_memory();
+    assert!(state.take_shrink(true)?.is_some());
+    assert!(state.take_shrink(true)?.is_none());
+    // Sessions without an arena would fail to run with the shrink option
+    assert!(without_arena.take_shrink(false)?.is_none());
+    Ok(())
+  }
+}
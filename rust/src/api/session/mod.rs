@@ -1,14 +1,23 @@
 pub mod builder;
+pub mod scheduler;
 
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
 use flutter_rust_bridge::frb;
-use ort::Result;
+use ort::{AsPointer, Result};
 use ort::session::builder::{GraphOptimizationLevel, SessionBuilder};
-use ort::session::{Session, SessionInputs};
+use ort::session::run_options::RunOptions;
+use ort::session::{Session, SessionInputs, SessionOutputs};
 use ort::value::ValueRef;
 pub use ort::value::ValueType;
 use crate::api::execution_providers::{ExecutionProvider, ExecutionProviderBase};
-use crate::api::tensor::TensorImpl;
+use crate::api::memory::{env_cpu_allocator, Allocator, EnvCpuAllocator, MemoryInfo};
+use crate::api::memory::budget::{self, ChargeKind, Evictable, MemoryCharge};
+use crate::api::memory::stats::{self, ArenaStats, RunMemoryUsage, RunWatch};
+use crate::api::memory::trim::{TrimState, Trimmed};
+use crate::api::tensor::{TensorElementType, TensorImpl};
+use crate::api::tensor::value::ValueImpl;
 
 // #[derive(Debug)]
 // #[frb(opaque)]
@@ -51,10 +60,53 @@ pub struct SessionBuilderOptions {
   pub parallel_execution: Option<bool>,
   pub optimization_level: Option<GraphOptimizationLevel>,
   pub memory_pattern: Option<bool>,
+  /// Allocates CPU memory from the allocator shared through the environment, see
+  /// [`configure_env_arena`](crate::api::memory::arena::configure_env_arena), rather than an arena of the session's
+  /// own. Defaults to `true` if allocator tracking is enabled and `false` otherwise.
+  pub env_allocators: Option<bool>,
+  /// Enables/disables the CPU memory arena of the session. Disabling it returns memory to the system as soon as it's
+  /// freed, trading some speed for a lower peak. Takes precedence over the CPU execution provider's setting.
+  pub cpu_memory_arena: Option<bool>,
+}
+
+// Ort doesn't implement the Clone trait for GraphOptimizationLevel so it is cloned below
+impl Clone for SessionBuilderOptions {
+  fn clone(&self) -> Self {
+    SessionBuilderOptions {
+      execution_providers: self.execution_providers.clone(),
+      intra_threads: self.intra_threads,
+      inter_threads: self.inter_threads,
+      parallel_execution: self.parallel_execution,
+      optimization_level: self.optimization_level.as_ref().map(|level| match level {
+        GraphOptimizationLevel::Disable => GraphOptimizationLevel::Disable,
+        GraphOptimizationLevel::Level1 => GraphOptimizationLevel::Level1,
+        GraphOptimizationLevel::Level2 => GraphOptimizationLevel::Level2,
+        GraphOptimizationLevel::Level3 => GraphOptimizationLevel::Level3,
+      }),
+      memory_pattern: self.memory_pattern,
+      env_allocators: self.env_allocators,
+      cpu_memory_arena: self.cpu_memory_arena,
+    }
+  }
 }
 
 impl SessionBuilderOptions {
+  fn uses_env_allocators(&self) -> bool {
+    self.env_allocators.unwrap_or_else(stats::is_allocator_tracking_enabled)
+  }
+
+  /// The CPU memory arena a session committed now allocates from.
+  fn cpu_arena(&self) -> CpuArena {
+    match *env_cpu_allocator() {
+      Some(EnvCpuAllocator::Arena) if self.uses_env_allocators() => CpuArena::Env,
+      Some(_) if self.uses_env_allocators() => CpuArena::None,
+      _ if self.cpu_memory_arena.unwrap_or(true) => CpuArena::Own,
+      _ => CpuArena::None,
+    }
+  }
+
   fn build_session(self) -> Result<SessionBuilder> {
+    let env_allocators = self.uses_env_allocators();
     let mut session = Session::builder()?;
 
     if let Some(execution_providers) = self.execution_providers {
@@ -87,6 +139,15 @@ impl SessionBuilderOptions {
     if let Some(memory_pattern) = self.memory_pattern {
       session = session.with_memory_pattern(memory_pattern)?;
     }
+    if env_allocators {
+      session = session.with_env_allocators()?;
+    }
+    // Set after the execution providers, as registering the CPU execution provider sets it as well
+    match self.cpu_memory_arena {
+      Some(true) => unsafe { ort::error::status_to_result((ort::api().EnableCpuMemArena)(session.ptr_mut()))? },
+      Some(false) => unsafe { ort::error::status_to_result((ort::api().DisableCpuMemArena)(session.ptr_mut()))? },
+      None => {},
+    }
 
     Ok(session)
   }
@@ -161,25 +222,175 @@ impl SessionBuilderOptions {
   //   Ok(self)
   // }
 
+  fn commit(self, model: Model<'_>) -> Result<Session> {
+    let options = self.build_session()?;
+    match model {
+      Model::Memory(model_bytes) => options
+        .commit_from_memory(model_bytes)
+        .map_err(|_| ort::Error::new("failed to commit session from memory")),
+      Model::File(model_filepath) => options
+        .commit_from_file(model_filepath)
+        .map_err(|_| ort::Error::new("failed to commit session from file")),
+    }
+  }
+
   /// Load an ONNX graph from memory and commit the session.
+  ///
+  /// The session doesn't keep `model_bytes`, so it can't be suspended, see
+  /// [`SessionBuilderOptions::commit_from_memory_suspendable`].
   pub fn commit_from_memory(self, model_bytes: &[u8]) -> Result<SessionImpl> {
-    let options = self.build_session()?;
+    SessionImpl::commit(self, Model::Memory(model_bytes))
+  }
 
-    if let Ok(inner) = options.commit_from_memory(model_bytes) {
-      Ok(SessionImpl::from_session(inner))
-    } else {
-      Err(ort::Error::new("failed to commit session from memory"))
-    }
+  /// Loads an ONNX graph from memory and commits the session, keeping `model_bytes` to commit it again after
+  /// [`SessionImpl::suspend`]. The model stays in memory for as long as the session exists.
+  pub fn commit_from_memory_suspendable(self, model_bytes: Vec<u8>) -> Result<SessionImpl> {
+    let source = ModelSource::Memory(model_bytes);
+    SessionImpl::commit(self, source.model()).map(|session| session.with_source(source))
   }
 
   /// Loads an ONNX model from a file and builds the session.
   pub fn commit_from_file(self, model_filepath: String) -> Result<SessionImpl> {
-    let options = self.build_session()?;
+    let source = ModelSource::File(model_filepath);
+    SessionImpl::commit(self, source.model()).map(|session| session.with_source(source))
+  }
+}
+
+/// The model a session is committed from.
+#[derive(Clone, Copy)]
+enum Model<'m> {
+  Memory(&'m [u8]),
+  File(&'m str),
+}
+
+impl Model<'_> {
+  /// The size of the model, which the memory budget counts as an estimate of the session's footprint.
+  fn size(self) -> u64 {
+    match self {
+      Model::Memory(model_bytes) => model_bytes.len() as u64,
+      Model::File(model_filepath) => std::fs::metadata(model_filepath).map(|m| m.len()).unwrap_or_default(),
+    }
+  }
+}
+
+/// Where the model of a suspendable session came from, so that it can be committed again after
+/// [`SessionImpl::suspend`].
+enum ModelSource {
+  Memory(Vec<u8>),
+  File(String),
+}
+
+impl ModelSource {
+  fn model(&self) -> Model<'_> {
+    match self {
+      ModelSource::Memory(model_bytes) => Model::Memory(model_bytes),
+      ModelSource::File(model_filepath) => Model::File(model_filepath),
+    }
+  }
+}
+
+/// The CPU memory arena a session allocates from, see [`SessionBuilderOptions::cpu_memory_arena`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CpuArena {
+  None,
+  Own,
+  /// The environment's, shared with other sessions, see [`SessionBuilderOptions::env_allocators`].
+  Env,
+}
+
+/// A committed session and its charge against the memory budget.
+struct Committed {
+  session: Session,
+  /// Shared with the allocators created from the session, as they keep the session's memory alive, see
+  /// [`SessionImpl::allocator`].
+  charge: Arc<MemoryCharge>,
+  arena: CpuArena,
+}
+
+impl Committed {
+  fn new(options: &SessionBuilderOptions, model: Model<'_>) -> Result<Committed> {
+    let charge = MemoryCharge::reserve(ChargeKind::Session, model.size())?;
+    Ok(Committed {
+      session: options.clone().commit(model)?,
+      charge: Arc::new(charge),
+      arena: options.cpu_arena(),
+    })
+  }
+
+  /// If allocators created from the session are still alive, in which case dropping it releases nothing.
+  fn is_pinned(&self) -> bool {
+    Arc::strong_count(&self.charge) > 1
+  }
+
+  /// An estimate of the bytes dropping the session releases: the size of its model, plus what its own CPU arena has
+  /// allocated if ONNX Runtime reports it, see [`SessionImpl::arena_stats`].
+  fn size_estimate(&self) -> u64 {
+    let arena_bytes = match self.arena {
+      CpuArena::Own => ort::memory::Allocator::new(&self.session, self.session.allocator().memory_info().clone())
+        .and_then(|allocator| stats::arena_stats(&allocator))
+        .map_or(0, |stats| stats.total_allocated_bytes),
+      CpuArena::None | CpuArena::Env => 0,
+    };
+    self.charge.bytes() + arena_bytes
+  }
+}
+
+/// Holds the committed session where the memory budget can suspend it, see
+/// [`EvictionPolicy::SuspendIdleSessions`](crate::api::memory::budget::EvictionPolicy::SuspendIdleSessions).
+struct SessionSlot {
+  committed: Mutex<Option<Committed>>,
+  last_used: AtomicU64,
+  /// If the session keeps its model, without which it can't be committed again.
+  suspendable: AtomicBool,
+  /// If the last committed session allocates from a CPU memory arena, readable while it's locked.
+  uses_arena: AtomicBool,
+}
+
+impl SessionSlot {
+  fn lock(&self) -> MutexGuard<'_, Option<Committed>> {
+    self.committed.lock().unwrap_or_else(|e| e.into_inner())
+  }
 
-    if let Ok(inner) = options.commit_from_file(model_filepath) {
-      Ok(SessionImpl::from_session(inner))
-    } else {
-      Err(ort::Error::new("failed to commit session from file"))
+  /// Stores a newly committed session.
+  fn insert<'c>(&self, slot: &'c mut Option<Committed>, committed: Committed) -> &'c mut Committed {
+    self.uses_arena.store(committed.arena != CpuArena::None, Ordering::Relaxed);
+    slot.insert(committed)
+  }
+}
+
+impl Evictable for SessionSlot {
+  fn last_used(&self) -> u64 {
+    self.last_used.load(Ordering::Relaxed)
+  }
+
+  fn try_evict(&self) -> bool {
+    if !self.suspendable.load(Ordering::Relaxed) {
+      return false;
+    }
+    // The session is locked while it runs or commits
+    match self.committed.try_lock() {
+      Ok(mut committed) if committed.as_ref().is_some_and(|c| !c.is_pinned()) => committed.take().is_some(),
+      _ => false,
+    }
+  }
+
+  fn trim(&self) -> Trimmed {
+    let scheduled = match self.uses_arena.load(Ordering::Relaxed) {
+      true => Trimmed::Scheduled,
+      false => Trimmed::Untouched,
+    };
+    // The session is locked while it runs or commits, and shrinks its arena at the end of its next run instead
+    let Ok(mut committed) = self.committed.try_lock() else {
+      return scheduled;
+    };
+    match committed.as_ref() {
+      None => Trimmed::Untouched,
+      Some(c) if !self.suspendable.load(Ordering::Relaxed) || c.is_pinned() => scheduled,
+      Some(c) => {
+        let released_bytes = c.size_estimate();
+        *committed = None;
+        Trimmed::Suspended(released_bytes)
+      },
     }
   }
 }
@@ -206,14 +417,184 @@ pub struct Output {
   // pub output_type: ValueType
 }
 
+/// What a session does when a run produces outputs containing NaN or infinite values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NonFiniteCheck {
+  /// Outputs aren't checked.
+  #[default]
+  Disabled,
+  /// Outputs with non-finite values are logged as a warning and listed by [`SessionImpl::non_finite_outputs`].
+  Warn,
+  /// The run fails with an error naming the outputs with non-finite values.
+  Error,
+}
+
+/// Runs `session`, shrinking its arena afterwards if `shrink` is given, see [`TrimState::take_shrink`].
+fn run_session<'s, 'i, 'v: 'i>(
+  session: &'s mut Session,
+  inputs: SessionInputs<'i, 'v>,
+  shrink: Option<&'s RunOptions>,
+) -> Result<SessionOutputs<'s>> {
+  match shrink {
+    Some(shrink) => session.run_with_options(inputs, shrink),
+    None => session.run(inputs),
+  }
+}
+
 pub struct SessionImpl {
-  inner: Session,
+  /// The committed session, or `None` while suspended.
+  slot: Arc<SessionSlot>,
+  options: SessionBuilderOptions,
+  /// The model to commit again after a suspend, or `None` if the session can't be suspended.
+  source: Option<ModelSource>,
+  /// The names and types of the graph's inputs and outputs, which stay available while suspended.
+  input_types: Vec<(String, ValueType)>,
+  output_types: Vec<(String, ValueType)>,
+  non_finite_check: NonFiniteCheck,
+  non_finite_outputs: Vec<String>,
+  /// If the session allocates from the tracked allocator, see [`stats::enable_allocator_tracking`].
+  track_memory: bool,
+  last_run_memory: Option<RunMemoryUsage>,
+  /// If the session's arena is due to be shrunk, see [`trim_memory`](crate::api::memory::trim::trim_memory).
+  trim: TrimState,
 }
 
 impl SessionImpl {
-  fn from_session(inner: Session) -> Self {
-    SessionImpl {
-      inner,
+  fn commit(options: SessionBuilderOptions, model: Model<'_>) -> Result<Self> {
+    let track_memory = options.uses_env_allocators() && stats::is_allocator_tracking_enabled();
+    let committed = Committed::new(&options, model)?;
+    let input_types = committed.session.inputs.iter().map(|i| (i.name.clone(), i.input_type.clone())).collect();
+    let output_types = committed.session.outputs.iter().map(|o| (o.name.clone(), o.output_type.clone())).collect();
+    let slot = Arc::new(SessionSlot {
+      uses_arena: AtomicBool::new(committed.arena != CpuArena::None),
+      committed: Mutex::new(Some(committed)),
+      last_used: AtomicU64::new(budget::next_use()),
+      suspendable: AtomicBool::new(false),
+    });
+    budget::register_evictable(&(slot.clone() as Arc<dyn Evictable>));
+    Ok(SessionImpl {
+      input_types,
+      output_types,
+      slot,
+      options,
+      source: None,
+      non_finite_check: NonFiniteCheck::Disabled,
+      non_finite_outputs: Vec::new(),
+      track_memory,
+      last_run_memory: None,
+      trim: TrimState::new(),
+    })
+  }
+
+  /// Makes the session suspendable by keeping the model it was committed from.
+  fn with_source(mut self, source: ModelSource) -> Self {
+    self.source = Some(source);
+    self.slot.suspendable.store(true, Ordering::Relaxed);
+    self
+  }
+
+  /// Drops the underlying session, and with it the memory of its weights and arena, while keeping everything needed
+  /// to commit it again. The next run commits it again first, which takes as long as committing it did originally.
+  /// Fails for sessions committed with [`SessionBuilderOptions::commit_from_memory`], which don't keep their model.
+  ///
+  /// Meant for apps that move to the background, so that they don't have to rebuild their session objects. Tensors
+  /// and allocators obtained from the session stay valid, but allocators from [`SessionImpl::allocator`] and the
+  /// tensors allocated with them keep the session's memory alive: it's only released, and its charge against the
+  /// memory budget only dropped, once they're dropped too. The memory budget suspends idle sessions without such
+  /// allocators itself with
+  /// [`EvictionPolicy::SuspendIdleSessions`](crate::api::memory::budget::EvictionPolicy::SuspendIdleSessions).
+  #[frb(sync)]
+  pub fn suspend(&mut self) -> Result<()> {
+    if self.source.is_none() {
+      return Err(ort::Error::new(
+        "Session can't be suspended as it doesn't keep its model, commit it with commit_from_memory_suspendable()",
+      ));
+    }
+    *self.slot.lock() = None;
+    Ok(())
+  }
+
+  /// Commits the session again if it's suspended, e.g. ahead of time to keep the first run after
+  /// [`SessionImpl::suspend`] fast. Fails if the model doesn't fit into the memory budget.
+  pub fn resume(&mut self) -> Result<()> {
+    let slot = self.slot.clone();
+    self.session(&mut slot.lock())?;
+    Ok(())
+  }
+
+  /// If the session was suspended and hasn't been committed again since, see [`SessionImpl::suspend`].
+  #[frb(sync)]
+  pub fn is_suspended(&self) -> bool {
+    self.slot.lock().is_none()
+  }
+
+  /// The committed session held by `committed`, committing it again first if it's suspended.
+  fn session<'c>(&mut self, committed: &'c mut Option<Committed>) -> Result<&'c mut Committed> {
+    self.slot.last_used.store(budget::next_use(), Ordering::Relaxed);
+    let session = match committed.take() {
+      Some(session) => session,
+      None => {
+        self.track_memory = self.options.uses_env_allocators() && stats::is_allocator_tracking_enabled();
+        let Some(source) = &self.source else {
+          return Err(ort::Error::new("Session can't be committed again as it doesn't keep its model"));
+        };
+        Committed::new(&self.options, source.model())?
+      },
+    };
+    Ok(self.slot.insert(committed, session))
+  }
+
+  /// The memory used by the last successful run, if the session was created after [`stats::enable_allocator_tracking`].
+  #[frb(sync)]
+  pub fn last_run_memory(&self) -> Option<RunMemoryUsage> {
+    self.last_run_memory
+  }
+
+  /// Sets whether the floating point outputs of every run are checked for NaN and infinite values. Checking reads
+  /// every output once, so it's disabled by default.
+  #[frb(sync)]
+  pub fn set_non_finite_check(&mut self, check: NonFiniteCheck) {
+    self.non_finite_check = check;
+  }
+
+  /// The names of the outputs of the last checked run that contained NaN or infinite values.
+  #[frb(sync)]
+  pub fn non_finite_outputs(&self) -> Vec<String> {
+    self.non_finite_outputs.clone()
+  }
+
+  fn check_non_finite<'a>(&mut self, outputs: impl Iterator<Item = (&'a String, &'a TensorImpl)>) -> Result<()> {
+    self.non_finite_outputs.clear();
+    if self.non_finite_check == NonFiniteCheck::Disabled {
+      return Ok(());
+    }
+
+    let mut reports = Vec::new();
+    for (name, tensor) in outputs {
+      if let Some(stats) = tensor.non_finite_stats()? {
+        reports.push(format!(
+          "'{}' ({} NaN, {} +Inf, {} -Inf)",
+          name,
+          stats.nan_count,
+          stats.positive_infinity_count,
+          stats.negative_infinity_count
+        ));
+        self.non_finite_outputs.push(name.clone());
+      }
+    }
+    if reports.is_empty() {
+      return Ok(());
+    }
+    reports.sort();
+    self.non_finite_outputs.sort();
+
+    let message = format!("Session outputs contain non-finite values: {}", reports.join(", "));
+    match self.non_finite_check {
+      NonFiniteCheck::Error => Err(ort::Error::new(message)),
+      _ => {
+        log::warn!("{}", message);
+        Ok(())
+      },
     }
   }
 
@@ -226,12 +607,11 @@ impl SessionImpl {
   /// Information about the graph's inputs.
   #[frb(sync)]
   pub fn inputs(&self) -> Vec<Input> {
-    self.inner
-      .inputs
+    self.input_types
       .iter()
-      .map(|i| Input {
-        name: i.name.clone(),
-        // input_type: i.input_type.clone(),
+      .map(|(name, _input_type)| Input {
+        name: name.clone(),
+        // input_type: input_type.clone(),
       })
       .collect()
   }
@@ -239,16 +619,53 @@ impl SessionImpl {
   /// Information about the graph's outputs.
   #[frb(sync)]
   pub fn outputs(&self) -> Vec<Output> {
-    self.inner
-      .outputs
+    self.output_types
       .iter()
-      .map(|o| Output {
-        name: o.name.clone(),
-        // output_type: o.output_type.clone(),
+      .map(|(name, _output_type)| Output {
+        name: name.clone(),
+        // output_type: output_type.clone(),
       })
       .collect()
   }
 
+  /// Creates an [`Allocator`] for the device described by `memory_info`, which one of the session's execution
+  /// providers must support. Leaving `memory_info` out creates an allocator like the one the session uses for its
+  /// outputs. Fails while the session is suspended.
+  ///
+  /// The allocator, and every tensor allocated with it, keeps the session's memory alive, even after
+  /// [`SessionImpl::suspend`].
+  #[frb(sync)]
+  pub fn allocator(&self, memory_info: Option<MemoryInfo>) -> Result<Allocator> {
+    let (allocator, charge) = self.ort_allocator(memory_info)?;
+    Ok(Allocator::from_ort(allocator).pinning(charge))
+  }
+
+  /// Returns the statistics ONNX Runtime keeps for the session's allocator for the device described by
+  /// `memory_info`, or the one it uses for its outputs if left out. With a memory arena these are the arena's, which
+  /// is shared with other sessions when it's the environment's, see [`SessionBuilderOptions::env_allocators`].
+  /// Allocators without statistics, like the CPU allocator of a session without an arena, report zeros.
+  ///
+  /// Requires ONNX Runtime 1.23 or newer, and fails while the session is suspended.
+  #[frb(sync)]
+  pub fn arena_stats(&self, memory_info: Option<MemoryInfo>) -> Result<ArenaStats> {
+    let (allocator, _) = self.ort_allocator(memory_info)?;
+    stats::arena_stats(&allocator)
+  }
+
+  /// Creates an `ort` allocator for the device described by `memory_info` from the committed session, along with the
+  /// session's budget charge, which the allocator keeps alive.
+  fn ort_allocator(&self, memory_info: Option<MemoryInfo>) -> Result<(ort::memory::Allocator, Arc<MemoryCharge>)> {
+    let committed = self.slot.lock();
+    let Some(Committed { session, charge, .. }) = &*committed else {
+      return Err(ort::Error::new("Session is suspended, call resume() first"));
+    };
+    let memory_info = match memory_info {
+      Some(memory_info) => memory_info.to_ort()?,
+      None => session.allocator().memory_info().clone(),
+    };
+    Ok((ort::memory::Allocator::new(session, memory_info)?, charge.clone()))
+  }
+
   /// Run input data through the ONNX graph, performing inference.
   ///
   /// See [`crate::inputs!`] for a convenient macro which will help you create your session inputs from `ndarray`s or
@@ -266,25 +683,171 @@ impl SessionImpl {
   /// # }
   /// ```
   pub fn run(&mut self, input_values: HashMap<String, TensorImpl>) -> Result<HashMap<String, TensorImpl>> {
+    self.run_ref(&input_values)
+  }
+
+  /// Like [`SessionImpl::run`], but borrows the inputs so that they can be run again.
+  pub(crate) fn run_ref(&mut self, input_values: &HashMap<String, TensorImpl>) -> Result<HashMap<String, TensorImpl>> {
     let inputs: HashMap<String, ValueRef> = input_values
       .iter()
       .map(|(k, v)| (k.clone(), v.tensor.view().into_dyn()))
       .collect();
     let inputs = SessionInputs::from(inputs);
 
-    if let Ok(outputs) = self.inner.run(inputs) {
-      Ok(
-        outputs
-          .iter()
-          .map(|(k, v)| (
-            k.to_string(),
-            TensorImpl::from_value_ref(v),
-          ))
-          .collect()
-      )
-    } else {
-      Err(ort::Error::new("failed to run session"))
+    // Locked for the whole run, so that the memory budget can't suspend the session meanwhile
+    let slot = self.slot.clone();
+    let mut committed = slot.lock();
+    let Committed { session, arena, .. } = self.session(&mut committed)?;
+    let shrink = self.trim.take_shrink(*arena != CpuArena::None)?;
+    let watch = self.track_memory.then(RunWatch::start);
+    let outputs: HashMap<String, TensorImpl> = run_session(session, inputs, shrink.as_ref())?
+      .iter()
+      .map(|(k, v)| Ok((
+        k.to_string(),
+        TensorImpl::from_value_ref(v).map_err(|_| ort::Error::new(format!(
+          "output '{}' is not a tensor, use `run_values` for models with sequence, map or optional outputs",
+          k
+        )))?,
+      )))
+      .collect::<Result<_>>()?;
+    self.last_run_memory = watch.map(RunWatch::finish);
+    self.check_non_finite(outputs.iter())?;
+    Ok(outputs)
+  }
+
+  /// Run input data through the ONNX graph like [`SessionImpl::run`], for models whose inputs or outputs are not
+  /// only tensors but also sequences, maps or optional values.
+  pub fn run_values(&mut self, input_values: HashMap<String, ValueImpl>) -> Result<HashMap<String, ValueImpl>> {
+    // Declared before the inputs so that it outlives them
+    let mut backings = Vec::new();
+    let mut inputs = Vec::with_capacity(input_values.len());
+    for (name, value) in input_values {
+      // Absent optional inputs are left out entirely
+      if let Some(value) = value.into_value(&mut backings)? {
+        inputs.push((name, value));
+      }
     }
+
+    let output_types: HashMap<String, ValueType> = self.output_types.iter().cloned().collect();
+
+    let slot = self.slot.clone();
+    let mut committed = slot.lock();
+    let Committed { session, arena, .. } = self.session(&mut committed)?;
+    let shrink = self.trim.take_shrink(*arena != CpuArena::None)?;
+    let watch = self.track_memory.then(RunWatch::start);
+    let outputs: HashMap<String, ValueImpl> = run_session(session, SessionInputs::from(inputs), shrink.as_ref())?
+      .iter()
+      .map(|(k, v)| Ok((k.to_string(), ValueImpl::from_value(&v, output_types.get(k))?)))
+      .collect::<Result<_>>()?;
+    self.last_run_memory = watch.map(RunWatch::finish);
+
+    let tensors = outputs.iter().filter_map(|(k, v)| match v {
+      ValueImpl::Tensor(tensor) => Some((k, tensor)),
+      ValueImpl::Optional(Some(value)) => match value.as_ref() {
+        ValueImpl::Tensor(tensor) => Some((k, tensor)),
+        _ => None,
+      },
+      _ => None,
+    });
+    self.check_non_finite(tensors)?;
+    Ok(outputs)
+  }
+
+  /// Run several independent sets of input data through the ONNX graph.
+  ///
+  /// Compatible inputs (same names, element types and dimensions after the batch dimension) are stacked along the
+  /// batch dimension so the graph only runs once, and the outputs are split back up so that every entry of the
+  /// result belongs to the entry of `batch` at the same index. If the model's inputs or outputs don't have a dynamic
+  /// batch dimension, or the inputs can't be stacked, every entry is run on its own instead.
+  pub fn run_batch(&mut self, batch: Vec<HashMap<String, TensorImpl>>) -> Result<Vec<HashMap<String, TensorImpl>>> {
+    self.run_batch_ref(&batch)
+  }
+
+  /// Like [`SessionImpl::run_batch`], but borrows the inputs so that they can be run again.
+  pub(crate) fn run_batch_ref(
+    &mut self,
+    batch: &[HashMap<String, TensorImpl>],
+  ) -> Result<Vec<HashMap<String, TensorImpl>>> {
+    let Some(batch_sizes) = self.batch_sizes(batch) else {
+      return batch.iter().map(|input_values| self.run_ref(input_values)).collect();
+    };
+    let total = batch_sizes.iter().sum::<usize>() as i64;
+
+    let stacked: Result<HashMap<String, TensorImpl>> = batch[0]
+      .keys()
+      .map(|name| {
+        let tensors: Vec<&TensorImpl> = batch.iter().map(|input_values| &input_values[name]).collect();
+        Ok((name.clone(), TensorImpl::concat_batch(&tensors)?))
+      })
+      .collect();
+    let Ok(stacked) = stacked else {
+      return batch.iter().map(|input_values| self.run_ref(input_values)).collect();
+    };
+
+    let outputs = self.run_ref(&stacked)?;
+    if outputs.values().any(|output| output.shape().first() != Some(&total)) {
+      // The outputs' batch dimension is dynamic but didn't follow the inputs', so they can't be split back up
+      return batch.iter().map(|input_values| self.run_ref(input_values)).collect();
+    }
+
+    let mut results: Vec<HashMap<String, TensorImpl>> = batch.iter().map(|_| HashMap::new()).collect();
+    for (name, output) in outputs {
+      for (result, part) in results.iter_mut().zip(output.split_batch(&batch_sizes)?) {
+        result.insert(name.clone(), part);
+      }
+    }
+    Ok(results)
+  }
+
+  /// Returns the size of the batch dimension of every entry in `batch` if they can be stacked into a single run, or
+  /// `None` if they have to be run one by one.
+  fn batch_sizes(&self, batch: &[HashMap<String, TensorImpl>]) -> Option<Vec<usize>> {
+    let first = batch.first()?;
+    if batch.len() < 2 || first.is_empty() {
+      return None;
+    }
+
+    for name in first.keys() {
+      let (_, input_type) = self.input_types.iter().find(|(input_name, _)| input_name == name)?;
+      // Only a dynamic (-1) leading dimension can take more than one entry
+      match input_type {
+        ValueType::Tensor { shape, .. } if shape.first() == Some(&-1) => {},
+        _ => return None,
+      }
+    }
+    // The outputs have to be batched too to be split back up per entry
+    let batched_outputs = self.output_types.iter().all(|(_, output_type)| {
+      matches!(output_type, ValueType::Tensor { shape, .. } if shape.first() == Some(&-1))
+    });
+    if !batched_outputs {
+      return None;
+    }
+
+    let mut sizes = Vec::with_capacity(batch.len());
+    for input_values in batch {
+      if input_values.len() != first.len() {
+        return None;
+      }
+
+      let mut size = None;
+      for (name, reference) in first {
+        let tensor = input_values.get(name)?;
+        let shape = tensor.shape();
+        let reference_shape = reference.shape();
+        if tensor.dtype() != reference.dtype()
+          || matches!(tensor.dtype(), TensorElementType::Int4 | TensorElementType::Uint4)
+          || shape.is_empty()
+          || shape[1..] != reference_shape[1..] {
+          return None;
+        }
+        if *size.get_or_insert(shape[0]) != shape[0] {
+          return None;
+        }
+      }
+      sizes.push(size? as usize);
+    }
+
+    Some(sizes)
   }
 }
 
@@ -292,8 +855,11 @@ impl SessionImpl {
 mod tests {
   use std::collections::HashMap;
   use ort::error::Result;
-  use crate::api::session::SessionImpl;
-  use crate::api::tensor::TensorImpl;
+  use crate::api::memory::trim::trim_memory;
+  use crate::api::session::{NonFiniteCheck, SessionImpl};
+  use crate::api::tensor::index::TensorElement;
+  use crate::api::tensor::{TensorElementType, TensorImpl};
+  use crate::api::tensor::value::ValueImpl;
 
   const MATMUL_MODEL: &[u8] = &[
     8, 9, 18, 0, 58, 55, 10, 17, 10, 1, 97, 10, 1, 98, 18, 1, 99, 34, 6, 77, 97, 116, 77, 117, 108,
@@ -301,6 +867,13 @@ mod tests {
     10, 1, 99, 18, 4, 10, 2, 8, 1, 66, 2, 16, 20
   ];
 
+  /// `y = x + x` for an `x` of shape `[N, 2]` with a dynamic batch dimension `N`.
+  const ADD_BATCH_MODEL: &[u8] = &[
+    8, 9, 18, 0, 58, 63, 10, 14, 10, 1, 120, 10, 1, 120, 18, 1, 121, 34, 3, 65, 100, 100, 18, 1, 114, 90, 20, 10, 1,
+    120, 18, 15, 10, 13, 8, 1, 18, 9, 10, 3, 18, 1, 78, 10, 2, 8, 2, 98, 20, 10, 1, 121, 18, 15, 10, 13, 8, 1, 18, 9,
+    10, 3, 18, 1, 78, 10, 2, 8, 2, 66, 2, 16, 20
+  ];
+
   #[test]
   fn test_run_session() -> Result<()> {
     let mut session = SessionImpl::builder().commit_from_memory(MATMUL_MODEL)?;
@@ -319,4 +892,155 @@ mod tests {
 
     Ok(())
   }
+
+  #[test]
+  fn test_run_suspended_session() -> Result<()> {
+    assert!(SessionImpl::builder().commit_from_memory(MATMUL_MODEL)?.suspend().is_err());
+
+    let mut session = SessionImpl::builder().commit_from_memory_suspendable(MATMUL_MODEL.to_vec())?;
+    session.suspend()?;
+    assert!(session.is_suspended());
+    assert_eq!(session.inputs().len(), 2);
+    assert!(session.allocator(None).is_err());
+
+    let output = session.run(HashMap::from([
+      ("a".to_string(), TensorImpl::from_array_f32(None, vec![1., 2., 3.])?),
+      ("b".to_string(), TensorImpl::from_array_f32(None, vec![1., 2., 3.])?),
+    ]))?;
+    assert!(!session.is_suspended());
+    assert_eq!(output.get("c").unwrap().tensor.try_extract_tensor::<f32>()?.1, vec![14.]);
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_trim_memory_suspends_idle_sessions() -> Result<()> {
+    let mut idle = SessionImpl::builder().commit_from_memory_suspendable(MATMUL_MODEL.to_vec())?;
+    let pinned = SessionImpl::builder().commit_from_memory_suspendable(MATMUL_MODEL.to_vec())?;
+    let allocator = pinned.allocator(None)?;
+    let mut kept = SessionImpl::builder().commit_from_memory(MATMUL_MODEL)?;
+
+    // Other tests' sessions are trimmed too
+    let report = trim_memory();
+    assert!(report.sessions_suspended >= 1);
+    assert!(report.released_bytes >= MATMUL_MODEL.len() as u64);
+    assert!(report.sessions_scheduled >= 2);
+    assert!(idle.is_suspended());
+    assert!(!pinned.is_suspended());
+    assert!(!kept.is_suspended());
+    drop(allocator);
+
+    // The sessions that weren't suspended shrink their arenas on their next run
+    for session in [&mut idle, &mut kept] {
+      let output = session.run(HashMap::from([
+        ("a".to_string(), TensorImpl::from_array_f32(None, vec![1., 2., 3.])?),
+        ("b".to_string(), TensorImpl::from_array_f32(None, vec![1., 2., 3.])?),
+      ]))?;
+      assert_eq!(output.get("c").unwrap().tensor.try_extract_tensor::<f32>()?.1, vec![14.]);
+    }
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_run_values_with_reshaped_view() -> Result<()> {
+    let mut session = SessionImpl::builder().commit_from_memory(MATMUL_MODEL)?;
+
+    let view = TensorImpl::from_array_f32(Some(vec![1, 3]), vec![1., 2., 3.])?.reshape(vec![3])?;
+    // Only the view keeps the data it points into alive now that the tensor it was created from is dropped
+    let output = session.run_values(HashMap::from([
+      ("a".to_string(), ValueImpl::Tensor(view)),
+      ("b".to_string(), ValueImpl::Tensor(TensorImpl::from_array_f32(None, vec![1., 2., 3.])?)),
+    ]))?;
+    let ValueImpl::Tensor(c) = &output["c"] else {
+      panic!("expected a tensor");
+    };
+    assert_eq!(c.extract_f32()?, vec![14.]);
+    Ok(())
+  }
+
+  #[test]
+  fn test_run_with_allocated_input() -> Result<()> {
+    let mut session = SessionImpl::builder().commit_from_memory(MATMUL_MODEL)?;
+    let allocator = session.allocator(None)?;
+    assert!(allocator.memory_info().is_cpu_accessible());
+
+    let mut tensor_a = TensorImpl::new_with_allocator(&allocator, TensorElementType::Float32, vec![3])?;
+    assert_eq!(tensor_a.extract_f32()?, vec![0., 0., 0.]);
+    tensor_a.set(vec![1], TensorElement::Float(2.))?;
+    // The tensor keeps the allocator alive
+    drop(allocator);
+
+    let output = session.run(HashMap::from([
+      ("a".to_string(), tensor_a),
+      ("b".to_string(), TensorImpl::from_array_f32(None, vec![1., 2., 3.])?),
+    ]))?;
+    assert_eq!(output["c"].extract_f32()?, vec![4.]);
+    Ok(())
+  }
+
+  #[test]
+  fn test_run_batch_sequential_fallback() -> Result<()> {
+    let mut session = SessionImpl::builder().commit_from_memory(MATMUL_MODEL)?;
+
+    let batch = vec![
+      HashMap::from([
+        ("a".to_string(), TensorImpl::from_array_f32(None, vec![1., 2., 3.])?),
+        ("b".to_string(), TensorImpl::from_array_f32(None, vec![1., 2., 3.])?),
+      ]),
+      HashMap::from([
+        ("a".to_string(), TensorImpl::from_array_f32(None, vec![1., 0., 1.])?),
+        ("b".to_string(), TensorImpl::from_array_f32(None, vec![2., 2., 2.])?),
+      ]),
+    ];
+    let outputs = session.run_batch(batch)?;
+
+    assert_eq!(outputs.len(), 2);
+    assert_eq!(outputs[0].get("c").unwrap().tensor.try_extract_tensor::<f32>()?.1, vec![14.]);
+    assert_eq!(outputs[1].get("c").unwrap().tensor.try_extract_tensor::<f32>()?.1, vec![4.]);
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_run_batch_stacked() -> Result<()> {
+    let mut session = SessionImpl::builder().commit_from_memory(ADD_BATCH_MODEL)?;
+
+    let batch = vec![
+      HashMap::from([("x".to_string(), TensorImpl::from_array_f32(Some(vec![1, 2]), vec![1., 2.])?)]),
+      HashMap::from([("x".to_string(), TensorImpl::from_array_f32(Some(vec![2, 2]), vec![3., 4., 5., 6.])?)]),
+    ];
+    assert_eq!(session.batch_sizes(&batch), Some(vec![1, 2]));
+    let outputs = session.run_batch(batch)?;
+
+    assert_eq!(outputs.len(), 2);
+    let first = outputs[0].get("y").unwrap();
+    assert_eq!(first.shape(), vec![1, 2]);
+    assert_eq!(first.tensor.try_extract_tensor::<f32>()?.1, vec![2., 4.]);
+    let second = outputs[1].get("y").unwrap();
+    assert_eq!(second.shape(), vec![2, 2]);
+    assert_eq!(second.tensor.try_extract_tensor::<f32>()?.1, vec![6., 8., 10., 12.]);
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_run_non_finite_check() -> Result<()> {
+    let mut session = SessionImpl::builder().commit_from_memory(MATMUL_MODEL)?;
+    let inputs = || -> Result<HashMap<String, TensorImpl>> {
+      Ok(HashMap::from([
+        ("a".to_string(), TensorImpl::from_array_f32(None, vec![f32::NAN, 1., 1.])?),
+        ("b".to_string(), TensorImpl::from_array_f32(None, vec![1., 1., 1.])?),
+      ]))
+    };
+
+    session.set_non_finite_check(NonFiniteCheck::Warn);
+    session.run(inputs()?)?;
+    assert_eq!(session.non_finite_outputs(), vec!["c".to_string()]);
+
+    session.set_non_finite_check(NonFiniteCheck::Error);
+    assert!(session.run(inputs()?).is_err());
+
+    Ok(())
+  }
 }
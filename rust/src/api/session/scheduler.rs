@@ -0,0 +1,246 @@
+use std::collections::HashMap;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+use flutter_rust_bridge::frb;
+use ort::{Error, Result};
+use crate::api::session::SessionImpl;
+use crate::api::tensor::TensorImpl;
+
+/// Options for a [`BatchScheduler`].
+#[derive(Default)]
+#[frb(dart_metadata=("freezed"))]
+pub struct BatchSchedulerOptions {
+  /// The most requests that are run together in one batch. Defaults to 16.
+  pub max_batch_size: Option<usize>,
+  /// How long, in milliseconds, to wait for more requests after the first request of a batch arrives. Defaults to 5.
+  pub max_delay_ms: Option<u64>,
+}
+
+struct BatchRequest<I, O> {
+  input: I,
+  response: Sender<Result<O>>,
+}
+
+/// What a [`BatchScheduler`] runs its requests with, i.e. a session.
+trait BatchRunner: Send + 'static {
+  type Input: Send + 'static;
+  type Output: Send + 'static;
+
+  /// Runs every entry of `batch` together, returning their outputs in the same order.
+  fn run_together(&mut self, batch: &[Self::Input]) -> Result<Vec<Self::Output>>;
+  /// Runs a single entry on its own.
+  fn run_alone(&mut self, input: &Self::Input) -> Result<Self::Output>;
+}
+
+impl BatchRunner for SessionImpl {
+  type Input = HashMap<String, TensorImpl>;
+  type Output = HashMap<String, TensorImpl>;
+
+  fn run_together(&mut self, batch: &[Self::Input]) -> Result<Vec<Self::Output>> {
+    self.run_batch_ref(batch)
+  }
+
+  fn run_alone(&mut self, input: &Self::Input) -> Result<Self::Output> {
+    self.run_ref(input)
+  }
+}
+
+/// Collects single requests from many callers and runs them together through [`SessionImpl::run_batch`].
+///
+/// Once the first request of a batch arrives the scheduler waits up to `max_delay_ms` for more requests, or until
+/// `max_batch_size` requests are queued, then runs them all at once and hands every caller back its own outputs. If
+/// the batch fails, its requests are run again one by one, so that a malformed request only fails its own caller.
+pub struct BatchScheduler {
+  batcher: Batcher<HashMap<String, TensorImpl>, HashMap<String, TensorImpl>>,
+}
+
+impl BatchScheduler {
+  /// Creates a scheduler that takes ownership of `session` and runs it on a background thread.
+  #[frb(sync)]
+  pub fn new(session: SessionImpl, options: BatchSchedulerOptions) -> Result<BatchScheduler> {
+    Ok(BatchScheduler {
+      batcher: Batcher::start(session, options)?,
+    })
+  }
+
+  /// Queues a single set of inputs and waits until the batch it ends up in has run.
+  pub fn run(&self, input_values: HashMap<String, TensorImpl>) -> Result<HashMap<String, TensorImpl>> {
+    self.batcher.run(input_values)
+  }
+}
+
+/// The queue and background thread of a [`BatchScheduler`].
+struct Batcher<I, O> {
+  sender: Option<Sender<BatchRequest<I, O>>>,
+  worker: Option<JoinHandle<()>>,
+}
+
+impl<I: Send + 'static, O: Send + 'static> Batcher<I, O> {
+  fn start<R: BatchRunner<Input = I, Output = O>>(runner: R, options: BatchSchedulerOptions) -> Result<Batcher<I, O>> {
+    let max_batch_size = options.max_batch_size.unwrap_or(16);
+    if max_batch_size == 0 {
+      return Err(Error::new("max_batch_size must be at least 1"));
+    }
+    let max_delay = Duration::from_millis(options.max_delay_ms.unwrap_or(5));
+
+    let (sender, receiver) = mpsc::channel();
+    let worker = std::thread::Builder::new()
+      .name("ort-batch-scheduler".to_string())
+      .spawn(move || work(runner, receiver, max_batch_size, max_delay))
+      .map_err(|e| Error::new(format!("Failed to start batch scheduler: {}", e)))?;
+
+    Ok(Batcher {
+      sender: Some(sender),
+      worker: Some(worker),
+    })
+  }
+
+  fn run(&self, input: I) -> Result<O> {
+    let (response, result) = mpsc::channel();
+    self.sender
+      .as_ref()
+      .ok_or_else(|| Error::new("Batch scheduler has been shut down"))?
+      .send(BatchRequest { input, response })
+      .map_err(|_| Error::new("Batch scheduler has been shut down"))?;
+
+    result.recv().map_err(|_| Error::new("Batch scheduler stopped before the request was run"))?
+  }
+}
+
+impl<I, O> Drop for Batcher<I, O> {
+  fn drop(&mut self) {
+    // Closing the channel stops the worker after it finishes the batch it's running
+    drop(self.sender.take());
+    if let Some(worker) = self.worker.take() {
+      let _ = worker.join();
+    }
+  }
+}
+
+fn work<R: BatchRunner>(
+  mut runner: R,
+  receiver: Receiver<BatchRequest<R::Input, R::Output>>,
+  max_batch_size: usize,
+  max_delay: Duration,
+) {
+  // Wait for the first request of every batch; this returns an error once the scheduler is dropped
+  while let Ok(first) = receiver.recv() {
+    let deadline = Instant::now() + max_delay;
+    let mut requests = vec![first];
+
+    while requests.len() < max_batch_size {
+      let timeout = deadline.saturating_duration_since(Instant::now());
+      match receiver.recv_timeout(timeout) {
+        Ok(request) => requests.push(request),
+        Err(_) => break,
+      }
+    }
+
+    let (batch, responses): (Vec<_>, Vec<_>) = requests
+      .into_iter()
+      .map(|r| (r.input, r.response))
+      .unzip();
+
+    let results: Vec<Result<R::Output>> = match runner.run_together(&batch) {
+      Ok(outputs) => outputs.into_iter().map(Ok).collect(),
+      Err(e) if batch.len() == 1 => vec![Err(e)],
+      // The batch fails as a whole if any of its requests is bad, so each is run on its own for a result of its own
+      Err(_) => batch.iter().map(|input| runner.run_alone(input)).collect(),
+    };
+    for (response, result) in responses.into_iter().zip(results) {
+      // The caller may have gone away in the meantime, which is fine
+      let _ = response.send(result);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::{Arc, Barrier, Mutex};
+  use std::time::Duration;
+  use ort::{Error, Result};
+  use crate::api::session::scheduler::{BatchRunner, BatchSchedulerOptions, Batcher};
+
+  type BatchSizes = Arc<Mutex<Vec<usize>>>;
+
+  /// Doubles its inputs and records the size of every batch it runs. Negative inputs are malformed.
+  struct Doubler {
+    batch_sizes: BatchSizes,
+  }
+
+  impl BatchRunner for Doubler {
+    type Input = i64;
+    type Output = i64;
+
+    fn run_together(&mut self, batch: &[i64]) -> Result<Vec<i64>> {
+      self.batch_sizes.lock().unwrap().push(batch.len());
+      batch.iter().map(|input| self.run_alone(input)).collect()
+    }
+
+    fn run_alone(&mut self, input: &i64) -> Result<i64> {
+      match *input {
+        input if input < 0 => Err(Error::new(format!("malformed request {}", input))),
+        input => Ok(input * 2),
+      }
+    }
+  }
+
+  fn start(max_batch_size: usize, max_delay_ms: u64) -> Result<(Arc<Batcher<i64, i64>>, BatchSizes)> {
+    let batch_sizes = Arc::new(Mutex::new(Vec::new()));
+    let batcher = Batcher::start(Doubler { batch_sizes: batch_sizes.clone() }, BatchSchedulerOptions {
+      max_batch_size: Some(max_batch_size),
+      max_delay_ms: Some(max_delay_ms),
+    })?;
+    Ok((Arc::new(batcher), batch_sizes))
+  }
+
+  /// Sends `inputs` from one thread each, all at about the same time, returning their results in the same order.
+  fn run_concurrently(batcher: &Arc<Batcher<i64, i64>>, inputs: &[i64]) -> Vec<Result<i64>> {
+    let barrier = Arc::new(Barrier::new(inputs.len()));
+    let callers: Vec<_> = inputs
+      .iter()
+      .map(|&input| {
+        let (batcher, barrier) = (batcher.clone(), barrier.clone());
+        std::thread::spawn(move || {
+          barrier.wait();
+          batcher.run(input)
+        })
+      })
+      .collect();
+    callers.into_iter().map(|caller| caller.join().unwrap()).collect()
+  }
+
+  #[test]
+  fn batches_up_to_max_batch_size() -> Result<()> {
+    // The delay is long enough for every caller to arrive, so batches are only cut by their size
+    let (batcher, batch_sizes) = start(4, 2000)?;
+    let results = run_concurrently(&batcher, &[1, 2, 3, 4, 5, 6, 7, 8]);
+    for (input, result) in (1..=8).zip(results) {
+      assert_eq!(result?, input * 2);
+    }
+    assert_eq!(*batch_sizes.lock().unwrap(), vec![4, 4]);
+    Ok(())
+  }
+
+  #[test]
+  fn runs_a_partial_batch_after_max_delay() -> Result<()> {
+    let (batcher, batch_sizes) = start(16, 10)?;
+    assert_eq!(batcher.run(21)?, 42);
+    std::thread::sleep(Duration::from_millis(50));
+    assert_eq!(batcher.run(1)?, 2);
+    assert_eq!(*batch_sizes.lock().unwrap(), vec![1, 1]);
+    Ok(())
+  }
+
+  #[test]
+  fn isolates_malformed_requests() -> Result<()> {
+    let (batcher, batch_sizes) = start(3, 2000)?;
+    let results = run_concurrently(&batcher, &[1, -1, 3]);
+    assert_eq!(results[0].as_ref().ok(), Some(&2));
+    assert!(results[1].as_ref().is_err_and(|e| e.to_string().contains("malformed request -1")));
+    assert_eq!(results[2].as_ref().ok(), Some(&6));
+    assert_eq!(*batch_sizes.lock().unwrap(), vec![3]);
+    Ok(())
+  }
+}
@@ -0,0 +1,254 @@
+use flutter_rust_bridge::frb;
+use ort::{Error, Result};
+use crate::api::tensor::ops::{float_dtype, with_numeric_type, Numeric};
+use crate::api::tensor::shape::{contiguous_strides, strided_indices};
+use crate::api::tensor::{create_tensor_from_vec, TensorElementType, TensorImpl};
+
+#[derive(Clone, Copy)]
+enum BinaryOp {
+  Add,
+  Sub,
+  Mul,
+  Div,
+  Pow,
+}
+
+impl BinaryOp {
+  fn apply<T: Numeric>(self, a: T, b: T) -> Result<T> {
+    Ok(match self {
+      BinaryOp::Add => a.add(b),
+      BinaryOp::Sub => a.sub(b),
+      BinaryOp::Mul => a.mul(b),
+      BinaryOp::Div => a.div(b).ok_or_else(|| Error::new("Integer division by zero"))?,
+      BinaryOp::Pow => a.pow(b).ok_or_else(|| Error::new(format!(
+        "{:?} to the power of {:?} overflows or has a negative exponent",
+        a,
+        b
+      )))?,
+    })
+  }
+}
+
+/// Works out the shape two tensors broadcast to under NumPy's rules: shapes are aligned at their last dimension, and
+/// every pair of dimensions must either be equal or contain a 1.
+pub(crate) fn broadcast_shape(a: &[i64], b: &[i64]) -> Result<Vec<i64>> {
+  let rank = a.len().max(b.len());
+  let dim = |shape: &[i64], i: usize| if i < rank - shape.len() { 1 } else { shape[i - (rank - shape.len())] };
+  (0..rank)
+    .map(|i| match (dim(a, i), dim(b, i)) {
+      (x, y) if x == y || y == 1 => Ok(x),
+      (1, y) => Ok(y),
+      _ => Err(Error::new(format!("Cannot broadcast shapes {:?} and {:?} together", a, b))),
+    })
+    .collect()
+}
+
+/// The flat index into a tensor of `shape` of every element of a tensor of the broadcast shape `out_shape`.
+pub(crate) fn broadcast_indices(shape: &[i64], out_shape: &[i64]) -> Vec<usize> {
+  let padding = out_shape.len() - shape.len();
+  let padded: Vec<i64> = std::iter::repeat_n(1, padding).chain(shape.iter().copied()).collect();
+  let strides: Vec<isize> = contiguous_strides(&padded)
+    .into_iter()
+    .zip(&padded)
+    .map(|(stride, &d)| if d == 1 { 0 } else { stride as isize })
+    .collect();
+  strided_indices(0, &out_shape.iter().map(|&d| d as usize).collect::<Vec<_>>(), &strides)
+}
+
+/// Converts `value` to an element of `dtype`. Fails for integer types if `value` isn't one of their values, rather than
+/// truncating or saturating it.
+fn exact_scalar<T: Numeric>(value: f64, dtype: TensorElementType) -> Result<T> {
+  let scalar = T::from_f64(value);
+  if !T::IS_FLOAT && scalar.to_f64() != value {
+    return Err(Error::new(format!(
+      "{} cannot be represented exactly as {}; use `cast` to convert the tensor to a floating point type first",
+      value,
+      dtype
+    )));
+  }
+  Ok(scalar)
+}
+
+impl TensorImpl {
+  fn binary(&self, other: &TensorImpl, op: BinaryOp) -> Result<TensorImpl> {
+    let dtype = self.dtype();
+    if other.dtype() != dtype {
+      return Err(Error::new(format!(
+        "Both tensors must have the same element type, got {} and {}; use `cast` to convert one of them",
+        dtype,
+        other.dtype()
+      )));
+    }
+    let (a_shape, b_shape) = (self.shape(), other.shape());
+    let out_shape = broadcast_shape(&a_shape, &b_shape)?;
+
+    with_numeric_type!(dtype, T => {
+      let (a, b) = (self.numeric_data::<T>()?, other.numeric_data::<T>()?);
+      let data = if a_shape == b_shape {
+        a.iter().zip(b).map(|(&a, &b)| op.apply(a, b)).collect::<Result<Vec<T>>>()?
+      } else {
+        broadcast_indices(&a_shape, &out_shape)
+          .into_iter()
+          .zip(broadcast_indices(&b_shape, &out_shape))
+          .map(|(i, j)| op.apply(a[i], b[j]))
+          .collect::<Result<Vec<T>>>()?
+      };
+      create_tensor_from_vec(Some(out_shape), data)
+    })
+  }
+
+  /// Creates a scalar tensor of the same element type as this one, failing like [`exact_scalar`].
+  fn scalar_like(&self, value: f64) -> Result<TensorImpl> {
+    let dtype = self.dtype();
+    with_numeric_type!(dtype, T => create_tensor_from_vec(Some(vec![]), vec![exact_scalar::<T>(value, dtype)?]))
+  }
+
+  /// Adds two tensors of the same numeric type element-wise, broadcasting their shapes following NumPy's rules.
+  /// Integers wrap around on overflow.
+  #[frb(sync)]
+  pub fn add(&self, other: &TensorImpl) -> Result<TensorImpl> {
+    self.binary(other, BinaryOp::Add)
+  }
+
+  /// Subtracts `other` from this tensor element-wise, broadcasting as in [`TensorImpl::add`].
+  #[frb(sync)]
+  pub fn sub(&self, other: &TensorImpl) -> Result<TensorImpl> {
+    self.binary(other, BinaryOp::Sub)
+  }
+
+  /// Multiplies two tensors element-wise, broadcasting as in [`TensorImpl::add`].
+  #[frb(sync)]
+  pub fn mul(&self, other: &TensorImpl) -> Result<TensorImpl> {
+    self.binary(other, BinaryOp::Mul)
+  }
+
+  /// Divides this tensor by `other` element-wise, broadcasting as in [`TensorImpl::add`]. Integer division rounds
+  /// towards zero and fails on division by zero.
+  #[frb(sync)]
+  pub fn div(&self, other: &TensorImpl) -> Result<TensorImpl> {
+    self.binary(other, BinaryOp::Div)
+  }
+
+  /// Raises this tensor to the power of `other` element-wise, broadcasting as in [`TensorImpl::add`]. Integer powers
+  /// are computed exactly and fail on overflow or a negative exponent.
+  #[frb(sync)]
+  pub fn pow(&self, other: &TensorImpl) -> Result<TensorImpl> {
+    self.binary(other, BinaryOp::Pow)
+  }
+
+  /// Adds `value` to every element. Integer tensors require `value` to be a whole number in the range of their type.
+  #[frb(sync)]
+  pub fn add_scalar(&self, value: f64) -> Result<TensorImpl> {
+    self.binary(&self.scalar_like(value)?, BinaryOp::Add)
+  }
+
+  /// Subtracts `value` from every element, which must fit the element type as in [`TensorImpl::add_scalar`].
+  #[frb(sync)]
+  pub fn sub_scalar(&self, value: f64) -> Result<TensorImpl> {
+    self.binary(&self.scalar_like(value)?, BinaryOp::Sub)
+  }
+
+  /// Multiplies every element by `value`, which must fit the element type as in [`TensorImpl::add_scalar`].
+  #[frb(sync)]
+  pub fn mul_scalar(&self, value: f64) -> Result<TensorImpl> {
+    self.binary(&self.scalar_like(value)?, BinaryOp::Mul)
+  }
+
+  /// Divides every element by `value`, which must fit the element type as in [`TensorImpl::add_scalar`].
+  #[frb(sync)]
+  pub fn div_scalar(&self, value: f64) -> Result<TensorImpl> {
+    self.binary(&self.scalar_like(value)?, BinaryOp::Div)
+  }
+
+  /// Raises every element to the power of `value`. Integer tensors require `value` to be a whole number in the range
+  /// of their type, and fail like [`TensorImpl::pow`].
+  #[frb(sync)]
+  pub fn pow_scalar(&self, value: f64) -> Result<TensorImpl> {
+    let dtype = self.dtype();
+    if float_dtype(dtype) != dtype {
+      return self.binary(&self.scalar_like(value)?, BinaryOp::Pow);
+    }
+    let data = self.to_f64_data()?.into_iter().map(|v| v.powf(value)).collect();
+    TensorImpl::from_f64_data(dtype, self.shape(), data)
+  }
+
+  /// Limits every element to the range `[min, max]`. Either bound may be left out, and NaN elements are kept as they
+  /// are. Integer tensors require the bounds to be whole numbers in the range of their type.
+  #[frb(sync)]
+  pub fn clamp(&self, min: Option<f64>, max: Option<f64>) -> Result<TensorImpl> {
+    if let (Some(min), Some(max)) = (min, max) {
+      if min > max {
+        return Err(Error::new(format!("The lower bound ({}) is larger than the upper bound ({})", min, max)));
+      }
+    }
+
+    let dtype = self.dtype();
+    with_numeric_type!(dtype, T => {
+      let min = min.map(|min| exact_scalar::<T>(min, dtype)).transpose()?;
+      let max = max.map(|max| exact_scalar::<T>(max, dtype)).transpose()?;
+      let data = self.numeric_data::<T>()?
+        .iter()
+        .map(|&v| match (min, max) {
+          (Some(min), _) if v < min => min,
+          (_, Some(max)) if v > max => max,
+          _ => v,
+        })
+        .collect::<Vec<T>>();
+      create_tensor_from_vec(Some(self.shape()), data)
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ort::error::Result;
+  use crate::api::tensor::TensorImpl;
+
+  #[test]
+  fn tensor_normalize_per_channel() -> Result<()> {
+    // A 1x2x2x3 NHWC image, normalized with a mean and standard deviation per channel
+    let image = TensorImpl::from_array_f32(Some(vec![1, 2, 2, 3]), vec![
+      10.0, 20.0, 30.0, 12.0, 22.0, 32.0,
+      14.0, 24.0, 34.0, 16.0, 26.0, 36.0,
+    ])?;
+    let mean = TensorImpl::from_array_f32(None, vec![10.0, 20.0, 30.0])?;
+    let std = TensorImpl::from_array_f32(Some(vec![1, 1, 1, 3]), vec![2.0, 2.0, 2.0])?;
+
+    let normalized = image.sub(&mean)?.div(&std)?;
+    assert_eq!(normalized.shape(), vec![1, 2, 2, 3]);
+    assert_eq!(normalized.extract_f32()?, vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 2.0, 2.0, 2.0, 3.0, 3.0, 3.0]);
+
+    let restored = normalized.mul_scalar(255.0)?.clamp(Some(0.0), Some(500.0))?;
+    assert_eq!(restored.extract_f32()?[9], 500.0);
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_broadcast_mismatch_and_integer_division() -> Result<()> {
+    let a = TensorImpl::from_array_i32(Some(vec![2, 3]), vec![1, 2, 3, 4, 5, 6])?;
+    let b = TensorImpl::from_array_i32(None, vec![1, 2])?;
+    assert!(a.add(&b).is_err());
+
+    assert_eq!(a.div_scalar(2.0)?.extract_i32()?, vec![0, 1, 1, 2, 2, 3]);
+    assert!(a.div_scalar(0.0).is_err());
+    // Scalars an integer type can't hold are refused rather than truncated to 0 or wrapped around
+    assert!(a.mul_scalar(0.5).is_err());
+    assert!(TensorImpl::from_array_u8(None, vec![1, 2])?.add_scalar(-1.0).is_err());
+    assert!(a.clamp(Some(0.5), None).is_err());
+    assert_eq!(a.clamp(Some(2.0), Some(4.0))?.extract_i32()?, vec![2, 2, 3, 4, 4, 4]);
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_integer_pow_is_exact() -> Result<()> {
+    // 3^39 needs 62 bits, more than the 53 bits of an f64 mantissa
+    let a = TensorImpl::from_array_i64(None, vec![3, -1, 2])?;
+    assert_eq!(a.pow_scalar(39.0)?.extract_i64()?, vec![4052555153018976267, -1, 549755813888]);
+    assert!(a.pow_scalar(64.0).is_err());
+    assert!(a.pow_scalar(-1.0).is_err());
+
+    let exponents = TensorImpl::from_array_i64(None, vec![0, 1 << 40, 2])?;
+    assert_eq!(TensorImpl::from_array_i64(None, vec![5, -1, 7])?.pow(&exponents)?.extract_i64()?, vec![1, 1, 49]);
+    Ok(())
+  }
+}
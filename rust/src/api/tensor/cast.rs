@@ -0,0 +1,215 @@
+use half::{bf16, f16};
+use flutter_rust_bridge::frb;
+use ort::{Error, Result};
+use crate::api::tensor::ops::with_numeric_type;
+use crate::api::tensor::{create_tensor_from_vec, TensorElementType, TensorImpl};
+
+/// How [`TensorImpl::cast`] handles values that the target type can't represent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CastMode {
+  /// Out of range values are clamped to the closest value of the target type, and NaN becomes 0 for integer types.
+  #[default]
+  Saturating,
+  /// The cast fails on the first out of range value, or on NaN when casting to an integer type.
+  Checked,
+}
+
+/// An element on its way from one type to another. Every integer type fits in an `i128` without loss.
+#[derive(Clone, Copy)]
+pub(crate) enum Scalar {
+  Int(i128),
+  Float(f64),
+}
+
+impl Scalar {
+  pub(crate) fn parse(s: &str) -> Result<Scalar> {
+    let s = s.trim();
+    match s {
+      "true" => Ok(Scalar::Int(1)),
+      "false" => Ok(Scalar::Int(0)),
+      _ => s.parse::<i128>()
+        .map(Scalar::Int)
+        .or_else(|_| s.parse::<f64>().map(Scalar::Float))
+        .map_err(|_| Error::new(format!("Cannot parse '{}' as a number", s))),
+    }
+  }
+}
+
+fn out_of_range(value: impl std::fmt::Display, target: TensorElementType) -> Error {
+  Error::new(format!("{} is out of range for {}", value, target))
+}
+
+pub(crate) trait Castable: Sized {
+  fn to_scalar(self) -> Scalar;
+
+  fn from_scalar(value: Scalar, mode: CastMode) -> Result<Self>;
+}
+
+macro_rules! impl_castable_int {
+  ($($t:ty),*) => {
+    $(
+      impl Castable for $t {
+        fn to_scalar(self) -> Scalar {
+          Scalar::Int(self as i128)
+        }
+
+        fn from_scalar(value: Scalar, mode: CastMode) -> Result<Self> {
+          match value {
+            Scalar::Int(i) => match <$t>::try_from(i) {
+              Ok(v) => Ok(v),
+              Err(_) if mode == CastMode::Checked => Err(out_of_range(i, <$t as ort::tensor::IntoTensorElementType>::into_tensor_element_type())),
+              Err(_) => Ok(if i < 0 { <$t>::MIN } else { <$t>::MAX }),
+            },
+            Scalar::Float(f) if f.is_nan() => match mode {
+              CastMode::Checked => Err(Error::new("Cannot cast NaN to an integer type")),
+              CastMode::Saturating => Ok(0),
+            },
+            // Truncating into an `i128` is exact for every value an integer type can hold, and saturates the rest
+            Scalar::Float(f) => match <$t>::try_from(f.trunc() as i128) {
+              Ok(v) => Ok(v),
+              Err(_) if mode == CastMode::Checked => Err(out_of_range(f, <$t as ort::tensor::IntoTensorElementType>::into_tensor_element_type())),
+              Err(_) => Ok(if f < 0.0 { <$t>::MIN } else { <$t>::MAX }),
+            },
+          }
+        }
+      }
+    )*
+  };
+}
+
+macro_rules! impl_castable_float {
+  ($($t:ty: $from_f64:expr),*) => {
+    $(
+      impl Castable for $t {
+        fn to_scalar(self) -> Scalar {
+          Scalar::Float(self.into())
+        }
+
+        fn from_scalar(value: Scalar, mode: CastMode) -> Result<Self> {
+          let f = match value {
+            Scalar::Int(i) => i as f64,
+            Scalar::Float(f) => f,
+          };
+          let v: $t = $from_f64(f);
+          if f.is_finite() && v.is_infinite() {
+            return match mode {
+              CastMode::Checked => Err(out_of_range(f, <$t as ort::tensor::IntoTensorElementType>::into_tensor_element_type())),
+              CastMode::Saturating => Ok(if f < 0.0 { <$t>::MIN } else { <$t>::MAX }),
+            };
+          }
+          Ok(v)
+        }
+      }
+    )*
+  };
+}
+
+impl_castable_int!(i8, i16, i32, i64, u8, u16, u32, u64);
+impl_castable_float!(
+  f32: |f: f64| f as f32,
+  f64: |f: f64| f,
+  f16: f16::from_f64,
+  bf16: bf16::from_f64
+);
+
+impl TensorImpl {
+  fn to_scalars(&self) -> Result<Vec<Scalar>> {
+    match self.dtype() {
+      TensorElementType::String => self.tensor.try_extract_strings()?.1.iter().map(|s| Scalar::parse(s)).collect(),
+      TensorElementType::Bool => Ok(self.extract_bool()?.into_iter().map(|b| Scalar::Int(b as i128)).collect()),
+      dtype => with_numeric_type!(dtype, T => Ok(self.numeric_data::<T>()?.iter().map(|&v| v.to_scalar()).collect())),
+    }
+  }
+
+  fn to_strings(&self) -> Result<Vec<String>> {
+    match self.dtype() {
+      TensorElementType::String => self.extract_string(),
+      TensorElementType::Bool => Ok(self.extract_bool()?.into_iter().map(|b| b.to_string()).collect()),
+      dtype => with_numeric_type!(dtype, T => Ok(self.numeric_data::<T>()?.iter().map(|v| v.to_string()).collect())),
+    }
+  }
+
+  /// Converts the tensor to another element type, creating a new tensor of the same shape.
+  ///
+  /// Any numeric type, `bool`, `float16`, `bfloat16` and `string` can be cast to any other. Floats are truncated
+  /// towards zero when cast to integers, anything non-zero (including NaN) becomes `true`, and `true`/`false` become
+  /// 1 and 0. Strings are parsed as integers or floats, also accepting `true`, `false`, `NaN` and `inf`, and numbers
+  /// are formatted in their shortest exact form.
+  ///
+  /// The 8-bit float types, `int4`/`uint4` and the complex types are not supported: casting from or to them fails.
+  ///
+  /// `mode` decides what happens to values the target type can't represent and defaults to
+  /// [`CastMode::Saturating`].
+  #[frb(sync)]
+  pub fn cast(&self, target: TensorElementType, mode: Option<CastMode>) -> Result<TensorImpl> {
+    let mode = mode.unwrap_or_default();
+    let shape = Some(self.shape());
+    match target {
+      TensorElementType::String => TensorImpl::from_array_string(shape, self.to_strings()?),
+      TensorElementType::Bool => {
+        let data = self.to_scalars()?
+          .into_iter()
+          .map(|v| match v {
+            Scalar::Int(i) => i != 0,
+            Scalar::Float(f) => f != 0.0,
+          })
+          .collect::<Vec<_>>();
+        create_tensor_from_vec(shape, data)
+      },
+      target => with_numeric_type!(target, T => {
+        let data = self.to_scalars()?
+          .into_iter()
+          .map(|v| T::from_scalar(v, mode))
+          .collect::<Result<Vec<T>>>()?;
+        create_tensor_from_vec(shape, data)
+      }),
+    }
+    .map_err(|e| Error::new(format!("Failed to cast {} tensor to {}: {}", self.dtype(), target, e)))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ort::error::Result;
+  use crate::api::tensor::cast::CastMode;
+  use crate::api::tensor::{TensorElementType, TensorImpl};
+
+  #[test]
+  fn tensor_cast_saturating_and_checked() -> Result<()> {
+    let tensor = TensorImpl::from_array_f32(None, vec![-1.5, 2.7, 300.0, f32::NAN])?;
+
+    let saturated = tensor.cast(TensorElementType::Uint8, None)?;
+    assert_eq!(saturated.extract_u8()?, vec![0, 2, 255, 0]);
+    assert!(tensor.cast(TensorElementType::Uint8, Some(CastMode::Checked)).is_err());
+
+    let half = TensorImpl::from_array_f64(None, vec![1e6])?.cast(TensorElementType::Float16, None)?;
+    assert_eq!(half.extract_f16()?, vec![65504.0]);
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_cast_string_round_trip() -> Result<()> {
+    let tensor = TensorImpl::from_array_i64(None, vec![i64::MAX, -3])?;
+
+    let strings = tensor.cast(TensorElementType::String, None)?;
+    assert_eq!(strings.extract_string()?, vec![i64::MAX.to_string(), "-3".to_string()]);
+    assert_eq!(strings.cast(TensorElementType::Int64, Some(CastMode::Checked))?.extract_i64()?, vec![i64::MAX, -3]);
+
+    let invalid = TensorImpl::from_array_string(None, vec!["1.5".to_string(), "cat".to_string()])?;
+    assert!(invalid.cast(TensorElementType::Float32, None).is_err());
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_cast_unsupported_types() -> Result<()> {
+    let tensor = TensorImpl::from_array_f32(None, vec![1.0, 2.0])?;
+    for target in [TensorElementType::Float8E4M3FN, TensorElementType::Int4, TensorElementType::Complex64] {
+      let error = tensor.cast(target, None).unwrap_err();
+      assert!(error.to_string().contains("not supported"));
+    }
+
+    let packed = TensorImpl::from_raw_data(TensorElementType::Uint4, &[2], &[0x21], false)?;
+    assert!(packed.cast(TensorElementType::Float32, None).is_err());
+    Ok(())
+  }
+}
@@ -0,0 +1,213 @@
+use flutter_rust_bridge::frb;
+use ort::{Error, Result};
+use crate::api::tensor::{TensorElementType, TensorImpl};
+
+/// How two tensors differ, see [`TensorImpl::diff`].
+#[derive(Debug, Clone, PartialEq)]
+#[frb(dart_metadata=("freezed"))]
+pub struct TensorDiff {
+  /// If every element is within tolerance and the shapes and element types match.
+  pub is_close: bool,
+  pub dtype_mismatch: bool,
+  pub shape_mismatch: bool,
+  /// The number of elements that aren't within tolerance.
+  pub mismatch_count: u64,
+  /// The largest absolute difference between two numeric elements.
+  pub max_abs_error: f64,
+  /// The largest difference between two numeric elements relative to the expected element.
+  pub max_rel_error: f64,
+  /// The index of the element with the largest absolute difference, or of the first mismatching element for
+  /// non-numeric tensors. `None` if there is no mismatch or the shapes differ.
+  pub worst_index: Option<Vec<i64>>,
+  /// The element of this tensor at `worst_index`, formatted as text.
+  pub actual: Option<String>,
+  /// The element of the expected tensor at `worst_index`, formatted as text.
+  pub expected: Option<String>,
+}
+
+impl TensorDiff {
+  /// Describes the difference in a single line, for test failure messages and logs.
+  #[frb(sync)]
+  pub fn summary(&self) -> String {
+    if self.is_close {
+      return "tensors are close".to_string();
+    }
+    let mut parts = Vec::new();
+    if self.dtype_mismatch {
+      parts.push("element types differ".to_string());
+    }
+    if self.shape_mismatch {
+      parts.push("shapes differ".to_string());
+    }
+    if self.mismatch_count > 0 {
+      parts.push(format!(
+        "{} elements differ, max abs error {}, max rel error {}",
+        self.mismatch_count,
+        self.max_abs_error,
+        self.max_rel_error
+      ));
+    }
+    if let (Some(index), Some(actual), Some(expected)) = (&self.worst_index, &self.actual, &self.expected) {
+      parts.push(format!("worst at {:?}: {} != {}", index, actual, expected));
+    }
+    parts.join("; ")
+  }
+}
+
+/// Converts a flat element index into an index per dimension.
+fn unravel(mut index: usize, shape: &[i64]) -> Vec<i64> {
+  let mut unraveled = vec![0; shape.len()];
+  for (i, &d) in shape.iter().enumerate().rev() {
+    let d = d.max(1) as usize;
+    unraveled[i] = (index % d) as i64;
+    index /= d;
+  }
+  unraveled
+}
+
+fn is_numeric(dtype: TensorElementType) -> bool {
+  matches!(
+    dtype,
+    TensorElementType::Float16
+      | TensorElementType::Bfloat16
+      | TensorElementType::Float32
+      | TensorElementType::Float64
+      | TensorElementType::Int8
+      | TensorElementType::Int16
+      | TensorElementType::Int32
+      | TensorElementType::Int64
+      | TensorElementType::Uint8
+      | TensorElementType::Uint16
+      | TensorElementType::Uint32
+      | TensorElementType::Uint64
+  )
+}
+
+impl TensorImpl {
+  /// Compares this tensor element by element with an `expected` tensor of the same shape and reports how they differ.
+  ///
+  /// Numeric elements are close if `|actual - expected| <= atol + rtol * |expected|`, as in NumPy's `allclose`;
+  /// `rtol` defaults to `1e-5` and `atol` to `1e-8`. NaN is never close to anything, and infinities are only close to
+  /// the same infinity. Numeric tensors of different types are compared by value but never reported as close.
+  /// Elements of any other type, including strings, must be exactly equal.
+  #[frb(sync)]
+  pub fn diff(&self, expected: &TensorImpl, rtol: Option<f64>, atol: Option<f64>) -> Result<TensorDiff> {
+    let (rtol, atol) = (rtol.unwrap_or(1e-5), atol.unwrap_or(1e-8));
+    let (dtype, expected_dtype) = (self.dtype(), expected.dtype());
+    let shape = self.shape();
+    let mut diff = TensorDiff {
+      is_close: false,
+      dtype_mismatch: dtype != expected_dtype,
+      shape_mismatch: shape != expected.shape(),
+      mismatch_count: 0,
+      max_abs_error: 0.0,
+      max_rel_error: 0.0,
+      worst_index: None,
+      actual: None,
+      expected: None,
+    };
+    if diff.shape_mismatch {
+      return Ok(diff);
+    }
+
+    let mut worst = None;
+    if is_numeric(dtype) && is_numeric(expected_dtype) {
+      let (actual, expected) = (self.to_f64_data()?, expected.to_f64_data()?);
+      let mut worst_error = -1.0;
+      for (i, (&a, &e)) in actual.iter().zip(&expected).enumerate() {
+        let close = a == e || (a - e).abs() <= atol + rtol * e.abs();
+        let abs_error = if a == e { 0.0 } else { (a - e).abs() };
+        let abs_error = if abs_error.is_nan() { f64::INFINITY } else { abs_error };
+        let rel_error = if abs_error == 0.0 { 0.0 } else { abs_error / e.abs() };
+        diff.max_abs_error = diff.max_abs_error.max(abs_error);
+        diff.max_rel_error = diff.max_rel_error.max(rel_error);
+        if !close {
+          diff.mismatch_count += 1;
+          if abs_error > worst_error {
+            worst_error = abs_error;
+            worst = Some((i, a.to_string(), e.to_string()));
+          }
+        }
+      }
+    } else if diff.dtype_mismatch {
+      return Ok(diff);
+    } else if dtype == TensorElementType::String {
+      let (actual, expected) = (self.extract_string()?, expected.extract_string()?);
+      for (i, (a, e)) in actual.iter().zip(&expected).enumerate() {
+        if a != e {
+          diff.mismatch_count += 1;
+          worst.get_or_insert_with(|| (i, format!("{:?}", a), format!("{:?}", e)));
+        }
+      }
+    } else if dtype == TensorElementType::Bool {
+      let (actual, expected) = (self.extract_bool()?, expected.extract_bool()?);
+      for (i, (a, e)) in actual.iter().zip(&expected).enumerate() {
+        if a != e {
+          diff.mismatch_count += 1;
+          worst.get_or_insert_with(|| (i, a.to_string(), e.to_string()));
+        }
+      }
+    } else {
+      // Other fixed-width types are compared by their raw bytes, which only tells whether they differ at all
+      let (actual, expected) = (self.raw_data()?, expected.raw_data()?);
+      if actual.len() != expected.len() {
+        return Err(Error::new("Tensors of the same type and shape have different data sizes"));
+      }
+      diff.mismatch_count = (actual != expected) as u64;
+    }
+
+    if let Some((index, actual, expected)) = worst {
+      diff.worst_index = Some(unravel(index, &shape));
+      diff.actual = Some(actual);
+      diff.expected = Some(expected);
+    }
+    diff.is_close = diff.mismatch_count == 0 && !diff.dtype_mismatch;
+    Ok(diff)
+  }
+
+  /// If this tensor has the same type and shape as `expected` and every element is close to it, see
+  /// [`TensorImpl::diff`].
+  #[frb(sync)]
+  pub fn allclose(&self, expected: &TensorImpl, rtol: Option<f64>, atol: Option<f64>) -> Result<bool> {
+    Ok(self.diff(expected, rtol, atol)?.is_close)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ort::error::Result;
+  use crate::api::tensor::TensorImpl;
+
+  #[test]
+  fn tensor_diff_reports_worst_element() -> Result<()> {
+    let actual = TensorImpl::from_array_f32(Some(vec![2, 2]), vec![1.0, 2.0, 3.5, f32::NAN])?;
+    let expected = TensorImpl::from_array_f32(Some(vec![2, 2]), vec![1.0, 2.000001, 3.0, 4.0])?;
+
+    let diff = actual.diff(&expected, None, Some(1e-3))?;
+    assert!(!diff.is_close);
+    assert_eq!(diff.mismatch_count, 2);
+    assert_eq!(diff.worst_index, Some(vec![1, 1]));
+    assert_eq!(diff.actual.as_deref(), Some("NaN"));
+
+    // NaN isn't even close to itself
+    assert!(!actual.allclose(&actual.clone(), None, None)?);
+    assert!(expected.allclose(&expected.clone(), None, None)?);
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_diff_strings_and_mismatches() -> Result<()> {
+    let actual = TensorImpl::from_array_string(None, vec!["cat".to_string(), "dog".to_string()])?;
+    let expected = TensorImpl::from_array_string(None, vec!["cat".to_string(), "fox".to_string()])?;
+
+    let diff = actual.diff(&expected, None, None)?;
+    assert_eq!(diff.mismatch_count, 1);
+    assert_eq!(diff.worst_index, Some(vec![1]));
+
+    let other_shape = TensorImpl::from_array_string(Some(vec![1, 2]), vec!["cat".to_string(), "dog".to_string()])?;
+    assert!(actual.diff(&other_shape, None, None)?.shape_mismatch);
+    let ints = TensorImpl::from_array_i32(None, vec![1, 2])?;
+    assert!(ints.diff(&TensorImpl::from_array_i64(None, vec![1, 2])?, None, None)?.dtype_mismatch);
+    Ok(())
+  }
+}
@@ -0,0 +1,166 @@
+use std::ffi::c_void;
+use std::sync::Arc;
+use flutter_rust_bridge::frb;
+use ort::{Error, Result};
+use crate::api::tensor::{element_byte_len, TensorElementType, TensorImpl};
+
+/// A buffer owned by the caller of [`TensorImpl::from_external_data`], handed back through its release function once
+/// no tensor uses it anymore.
+struct ExternalBuffer {
+  ptr: usize,
+  release: Option<extern "C" fn(*mut c_void)>,
+}
+
+impl Drop for ExternalBuffer {
+  fn drop(&mut self) {
+    if let Some(release) = self.release {
+      release(self.ptr as *mut c_void);
+    }
+  }
+}
+
+impl TensorImpl {
+  /// Wraps `byte_len` bytes of memory at `ptr`, e.g. a buffer allocated from Dart with `malloc`, as a CPU tensor
+  /// without copying it.
+  ///
+  /// The data must be laid out as described in [`TensorImpl::from_bytes`] and aligned to the size of the element
+  /// type. `release` is the address of an `extern "C" fn(*mut c_void)`, such as `free` or the `nativeFree` of Dart's
+  /// `malloc`, which is called with `ptr` exactly once, when the tensor and every view of it are gone; leave it out if
+  /// the memory outlives the tensor anyway.
+  ///
+  /// Ownership passes to the tensor as soon as this is called, so `release` is also called if an error is returned.
+  /// With `immutable` set, the tensor is marked as not mutable, see [`TensorImpl::is_mutable`].
+  ///
+  /// The caller is responsible for the pointers, which can't be checked:
+  /// - `ptr` must point to `byte_len` bytes that are valid for reads, and for writes unless `immutable` is set, until
+  ///   `release` is called. Nothing else may write to them or free them meanwhile, so Dart must not free the buffer
+  ///   itself or let a finalizer free it.
+  /// - `release`, if given, must be the address of a function with that signature that may be called from any thread,
+  ///   e.g. a `Pointer.fromFunction` or `NativeCallable.listener` address, or a native function's address.
+  ///
+  /// Rust code should use [`TensorImpl::from_external_data_unchecked`], which states these requirements in its
+  /// signature.
+  #[frb(sync)]
+  pub fn from_external_data(
+    dtype: TensorElementType,
+    shape: Option<Vec<i64>>,
+    ptr: usize,
+    byte_len: usize,
+    release: Option<usize>,
+    immutable: bool,
+  ) -> Result<TensorImpl> {
+    // The caller takes on the safety requirements, as Dart can't call unsafe functions
+    unsafe { TensorImpl::from_external_data_unchecked(dtype, shape, ptr, byte_len, release, immutable) }
+  }
+
+  /// Like [`TensorImpl::from_external_data`], for callers in Rust.
+  ///
+  /// # Safety
+  ///
+  /// `ptr` must point to `byte_len` bytes that are valid for reads, and for writes unless `immutable` is set, until
+  /// the tensor and every view of it are gone, and nothing else may write to them meanwhile. `release`, if given,
+  /// must be the address of a function with the signature `extern "C" fn(*mut c_void)` that may be called with `ptr`
+  /// from any thread.
+  #[frb(ignore)]
+  pub unsafe fn from_external_data_unchecked(
+    dtype: TensorElementType,
+    shape: Option<Vec<i64>>,
+    ptr: usize,
+    byte_len: usize,
+    release: Option<usize>,
+    immutable: bool,
+  ) -> Result<TensorImpl> {
+    let buffer = ExternalBuffer {
+      ptr,
+      release: release
+        .filter(|&release| release != 0)
+        // The caller guarantees that `release` is the address of such a function
+        .map(|release| unsafe { std::mem::transmute::<usize, extern "C" fn(*mut c_void)>(release) }),
+    };
+    if ptr == 0 {
+      return Err(Error::new("Cannot create a tensor from a null pointer"));
+    }
+    let alignment = element_byte_len(dtype, 1).unwrap_or(1);
+    if !ptr.is_multiple_of(alignment) {
+      return Err(Error::new(format!("Data for {} tensors must be aligned to {} bytes", dtype, alignment)));
+    }
+
+    let shape = TensorImpl::shape_for_byte_len(dtype, shape, byte_len)?;
+    TensorImpl::check_byte_len(dtype, &shape, byte_len)?;
+    let memory_info = ort::memory::MemoryInfo::default();
+    unsafe {
+      TensorImpl::from_borrowed_data(dtype, &shape, ptr as *mut c_void, byte_len, &memory_info, Arc::new(buffer), !immutable)
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::ffi::c_void;
+  use std::sync::atomic::{AtomicUsize, Ordering};
+  use ort::error::Result;
+  use crate::api::tensor::{TensorElementType, TensorImpl};
+
+  static RELEASED: AtomicUsize = AtomicUsize::new(0);
+  static RELEASE_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+  extern "C" fn release(ptr: *mut c_void) {
+    RELEASED.store(ptr as usize, Ordering::SeqCst);
+    RELEASE_CALLS.fetch_add(1, Ordering::SeqCst);
+    drop(unsafe { Box::from_raw(ptr as *mut [f32; 4]) });
+  }
+
+  static REJECTED_RELEASE_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+  extern "C" fn release_rejected(ptr: *mut c_void) {
+    REJECTED_RELEASE_CALLS.fetch_add(1, Ordering::SeqCst);
+    drop(unsafe { Box::from_raw(ptr as *mut [u8; 3]) });
+  }
+
+  #[test]
+  fn tensor_from_external_data_releases_buffer() -> Result<()> {
+    let ptr = Box::into_raw(Box::new([1.0f32, 2.0, 3.0, 4.0])) as usize;
+
+    // The buffer stays valid until `release` frees it
+    let tensor = TensorImpl::from_external_data(
+      TensorElementType::Float32,
+      Some(vec![2, -1]),
+      ptr,
+      16,
+      Some(release as extern "C" fn(*mut c_void) as usize),
+      true,
+    )?;
+    assert_eq!(tensor.shape(), vec![2, 2]);
+    assert!(!tensor.is_mutable());
+    assert_eq!(tensor.data_view()?.ptr(), ptr);
+
+    // Views keep the buffer alive after the tensor itself is gone
+    let view = tensor.reshape(vec![4])?;
+    let other_view = view.reshape(vec![1, 4])?;
+    drop(tensor);
+    drop(view);
+    assert_eq!(RELEASE_CALLS.load(Ordering::SeqCst), 0);
+    assert_eq!(other_view.extract_f32()?, vec![1.0, 2.0, 3.0, 4.0]);
+    drop(other_view);
+    assert_eq!(RELEASED.load(Ordering::SeqCst), ptr);
+    assert_eq!(RELEASE_CALLS.load(Ordering::SeqCst), 1);
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_from_external_data_releases_rejected_buffer() {
+    let ptr = Box::into_raw(Box::new([0u8; 3])) as usize;
+
+    // 3 bytes can't hold a whole number of 16-bit elements
+    let result = TensorImpl::from_external_data(
+      TensorElementType::Uint16,
+      None,
+      ptr,
+      3,
+      Some(release_rejected as extern "C" fn(*mut c_void) as usize),
+      false,
+    );
+    assert!(result.is_err());
+    assert_eq!(REJECTED_RELEASE_CALLS.load(Ordering::SeqCst), 1);
+  }
+}
@@ -0,0 +1,269 @@
+use std::ffi::CString;
+use flutter_rust_bridge::frb;
+use ort::{AsPointer, Error, Result};
+use crate::api::tensor::cast::{CastMode, Castable, Scalar};
+use crate::api::tensor::ops::with_numeric_type;
+use crate::api::tensor::shape::contiguous_strides;
+use crate::api::tensor::{TensorElementType, TensorImpl};
+
+/// A single element of a tensor, see [`TensorImpl::get`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum TensorElement {
+  Bool(bool),
+  /// An element of a signed integer type.
+  Int(i64),
+  /// An element of an unsigned integer type.
+  Uint(u64),
+  /// An element of a floating point type, including `float16` and `bfloat16`.
+  Float(f64),
+  String(String),
+}
+
+impl TensorElement {
+  fn to_scalar(&self) -> Result<Scalar> {
+    Ok(match self {
+      TensorElement::Bool(b) => Scalar::Int(*b as i128),
+      TensorElement::Int(i) => Scalar::Int(*i as i128),
+      TensorElement::Uint(u) => Scalar::Int(*u as i128),
+      TensorElement::Float(f) => Scalar::Float(*f),
+      TensorElement::String(s) => Scalar::parse(s)?,
+    })
+  }
+
+  fn from_scalar(value: Scalar, dtype: TensorElementType) -> TensorElement {
+    match value {
+      Scalar::Float(f) => TensorElement::Float(f),
+      Scalar::Int(i) => match dtype {
+        TensorElementType::Uint8 | TensorElementType::Uint16 | TensorElementType::Uint32 | TensorElementType::Uint64 => {
+          TensorElement::Uint(i as u64)
+        },
+        _ => TensorElement::Int(i as i64),
+      },
+    }
+  }
+}
+
+/// Resolves a multi-dimensional index into a strided view of a tensor's data to the flat index of the element. Negative
+/// entries count from the end of their dimension.
+fn flat_index(index: &[i64], offset: isize, shape: &[usize], strides: &[isize]) -> Result<usize> {
+  if index.len() != shape.len() {
+    return Err(Error::new(format!("Expected an index with {} entries for shape {:?}, got {:?}", shape.len(), shape, index)));
+  }
+  let mut flat = offset;
+  for ((&i, &dim), &stride) in index.iter().zip(shape).zip(strides) {
+    let resolved = if i < 0 { i + dim as i64 } else { i };
+    if resolved < 0 || resolved >= dim as i64 {
+      return Err(Error::new(format!("Index {:?} is out of bounds for shape {:?}", index, shape)));
+    }
+    flat += resolved as isize * stride;
+  }
+  Ok(flat as usize)
+}
+
+impl TensorImpl {
+  fn shape_and_strides(&self) -> (Vec<usize>, Vec<isize>) {
+    let shape = self.shape();
+    let strides = contiguous_strides(&shape).into_iter().map(|s| s as isize).collect();
+    (shape.into_iter().map(|d| d as usize).collect(), strides)
+  }
+
+  fn element_at(&self, flat: usize) -> Result<TensorElement> {
+    let dtype = self.dtype();
+    match dtype {
+      TensorElementType::String => {
+        // Reads only the requested string, rather than every string in the tensor
+        let mut len = 0;
+        unsafe {
+          ort::error::status_to_result((ort::api().GetStringTensorElementLength)(self.tensor.ptr(), flat, &mut len))?;
+        }
+        let mut bytes = vec![0u8; len];
+        unsafe {
+          ort::error::status_to_result((ort::api().GetStringTensorElement)(
+            self.tensor.ptr(),
+            len,
+            flat,
+            bytes.as_mut_ptr().cast(),
+          ))?;
+        }
+        let s = String::from_utf8(bytes).map_err(|e| Error::new(format!("String element is not valid UTF-8: {}", e)))?;
+        Ok(TensorElement::String(s))
+      },
+      TensorElementType::Bool => Ok(TensorElement::Bool(self.tensor.try_extract_tensor::<bool>()?.1[flat])),
+      dtype => with_numeric_type!(dtype, T => {
+        Ok(TensorElement::from_scalar(self.numeric_data::<T>()?[flat].to_scalar(), dtype))
+      }),
+    }
+  }
+
+  fn set_element_at(&mut self, flat: usize, value: TensorElement) -> Result<()> {
+    self.ensure_mutable()?;
+    let dtype = self.dtype();
+    match dtype {
+      TensorElementType::String => {
+        let s = match value {
+          TensorElement::String(s) => s,
+          TensorElement::Bool(b) => b.to_string(),
+          TensorElement::Int(i) => i.to_string(),
+          TensorElement::Uint(u) => u.to_string(),
+          TensorElement::Float(f) => f.to_string(),
+        };
+        let s = CString::new(s).map_err(|e| Error::new(format!("Failed to create CString: {}", e)))?;
+        unsafe {
+          ort::error::status_to_result((ort::api().FillStringTensorElement)(self.tensor.ptr_mut(), s.as_ptr(), flat))?;
+        }
+      },
+      TensorElementType::Bool => {
+        let b = match value.to_scalar()? {
+          Scalar::Int(i) => i != 0,
+          Scalar::Float(f) => f != 0.0,
+        };
+        self.tensor.try_extract_tensor_mut::<bool>()?.1[flat] = b;
+      },
+      dtype => with_numeric_type!(dtype, T => {
+        self.tensor.try_extract_tensor_mut::<T>()?.1[flat] = T::from_scalar(value.to_scalar()?, CastMode::Checked)?;
+        Ok(())
+      })?,
+    }
+    Ok(())
+  }
+
+  /// Reads the element at `index`, which has one entry per dimension. Negative entries count from the end of their
+  /// dimension.
+  #[frb(sync)]
+  pub fn get(&self, index: Vec<i64>) -> Result<TensorElement> {
+    let (shape, strides) = self.shape_and_strides();
+    self.element_at(flat_index(&index, 0, &shape, &strides)?)
+  }
+
+  /// Writes the element at `index`, converting `value` to the tensor's element type like [`TensorImpl::cast`] does
+  /// with [`CastMode::Checked`]. Fails if the tensor isn't mutable.
+  #[frb(sync)]
+  pub fn set(&mut self, index: Vec<i64>, value: TensorElement) -> Result<()> {
+    let (shape, strides) = self.shape_and_strides();
+    let flat = flat_index(&index, 0, &shape, &strides)?;
+    self.set_element_at(flat, value)
+  }
+
+  /// Returns a view of part of this tensor, selected like in [`TensorImpl::slice`], without copying any data.
+  ///
+  /// The view shares this tensor's data, including its strings, so writes through either one are visible in both. This
+  /// makes it cheap to read a few elements out of a large output, e.g. the first rows of a list of detections.
+  #[frb(sync)]
+  pub fn slice_view(&self, starts: Vec<i64>, ends: Vec<i64>, axes: Option<Vec<i64>>, steps: Option<Vec<i64>>) -> Result<TensorSlice> {
+    let (offset, shape, strides) = self.slice_layout(starts, ends, axes, steps)?;
+    Ok(TensorSlice {
+      source: TensorImpl {
+        tensor: self.shared_tensor(),
+        mutable: self.mutable,
+        backing: self.backing.clone(),
+      },
+      offset,
+      shape,
+      strides,
+    })
+  }
+}
+
+/// A strided view of part of a tensor's data, see [`TensorImpl::slice_view`]. It keeps the data alive on its own.
+#[derive(Debug)]
+pub struct TensorSlice {
+  source: TensorImpl,
+  /// The flat index of the first element of the view in `source`.
+  offset: isize,
+  shape: Vec<usize>,
+  /// The distance, in elements, between neighbouring elements of each dimension in `source`. Negative for dimensions
+  /// that were sliced backwards.
+  strides: Vec<isize>,
+}
+
+impl TensorSlice {
+  /// The data type of the elements.
+  #[frb(sync)]
+  pub fn dtype(&self) -> TensorElementType {
+    self.source.dtype()
+  }
+
+  /// The shape of the view.
+  #[frb(sync)]
+  pub fn shape(&self) -> Vec<i64> {
+    self.shape.iter().map(|&d| d as i64).collect()
+  }
+
+  /// The distance, in elements, between neighbouring elements of each dimension in the viewed tensor.
+  #[frb(sync)]
+  pub fn strides(&self) -> Vec<i64> {
+    self.strides.iter().map(|&s| s as i64).collect()
+  }
+
+  /// If the viewed tensor is mutable, and so elements can be written through this view.
+  #[frb(sync)]
+  pub fn is_mutable(&self) -> bool {
+    self.source.is_mutable()
+  }
+
+  /// Reads the element at `index` of the view, see [`TensorImpl::get`].
+  #[frb(sync)]
+  pub fn get(&self, index: Vec<i64>) -> Result<TensorElement> {
+    self.source.element_at(flat_index(&index, self.offset, &self.shape, &self.strides)?)
+  }
+
+  /// Writes the element at `index` of the view, and so of the viewed tensor, see [`TensorImpl::set`].
+  #[frb(sync)]
+  pub fn set(&mut self, index: Vec<i64>, value: TensorElement) -> Result<()> {
+    let flat = flat_index(&index, self.offset, &self.shape, &self.strides)?;
+    self.source.set_element_at(flat, value)
+  }
+
+  /// Copies the elements of the view into a new, contiguous tensor.
+  #[frb(sync)]
+  pub fn to_tensor(&self) -> Result<TensorImpl> {
+    self.source.gather_strided(self.offset, &self.shape, &self.strides)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ort::error::Result;
+  use crate::api::tensor::index::TensorElement;
+  use crate::api::tensor::TensorImpl;
+
+  #[test]
+  fn tensor_get_and_set() -> Result<()> {
+    let mut tensor = TensorImpl::from_array_u8(Some(vec![2, 3]), vec![0, 1, 2, 3, 4, 5])?;
+    assert_eq!(tensor.get(vec![1, -1])?, TensorElement::Uint(5));
+
+    tensor.set(vec![0, 1], TensorElement::Float(7.9))?;
+    assert_eq!(tensor.get(vec![0, 1])?, TensorElement::Uint(7));
+    assert!(tensor.set(vec![0, 0], TensorElement::Int(-1)).is_err());
+    assert!(tensor.get(vec![2, 0]).is_err());
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_slice_view_shares_data() -> Result<()> {
+    let tensor = TensorImpl::from_array_f32(Some(vec![3, 4]), (0..12).map(|v| v as f32).collect())?;
+    // The last column, bottom to top
+    let mut column = tensor.slice_view(vec![-1, 3], vec![-4, 4], None, Some(vec![-1, 1]))?;
+    assert_eq!(column.shape(), vec![3, 1]);
+    assert_eq!(column.strides(), vec![-4, 1]);
+    assert_eq!(column.get(vec![0, 0])?, TensorElement::Float(11.0));
+
+    column.set(vec![2, 0], TensorElement::Float(-3.0))?;
+    assert_eq!(tensor.get(vec![0, 3])?, TensorElement::Float(-3.0));
+    assert_eq!(column.to_tensor()?.extract_f32()?, vec![11.0, 7.0, -3.0]);
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_slice_view_of_empty_axis() -> Result<()> {
+    let tensor = TensorImpl::from_array_f32(Some(vec![0, 3]), vec![])?;
+
+    for step in [1, -1] {
+      let view = tensor.slice_view(vec![-1], vec![i64::MIN], None, Some(vec![step]))?;
+      assert_eq!(view.shape(), vec![0, 3]);
+      assert!(view.get(vec![0, 0]).is_err());
+      assert!(view.to_tensor()?.extract_f32()?.is_empty());
+    }
+    Ok(())
+  }
+}
@@ -0,0 +1,1098 @@
+pub mod arithmetic;
+pub mod cast;
+pub mod compare;
+pub mod external;
+pub mod index;
+pub mod npy;
+pub mod ops;
+pub mod proto;
+pub mod safetensors;
+pub mod shape;
+pub mod stats;
+pub mod value;
+
+use std::any::Any;
+use std::ffi::{CString, NulError};
+use std::fmt::Debug;
+use std::os::raw::c_char;
+use std::ptr::NonNull;
+use std::sync::Arc;
+use flutter_rust_bridge::frb;
+use ort::{AsPointer, Error};
+pub use ort::error::Result;
+use half::{bf16, f16};
+use ort::tensor::{IntoTensorElementType, PrimitiveTensorElementType};
+pub use ort::tensor::TensorElementType;
+pub use ort::value::{DynValue, Tensor};
+use ort::value::{DynTensor, ValueRef};
+use crate::api::memory::{Allocator, MemoryInfo};
+use crate::api::memory::budget::{ChargeKind, MemoryCharge};
+
+/// Enum mapping ONNX Runtime's supported tensor data types.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[frb(mirror(TensorElementType))]
+pub enum _TensorElementType {
+  /// 32-bit floating point number, equivalent to Rust's `f32`.
+  Float32,
+  /// Unsigned 8-bit integer, equivalent to Rust's `u8`.
+  Uint8,
+  /// Signed 8-bit integer, equivalent to Rust's `i8`.
+  Int8,
+  /// Unsigned 16-bit integer, equivalent to Rust's `u16`.
+  Uint16,
+  /// Signed 16-bit integer, equivalent to Rust's `i16`.
+  Int16,
+  /// Signed 32-bit integer, equivalent to Rust's `i32`.
+  Int32,
+  /// Signed 64-bit integer, equivalent to Rust's `i64`.
+  Int64,
+  /// String, equivalent to Rust's `String`.
+  String,
+  /// Boolean, equivalent to Rust's `bool`.
+  Bool,
+  /// 16-bit floating point number, equivalent to [`half::f16`] (with the `half` feature).
+  Float16,
+  /// 64-bit floating point number, equivalent to Rust's `f64`. Also known as `double`.
+  Float64,
+  /// Unsigned 32-bit integer, equivalent to Rust's `u32`.
+  Uint32,
+  /// Unsigned 64-bit integer, equivalent to Rust's `u64`.
+  Uint64,
+  /// Brain 16-bit floating point number, equivalent to [`half::bf16`] (with the `half` feature).
+  Bfloat16,
+  Complex64,
+  Complex128,
+  /// 8-bit floating point number with 4 exponent bits and 3 mantissa bits, with only NaN values and no infinite
+  /// values.
+  Float8E4M3FN,
+  /// 8-bit floating point number with 4 exponent bits and 3 mantissa bits, with only NaN values, no infinite
+  /// values, and no negative zero.
+  Float8E4M3FNUZ,
+  /// 8-bit floating point number with 5 exponent bits and 2 mantissa bits.
+  Float8E5M2,
+  /// 8-bit floating point number with 5 exponent bits and 2 mantissa bits, with only NaN values, no infinite
+  /// values, and no negative zero.
+  Float8E5M2FNUZ,
+  /// 4-bit unsigned integer.
+  Uint4,
+  /// 4-bit signed integer.
+  Int4,
+  Undefined
+}
+
+pub struct ArrayPointer {
+  pub ptr: usize,
+  pub len: usize,
+}
+
+/// A read-only, zero-copy view of a tensor's data.
+///
+/// The view holds its own reference to the tensor's data, so [`TensorDataView::ptr`] stays valid for as long as the
+/// view exists, even if the [`TensorImpl`] it was created from is disposed first. Disposing the view releases that
+/// reference.
+pub struct TensorDataView {
+  tensor: DynTensor,
+  /// What the data depends on, see [`TensorImpl::backing`]. Declared after `tensor` so that it's dropped last.
+  _backing: Option<Arc<dyn Any + Send + Sync>>,
+}
+
+impl TensorDataView {
+  /// The address of the first element. May be `0` for tensors without any elements.
+  #[frb(sync)]
+  pub fn ptr(&self) -> usize {
+    self.tensor.data_ptr() as usize
+  }
+
+  /// The number of elements in the view.
+  #[frb(sync)]
+  pub fn len(&self) -> usize {
+    self.tensor.shape().num_elements()
+  }
+
+  /// If the view has no elements.
+  #[frb(sync)]
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// The number of bytes the elements take up.
+  #[frb(sync)]
+  pub fn byte_len(&self) -> usize {
+    element_byte_len(*self.tensor.data_type(), self.len()).unwrap_or_default()
+  }
+
+  /// The data type of the elements.
+  #[frb(sync)]
+  pub fn dtype(&self) -> TensorElementType {
+    *self.tensor.data_type()
+  }
+
+  /// The shape of the viewed tensor.
+  #[frb(sync)]
+  pub fn shape(&self) -> Vec<i64> {
+    self.tensor.shape().to_vec()
+  }
+}
+
+/// A tensor and its data.
+///
+/// Tensors can share their data with each other:
+/// - Views created by operations such as [`TensorImpl::reshape`] read and write the data of the tensor they were
+///   created from, so changes made through one are seen by the other.
+/// - A [`TensorDataView`] shares the data of its tensor, and sees any later changes to it.
+/// - Tensors over external memory or a memory-mapped file share it with their owner.
+///
+/// Data that must not be changed, like the outputs of a session or a memory-mapped file, belongs to immutable
+/// tensors, and views of an immutable tensor are immutable themselves. Writable pointers are only handed out for
+/// mutable tensors; [`TensorImpl::make_mutable`] gives an immutable tensor its own copy of the data first. Copies,
+/// e.g. from [`TensorImpl::clone`], never share data and are always mutable.
+pub struct TensorImpl {
+  pub(crate) tensor: DynTensor,
+  mutable: bool,
+  /// Keeps alive what `tensor` depends on but doesn't own, i.e. the tensor a view was created from or the allocator
+  /// its data came from, and the charge of its data against the memory budget.
+  backing: Option<Arc<dyn Any + Send + Sync>>,
+}
+
+impl Debug for TensorImpl {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("TensorImpl")
+      .field("tensor", &self.tensor)
+      .field("mutable", &self.mutable)
+      .field("has_backing", &self.backing.is_some())
+      .finish()
+  }
+}
+
+#[frb(ignore)]
+fn create_tensor<T: IntoTensorElementType + Debug>(tensor: Tensor<T>, mutable: bool) -> Result<TensorImpl> {
+  TensorImpl {
+    tensor: tensor.upcast(),
+    mutable,
+    backing: None,
+  }
+  .reserve_budget()
+}
+
+/// Returns the number of bytes needed to store `len` elements of `dtype`, or `None` for element types that don't have
+/// a fixed width (strings).
+#[frb(ignore)]
+pub(crate) fn element_byte_len(dtype: TensorElementType, len: usize) -> Option<usize> {
+  match dtype {
+    TensorElementType::Bool
+    | TensorElementType::Int8
+    | TensorElementType::Uint8
+    | TensorElementType::Float8E4M3FN
+    | TensorElementType::Float8E4M3FNUZ
+    | TensorElementType::Float8E5M2
+    | TensorElementType::Float8E5M2FNUZ => Some(len),
+    TensorElementType::Int16
+    | TensorElementType::Uint16
+    | TensorElementType::Float16
+    | TensorElementType::Bfloat16 => Some(len * 2),
+    TensorElementType::Int32 | TensorElementType::Uint32 | TensorElementType::Float32 => Some(len * 4),
+    TensorElementType::Int64
+    | TensorElementType::Uint64
+    | TensorElementType::Float64
+    | TensorElementType::Complex64 => Some(len * 8),
+    TensorElementType::Complex128 => Some(len * 16),
+    // Two 4-bit elements are packed into every byte
+    TensorElementType::Int4 | TensorElementType::Uint4 => Some(len.div_ceil(2)),
+    TensorElementType::String | TensorElementType::Undefined => None,
+  }
+}
+
+#[frb(ignore)]
+fn create_tensor_from_vec<T: PrimitiveTensorElementType + Debug + Clone + 'static>(shape: Option<Vec<i64>>, data: Vec<T>) -> Result<TensorImpl> {
+  let shape = TensorImpl::parse_shape(shape, &data)?;
+  let tensor = if shape.contains(&0) {
+    if !data.is_empty() {
+      return Err(Error::new("Data must be empty for tensors with a zero dimension"));
+    }
+
+    let shape: Vec<usize> = shape.iter().map(|&d| d as usize).collect();
+    let array = ndarray::Array::from_shape_vec(shape, data).unwrap();
+    Tensor::from_array(array)?
+  } else {
+    Tensor::<T>::from_array((shape, data))?
+  };
+  create_tensor(tensor, true)
+}
+
+macro_rules! impl_type {
+	($t:ty) => {
+    ::paste::paste! {
+      #[frb(sync)]
+      pub fn [<from_array_ $t>](shape: Option<Vec<i64>>, data: Vec<$t>) -> Result<TensorImpl> {
+        create_tensor_from_vec(shape, data)
+      }
+
+      /// Copies the tensor's data into a new `Vec`.
+      #[frb(sync)]
+      pub fn [<extract_ $t>](&self) -> Result<Vec<$t>> {
+        Ok(self.tensor.try_extract_tensor::<$t>()?.1.to_vec())
+      }
+
+      /// Returns the address and length of the tensor's data, which may be written to. The pointer is only valid
+      /// while this tensor is alive; prefer [`TensorImpl::data_view`], which keeps the data alive on its own. Fails if
+      /// the tensor isn't mutable, see [`TensorImpl::make_mutable`].
+      #[frb(sync)]
+      pub fn [<get_data_ $t _pointer>](&mut self) -> Result<ArrayPointer> {
+        self.ensure_mutable()?;
+        let arr = self.tensor.try_extract_tensor_mut::<$t>()?.1;
+        let ptr = arr.as_mut_ptr() as usize;
+        let len = arr.len();
+        Ok(ArrayPointer { ptr, len })
+      }
+
+      /// Releases a pointer returned by the matching `get_data_*_pointer`. The data belongs to the tensor, so there
+      /// is nothing to free.
+      #[frb(sync)]
+      pub fn [<free_ $t _pointer>](arr: ArrayPointer) {
+        let _ = arr;
+      }
+    }
+	};
+}
+
+/// Half-precision floats have no Dart counterpart, so they are created from and extracted to `f32`s, or their raw
+/// `u16` bits.
+macro_rules! impl_half_type {
+	($t:ty) => {
+    ::paste::paste! {
+      /// Creates a tensor by converting every `f32` in `data` to the nearest representable value.
+      #[frb(sync)]
+      pub fn [<from_array_ $t>](shape: Option<Vec<i64>>, data: Vec<f32>) -> Result<TensorImpl> {
+        create_tensor_from_vec(shape, data.into_iter().map($t::from_f32).collect::<Vec<_>>())
+      }
+
+      /// Creates a tensor from the raw bit patterns of its elements.
+      #[frb(sync)]
+      pub fn [<from_array_ $t _bits>](shape: Option<Vec<i64>>, data: Vec<u16>) -> Result<TensorImpl> {
+        create_tensor_from_vec(shape, data.into_iter().map($t::from_bits).collect::<Vec<_>>())
+      }
+
+      /// Copies the tensor's data into a new `Vec`, converting every element to `f32`.
+      #[frb(sync)]
+      pub fn [<extract_ $t>](&self) -> Result<Vec<f32>> {
+        Ok(self.tensor.try_extract_tensor::<$t>()?.1.iter().map(|v| v.to_f32()).collect())
+      }
+
+      /// Copies the raw bit patterns of the tensor's elements into a new `Vec`.
+      #[frb(sync)]
+      pub fn [<extract_ $t _bits>](&self) -> Result<Vec<u16>> {
+        Ok(self.tensor.try_extract_tensor::<$t>()?.1.iter().map(|v| v.to_bits()).collect())
+      }
+
+      /// Returns the address and length of the tensor's data, which Dart can access as a `Uint16List`. The pointer is
+      /// only valid while this tensor is alive; prefer [`TensorImpl::data_view`], which keeps the data alive on its
+      /// own. Fails if the tensor isn't mutable, see [`TensorImpl::make_mutable`].
+      #[frb(sync)]
+      pub fn [<get_data_ $t _pointer>](&mut self) -> Result<ArrayPointer> {
+        self.ensure_mutable()?;
+        let arr = self.tensor.try_extract_tensor_mut::<$t>()?.1;
+        let ptr = arr.as_mut_ptr() as usize;
+        let len = arr.len();
+        Ok(ArrayPointer { ptr, len })
+      }
+
+      /// Releases a pointer returned by the matching `get_data_*_pointer`. The data belongs to the tensor, so there
+      /// is nothing to free.
+      #[frb(sync)]
+      pub fn [<free_ $t _pointer>](arr: ArrayPointer) {
+        let _ = arr;
+      }
+    }
+	};
+}
+
+impl TensorImpl {
+  /// A helper method to get the shape of the data. Handles determining -1 and 0 for dynamic sizes
+  /// or treating the shape as a 1-D array if no shape was provided.
+  fn parse_shape<T>(shape: Option<Vec<i64>>, data: &[T]) -> Result<Vec<i64>> {
+    TensorImpl::parse_shape_len(shape, data.len())
+  }
+
+  /// Like [`TensorImpl::parse_shape`], for data holding `len` elements.
+  pub(crate) fn parse_shape_len(shape: Option<Vec<i64>>, len: usize) -> Result<Vec<i64>> {
+    if let Some(shape) = shape {
+      let mut inferred_shape = Vec::with_capacity(shape.len());
+      let mut product = 1;
+      let mut unknown_dim_idx = None;
+
+      for (i, &dim) in shape.iter().enumerate() {
+        if dim == -1 {
+          if unknown_dim_idx.is_some() {
+            return Err(Error::new("Only one dynamic dimension (-1 or 0) is allowed in the shape"));
+          }
+          unknown_dim_idx = Some(i);
+          inferred_shape.push(1); // Placeholder, will be updated later
+        } else if dim < -1 {
+          return Err(Error::new(format!("Invalid dimension in shape: {}", dim)));
+        } else {
+          inferred_shape.push(dim);
+          product *= dim;
+        }
+      }
+
+      if let Some(idx) = unknown_dim_idx {
+        if product == 0 {
+          return Err(Error::new("Cannot infer a dynamic dimension (-1) alongside a zero dimension"));
+        }
+        if len as i64 % product != 0 {
+          return Err(Error::new(format!(
+            "Data length ({}) is not divisible by the product of known dimensions ({})",
+            len,
+            product
+          )));
+        }
+        inferred_shape[idx] = len as i64 / product;
+      } else if product != len as i64 {
+        return Err(Error::new(format!(
+          "Product of shape dimensions ({}) does not match data length ({})",
+          product,
+          len
+        )));
+      }
+
+      return Ok(inferred_shape);
+    }
+
+    // If no shape was provided then default to the length of the data (1-D array)
+    Ok(vec![len as i64])
+  }
+
+  pub(crate) fn from_value_ref(tensor: ValueRef) -> Result<TensorImpl> {
+    Ok(TensorImpl::from_output(DynTensor::from(tensor.downcast()?.clone())))
+  }
+
+  /// Wraps a tensor output by a session, whose data is immutable.
+  pub(crate) fn from_output(tensor: DynTensor) -> TensorImpl {
+    TensorImpl {
+      tensor,
+      mutable: false,
+      backing: None,
+    }
+    .charge_budget()
+  }
+
+  /// The bytes of data counted against the memory budget for this tensor.
+  fn budget_bytes(&self) -> u64 {
+    self.size_in_bytes().unwrap_or_default() as u64
+  }
+
+  fn with_charge(mut self, charge: MemoryCharge) -> TensorImpl {
+    self.backing = Some(match self.backing.take() {
+      Some(backing) => Arc::new((backing, charge)),
+      None => Arc::new(charge),
+    });
+    self
+  }
+
+  /// Counts the data this tensor owns against the memory budget, failing if it doesn't fit, see
+  /// [`set_memory_budget`](crate::api::memory::budget::set_memory_budget).
+  fn reserve_budget(self) -> Result<TensorImpl> {
+    match self.budget_bytes() {
+      0 => Ok(self),
+      bytes => Ok(self.with_charge(MemoryCharge::reserve(ChargeKind::Tensor, bytes)?)),
+    }
+  }
+
+  /// Counts `bytes` of data a new tensor is about to own against the memory budget before they're allocated, failing
+  /// if they don't fit. The charge is handed to the tensor with [`TensorImpl::with_reserved`].
+  fn reserve_bytes(bytes: usize) -> Result<Option<MemoryCharge>> {
+    match bytes {
+      0 => Ok(None),
+      bytes => MemoryCharge::reserve(ChargeKind::Tensor, bytes as u64).map(Some),
+    }
+  }
+
+  fn with_reserved(self, charge: Option<MemoryCharge>) -> TensorImpl {
+    match charge {
+      Some(charge) => self.with_charge(charge),
+      None => self,
+    }
+  }
+
+  /// Counts the data this tensor owns against the memory budget even if it doesn't fit, for data that is already
+  /// allocated anyway.
+  fn charge_budget(self) -> TensorImpl {
+    match self.budget_bytes() {
+      0 => self,
+      bytes => self.with_charge(MemoryCharge::charge(ChargeKind::Tensor, bytes)),
+    }
+  }
+
+  /// The number of bytes of raw data a tensor of the given type and shape holds. The shape may come from untrusted
+  /// input, so sizes that overflow are an error.
+  fn raw_byte_len(dtype: TensorElementType, shape: &[i64]) -> Result<usize> {
+    if shape.iter().any(|&d| d < 0) {
+      return Err(Error::new(format!("Invalid shape for a new tensor: {:?}", shape)));
+    }
+    let num_elements = shape
+      .iter()
+      .try_fold(1usize, |n, &d| n.checked_mul(d as usize))
+      .ok_or_else(|| Error::new(format!("A tensor of shape {:?} has too many elements", shape)))?;
+    // 16 bytes per element is the most any type takes, so this can't overflow within `element_byte_len`
+    if num_elements > usize::MAX / 16 {
+      return Err(Error::new(format!("A tensor of shape {:?} has too many elements", shape)));
+    }
+    element_byte_len(dtype, num_elements)
+      .ok_or_else(|| Error::new(format!("Tensors of type {} can't be created from raw data", dtype)))
+  }
+
+  /// Checks that `len` bytes of raw data are exactly what a tensor of the given type and shape holds.
+  fn check_byte_len(dtype: TensorElementType, shape: &[i64], len: usize) -> Result<usize> {
+    let byte_len = TensorImpl::raw_byte_len(dtype, shape)?;
+    if byte_len != len {
+      return Err(Error::new(format!(
+        "Data length ({}) does not match the {} bytes required for shape {:?}",
+        len,
+        byte_len,
+        shape
+      )));
+    }
+    Ok(byte_len)
+  }
+
+  /// Allocates an uninitialized tensor of the given type and shape with `allocator`.
+  ///
+  /// `DynTensor::new` zeroes the new buffer using `TensorElementType::byte_size`, which overestimates the size of
+  /// 8-bit float types, so the tensor is created through the C API directly.
+  fn allocate(allocator: &ort::memory::Allocator, dtype: TensorElementType, shape: &[i64]) -> Result<DynTensor> {
+    let mut value_ptr: *mut ort::sys::OrtValue = std::ptr::null_mut();
+    unsafe {
+      ort::error::status_to_result((ort::api().CreateTensorAsOrtValue)(
+        allocator.ptr().cast_mut(),
+        shape.as_ptr(),
+        shape.len(),
+        dtype.into(),
+        &mut value_ptr,
+      ))?;
+    }
+    let value_ptr = NonNull::new(value_ptr).ok_or_else(|| Error::new("Failed to allocate tensor"))?;
+    Ok(unsafe { DynTensor::from_ptr(value_ptr, None) })
+  }
+
+  /// Creates a CPU tensor of the given type and shape and copies `data` into it. `data` must hold exactly the
+  /// number of bytes the shape requires.
+  pub(crate) fn from_raw_data(dtype: TensorElementType, shape: &[i64], data: &[u8], mutable: bool) -> Result<TensorImpl> {
+    let byte_len = TensorImpl::check_byte_len(dtype, shape, data.len())?;
+    let charge = TensorImpl::reserve_bytes(byte_len)?;
+
+    let mut tensor = TensorImpl::allocate(&ort::memory::Allocator::default(), dtype, shape)?;
+    if byte_len > 0 {
+      unsafe {
+        std::ptr::copy_nonoverlapping(data.as_ptr(), tensor.data_ptr_mut().cast::<u8>(), byte_len);
+      }
+    }
+
+    Ok(TensorImpl {
+      tensor,
+      mutable,
+      backing: None,
+    }
+    .with_reserved(charge))
+  }
+
+  /// Creates a tensor over `byte_len` bytes of existing memory at `data` without copying them.
+  ///
+  /// # Safety
+  /// `data` must point to `byte_len` bytes laid out as a tensor of `dtype` and `shape` on the device described by
+  /// `memory_info`, and `backing` must keep that memory alive and in place for as long as the tensor exists. If
+  /// `mutable` is set the memory must also be writable.
+  pub(crate) unsafe fn from_borrowed_data(
+    dtype: TensorElementType,
+    shape: &[i64],
+    data: *mut std::ffi::c_void,
+    byte_len: usize,
+    memory_info: &ort::memory::MemoryInfo,
+    backing: Arc<dyn Any + Send + Sync>,
+    mutable: bool,
+  ) -> Result<TensorImpl> {
+    let mut value_ptr: *mut ort::sys::OrtValue = std::ptr::null_mut();
+    ort::error::status_to_result((ort::api().CreateTensorWithDataAsOrtValue)(
+      memory_info.ptr(),
+      data,
+      byte_len,
+      shape.as_ptr(),
+      shape.len(),
+      dtype.into(),
+      &mut value_ptr,
+    ))?;
+    let value_ptr = NonNull::new(value_ptr).ok_or_else(|| Error::new("Failed to create tensor"))?;
+
+    Ok(TensorImpl {
+      tensor: DynTensor::from_ptr(value_ptr, None),
+      mutable,
+      backing: Some(backing),
+    })
+  }
+
+  /// Creates a tensor of any fixed-width element type from the raw, native-endian bytes of its elements.
+  ///
+  /// This covers every type in [`TensorElementType`] except [`TensorElementType::String`], including the 8-bit float
+  /// types. [`TensorElementType::Complex64`] and [`TensorElementType::Complex128`] elements are stored as a real part
+  /// followed by an imaginary part, and [`TensorElementType::Int4`]/[`TensorElementType::Uint4`] elements are packed
+  /// two to a byte with the first element in the low nibble, as in ONNX.
+  ///
+  /// As with the `from_array_*` functions, one dimension of `shape` may be `-1` to have it inferred from the length of
+  /// `bytes`, and leaving `shape` out creates a 1-D tensor.
+  #[frb(sync)]
+  pub fn from_bytes(dtype: TensorElementType, shape: Option<Vec<i64>>, bytes: Vec<u8>) -> Result<TensorImpl> {
+    let shape = TensorImpl::shape_for_byte_len(dtype, shape, bytes.len())?;
+    TensorImpl::from_raw_data(dtype, &shape, &bytes, true)
+  }
+
+  /// Creates a tensor of the given type and shape with its data allocated by `allocator`, e.g. on the device of an
+  /// execution provider. The data of tensors in CPU accessible memory is zeroed; strings are empty.
+  #[frb(sync)]
+  pub fn new_with_allocator(allocator: &Allocator, dtype: TensorElementType, shape: Vec<i64>) -> Result<TensorImpl> {
+    if shape.iter().any(|&d| d < 0) {
+      return Err(Error::new(format!("Invalid shape for a new tensor: {:?}", shape)));
+    }
+    // New string tensors hold empty strings, so only fixed-width data is counted
+    let byte_len = element_byte_len(dtype, shape.iter().product::<i64>() as usize).unwrap_or_default();
+    let charge = TensorImpl::reserve_bytes(byte_len)?;
+    let mut tensor = TensorImpl::allocate(&allocator.lock(), dtype, &shape)?;
+    if byte_len > 0 && tensor.memory_info().is_cpu_accessible() {
+      unsafe {
+        tensor.data_ptr_mut().cast::<u8>().write_bytes(0, byte_len);
+      }
+    }
+    Ok(TensorImpl {
+      tensor,
+      mutable: true,
+      // The allocator frees the data once the tensor is released, so it must outlive the tensor
+      backing: Some(allocator.keep_alive()),
+    }
+    .with_reserved(charge))
+  }
+
+  /// Resolves the shape of `byte_len` bytes of raw data like [`TensorImpl::parse_shape`] does for arrays. The exact
+  /// number of bytes still has to be checked against the resulting shape.
+  fn shape_for_byte_len(dtype: TensorElementType, shape: Option<Vec<i64>>, byte_len: usize) -> Result<Vec<i64>> {
+    match shape {
+      // An odd number of 4-bit elements is only caught by that check
+      Some(shape) if !shape.contains(&-1) => Ok(shape),
+      shape => {
+        let len = match dtype {
+          TensorElementType::Int4 | TensorElementType::Uint4 => byte_len * 2,
+          _ => {
+            let element_size = element_byte_len(dtype, 1)
+              .ok_or_else(|| Error::new(format!("Tensors of type {} can't be created from bytes", dtype)))?;
+            if !byte_len.is_multiple_of(element_size) {
+              return Err(Error::new(format!(
+                "Data length ({}) is not a multiple of the {} byte size of {}",
+                byte_len,
+                element_size,
+                dtype
+              )));
+            }
+            byte_len / element_size
+          },
+        };
+        TensorImpl::parse_shape_len(shape, len)
+      },
+    }
+  }
+
+  /// Copies the raw, native-endian bytes of this tensor's elements, laid out as described in
+  /// [`TensorImpl::from_bytes`].
+  #[frb(sync)]
+  pub fn to_bytes(&self) -> Result<Vec<u8>> {
+    Ok(self.raw_data()?.to_vec())
+  }
+
+  /// Borrows the underlying bytes of a fixed-width tensor that lives in CPU accessible memory.
+  pub(crate) fn raw_data(&self) -> Result<&[u8]> {
+    let dtype = *self.tensor.data_type();
+    let byte_len = element_byte_len(dtype, self.tensor.shape().num_elements())
+      .ok_or_else(|| Error::new(format!("Tensors of type {} have no raw data", dtype)))?;
+    if !self.tensor.memory_info().is_cpu_accessible() {
+      return Err(Error::new("Tensor data is not accessible from the CPU"));
+    }
+    if byte_len == 0 {
+      return Ok(&[]);
+    }
+    Ok(unsafe { std::slice::from_raw_parts(self.tensor.data_ptr().cast::<u8>(), byte_len) })
+  }
+
+  /// Stacks tensors along their first (batch) dimension. All tensors must share the same element type and the same
+  /// dimensions after the first one.
+  pub(crate) fn concat_batch(tensors: &[&TensorImpl]) -> Result<TensorImpl> {
+    let first = tensors.first().ok_or_else(|| Error::new("Cannot concatenate an empty list of tensors"))?;
+    let dtype = first.dtype();
+    let inner_shape = first.shape().get(1..).map(|s| s.to_vec())
+      .ok_or_else(|| Error::new("Cannot concatenate scalar tensors along the batch dimension"))?;
+
+    let mut batch = 0;
+    for tensor in tensors {
+      let shape = tensor.shape();
+      if tensor.dtype() != dtype || shape.get(1..) != Some(&inner_shape[..]) {
+        return Err(Error::new(format!(
+          "Cannot concatenate a {} tensor of shape {:?} with a {} tensor of shape {:?}",
+          tensor.dtype(),
+          shape,
+          dtype,
+          first.shape()
+        )));
+      }
+      batch += shape[0];
+    }
+    let shape: Vec<i64> = std::iter::once(batch).chain(inner_shape).collect();
+
+    if dtype == TensorElementType::String {
+      let mut data = Vec::new();
+      for tensor in tensors {
+        data.extend(tensor.tensor.try_extract_strings()?.1);
+      }
+      return TensorImpl::from_array_string(Some(shape), data);
+    }
+
+    let mut data = Vec::new();
+    for tensor in tensors {
+      data.extend_from_slice(tensor.raw_data()?);
+    }
+    TensorImpl::from_raw_data(dtype, &shape, &data, true)
+  }
+
+  /// Splits a tensor along its first (batch) dimension into consecutive parts of the given sizes, the inverse of
+  /// [`TensorImpl::concat_batch`].
+  pub(crate) fn split_batch(&self, sizes: &[usize]) -> Result<Vec<TensorImpl>> {
+    let shape = self.shape();
+    let batch = sizes.iter().sum::<usize>() as i64;
+    if shape.first() != Some(&batch) {
+      return Err(Error::new(format!("Cannot split a tensor of shape {:?} into batches of {:?}", shape, sizes)));
+    }
+    let dtype = self.dtype();
+    let row_len = shape[1..].iter().product::<i64>() as usize;
+    if matches!(dtype, TensorElementType::Int4 | TensorElementType::Uint4) && sizes.iter().any(|s| !(s * row_len).is_multiple_of(2)) {
+      return Err(Error::new("Cannot split a 4-bit tensor in the middle of a byte"));
+    }
+
+    let mut parts = Vec::with_capacity(sizes.len());
+    let mut offset = 0;
+    if dtype == TensorElementType::String {
+      let data = self.tensor.try_extract_strings()?.1;
+      for &size in sizes {
+        let part_shape: Vec<i64> = std::iter::once(size as i64).chain(shape[1..].iter().copied()).collect();
+        let part = data[offset * row_len..(offset + size) * row_len].to_vec();
+        parts.push(TensorImpl::from_array_string(Some(part_shape), part)?);
+        offset += size;
+      }
+      return Ok(parts);
+    }
+
+    let data = self.raw_data()?;
+    for &size in sizes {
+      let part_shape: Vec<i64> = std::iter::once(size as i64).chain(shape[1..].iter().copied()).collect();
+      let start = element_byte_len(dtype, offset * row_len).unwrap_or_default();
+      let end = element_byte_len(dtype, (offset + size) * row_len).unwrap_or_default();
+      parts.push(TensorImpl::from_raw_data(dtype, &part_shape, &data[start..end], self.mutable)?);
+      offset += size;
+    }
+    Ok(parts)
+  }
+
+  impl_type!(f64);
+  impl_type!(i64);
+  impl_type!(u64);
+  impl_type!(f32);
+  impl_type!(u32);
+  impl_type!(i32);
+  impl_type!(u16);
+  impl_type!(i16);
+  impl_type!(u8);
+  impl_type!(i8);
+  impl_type!(bool);
+  impl_half_type!(f16);
+  impl_half_type!(bf16);
+
+  #[frb(sync)]
+  pub fn from_array_string(shape: Option<Vec<i64>>, data: Vec<String>) -> Result<TensorImpl> {
+    let shape = TensorImpl::parse_shape(shape, &data)?;
+    let tensor = Tensor::from_string_array((shape, &*data))?;
+    create_tensor(tensor, false)
+  }
+
+  /// Copies the tensor's strings into a new `Vec`.
+  #[frb(sync)]
+  pub fn extract_string(&self) -> Result<Vec<String>> {
+    Ok(self.tensor.try_extract_strings()?.1)
+  }
+
+  /// Returns an array of pointers to nul-terminated copies of the tensor's strings. The copies are owned by the caller
+  /// and must be released with [`TensorImpl::free_string_pointer`]. Since they are copies, this works for immutable
+  /// tensors as well.
+  #[frb(sync)]
+  pub fn get_data_string_pointer(&mut self) -> Result<ArrayPointer> {
+    // Extract strings and convert to CStrings, handling potential null bytes
+    let c_strings: std::result::Result<Vec<CString>, NulError> = self.tensor.try_extract_strings()?
+      .1
+      .into_iter()
+      .map(CString::new)
+      .collect();
+    let c_strings = c_strings.map_err(|e| Error::new(format!("Failed to create CString: {}", e)))?;
+
+    // Hand ownership of the strings and of the array pointing to them over to the caller
+    let pointers: Box<[*mut c_char]> = c_strings
+      .into_iter()
+      .map(CString::into_raw)
+      .collect();
+    let len = pointers.len();
+    let ptr = Box::into_raw(pointers) as *mut *mut c_char as usize;
+
+    Ok(ArrayPointer { ptr, len })
+  }
+
+  /// Frees the strings and the array returned by [`TensorImpl::get_data_string_pointer`].
+  #[frb(sync)]
+  pub fn free_string_pointer(arr: ArrayPointer) {
+    if arr.ptr == 0 {
+      return;
+    }
+
+    unsafe {
+      let pointers = Box::from_raw(std::ptr::slice_from_raw_parts_mut(arr.ptr as *mut *mut c_char, arr.len));
+      for &pointer in pointers.iter() {
+        drop(CString::from_raw(pointer));
+      }
+    }
+  }
+
+  /// Creates a zero-copy, read-only view of this tensor's data that keeps the data alive until the view is disposed.
+  /// Only tensors with fixed-width elements in CPU accessible memory can be viewed; use `extract_string` for strings.
+  #[frb(sync)]
+  pub fn data_view(&self) -> Result<TensorDataView> {
+    // Checks the element type and the device
+    self.raw_data()?;
+    Ok(TensorDataView {
+      tensor: self.shared_tensor(),
+      _backing: self.backing.clone(),
+    })
+  }
+
+  /// Returns a second handle to this tensor's data without copying it, so the data stays alive as long as either
+  /// handle does.
+  pub(crate) fn shared_tensor(&self) -> DynTensor {
+    match self.tensor.view().try_upgrade() {
+      Ok(tensor) => tensor,
+      // Values that can't be shared are copied instead
+      Err(tensor) => tensor.clone(),
+    }
+  }
+
+  /// If this Tensor's underlying data is mutable
+  #[frb(sync)]
+  pub fn is_mutable(&self) -> bool {
+    self.mutable
+  }
+
+  /// Makes the tensor mutable, copying its data first if it is immutable, so that writes never reach data that is
+  /// shared with an immutable tensor. Returns whether the data was copied.
+  #[frb(sync)]
+  pub fn make_mutable(&mut self) -> bool {
+    if self.mutable {
+      return false;
+    }
+    *self = self.clone();
+    true
+  }
+
+  fn ensure_mutable(&self) -> Result<()> {
+    if !self.mutable {
+      return Err(Error::new("Tensor is not mutable, call make_mutable() to write to a copy of its data"));
+    }
+    Ok(())
+  }
+
+  /// Get the data type of the Tensor
+  #[frb(sync)]
+  pub fn dtype(&self) -> TensorElementType {
+    self.tensor.data_type().clone()
+  }
+
+  /// Get the shape of the Tensor
+  #[frb(sync)]
+  pub fn shape(&self) -> Vec<i64> {
+    self.tensor.shape().to_vec()
+  }
+
+  /// The number of dimensions of the Tensor
+  #[frb(sync)]
+  pub fn rank(&self) -> usize {
+    self.tensor.shape().len()
+  }
+
+  /// The total number of elements in the Tensor
+  #[frb(sync)]
+  pub fn num_elements(&self) -> usize {
+    self.tensor.shape().num_elements()
+  }
+
+  /// The number of elements between neighbouring elements of each dimension, in the row-major order tensors are
+  /// always stored in.
+  #[frb(sync)]
+  pub fn strides(&self) -> Vec<i64> {
+    shape::contiguous_strides(&self.shape()).into_iter().map(|s| s as i64).collect()
+  }
+
+  /// The size of the Tensor's data in bytes. For string tensors this is the combined length of the strings in UTF-8,
+  /// which leaves out the overhead of storing each string.
+  #[frb(sync)]
+  pub fn size_in_bytes(&self) -> Result<usize> {
+    let dtype = self.dtype();
+    if let Some(byte_len) = element_byte_len(dtype, self.num_elements()) {
+      return Ok(byte_len);
+    }
+    if dtype != TensorElementType::String {
+      return Err(Error::new(format!("Tensors of type {} have no defined size", dtype)));
+    }
+    let mut len = 0;
+    unsafe {
+      ort::error::status_to_result((ort::api().GetStringTensorDataLength)(self.tensor.ptr(), &mut len))?;
+    }
+    Ok(len)
+  }
+
+  /// Describes the device and memory the Tensor's data resides in, like [`TensorImpl::memory_info`] but infallible.
+  #[frb(sync)]
+  pub fn device(&self) -> MemoryInfo {
+    MemoryInfo::from_ort(self.tensor.memory_info())
+  }
+
+  /// Creates a copy of this tensor and its data on the same device it resides on. The copy is mutable, and counts
+  /// against the memory budget even if it doesn't fit.
+  #[frb(sync)]
+  pub fn clone(&self) -> TensorImpl {
+    Self {
+      tensor: self.tensor.clone(),
+      mutable: true,
+      backing: None,
+    }
+    .charge_budget()
+  }
+
+  /// Describes the device and memory the Tensor's data resides in.
+  #[frb(sync)]
+  pub fn memory_info(&self) -> Result<MemoryInfo> {
+    Ok(MemoryInfo::from_ort(self.tensor.memory_info()))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ort::error::Result;
+  use ort::tensor::{TensorElementType};
+  use crate::api::tensor::TensorImpl;
+
+  #[test]
+  fn tensor_parse_shape_1d() {
+    let shape = None;
+    let data = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+    let result = TensorImpl::parse_shape(shape, &data).unwrap();
+
+    assert_eq!(result, vec![data.len() as i64]);
+  }
+
+  #[test]
+  fn tensor_parse_shape_dynamic_dim_negative_one() {
+    let shape = Some(vec![4, -1]);
+    let data = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+
+    let result = TensorImpl::parse_shape(shape, &data).unwrap();
+
+    assert_eq!(result, vec![4, 3]);
+  }
+
+  #[test]
+  fn tensor_parse_shape_no_dynamic_dim_match_data() {
+    let shape = Some(vec![3, 4]);
+    let data = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+
+    let result = TensorImpl::parse_shape(shape, &data).unwrap();
+
+    assert_eq!(result, vec![3, 4]);
+  }
+
+  #[test]
+  fn tensor_parse_shape_dynamic_dim_negative_one_4d() {
+    let shape = Some(vec![1, 2, -1, 2]);
+    let data = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+
+    let result = TensorImpl::parse_shape(shape, &data).unwrap();
+
+    assert_eq!(result, vec![1, 2, 3, 2]);
+  }
+
+
+  #[test]
+  fn tensor_parse_shape_no_dynamic_dim_mismatch_data() {
+    let shape = Some(vec![3, 3]);
+    let data = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+
+    let result = TensorImpl::parse_shape(shape, &data);
+
+    assert!(result.is_err());
+    assert_eq!(
+      result.unwrap_err().to_string(),
+      "Product of shape dimensions (9) does not match data length (12)"
+    );
+  }
+
+  #[test]
+  fn tensor_parse_shape_dynamic_dim_not_divisible() {
+    let shape = Some(vec![3, -1]);
+    let data = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10]; // 10 is not divisible by 3
+
+    let result = TensorImpl::parse_shape(shape, &data);
+
+    assert!(result.is_err());
+    assert_eq!(
+      result.unwrap_err().to_string(),
+      "Data length (10) is not divisible by the product of known dimensions (3)"
+    );
+  }
+
+  #[test]
+  fn test_from_array_f32() -> Result<()> {
+    let vec = vec![1., 2., 3.];
+    let tensor = TensorImpl::from_array_f32(None, vec)?;
+
+    assert_eq!(tensor.dtype(), TensorElementType::Float32);
+
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_from_array_with_zero_dim() -> Result<()> {
+    let shape = Some(vec![1, 3, 0, 64]);
+    let data: Vec<f32> = vec![];
+    let tensor = TensorImpl::from_array_f32(shape, data)?;
+
+    assert_eq!(tensor.shape(), vec![1, 3, 0, 64]);
+    assert_eq!(tensor.dtype(), TensorElementType::Float32);
+    assert!(tensor.is_mutable());
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_from_array_f16_converts_f32() -> Result<()> {
+    let tensor = TensorImpl::from_array_f16(None, vec![0.5, -2., 65504.])?;
+
+    assert_eq!(tensor.dtype(), TensorElementType::Float16);
+    assert_eq!(tensor.extract_f16()?, vec![0.5, -2., 65504.]);
+    assert_eq!(tensor.extract_f16_bits()?, vec![0x3800, 0xc000, 0x7bff]);
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_from_array_bf16_bits() -> Result<()> {
+    let tensor = TensorImpl::from_array_bf16_bits(Some(vec![1, 2]), vec![0x3f80, 0x4000])?;
+
+    assert_eq!(tensor.dtype(), TensorElementType::Bfloat16);
+    assert_eq!(tensor.shape(), vec![1, 2]);
+    assert_eq!(tensor.extract_bf16()?, vec![1., 2.]);
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_from_bytes_float8() -> Result<()> {
+    let bytes = vec![0x38, 0x40, 0x44, 0x48, 0x00, 0xb8];
+    let tensor = TensorImpl::from_bytes(TensorElementType::Float8E4M3FN, Some(vec![2, -1]), bytes.clone())?;
+
+    assert_eq!(tensor.dtype(), TensorElementType::Float8E4M3FN);
+    assert_eq!(tensor.shape(), vec![2, 3]);
+    assert_eq!(tensor.to_bytes()?, bytes);
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_from_bytes_odd_int4() -> Result<()> {
+    let tensor = TensorImpl::from_bytes(TensorElementType::Int4, Some(vec![3]), vec![0x21, 0x03])?;
+
+    assert_eq!(tensor.shape(), vec![3]);
+    assert_eq!(tensor.to_bytes()?, vec![0x21, 0x03]);
+    assert!(TensorImpl::from_bytes(TensorElementType::Int4, Some(vec![5]), vec![0x21, 0x03]).is_err());
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_from_bytes_complex64() -> Result<()> {
+    let bytes: Vec<u8> = [1f32, 2., 3., 4.].iter().flat_map(|v| v.to_ne_bytes()).collect();
+    let tensor = TensorImpl::from_bytes(TensorElementType::Complex64, None, bytes.clone())?;
+
+    assert_eq!(tensor.shape(), vec![2]);
+    assert_eq!(tensor.to_bytes()?, bytes);
+    assert!(TensorImpl::from_bytes(TensorElementType::Complex64, None, vec![0; 7]).is_err());
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_extract_copies_data() -> Result<()> {
+    let tensor = TensorImpl::from_array_i32(Some(vec![2, 2]), vec![1, 2, 3, 4])?;
+
+    assert_eq!(tensor.extract_i32()?, vec![1, 2, 3, 4]);
+    assert!(tensor.extract_f32().is_err());
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_data_view_outlives_tensor() -> Result<()> {
+    let tensor = TensorImpl::from_array_f32(None, vec![1., 2., 3.])?;
+    let view = tensor.data_view()?;
+    drop(tensor);
+
+    let data = unsafe { std::slice::from_raw_parts(view.ptr() as *const f32, view.len()) };
+    assert_eq!(data, &[1., 2., 3.]);
+    assert_eq!(view.byte_len(), 12);
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_string_pointer_round_trip() -> Result<()> {
+    let mut tensor = TensorImpl::from_array_string(None, vec!["a".to_string(), "bc".to_string()])?;
+    let arr = tensor.get_data_string_pointer()?;
+
+    let pointers = unsafe { std::slice::from_raw_parts(arr.ptr as *const *const std::os::raw::c_char, arr.len) };
+    let strings: Vec<&str> = pointers
+      .iter()
+      .map(|&p| unsafe { std::ffi::CStr::from_ptr(p) }.to_str().unwrap())
+      .collect();
+    assert_eq!(strings, vec!["a", "bc"]);
+
+    TensorImpl::free_string_pointer(arr);
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_make_mutable_copies_on_write() -> Result<()> {
+    let tensor = TensorImpl::from_raw_data(TensorElementType::Float32, &[2], &[0, 0, 128, 63, 0, 0, 0, 64], false)?;
+    let mut view = tensor.reshape(vec![1, 2])?;
+    assert!(view.get_data_f32_pointer().is_err());
+
+    assert!(view.make_mutable());
+    let arr = view.get_data_f32_pointer()?;
+    unsafe { *(arr.ptr as *mut f32) = 5. };
+    assert_eq!(view.extract_f32()?, vec![5., 2.]);
+    assert_eq!(tensor.extract_f32()?, vec![1., 2.]);
+    assert!(!view.make_mutable());
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_metadata() -> Result<()> {
+    let tensor = TensorImpl::from_array_i16(Some(vec![2, 3, 4]), vec![0; 24])?;
+    assert_eq!(tensor.rank(), 3);
+    assert_eq!(tensor.num_elements(), 24);
+    assert_eq!(tensor.size_in_bytes()?, 48);
+    assert_eq!(tensor.strides(), vec![12, 4, 1]);
+    assert!(tensor.device().is_cpu_accessible());
+
+    let strings = TensorImpl::from_array_string(None, vec!["ab".to_string(), "cde".to_string()])?;
+    assert_eq!(strings.size_in_bytes()?, 5);
+    Ok(())
+  }
+}
@@ -0,0 +1,357 @@
+use std::collections::HashMap;
+use std::io::{Cursor, Read, Write};
+use flutter_rust_bridge::frb;
+use ort::{Error, Result};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+use crate::api::tensor::{TensorElementType, TensorImpl};
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+/// The byte order character NumPy uses for data in this machine's byte order.
+const NATIVE_ORDER: char = if cfg!(target_endian = "little") { '<' } else { '>' };
+
+/// The element type and size of a NumPy `descr` string such as `<f4`.
+enum Descr {
+  Fixed { dtype: TensorElementType, size: usize, swap: bool },
+  /// Fixed-length UTF-32 strings of `chars` characters (`<U8`).
+  Unicode { chars: usize, swap: bool },
+  /// Fixed-length byte strings of `len` bytes (`|S8`).
+  Bytes { len: usize },
+}
+
+impl Descr {
+  fn parse(descr: &str) -> Result<Descr> {
+    let unsupported = || Error::new(format!("Unsupported NumPy dtype '{}'", descr));
+    let mut chars = descr.chars();
+    let order = chars.next().ok_or_else(unsupported)?;
+    let swap = match order {
+      '<' | '>' => order != NATIVE_ORDER,
+      '|' | '=' => false,
+      _ => return Err(unsupported()),
+    };
+    let kind = chars.next().ok_or_else(unsupported)?;
+    let size: usize = chars.as_str().parse().map_err(|_| unsupported())?;
+
+    let dtype = match (kind, size) {
+      ('U', chars) => return Ok(Descr::Unicode { chars, swap }),
+      ('S', len) => return Ok(Descr::Bytes { len }),
+      ('b', 1) => TensorElementType::Bool,
+      ('i', 1) => TensorElementType::Int8,
+      ('i', 2) => TensorElementType::Int16,
+      ('i', 4) => TensorElementType::Int32,
+      ('i', 8) => TensorElementType::Int64,
+      ('u', 1) => TensorElementType::Uint8,
+      ('u', 2) => TensorElementType::Uint16,
+      ('u', 4) => TensorElementType::Uint32,
+      ('u', 8) => TensorElementType::Uint64,
+      ('f', 2) => TensorElementType::Float16,
+      ('f', 4) => TensorElementType::Float32,
+      ('f', 8) => TensorElementType::Float64,
+      ('c', 8) => TensorElementType::Complex64,
+      ('c', 16) => TensorElementType::Complex128,
+      _ => return Err(unsupported()),
+    };
+    Ok(Descr::Fixed { dtype, size, swap })
+  }
+
+  /// The `descr` string for data of the given type in this machine's byte order.
+  fn for_dtype(dtype: TensorElementType) -> Result<String> {
+    let (kind, size) = match dtype {
+      TensorElementType::Bool => ('b', 1),
+      TensorElementType::Int8 => ('i', 1),
+      TensorElementType::Int16 => ('i', 2),
+      TensorElementType::Int32 => ('i', 4),
+      TensorElementType::Int64 => ('i', 8),
+      TensorElementType::Uint8 => ('u', 1),
+      TensorElementType::Uint16 => ('u', 2),
+      TensorElementType::Uint32 => ('u', 4),
+      TensorElementType::Uint64 => ('u', 8),
+      TensorElementType::Float16 => ('f', 2),
+      TensorElementType::Float32 => ('f', 4),
+      TensorElementType::Float64 => ('f', 8),
+      TensorElementType::Complex64 => ('c', 8),
+      TensorElementType::Complex128 => ('c', 16),
+      _ => return Err(Error::new(format!("Tensors of type {} have no NumPy equivalent", dtype))),
+    };
+    let order = if size == 1 { '|' } else { NATIVE_ORDER };
+    Ok(format!("{}{}{}", order, kind, size))
+  }
+}
+
+/// Finds the value of `key` in the Python dict literal of a `.npy` header.
+fn header_value<'a>(header: &'a str, key: &str) -> Result<&'a str> {
+  let missing = || Error::new(format!("The .npy header has no '{}' entry", key));
+  let start = header.find(&format!("'{}'", key)).ok_or_else(missing)? + key.len() + 2;
+  let value = header[start..].trim_start().strip_prefix(':').ok_or_else(missing)?.trim_start();
+  let end = if value.starts_with('(') {
+    value.find(')').map(|i| i + 1)
+  } else if let Some(quoted) = value.strip_prefix('\'') {
+    quoted.find('\'').map(|i| i + 2)
+  } else {
+    value.find([',', '}'])
+  };
+  Ok(value[..end.ok_or_else(missing)?].trim())
+}
+
+fn parse_shape(shape: &str) -> Result<Vec<i64>> {
+  shape
+    .trim_start_matches('(')
+    .trim_end_matches(')')
+    .split(',')
+    .map(str::trim)
+    .filter(|d| !d.is_empty())
+    .map(|d| match d.parse::<i64>() {
+      Ok(d) if d >= 0 => Ok(d),
+      _ => Err(Error::new(format!("Invalid shape in .npy header: {}", shape))),
+    })
+    .collect()
+}
+
+/// The first `num_elements` elements of `element_len` bytes each of `data`. The header is untrusted, so sizes that
+/// overflow are treated like data that is too short.
+fn element_data(data: &[u8], num_elements: usize, element_len: Option<usize>) -> Result<&[u8]> {
+  element_len
+    .and_then(|len| len.checked_mul(num_elements))
+    .and_then(|len| data.get(..len))
+    .ok_or_else(|| Error::new("The .npy data is truncated"))
+}
+
+fn swap_bytes(data: &mut [u8], size: usize) {
+  data.chunks_exact_mut(size).for_each(|element| element.reverse());
+}
+
+impl TensorImpl {
+  /// Loads a tensor from the contents of a NumPy `.npy` file.
+  ///
+  /// All numeric NumPy dtypes, `bool`, `complex64`/`complex128` and fixed-length `str`/`bytes` arrays are supported,
+  /// in either byte order. Fortran-ordered arrays are converted to row-major order.
+  #[frb(sync)]
+  pub fn from_npy(bytes: Vec<u8>) -> Result<TensorImpl> {
+    if !bytes.starts_with(MAGIC) || bytes.len() < 10 {
+      return Err(Error::new("Not a .npy file"));
+    }
+    let (header_len, header_start) = match bytes[6] {
+      1 => (u16::from_le_bytes([bytes[8], bytes[9]]) as usize, 10),
+      2 | 3 if bytes.len() >= 12 => (u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]) as usize, 12),
+      version => return Err(Error::new(format!("Unsupported .npy version {}", version))),
+    };
+    let header = bytes
+      .get(header_start..header_start + header_len)
+      .and_then(|h| std::str::from_utf8(h).ok())
+      .ok_or_else(|| Error::new("Invalid .npy header"))?;
+    let descr = Descr::parse(header_value(header, "descr")?.trim_matches('\''))?;
+    let fortran_order = header_value(header, "fortran_order")? == "True";
+    let mut shape = parse_shape(header_value(header, "shape")?)?;
+    if fortran_order {
+      shape.reverse();
+    }
+
+    let num_elements = shape
+      .iter()
+      .try_fold(1usize, |n, &d| n.checked_mul(d as usize))
+      .ok_or_else(|| Error::new(format!("The .npy shape {:?} has too many elements", shape)))?;
+    let data = &bytes[header_start + header_len..];
+    let tensor = match descr {
+      Descr::Fixed { dtype, size, swap } => {
+        let mut data = element_data(data, num_elements, Some(size))?.to_vec();
+        if swap {
+          // Complex numbers are swapped per component
+          let component = if matches!(dtype, TensorElementType::Complex64 | TensorElementType::Complex128) { size / 2 } else { size };
+          swap_bytes(&mut data, component);
+        }
+        TensorImpl::from_raw_data(dtype, &shape, &data, true)?
+      },
+      Descr::Unicode { chars: 0, .. } | Descr::Bytes { len: 0 } => {
+        TensorImpl::from_array_string(Some(shape), vec![String::new(); num_elements])?
+      },
+      Descr::Unicode { chars, swap } => {
+        let data = element_data(data, num_elements, chars.checked_mul(4))?;
+        let strings = data
+          .chunks_exact(chars * 4)
+          .map(|element| {
+            element
+              .chunks_exact(4)
+              .map(|c| u32::from_ne_bytes(c.try_into().unwrap()))
+              .map(|c| if swap { c.swap_bytes() } else { c })
+              .take_while(|&c| c != 0)
+              .map(|c| char::from_u32(c).ok_or_else(|| Error::new("Invalid character in .npy string")))
+              .collect::<Result<String>>()
+          })
+          .collect::<Result<Vec<_>>>()?;
+        TensorImpl::from_array_string(Some(shape), strings)?
+      },
+      Descr::Bytes { len } => {
+        let data = element_data(data, num_elements, Some(len))?;
+        let strings = data
+          .chunks_exact(len)
+          .map(|element| {
+            let end = element.iter().position(|&b| b == 0).unwrap_or(len);
+            String::from_utf8(element[..end].to_vec()).map_err(|_| Error::new("Invalid UTF-8 in .npy byte string"))
+          })
+          .collect::<Result<Vec<_>>>()?;
+        TensorImpl::from_array_string(Some(shape), strings)?
+      },
+    };
+
+    if fortran_order { tensor.transpose() } else { Ok(tensor) }
+  }
+
+  /// Serializes the tensor in NumPy's `.npy` format. String tensors are written as fixed-length `str` arrays.
+  #[frb(sync)]
+  pub fn to_npy(&self) -> Result<Vec<u8>> {
+    let dtype = self.dtype();
+    let (descr, data) = if dtype == TensorElementType::String {
+      let strings = self.extract_string()?;
+      let chars = strings.iter().map(|s| s.chars().count()).max().unwrap_or(0).max(1);
+      let mut data = Vec::with_capacity(strings.len() * chars * 4);
+      for s in &strings {
+        let padding = chars - s.chars().count();
+        for c in s.chars().map(|c| c as u32).chain(std::iter::repeat_n(0, padding)) {
+          data.extend_from_slice(&c.to_ne_bytes());
+        }
+      }
+      (format!("{}U{}", NATIVE_ORDER, chars), data)
+    } else {
+      (Descr::for_dtype(dtype)?, self.raw_data()?.to_vec())
+    };
+
+    let shape = self.shape();
+    let shape = match shape.len() {
+      1 => format!("({},)", shape[0]),
+      _ => format!("({})", shape.iter().map(|d| d.to_string()).collect::<Vec<_>>().join(", ")),
+    };
+    let mut header = format!("{{'descr': '{}', 'fortran_order': False, 'shape': {}, }}", descr, shape);
+    // The header is padded with spaces so that the data starts on a 64 byte boundary
+    let unpadded = MAGIC.len() + 4 + header.len() + 1;
+    header.extend(std::iter::repeat_n(' ', unpadded.next_multiple_of(64) - unpadded));
+    header.push('\n');
+    let header_len = u16::try_from(header.len()).map_err(|_| Error::new("The tensor has too many dimensions for .npy"))?;
+
+    let mut bytes = Vec::with_capacity(MAGIC.len() + 4 + header.len() + data.len());
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&[1, 0]);
+    bytes.extend_from_slice(&header_len.to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    bytes.extend_from_slice(&data);
+    Ok(bytes)
+  }
+
+  /// Loads a tensor from a `.npy` file, see [`TensorImpl::from_npy`].
+  pub fn load_npy(path: String) -> Result<TensorImpl> {
+    let bytes = std::fs::read(&path).map_err(|e| Error::new(format!("Failed to read '{}': {}", path, e)))?;
+    TensorImpl::from_npy(bytes)
+  }
+
+  /// Saves the tensor to a `.npy` file, see [`TensorImpl::to_npy`].
+  pub fn save_npy(&self, path: String) -> Result<()> {
+    std::fs::write(&path, self.to_npy()?).map_err(|e| Error::new(format!("Failed to write '{}': {}", path, e)))
+  }
+}
+
+/// Reads the tensors of a NumPy `.npz` archive, compressed or not, keyed by their array names. The result can be
+/// passed straight to `SessionImpl::run`.
+#[frb(sync)]
+pub fn read_npz(bytes: Vec<u8>) -> Result<HashMap<String, TensorImpl>> {
+  let invalid = |e: zip::result::ZipError| Error::new(format!("Invalid .npz archive: {}", e));
+  let mut archive = ZipArchive::new(Cursor::new(bytes)).map_err(invalid)?;
+
+  let mut tensors = HashMap::with_capacity(archive.len());
+  for i in 0..archive.len() {
+    let mut file = archive.by_index(i).map_err(invalid)?;
+    let name = file.name().strip_suffix(".npy").unwrap_or(file.name()).to_string();
+    let mut bytes = Vec::with_capacity(file.size() as usize);
+    file.read_to_end(&mut bytes).map_err(|e| Error::new(format!("Failed to read '{}' from .npz archive: {}", name, e)))?;
+    let tensor = TensorImpl::from_npy(bytes).map_err(|e| Error::new(format!("Failed to load '{}': {}", name, e)))?;
+    tensors.insert(name, tensor);
+  }
+  Ok(tensors)
+}
+
+/// Writes tensors to a NumPy `.npz` archive, as `numpy.savez` or, with `compressed`, `numpy.savez_compressed` would.
+#[frb(sync)]
+pub fn write_npz(tensors: HashMap<String, TensorImpl>, compressed: bool) -> Result<Vec<u8>> {
+  let failed = |e: zip::result::ZipError| Error::new(format!("Failed to write .npz archive: {}", e));
+  let options = SimpleFileOptions::default()
+    .compression_method(if compressed { CompressionMethod::Deflated } else { CompressionMethod::Stored })
+    .large_file(true);
+
+  // Sorted so that the same tensors always produce the same archive
+  let mut names: Vec<_> = tensors.keys().collect();
+  names.sort();
+  let mut archive = ZipWriter::new(Cursor::new(Vec::new()));
+  for name in names {
+    archive.start_file(format!("{}.npy", name), options).map_err(failed)?;
+    archive.write_all(&tensors[name].to_npy()?).map_err(|e| failed(e.into()))?;
+  }
+  Ok(archive.finish().map_err(failed)?.into_inner())
+}
+
+/// Loads the tensors of a `.npz` file, see [`read_npz`].
+pub fn load_npz(path: String) -> Result<HashMap<String, TensorImpl>> {
+  let bytes = std::fs::read(&path).map_err(|e| Error::new(format!("Failed to read '{}': {}", path, e)))?;
+  read_npz(bytes)
+}
+
+/// Saves tensors to a `.npz` file, see [`write_npz`].
+pub fn save_npz(tensors: HashMap<String, TensorImpl>, path: String, compressed: bool) -> Result<()> {
+  std::fs::write(&path, write_npz(tensors, compressed)?).map_err(|e| Error::new(format!("Failed to write '{}': {}", path, e)))
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+  use ort::error::Result;
+  use crate::api::tensor::npy::{read_npz, write_npz};
+  use crate::api::tensor::TensorImpl;
+
+  #[test]
+  fn tensor_from_npy_fortran_big_endian() -> Result<()> {
+    // np.asfortranarray(np.arange(6, dtype='>i2').reshape(2, 3))
+    let header = "{'descr': '>i2', 'fortran_order': True, 'shape': (2, 3), }";
+    let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+    bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    for v in [0i16, 3, 1, 4, 2, 5] {
+      bytes.extend_from_slice(&v.to_be_bytes());
+    }
+
+    let tensor = TensorImpl::from_npy(bytes)?;
+    assert_eq!(tensor.shape(), vec![2, 3]);
+    assert_eq!(tensor.extract_i16()?, vec![0, 1, 2, 3, 4, 5]);
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_from_npy_rejects_crafted_shapes() {
+    let npy = |descr: &str, shape: &str| {
+      let header = format!("{{'descr': '{}', 'fortran_order': False, 'shape': {}, }}", descr, shape);
+      let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+      bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+      bytes.extend_from_slice(header.as_bytes());
+      bytes.extend_from_slice(&[0; 16]);
+      bytes
+    };
+
+    assert!(TensorImpl::from_npy(npy("<f4", "(-2, -2)")).is_err());
+    // The element count overflows
+    assert!(TensorImpl::from_npy(npy("<f4", "(4294967296, 4294967296, 16)")).is_err());
+    // The element count fits but the byte length overflows
+    assert!(TensorImpl::from_npy(npy("<f8", "(4611686018427387904,)")).is_err());
+    assert!(TensorImpl::from_npy(npy("<U4611686018427387904", "(4,)")).is_err());
+  }
+
+  #[test]
+  fn npz_round_trip() -> Result<()> {
+    let mut tensors = HashMap::new();
+    tensors.insert("input".to_string(), TensorImpl::from_array_f32(Some(vec![2, 2]), vec![1.0, 2.0, 3.0, 4.0])?);
+    tensors.insert("labels".to_string(), TensorImpl::from_array_string(None, vec!["cat".to_string(), "ü".to_string()])?);
+
+    let bytes = write_npz(tensors, true)?;
+    let tensors = read_npz(bytes)?;
+    assert_eq!(tensors["input"].shape(), vec![2, 2]);
+    assert_eq!(tensors["input"].extract_f32()?, vec![1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(tensors["labels"].extract_string()?, vec!["cat", "ü"]);
+    Ok(())
+  }
+}
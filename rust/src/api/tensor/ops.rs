@@ -0,0 +1,559 @@
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use flutter_rust_bridge::frb;
+use half::{bf16, f16};
+use ort::{Error, Result};
+use ort::tensor::PrimitiveTensorElementType;
+use crate::api::tensor::shape::{contiguous_strides, normalize_axis, strided_indices};
+use crate::api::tensor::{create_tensor_from_vec, TensorElementType, TensorImpl};
+
+/// A numeric tensor element type that the native ops can work with.
+pub(crate) trait Numeric: PrimitiveTensorElementType + Debug + Copy + PartialOrd + Send + Sync + 'static {
+  const IS_FLOAT: bool;
+
+  fn to_f64(self) -> f64;
+
+  /// Converts from `f64`, rounding towards zero and saturating at the bounds of integer types.
+  fn from_f64(value: f64) -> Self;
+
+  /// Adds two values, wrapping around on integer overflow as ONNX Runtime does.
+  fn add(self, other: Self) -> Self;
+
+  /// Subtracts two values, wrapping around on integer overflow.
+  fn sub(self, other: Self) -> Self;
+
+  /// Multiplies two values, wrapping around on integer overflow.
+  fn mul(self, other: Self) -> Self;
+
+  /// Divides two values, or returns `None` for an integer division by zero.
+  fn div(self, other: Self) -> Option<Self>;
+
+  /// Raises to the power of `exponent`, or returns `None` if an integer result overflows or the exponent of an
+  /// integer is negative.
+  fn pow(self, exponent: Self) -> Option<Self>;
+
+  fn is_nan(self) -> bool {
+    Self::IS_FLOAT && self.to_f64().is_nan()
+  }
+}
+
+macro_rules! impl_numeric_int {
+  ($($t:ty),*) => {
+    $(
+      impl Numeric for $t {
+        const IS_FLOAT: bool = false;
+
+        fn to_f64(self) -> f64 {
+          self as f64
+        }
+
+        fn from_f64(value: f64) -> Self {
+          value as $t
+        }
+
+        fn add(self, other: Self) -> Self {
+          self.wrapping_add(other)
+        }
+
+        fn sub(self, other: Self) -> Self {
+          self.wrapping_sub(other)
+        }
+
+        fn mul(self, other: Self) -> Self {
+          self.wrapping_mul(other)
+        }
+
+        fn div(self, other: Self) -> Option<Self> {
+          if other == 0 { None } else { Some(self.wrapping_div(other)) }
+        }
+
+        fn pow(self, exponent: Self) -> Option<Self> {
+          match u32::try_from(exponent) {
+            Ok(exponent) => self.checked_pow(exponent),
+            // Exponents this large only don't overflow for -1, 0 and 1, whose powers only depend on the parity
+            Err(_) if exponent > 0 && self.checked_pow(2).is_some_and(|square| square <= 1) => {
+              self.checked_pow(if exponent % 2 == 0 { 2 } else { 1 })
+            },
+            Err(_) => None,
+          }
+        }
+      }
+    )*
+  };
+}
+
+macro_rules! impl_numeric_float {
+  ($($t:ty),*) => {
+    $(
+      impl Numeric for $t {
+        const IS_FLOAT: bool = true;
+
+        fn to_f64(self) -> f64 {
+          self.into()
+        }
+
+        fn from_f64(value: f64) -> Self {
+          value as $t
+        }
+
+        fn add(self, other: Self) -> Self {
+          self + other
+        }
+
+        fn sub(self, other: Self) -> Self {
+          self - other
+        }
+
+        fn mul(self, other: Self) -> Self {
+          self * other
+        }
+
+        fn div(self, other: Self) -> Option<Self> {
+          Some(self / other)
+        }
+
+        fn pow(self, exponent: Self) -> Option<Self> {
+          Some(Self::from_f64(self.to_f64().powf(exponent.to_f64())))
+        }
+      }
+    )*
+  };
+}
+
+macro_rules! impl_numeric_half {
+  ($($t:ty),*) => {
+    $(
+      impl Numeric for $t {
+        const IS_FLOAT: bool = true;
+
+        fn to_f64(self) -> f64 {
+          <$t>::to_f64(self)
+        }
+
+        fn from_f64(value: f64) -> Self {
+          <$t>::from_f64(value)
+        }
+
+        fn add(self, other: Self) -> Self {
+          self + other
+        }
+
+        fn sub(self, other: Self) -> Self {
+          self - other
+        }
+
+        fn mul(self, other: Self) -> Self {
+          self * other
+        }
+
+        fn div(self, other: Self) -> Option<Self> {
+          Some(self / other)
+        }
+
+        fn pow(self, exponent: Self) -> Option<Self> {
+          Some(Self::from_f64(self.to_f64().powf(exponent.to_f64())))
+        }
+      }
+    )*
+  };
+}
+
+impl_numeric_int!(i8, i16, i32, i64, u8, u16, u32, u64);
+impl_numeric_float!(f32, f64);
+impl_numeric_half!(f16, bf16);
+
+/// Runs `$body` with `$t` bound to the Rust type of the numeric element type `$dtype`, or evaluates to an error for
+/// other element types.
+macro_rules! with_numeric_type {
+  ($dtype:expr, $t:ident => $body:expr) => {
+    match $dtype {
+      TensorElementType::Float32 => { type $t = f32; $body },
+      TensorElementType::Float64 => { type $t = f64; $body },
+      TensorElementType::Float16 => { type $t = ::half::f16; $body },
+      TensorElementType::Bfloat16 => { type $t = ::half::bf16; $body },
+      TensorElementType::Int8 => { type $t = i8; $body },
+      TensorElementType::Int16 => { type $t = i16; $body },
+      TensorElementType::Int32 => { type $t = i32; $body },
+      TensorElementType::Int64 => { type $t = i64; $body },
+      TensorElementType::Uint8 => { type $t = u8; $body },
+      TensorElementType::Uint16 => { type $t = u16; $body },
+      TensorElementType::Uint32 => { type $t = u32; $body },
+      TensorElementType::Uint64 => { type $t = u64; $body },
+      dtype => Err(::ort::Error::new(format!("Tensors of type {} are not supported by this operation", dtype))),
+    }
+  };
+}
+pub(crate) use with_numeric_type;
+
+/// The element type results of floating point operations on a tensor of type `dtype` are returned in: floating point
+/// types are kept and everything else becomes [`TensorElementType::Float32`].
+pub(crate) fn float_dtype(dtype: TensorElementType) -> TensorElementType {
+  match dtype {
+    TensorElementType::Float16 | TensorElementType::Bfloat16 | TensorElementType::Float32 | TensorElementType::Float64 => dtype,
+    _ => TensorElementType::Float32,
+  }
+}
+
+/// The elements and indices picked by [`TensorImpl::top_k`].
+pub struct TopK {
+  /// The picked elements, of the same type as the input tensor.
+  pub values: TensorImpl,
+  /// The `int64` indices of the picked elements along the axis.
+  pub indices: TensorImpl,
+}
+
+/// Splits a shape around `axis` into the number of elements before it, its size and the number of elements after it.
+fn split_at_axis(shape: &[i64], axis: usize) -> (usize, usize, usize) {
+  let outer = shape[..axis].iter().product::<i64>() as usize;
+  let inner = shape[axis + 1..].iter().product::<i64>() as usize;
+  (outer, shape[axis] as usize, inner)
+}
+
+/// Orders two elements with NaN above every number, so that `top_k` sorts NaN like NumPy does.
+fn compare_nan_first<T: Numeric>(a: T, b: T) -> Ordering {
+  match (a.is_nan(), b.is_nan()) {
+    (true, true) => Ordering::Equal,
+    (true, false) => Ordering::Greater,
+    (false, true) => Ordering::Less,
+    (false, false) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+  }
+}
+
+/// If `candidate` replaces `current` when selecting the element ordered last by `ordering`. The first NaN is selected
+/// over every number in both directions, so that `max`/`min` and `argmax`/`argmin` propagate NaN as NumPy does.
+fn selects<T: Numeric>(candidate: T, current: T, ordering: Ordering) -> bool {
+  match (candidate.is_nan(), current.is_nan()) {
+    (_, true) => false,
+    (true, false) => true,
+    (false, false) => candidate.partial_cmp(&current) == Some(ordering),
+  }
+}
+
+impl TensorImpl {
+  /// Borrows the elements of a tensor in CPU accessible memory as `T`, which must match its element type.
+  pub(crate) fn numeric_data<T: Numeric>(&self) -> Result<&[T]> {
+    if self.tensor.shape().num_elements() == 0 {
+      return Ok(&[]);
+    }
+    if !self.tensor.memory_info().is_cpu_accessible() {
+      return Err(Error::new("Tensor data is not accessible from the CPU"));
+    }
+    Ok(self.tensor.try_extract_tensor::<T>()?.1)
+  }
+
+  /// Copies the elements of a numeric tensor, converted to `f64`.
+  pub(crate) fn to_f64_data(&self) -> Result<Vec<f64>> {
+    with_numeric_type!(self.dtype(), T => Ok(self.numeric_data::<T>()?.iter().map(|v| v.to_f64()).collect()))
+  }
+
+  /// Creates a numeric tensor of type `dtype` from `f64` values.
+  pub(crate) fn from_f64_data(dtype: TensorElementType, shape: Vec<i64>, data: Vec<f64>) -> Result<TensorImpl> {
+    with_numeric_type!(dtype, T => create_tensor_from_vec(Some(shape), data.into_iter().map(T::from_f64).collect::<Vec<T>>()))
+  }
+
+  /// Applies `f` to every slice of `axis_len` elements along `axis`, writing its results in place.
+  fn map_along_axis(&self, axis: i64, f: impl Fn(&mut [f64])) -> Result<TensorImpl> {
+    let shape = self.shape();
+    let axis = normalize_axis(axis, shape.len())?;
+    let (outer, axis_len, inner) = split_at_axis(&shape, axis);
+    let mut data = self.to_f64_data()?;
+
+    let mut lane = vec![0.0; axis_len];
+    for o in 0..outer {
+      for i in 0..inner {
+        let index = |k: usize| (o * axis_len + k) * inner + i;
+        for (k, v) in lane.iter_mut().enumerate() {
+          *v = data[index(k)];
+        }
+        f(&mut lane);
+        for (k, v) in lane.iter().enumerate() {
+          data[index(k)] = *v;
+        }
+      }
+    }
+
+    TensorImpl::from_f64_data(float_dtype(self.dtype()), shape, data)
+  }
+
+  /// Computes the softmax of the tensor along `axis` (`-1` for the last one), so that every slice along it sums to 1.
+  ///
+  /// Floating point tensors keep their type; other numeric tensors produce a `float32` tensor.
+  #[frb(sync)]
+  pub fn softmax(&self, axis: i64) -> Result<TensorImpl> {
+    self.map_along_axis(axis, |lane| {
+      // Subtracting the maximum keeps `exp` from overflowing
+      let max = lane.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+      let mut sum = 0.0;
+      for v in lane.iter_mut() {
+        *v = (*v - max).exp();
+        sum += *v;
+      }
+      lane.iter_mut().for_each(|v| *v /= sum);
+    })
+  }
+
+  /// Computes the logarithm of the softmax of the tensor along `axis`, more accurately than taking the logarithm of
+  /// [`TensorImpl::softmax`].
+  #[frb(sync)]
+  pub fn log_softmax(&self, axis: i64) -> Result<TensorImpl> {
+    self.map_along_axis(axis, |lane| {
+      let max = lane.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+      let log_sum = lane.iter().map(|v| (v - max).exp()).sum::<f64>().ln() + max;
+      lane.iter_mut().for_each(|v| *v -= log_sum);
+    })
+  }
+
+  /// Computes the logistic sigmoid `1 / (1 + e^-x)` of every element.
+  #[frb(sync)]
+  pub fn sigmoid(&self) -> Result<TensorImpl> {
+    let data = self.to_f64_data()?
+      .into_iter()
+      .map(|v| 1.0 / (1.0 + (-v).exp()))
+      .collect();
+    TensorImpl::from_f64_data(float_dtype(self.dtype()), self.shape(), data)
+  }
+
+  /// Finds the index along `axis` of the first element ordered last by `ordering`, returned as an `int64` tensor.
+  fn arg_select(&self, axis: i64, keep_dims: bool, ordering: Ordering) -> Result<TensorImpl> {
+    let mut shape = self.shape();
+    let axis = normalize_axis(axis, shape.len())?;
+    let (outer, axis_len, inner) = split_at_axis(&shape, axis);
+    if axis_len == 0 {
+      return Err(Error::new("Cannot select an element along an empty axis"));
+    }
+
+    let indices = with_numeric_type!(self.dtype(), T => {
+      let data = self.numeric_data::<T>()?;
+      let mut indices = Vec::with_capacity(outer * inner);
+      for o in 0..outer {
+        for i in 0..inner {
+          let index = |k: usize| (o * axis_len + k) * inner + i;
+          let best = (1..axis_len).fold(0, |best, k| {
+            if selects(data[index(k)], data[index(best)], ordering) { k } else { best }
+          });
+          indices.push(best as i64);
+        }
+      }
+      Ok(indices)
+    })?;
+
+    if keep_dims {
+      shape[axis] = 1;
+    } else {
+      shape.remove(axis);
+    }
+    create_tensor_from_vec(Some(shape), indices)
+  }
+
+  /// Returns the indices of the largest elements along `axis` as an `int64` tensor. The first occurrence wins ties,
+  /// and NaN is treated as larger than any number.
+  ///
+  /// With `keep_dims` the reduced axis is kept with size 1, otherwise it is removed.
+  #[frb(sync)]
+  pub fn argmax(&self, axis: i64, keep_dims: bool) -> Result<TensorImpl> {
+    self.arg_select(axis, keep_dims, Ordering::Greater)
+  }
+
+  /// Returns the indices of the smallest elements along `axis` as an `int64` tensor. The first occurrence wins ties,
+  /// and NaN is picked over any number, like in [`TensorImpl::argmax`].
+  #[frb(sync)]
+  pub fn argmin(&self, axis: i64, keep_dims: bool) -> Result<TensorImpl> {
+    self.arg_select(axis, keep_dims, Ordering::Less)
+  }
+
+  /// Picks the `k` largest (or with `largest` set to `false`, smallest) elements along `axis` (`-1` if left out),
+  /// sorted from first to last picked. Equal elements are picked in order of their index, as in ONNX's `TopK`.
+  #[frb(sync)]
+  pub fn top_k(&self, k: usize, axis: Option<i64>, largest: Option<bool>) -> Result<TopK> {
+    let mut shape = self.shape();
+    let axis = normalize_axis(axis.unwrap_or(-1), shape.len())?;
+    let largest = largest.unwrap_or(true);
+    let (outer, axis_len, inner) = split_at_axis(&shape, axis);
+    if k > axis_len {
+      return Err(Error::new(format!("Cannot pick {} elements along an axis of size {}", k, axis_len)));
+    }
+    shape[axis] = k as i64;
+
+    let dtype = self.dtype();
+    with_numeric_type!(dtype, T => {
+      let data = self.numeric_data::<T>()?;
+      let mut values = vec![T::from_f64(0.0); outer * k * inner];
+      let mut indices = vec![0i64; outer * k * inner];
+      let mut order: Vec<usize> = Vec::with_capacity(axis_len);
+      for o in 0..outer {
+        for i in 0..inner {
+          let index = |k: usize| (o * axis_len + k) * inner + i;
+          order.clear();
+          order.extend(0..axis_len);
+          // A stable sort keeps equal elements in index order
+          order.sort_by(|&a, &b| {
+            let ordering = compare_nan_first(data[index(a)], data[index(b)]);
+            if largest { ordering.reverse() } else { ordering }
+          });
+          for (j, &picked) in order[..k].iter().enumerate() {
+            let out = (o * k + j) * inner + i;
+            values[out] = data[index(picked)];
+            indices[out] = picked as i64;
+          }
+        }
+      }
+
+      Ok(TopK {
+        values: create_tensor_from_vec(Some(shape.clone()), values)?,
+        indices: create_tensor_from_vec(Some(shape), indices)?,
+      })
+    })
+  }
+
+  /// Works out the output shape of a reduction and, for every element of the tensor in order, the index of the output
+  /// element it's reduced into.
+  fn reduction(&self, axes: Option<Vec<i64>>, keep_dims: bool) -> Result<(Vec<i64>, Vec<usize>, usize)> {
+    let shape = self.shape();
+    let mut reduced = vec![axes.is_none(); shape.len()];
+    for axis in axes.unwrap_or_default() {
+      reduced[normalize_axis(axis, shape.len())?] = true;
+    }
+
+    let kept_shape: Vec<i64> = shape.iter().zip(&reduced).map(|(&d, &r)| if r { 1 } else { d }).collect();
+    let out_strides: Vec<isize> = contiguous_strides(&kept_shape)
+      .into_iter()
+      .zip(&reduced)
+      .map(|(s, &r)| if r { 0 } else { s as isize })
+      .collect();
+    let targets = strided_indices(0, &shape.iter().map(|&d| d as usize).collect::<Vec<_>>(), &out_strides);
+    let out_len = kept_shape.iter().product::<i64>() as usize;
+
+    let out_shape = if keep_dims {
+      kept_shape
+    } else {
+      shape.iter().zip(&reduced).filter(|(_, &r)| !r).map(|(&d, _)| d).collect()
+    };
+    Ok((out_shape, targets, out_len))
+  }
+
+  /// Sums the elements along `axes`, or over the whole tensor if `axes` is left out. The result has the same type as
+  /// the tensor, and integers wrap around on overflow.
+  ///
+  /// With `keep_dims` the reduced axes are kept with size 1, otherwise they are removed.
+  #[frb(sync)]
+  pub fn sum(&self, axes: Option<Vec<i64>>, keep_dims: bool) -> Result<TensorImpl> {
+    let (shape, targets, out_len) = self.reduction(axes, keep_dims)?;
+    with_numeric_type!(self.dtype(), T => {
+      let mut sums = vec![T::from_f64(0.0); out_len];
+      for (&v, &target) in self.numeric_data::<T>()?.iter().zip(&targets) {
+        sums[target] = sums[target].add(v);
+      }
+      create_tensor_from_vec(Some(shape), sums)
+    })
+  }
+
+  /// Averages the elements along `axes`, or over the whole tensor if `axes` is left out. Floating point tensors keep
+  /// their type; other numeric tensors produce a `float32` tensor.
+  #[frb(sync)]
+  pub fn mean(&self, axes: Option<Vec<i64>>, keep_dims: bool) -> Result<TensorImpl> {
+    let (shape, targets, out_len) = self.reduction(axes, keep_dims)?;
+    let mut sums = vec![0.0; out_len];
+    for (v, &target) in self.to_f64_data()?.into_iter().zip(&targets) {
+      sums[target] += v;
+    }
+    let count = targets.len() / out_len.max(1);
+    let means = sums.into_iter().map(|sum| sum / count as f64).collect();
+    TensorImpl::from_f64_data(float_dtype(self.dtype()), shape, means)
+  }
+
+  fn select_reduce(&self, axes: Option<Vec<i64>>, keep_dims: bool, ordering: Ordering) -> Result<TensorImpl> {
+    let (shape, targets, out_len) = self.reduction(axes, keep_dims)?;
+    if targets.is_empty() && out_len > 0 {
+      return Err(Error::new("Cannot reduce an empty tensor"));
+    }
+    with_numeric_type!(self.dtype(), T => {
+      let mut selected: Vec<Option<T>> = vec![None; out_len];
+      for (&v, &target) in self.numeric_data::<T>()?.iter().zip(&targets) {
+        let current = &mut selected[target];
+        if current.is_none_or(|current| selects(v, current, ordering)) {
+          *current = Some(v);
+        }
+      }
+      create_tensor_from_vec(Some(shape), selected.into_iter().flatten().collect::<Vec<T>>())
+    })
+  }
+
+  /// Finds the largest elements along `axes`, or of the whole tensor if `axes` is left out. NaN is propagated.
+  #[frb(sync)]
+  pub fn max(&self, axes: Option<Vec<i64>>, keep_dims: bool) -> Result<TensorImpl> {
+    self.select_reduce(axes, keep_dims, Ordering::Greater)
+  }
+
+  /// Finds the smallest elements along `axes`, or of the whole tensor if `axes` is left out. NaN is propagated.
+  #[frb(sync)]
+  pub fn min(&self, axes: Option<Vec<i64>>, keep_dims: bool) -> Result<TensorImpl> {
+    self.select_reduce(axes, keep_dims, Ordering::Less)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ort::error::Result;
+  use crate::api::tensor::TensorImpl;
+
+  #[test]
+  fn tensor_softmax_sums_to_one() -> Result<()> {
+    let logits = TensorImpl::from_array_f32(Some(vec![2, 3]), vec![1.0, 2.0, 3.0, 1000.0, 1000.0, 1000.0])?;
+
+    let probabilities = logits.softmax(-1)?.extract_f32()?;
+    assert!((probabilities[..3].iter().sum::<f32>() - 1.0).abs() < 1e-6);
+    assert!(probabilities[0] < probabilities[1] && probabilities[1] < probabilities[2]);
+    assert!(probabilities[3..].iter().all(|&p| (p - 1.0 / 3.0).abs() < 1e-6));
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_argmax_and_top_k() -> Result<()> {
+    let tensor = TensorImpl::from_array_i32(Some(vec![2, 4]), vec![3, 9, 1, 9, 0, -2, 5, 4])?;
+
+    let argmax = tensor.argmax(1, false)?;
+    assert_eq!(argmax.shape(), vec![2]);
+    assert_eq!(argmax.extract_i64()?, vec![1, 2]);
+
+    let top = tensor.top_k(2, None, None)?;
+    assert_eq!(top.values.extract_i32()?, vec![9, 9, 5, 4]);
+    assert_eq!(top.indices.extract_i64()?, vec![1, 3, 2, 3]);
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_arg_select_picks_nan() -> Result<()> {
+    let tensor = TensorImpl::from_array_f32(Some(vec![2, 3]), vec![1.0, f32::NAN, -1.0, 2.0, 0.0, f32::NAN])?;
+
+    assert_eq!(tensor.argmax(1, false)?.extract_i64()?, vec![1, 2]);
+    assert_eq!(tensor.argmin(1, false)?.extract_i64()?, vec![1, 2]);
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_min_and_max_propagate_nan() -> Result<()> {
+    let tensor = TensorImpl::from_array_f64(Some(vec![2, 2]), vec![f64::NAN, 1.0, 3.0, -4.0])?;
+
+    let max = tensor.max(Some(vec![1]), false)?.extract_f64()?;
+    assert!(max[0].is_nan());
+    assert_eq!(max[1], 3.0);
+    let min = tensor.min(Some(vec![1]), false)?.extract_f64()?;
+    assert!(min[0].is_nan());
+    assert_eq!(min[1], -4.0);
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_reductions() -> Result<()> {
+    let tensor = TensorImpl::from_array_u8(Some(vec![2, 3]), vec![1, 2, 3, 4, 5, 6])?;
+
+    assert_eq!(tensor.sum(Some(vec![0]), false)?.extract_u8()?, vec![5, 7, 9]);
+    let mean = tensor.mean(Some(vec![-1]), true)?;
+    assert_eq!(mean.shape(), vec![2, 1]);
+    assert_eq!(mean.extract_f32()?, vec![2.0, 5.0]);
+    let max = tensor.max(None, false)?;
+    assert!(max.shape().is_empty());
+    assert_eq!(max.extract_u8()?, vec![6]);
+    Ok(())
+  }
+}
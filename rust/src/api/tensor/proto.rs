@@ -0,0 +1,505 @@
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+use flutter_rust_bridge::frb;
+use ort::{Error, Result};
+use crate::api::tensor::{element_byte_len, TensorElementType, TensorImpl};
+
+// Field numbers of ONNX's `TensorProto` message
+const DIMS: u32 = 1;
+const DATA_TYPE: u32 = 2;
+const FLOAT_DATA: u32 = 4;
+const INT32_DATA: u32 = 5;
+const STRING_DATA: u32 = 6;
+const INT64_DATA: u32 = 7;
+const NAME: u32 = 8;
+const RAW_DATA: u32 = 9;
+const DOUBLE_DATA: u32 = 10;
+const UINT64_DATA: u32 = 11;
+const EXTERNAL_DATA: u32 = 13;
+const DATA_LOCATION: u32 = 14;
+
+const VARINT: u8 = 0;
+const FIXED64: u8 = 1;
+const LEN: u8 = 2;
+const FIXED32: u8 = 5;
+
+/// The `TensorProto.DataType` value of an element type.
+fn onnx_data_type(dtype: TensorElementType) -> Result<i32> {
+  Ok(match dtype {
+    TensorElementType::Float32 => 1,
+    TensorElementType::Uint8 => 2,
+    TensorElementType::Int8 => 3,
+    TensorElementType::Uint16 => 4,
+    TensorElementType::Int16 => 5,
+    TensorElementType::Int32 => 6,
+    TensorElementType::Int64 => 7,
+    TensorElementType::String => 8,
+    TensorElementType::Bool => 9,
+    TensorElementType::Float16 => 10,
+    TensorElementType::Float64 => 11,
+    TensorElementType::Uint32 => 12,
+    TensorElementType::Uint64 => 13,
+    TensorElementType::Complex64 => 14,
+    TensorElementType::Complex128 => 15,
+    TensorElementType::Bfloat16 => 16,
+    TensorElementType::Float8E4M3FN => 17,
+    TensorElementType::Float8E4M3FNUZ => 18,
+    TensorElementType::Float8E5M2 => 19,
+    TensorElementType::Float8E5M2FNUZ => 20,
+    TensorElementType::Uint4 => 21,
+    TensorElementType::Int4 => 22,
+    dtype => return Err(Error::new(format!("Tensors of type {} can't be serialized", dtype))),
+  })
+}
+
+fn element_type(data_type: i32) -> Result<TensorElementType> {
+  Ok(match data_type {
+    1 => TensorElementType::Float32,
+    2 => TensorElementType::Uint8,
+    3 => TensorElementType::Int8,
+    4 => TensorElementType::Uint16,
+    5 => TensorElementType::Int16,
+    6 => TensorElementType::Int32,
+    7 => TensorElementType::Int64,
+    8 => TensorElementType::String,
+    9 => TensorElementType::Bool,
+    10 => TensorElementType::Float16,
+    11 => TensorElementType::Float64,
+    12 => TensorElementType::Uint32,
+    13 => TensorElementType::Uint64,
+    14 => TensorElementType::Complex64,
+    15 => TensorElementType::Complex128,
+    16 => TensorElementType::Bfloat16,
+    17 => TensorElementType::Float8E4M3FN,
+    18 => TensorElementType::Float8E4M3FNUZ,
+    19 => TensorElementType::Float8E5M2,
+    20 => TensorElementType::Float8E5M2FNUZ,
+    21 => TensorElementType::Uint4,
+    22 => TensorElementType::Int4,
+    _ => return Err(Error::new(format!("Unsupported TensorProto data type {}", data_type))),
+  })
+}
+
+/// Converts raw tensor data between this machine's byte order and the little-endian order of `raw_data`. Complex
+/// numbers are swapped per component.
+fn swap_to_little_endian(data: &mut [u8], dtype: TensorElementType) {
+  if cfg!(target_endian = "little") {
+    return;
+  }
+  let size = match dtype {
+    TensorElementType::Complex64 => 4,
+    TensorElementType::Complex128 => 8,
+    _ => element_byte_len(dtype, 1).unwrap_or(1),
+  };
+  data.chunks_exact_mut(size).for_each(|element| element.reverse());
+}
+
+/// A minimal reader for the protobuf wire format.
+struct Reader<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+  fn truncated() -> Error {
+    Error::new("Truncated TensorProto message")
+  }
+
+  fn varint(&mut self) -> Result<u64> {
+    let mut value = 0;
+    for shift in (0..64).step_by(7) {
+      let (&byte, rest) = self.data.split_first().ok_or_else(Reader::truncated)?;
+      self.data = rest;
+      value |= ((byte & 0x7f) as u64) << shift;
+      if byte & 0x80 == 0 {
+        return Ok(value);
+      }
+    }
+    Err(Error::new("Invalid varint in TensorProto message"))
+  }
+
+  fn bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+    if len > self.data.len() {
+      return Err(Reader::truncated());
+    }
+    let (bytes, rest) = self.data.split_at(len);
+    self.data = rest;
+    Ok(bytes)
+  }
+
+  /// Reads the next field number and wire type, or `None` at the end of the message.
+  fn tag(&mut self) -> Result<Option<(u32, u8)>> {
+    if self.data.is_empty() {
+      return Ok(None);
+    }
+    let tag = self.varint()?;
+    Ok(Some(((tag >> 3) as u32, (tag & 7) as u8)))
+  }
+
+  fn length_delimited(&mut self) -> Result<&'a [u8]> {
+    let len = self.varint()? as usize;
+    self.bytes(len)
+  }
+
+  fn skip(&mut self, wire_type: u8) -> Result<()> {
+    match wire_type {
+      VARINT => self.varint().map(|_| ()),
+      FIXED64 => self.bytes(8).map(|_| ()),
+      LEN => self.length_delimited().map(|_| ()),
+      FIXED32 => self.bytes(4).map(|_| ()),
+      _ => Err(Error::new(format!("Unsupported protobuf wire type {}", wire_type))),
+    }
+  }
+
+  /// Reads the values of a repeated scalar field with elements of wire type `element_wire_type`, which may be packed
+  /// into one length-delimited field or not.
+  fn repeated(&mut self, wire_type: u8, element_wire_type: u8, values: &mut Vec<u64>) -> Result<()> {
+    if wire_type != LEN {
+      values.push(self.scalar(wire_type)?);
+      return Ok(());
+    }
+
+    let mut packed = Reader { data: self.length_delimited()? };
+    while !packed.data.is_empty() {
+      values.push(packed.scalar(element_wire_type)?);
+    }
+    Ok(())
+  }
+
+  fn scalar(&mut self, wire_type: u8) -> Result<u64> {
+    Ok(match wire_type {
+      VARINT => self.varint()?,
+      FIXED64 => u64::from_le_bytes(self.bytes(8)?.try_into().unwrap()),
+      FIXED32 => u32::from_le_bytes(self.bytes(4)?.try_into().unwrap()) as u64,
+      _ => return Err(Error::new(format!("Unexpected protobuf wire type {}", wire_type))),
+    })
+  }
+}
+
+/// A minimal writer for the protobuf wire format.
+#[derive(Default)]
+struct Writer {
+  data: Vec<u8>,
+}
+
+impl Writer {
+  fn varint(&mut self, mut value: u64) {
+    while value >= 0x80 {
+      self.data.push(value as u8 | 0x80);
+      value >>= 7;
+    }
+    self.data.push(value as u8);
+  }
+
+  fn tag(&mut self, field: u32, wire_type: u8) {
+    self.varint(((field as u64) << 3) | wire_type as u64);
+  }
+
+  fn varint_field(&mut self, field: u32, value: u64) {
+    self.tag(field, VARINT);
+    self.varint(value);
+  }
+
+  fn bytes_field(&mut self, field: u32, bytes: &[u8]) {
+    self.tag(field, LEN);
+    self.varint(bytes.len() as u64);
+    self.data.extend_from_slice(bytes);
+  }
+}
+
+/// The fields of a decoded `TensorProto` that are needed to recreate the tensor.
+#[derive(Default)]
+struct TensorProto<'a> {
+  name: String,
+  dims: Vec<u64>,
+  data_type: i32,
+  float_data: Vec<u64>,
+  int32_data: Vec<u64>,
+  string_data: Vec<&'a [u8]>,
+  int64_data: Vec<u64>,
+  raw_data: Option<&'a [u8]>,
+  double_data: Vec<u64>,
+  uint64_data: Vec<u64>,
+  external_data: HashMap<String, String>,
+  external: bool,
+}
+
+impl<'a> TensorProto<'a> {
+  fn decode(data: &'a [u8]) -> Result<TensorProto<'a>> {
+    let mut proto = TensorProto::default();
+    let mut reader = Reader { data };
+    while let Some((field, wire_type)) = reader.tag()? {
+      match (field, wire_type) {
+        (DIMS, _) => reader.repeated(wire_type, VARINT, &mut proto.dims)?,
+        (DATA_TYPE, VARINT) => proto.data_type = reader.varint()? as i32,
+        (FLOAT_DATA, _) => reader.repeated(wire_type, FIXED32, &mut proto.float_data)?,
+        (INT32_DATA, _) => reader.repeated(wire_type, VARINT, &mut proto.int32_data)?,
+        (STRING_DATA, LEN) => proto.string_data.push(reader.length_delimited()?),
+        (INT64_DATA, _) => reader.repeated(wire_type, VARINT, &mut proto.int64_data)?,
+        (NAME, LEN) => {
+          proto.name = String::from_utf8(reader.length_delimited()?.to_vec())
+            .map_err(|_| Error::new("Invalid UTF-8 in TensorProto name"))?;
+        },
+        (RAW_DATA, LEN) => proto.raw_data = Some(reader.length_delimited()?),
+        (DOUBLE_DATA, _) => reader.repeated(wire_type, FIXED64, &mut proto.double_data)?,
+        (UINT64_DATA, _) => reader.repeated(wire_type, VARINT, &mut proto.uint64_data)?,
+        (EXTERNAL_DATA, LEN) => {
+          // A `StringStringEntryProto` with the key in field 1 and the value in field 2
+          let mut entry = Reader { data: reader.length_delimited()? };
+          let (mut key, mut value) = (String::new(), String::new());
+          while let Some((field, wire_type)) = entry.tag()? {
+            match (field, wire_type) {
+              (1, LEN) => key = String::from_utf8_lossy(entry.length_delimited()?).into_owned(),
+              (2, LEN) => value = String::from_utf8_lossy(entry.length_delimited()?).into_owned(),
+              _ => entry.skip(wire_type)?,
+            }
+          }
+          proto.external_data.insert(key, value);
+        },
+        (DATA_LOCATION, VARINT) => proto.external = reader.varint()? == 1,
+        _ => reader.skip(wire_type)?,
+      }
+    }
+    Ok(proto)
+  }
+
+  /// Reads the `byte_len` bytes of tensor data stored in an external file, relative to `base_dir`.
+  ///
+  /// The location, offset and length come from the proto, so they're checked not to leave `base_dir` and to lie
+  /// within the file.
+  fn read_external_data(&self, base_dir: Option<&Path>, byte_len: usize) -> Result<Vec<u8>> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    let location = self.external_data
+      .get("location")
+      .ok_or_else(|| Error::new("External TensorProto data has no location"))?;
+    if !Path::new(location).components().all(|c| matches!(c, Component::Normal(_) | Component::CurDir)) {
+      return Err(Error::new(format!(
+        "External data location '{}' must be a relative path inside its directory",
+        location
+      )));
+    }
+    let parse = |key: &str| -> Result<Option<u64>> {
+      self.external_data
+        .get(key)
+        .map(|v| v.parse().map_err(|_| Error::new(format!("Invalid external data {}: {}", key, v))))
+        .transpose()
+    };
+    let (offset, length) = (parse("offset")?.unwrap_or(0), parse("length")?.unwrap_or(byte_len as u64));
+    if length != byte_len as u64 {
+      return Err(Error::new(format!(
+        "External data length ({}) does not match the {} bytes the tensor holds",
+        length,
+        byte_len
+      )));
+    }
+
+    let path = base_dir.map_or_else(|| PathBuf::from(location), |dir| dir.join(location));
+    let failed = |e: std::io::Error| Error::new(format!("Failed to read external data from '{}': {}", path.display(), e));
+    let mut file = std::fs::File::open(&path).map_err(failed)?;
+    let file_len = file.metadata().map_err(failed)?.len();
+    if offset.checked_add(length).is_none_or(|end| end > file_len) {
+      return Err(Error::new(format!(
+        "External data at offset {} with length {} lies outside of '{}' ({} bytes)",
+        offset,
+        length,
+        path.display(),
+        file_len
+      )));
+    }
+    file.seek(SeekFrom::Start(offset)).map_err(failed)?;
+    let mut data = vec![0; byte_len];
+    file.read_exact(&mut data).map_err(failed)?;
+    Ok(data)
+  }
+
+  fn into_tensor(self, base_dir: Option<&Path>) -> Result<TensorImpl> {
+    let dtype = element_type(self.data_type)?;
+    let shape: Vec<i64> = self.dims.iter().map(|&d| d as i64).collect();
+
+    if dtype == TensorElementType::String {
+      let strings = self.string_data
+        .iter()
+        .map(|s| String::from_utf8(s.to_vec()).map_err(|_| Error::new("Invalid UTF-8 in TensorProto string data")))
+        .collect::<Result<Vec<_>>>()?;
+      return TensorImpl::from_array_string(Some(shape), strings);
+    }
+
+    let mut data = if self.external {
+      self.read_external_data(base_dir, TensorImpl::raw_byte_len(dtype, &shape)?)?
+    } else if let Some(raw_data) = self.raw_data {
+      raw_data.to_vec()
+    } else {
+      // Typed fields hold each element in the smallest field type it fits in
+      let (values, size) = match dtype {
+        TensorElementType::Float32 | TensorElementType::Complex64 => (&self.float_data, 4),
+        TensorElementType::Float64 | TensorElementType::Complex128 => (&self.double_data, 8),
+        TensorElementType::Int64 => (&self.int64_data, 8),
+        TensorElementType::Uint64 => (&self.uint64_data, 8),
+        TensorElementType::Uint32 => (&self.uint64_data, 4),
+        TensorElementType::Int32 => (&self.int32_data, 4),
+        TensorElementType::Int16 | TensorElementType::Uint16 | TensorElementType::Float16 | TensorElementType::Bfloat16 => {
+          (&self.int32_data, 2)
+        },
+        // 8-bit types, and 4-bit types with two elements packed in each value
+        _ => (&self.int32_data, 1),
+      };
+      let mut data = Vec::with_capacity(values.len() * size);
+      for &value in values {
+        data.extend_from_slice(&value.to_le_bytes()[..size]);
+      }
+      data
+    };
+
+    swap_to_little_endian(&mut data, dtype);
+    TensorImpl::from_raw_data(dtype, &shape, &data, true)
+  }
+}
+
+impl TensorImpl {
+  /// Decodes a serialized ONNX `TensorProto`, such as the `input_0.pb` and `output_0.pb` files of the ONNX test data
+  /// layout.
+  ///
+  /// Data stored in `raw_data`, in the typed fields (`float_data`, `int32_data`, ...) or in `string_data` is
+  /// supported, as is external data, whose location is resolved relative to `base_dir`.
+  #[frb(sync)]
+  pub fn from_tensor_proto(bytes: Vec<u8>, base_dir: Option<String>) -> Result<TensorImpl> {
+    TensorProto::decode(&bytes)?.into_tensor(base_dir.as_deref().map(Path::new))
+  }
+
+  /// Serializes the tensor as an ONNX `TensorProto` with the given name. Fixed-width data is written to `raw_data` and
+  /// strings to `string_data`.
+  #[frb(sync)]
+  pub fn to_tensor_proto(&self, name: Option<String>) -> Result<Vec<u8>> {
+    let dtype = self.dtype();
+    let mut writer = Writer::default();
+    for d in self.shape() {
+      writer.varint_field(DIMS, d as u64);
+    }
+    writer.varint_field(DATA_TYPE, onnx_data_type(dtype)? as u64);
+    if dtype == TensorElementType::String {
+      for s in self.extract_string()? {
+        writer.bytes_field(STRING_DATA, s.as_bytes());
+      }
+    }
+    if let Some(name) = name {
+      writer.bytes_field(NAME, name.as_bytes());
+    }
+    if dtype != TensorElementType::String {
+      let mut data = self.raw_data()?.to_vec();
+      swap_to_little_endian(&mut data, dtype);
+      writer.bytes_field(RAW_DATA, &data);
+    }
+    Ok(writer.data)
+  }
+
+  /// Loads a tensor from a `TensorProto` file, resolving external data relative to the file's directory.
+  pub fn load_tensor_proto(path: String) -> Result<TensorImpl> {
+    let bytes = std::fs::read(&path).map_err(|e| Error::new(format!("Failed to read '{}': {}", path, e)))?;
+    TensorProto::decode(&bytes)?.into_tensor(Path::new(&path).parent())
+  }
+
+  /// Saves the tensor to a `TensorProto` file, see [`TensorImpl::to_tensor_proto`].
+  pub fn save_tensor_proto(&self, path: String, name: Option<String>) -> Result<()> {
+    std::fs::write(&path, self.to_tensor_proto(name)?).map_err(|e| Error::new(format!("Failed to write '{}': {}", path, e)))
+  }
+}
+
+/// Loads several `TensorProto` files, e.g. all `input_*.pb` files of an ONNX test data set, keyed by the names stored
+/// in them so they can be passed straight to `SessionImpl::run`.
+pub fn load_tensor_protos(paths: Vec<String>) -> Result<HashMap<String, TensorImpl>> {
+  let mut tensors = HashMap::with_capacity(paths.len());
+  for path in paths {
+    let bytes = std::fs::read(&path).map_err(|e| Error::new(format!("Failed to read '{}': {}", path, e)))?;
+    let proto = TensorProto::decode(&bytes)?;
+    if proto.name.is_empty() {
+      return Err(Error::new(format!("The TensorProto in '{}' has no name", path)));
+    }
+    let name = proto.name.clone();
+    tensors.insert(name, proto.into_tensor(Path::new(&path).parent())?);
+  }
+  Ok(tensors)
+}
+
+#[cfg(test)]
+mod tests {
+  use ort::error::Result;
+  use crate::api::tensor::proto::{
+    Writer, DATA_LOCATION, DATA_TYPE, DIMS, EXTERNAL_DATA, FLOAT_DATA, INT32_DATA, LEN,
+  };
+  use crate::api::tensor::TensorImpl;
+
+  #[test]
+  fn tensor_proto_round_trip() -> Result<()> {
+    let tensor = TensorImpl::from_array_i64(Some(vec![2, 2]), vec![1, -2, 3, i64::MIN])?;
+
+    let decoded = TensorImpl::from_tensor_proto(tensor.to_tensor_proto(Some("x".to_string()))?, None)?;
+    assert_eq!(decoded.shape(), vec![2, 2]);
+    assert_eq!(decoded.extract_i64()?, vec![1, -2, 3, i64::MIN]);
+
+    let strings = TensorImpl::from_array_string(None, vec!["a".to_string(), "bc".to_string()])?;
+    let decoded = TensorImpl::from_tensor_proto(strings.to_tensor_proto(None)?, None)?;
+    assert_eq!(decoded.extract_string()?, vec!["a", "bc"]);
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_proto_typed_fields() -> Result<()> {
+    // float_data packed, as written by `onnx.numpy_helper.from_array` for small tensors
+    let mut writer = Writer::default();
+    writer.varint_field(DIMS, 2);
+    writer.varint_field(DATA_TYPE, 1);
+    let packed: Vec<u8> = [1.5f32, -2.0].iter().flat_map(|f| f.to_le_bytes()).collect();
+    writer.bytes_field(FLOAT_DATA, &packed);
+    let tensor = TensorImpl::from_tensor_proto(writer.data, None)?;
+    assert_eq!(tensor.extract_f32()?, vec![1.5, -2.0]);
+
+    // int8 values unpacked in int32_data, with negative values sign-extended to 10 byte varints
+    let mut writer = Writer::default();
+    writer.varint_field(DIMS, 2);
+    writer.varint_field(DATA_TYPE, 3);
+    writer.varint_field(INT32_DATA, -5i64 as u64);
+    writer.varint_field(INT32_DATA, 7);
+    let tensor = TensorImpl::from_tensor_proto(writer.data, None)?;
+    assert_eq!(tensor.extract_i8()?, vec![-5, 7]);
+
+    assert!(TensorImpl::from_tensor_proto(vec![(DIMS << 3) as u8 | LEN, 5], None).is_err());
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_proto_external_data_is_checked() -> Result<()> {
+    let dir = std::env::temp_dir().join("ort_dart_tensor_proto_external_data");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("data.bin"), [0u8; 8]).unwrap();
+    let base_dir = Some(dir.to_string_lossy().into_owned());
+
+    // Float tensors whose data is stored externally
+    let proto = |dims: &[u64], entries: &[(&str, &str)]| {
+      let mut writer = Writer::default();
+      for &d in dims {
+        writer.varint_field(DIMS, d);
+      }
+      writer.varint_field(DATA_TYPE, 1);
+      for (key, value) in entries {
+        let mut entry = Writer::default();
+        entry.bytes_field(1, key.as_bytes());
+        entry.bytes_field(2, value.as_bytes());
+        writer.bytes_field(EXTERNAL_DATA, &entry.data);
+      }
+      writer.varint_field(DATA_LOCATION, 1);
+      writer.data
+    };
+    let read = |dims: &[u64], entries: &[(&str, &str)]| {
+      TensorImpl::from_tensor_proto(proto(dims, entries), base_dir.clone()).unwrap_err().to_string()
+    };
+
+    for location in ["../data.bin", "/etc/passwd", "nested/../../data.bin"] {
+      assert!(read(&[2], &[("location", location)]).contains("relative path"));
+    }
+    assert!(read(&[2], &[("location", "data.bin"), ("length", "4096")]).contains("does not match"));
+    assert!(read(&[2], &[("location", "data.bin"), ("offset", "4")]).contains("outside"));
+    assert!(read(&[2], &[("location", "data.bin"), ("offset", &u64::MAX.to_string())]).contains("outside"));
+    assert!(read(&[1 << 40, 1 << 40], &[("location", "data.bin")]).contains("too many"));
+    Ok(())
+  }
+}
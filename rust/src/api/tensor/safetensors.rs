@@ -0,0 +1,211 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::Arc;
+use flutter_rust_bridge::frb;
+use memmap2::Mmap;
+use ort::{Error, Result};
+use safetensors::tensor::{Dtype, TensorView, View};
+use safetensors::SafeTensors;
+use crate::api::tensor::{element_byte_len, TensorElementType, TensorImpl};
+
+fn element_type(dtype: Dtype) -> Result<TensorElementType> {
+  Ok(match dtype {
+    Dtype::BOOL => TensorElementType::Bool,
+    Dtype::U8 => TensorElementType::Uint8,
+    Dtype::I8 => TensorElementType::Int8,
+    Dtype::F8_E5M2 => TensorElementType::Float8E5M2,
+    Dtype::F8_E4M3 => TensorElementType::Float8E4M3FN,
+    Dtype::I16 => TensorElementType::Int16,
+    Dtype::U16 => TensorElementType::Uint16,
+    Dtype::F16 => TensorElementType::Float16,
+    Dtype::BF16 => TensorElementType::Bfloat16,
+    Dtype::I32 => TensorElementType::Int32,
+    Dtype::U32 => TensorElementType::Uint32,
+    Dtype::F32 => TensorElementType::Float32,
+    Dtype::F64 => TensorElementType::Float64,
+    Dtype::I64 => TensorElementType::Int64,
+    Dtype::U64 => TensorElementType::Uint64,
+    dtype => return Err(Error::new(format!("Unsupported safetensors dtype {:?}", dtype))),
+  })
+}
+
+fn safetensors_dtype(dtype: TensorElementType) -> Result<Dtype> {
+  Ok(match dtype {
+    TensorElementType::Bool => Dtype::BOOL,
+    TensorElementType::Uint8 => Dtype::U8,
+    TensorElementType::Int8 => Dtype::I8,
+    TensorElementType::Float8E5M2 => Dtype::F8_E5M2,
+    TensorElementType::Float8E4M3FN => Dtype::F8_E4M3,
+    TensorElementType::Int16 => Dtype::I16,
+    TensorElementType::Uint16 => Dtype::U16,
+    TensorElementType::Float16 => Dtype::F16,
+    TensorElementType::Bfloat16 => Dtype::BF16,
+    TensorElementType::Int32 => Dtype::I32,
+    TensorElementType::Uint32 => Dtype::U32,
+    TensorElementType::Float32 => Dtype::F32,
+    TensorElementType::Float64 => Dtype::F64,
+    TensorElementType::Int64 => Dtype::I64,
+    TensorElementType::Uint64 => Dtype::U64,
+    dtype => return Err(Error::new(format!("Tensors of type {} can't be stored in safetensors", dtype))),
+  })
+}
+
+fn invalid(e: safetensors::SafeTensorError) -> Error {
+  Error::new(format!("Invalid safetensors data: {:?}", e))
+}
+
+/// Safetensors data is little-endian, so on big-endian machines every element is byte swapped.
+fn to_native_order(mut data: Vec<u8>, dtype: TensorElementType) -> Vec<u8> {
+  if cfg!(target_endian = "big") {
+    let size = element_byte_len(dtype, 1).unwrap_or(1);
+    data.chunks_exact_mut(size).for_each(|element| element.reverse());
+  }
+  data
+}
+
+fn shape_of(view: &TensorView) -> Vec<i64> {
+  view.shape().iter().map(|&d| d as i64).collect()
+}
+
+fn copy_tensor(view: &TensorView) -> Result<TensorImpl> {
+  let dtype = element_type(view.dtype())?;
+  TensorImpl::from_raw_data(dtype, &shape_of(view), &to_native_order(view.data().to_vec(), dtype), true)
+}
+
+/// Reads the tensors of a `.safetensors` file held in memory, keyed by name. The data is copied into new tensors.
+#[frb(sync)]
+pub fn read_safetensors(bytes: Vec<u8>) -> Result<HashMap<String, TensorImpl>> {
+  SafeTensors::deserialize(&bytes)
+    .map_err(invalid)?
+    .tensors()
+    .iter()
+    .map(|(name, view)| Ok((name.clone(), copy_tensor(view)?)))
+    .collect()
+}
+
+/// Loads the tensors of a `.safetensors` file, keyed by name.
+///
+/// The file is memory-mapped and, where the data is suitably aligned, the tensors read it directly without copying.
+/// Such tensors are immutable and keep the file mapped until the last of them is dropped; the file must not be
+/// modified in the meantime.
+pub fn load_safetensors(path: String) -> Result<HashMap<String, TensorImpl>> {
+  let file = std::fs::File::open(&path).map_err(|e| Error::new(format!("Failed to open '{}': {}", path, e)))?;
+  let mmap = unsafe { Mmap::map(&file) }.map_err(|e| Error::new(format!("Failed to map '{}': {}", path, e)))?;
+  let mmap = Arc::new(mmap);
+  let safetensors = SafeTensors::deserialize(&mmap).map_err(invalid)?;
+  let memory_info = ort::memory::MemoryInfo::default();
+
+  let mut tensors = HashMap::new();
+  for (name, view) in safetensors.tensors() {
+    let dtype = element_type(view.dtype())?;
+    let data = view.data();
+    let aligned = data.as_ptr().align_offset(element_byte_len(dtype, 1).unwrap_or(1)) == 0;
+    let tensor = if cfg!(target_endian = "little") && aligned && !data.is_empty() {
+      unsafe {
+        TensorImpl::from_borrowed_data(
+          dtype,
+          &shape_of(&view),
+          data.as_ptr().cast_mut().cast(),
+          data.len(),
+          &memory_info,
+          mmap.clone(),
+          false,
+        )?
+      }
+    } else {
+      copy_tensor(&view)?
+    };
+    tensors.insert(name, tensor);
+  }
+  Ok(tensors)
+}
+
+/// A tensor's data in the form `safetensors` serializes.
+struct SafetensorsView {
+  dtype: Dtype,
+  shape: Vec<usize>,
+  data: Vec<u8>,
+}
+
+impl View for SafetensorsView {
+  fn dtype(&self) -> Dtype {
+    self.dtype
+  }
+
+  fn shape(&self) -> &[usize] {
+    &self.shape
+  }
+
+  fn data(&self) -> Cow<'_, [u8]> {
+    Cow::Borrowed(&self.data)
+  }
+
+  fn data_len(&self) -> usize {
+    self.data.len()
+  }
+}
+
+fn views(tensors: &HashMap<String, TensorImpl>) -> Result<Vec<(&str, SafetensorsView)>> {
+  tensors
+    .iter()
+    .map(|(name, tensor)| {
+      let dtype = tensor.dtype();
+      let view = SafetensorsView {
+        dtype: safetensors_dtype(dtype)?,
+        shape: tensor.shape().iter().map(|&d| d as usize).collect(),
+        // Swapping is its own inverse, so this converts to little-endian as well
+        data: to_native_order(tensor.raw_data()?.to_vec(), dtype),
+      };
+      Ok((name.as_str(), view))
+    })
+    .collect()
+}
+
+/// Serializes tensors in the `.safetensors` format, with optional string metadata stored in the header.
+#[frb(sync)]
+pub fn write_safetensors(tensors: HashMap<String, TensorImpl>, metadata: Option<HashMap<String, String>>) -> Result<Vec<u8>> {
+  safetensors::serialize(views(&tensors)?, &metadata).map_err(invalid)
+}
+
+/// Saves tensors to a `.safetensors` file, see [`write_safetensors`].
+pub fn save_safetensors(tensors: HashMap<String, TensorImpl>, path: String, metadata: Option<HashMap<String, String>>) -> Result<()> {
+  safetensors::serialize_to_file(views(&tensors)?, &metadata, std::path::Path::new(&path))
+    .map_err(|e| Error::new(format!("Failed to write '{}': {:?}", path, e)))
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+  use ort::error::Result;
+  use crate::api::tensor::safetensors::{load_safetensors, read_safetensors, save_safetensors, write_safetensors};
+  use crate::api::tensor::TensorImpl;
+
+  fn tensors() -> Result<HashMap<String, TensorImpl>> {
+    let mut tensors = HashMap::new();
+    tensors.insert("embedding".to_string(), TensorImpl::from_array_f32(Some(vec![2, 2]), vec![0.5, 1.5, 2.5, 3.5])?);
+    tensors.insert("ids".to_string(), TensorImpl::from_array_i64(None, vec![7, 8, 9])?);
+    Ok(tensors)
+  }
+
+  #[test]
+  fn safetensors_round_trip() -> Result<()> {
+    let tensors = read_safetensors(write_safetensors(tensors()?, None)?)?;
+    assert_eq!(tensors["embedding"].shape(), vec![2, 2]);
+    assert_eq!(tensors["embedding"].extract_f32()?, vec![0.5, 1.5, 2.5, 3.5]);
+    assert_eq!(tensors["ids"].extract_i64()?, vec![7, 8, 9]);
+    Ok(())
+  }
+
+  #[test]
+  fn safetensors_load_memory_mapped() -> Result<()> {
+    let path = std::env::temp_dir().join(format!("ort_dart_{}.safetensors", std::process::id()));
+    let path = path.to_string_lossy().into_owned();
+    save_safetensors(tensors()?, path.clone(), None)?;
+
+    let tensors = load_safetensors(path.clone())?;
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(tensors["ids"].extract_i64()?, vec![7, 8, 9]);
+    assert!(!tensors["embedding"].is_mutable());
+    Ok(())
+  }
+}
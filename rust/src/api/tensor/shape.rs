@@ -0,0 +1,443 @@
+use std::borrow::Cow;
+use std::ops::Range;
+use std::sync::Arc;
+use flutter_rust_bridge::frb;
+use ort::{Error, Result};
+use crate::api::tensor::{element_byte_len, TensorElementType, TensorImpl};
+
+/// The row-major strides, in elements, of a tensor with the given shape.
+pub(crate) fn contiguous_strides(shape: &[i64]) -> Vec<usize> {
+  let mut strides = vec![1; shape.len()];
+  for i in (0..shape.len().saturating_sub(1)).rev() {
+    strides[i] = strides[i + 1] * shape[i + 1].max(0) as usize;
+  }
+  strides
+}
+
+/// Resolves a possibly negative axis, counted from the end as in ONNX, against a tensor of the given rank.
+pub(crate) fn normalize_axis(axis: i64, rank: usize) -> Result<usize> {
+  let rank = rank as i64;
+  if axis < -rank || axis >= rank {
+    return Err(Error::new(format!("Axis {} is out of range for a tensor of rank {}", axis, rank)));
+  }
+  Ok(if axis < 0 { axis + rank } else { axis } as usize)
+}
+
+/// The flat index of every element of a strided view, in row-major order.
+pub(crate) fn strided_indices(offset: isize, shape: &[usize], strides: &[isize]) -> Vec<usize> {
+  let len = shape.iter().product::<usize>();
+  let mut indices = Vec::with_capacity(len);
+  if len == 0 {
+    return indices;
+  }
+
+  let mut position = vec![0; shape.len()];
+  let mut index = offset;
+  for _ in 0..len {
+    indices.push(index as usize);
+    // Advance the last dimension and carry into the ones before it, like an odometer
+    for dim in (0..shape.len()).rev() {
+      position[dim] += 1;
+      index += strides[dim];
+      if position[dim] < shape[dim] {
+        break;
+      }
+      index -= strides[dim] * shape[dim] as isize;
+      position[dim] = 0;
+    }
+  }
+  indices
+}
+
+/// The elements of a tensor in a form that can be rearranged independently of their type.
+enum Elements<'a> {
+  /// Fixed-width elements of `size` bytes each. 4-bit elements are unpacked to one byte each.
+  Fixed { bytes: Cow<'a, [u8]>, size: usize },
+  Strings(Vec<String>),
+}
+
+impl<'a> Elements<'a> {
+  fn read(tensor: &'a TensorImpl) -> Result<Elements<'a>> {
+    let dtype = tensor.dtype();
+    Ok(match dtype {
+      TensorElementType::String => Elements::Strings(tensor.tensor.try_extract_strings()?.1),
+      TensorElementType::Int4 | TensorElementType::Uint4 => {
+        let len = tensor.tensor.shape().num_elements();
+        let bytes = tensor.raw_data()?
+          .iter()
+          .flat_map(|b| [b & 0x0f, b >> 4])
+          .take(len)
+          .collect::<Vec<_>>();
+        Elements::Fixed { bytes: Cow::Owned(bytes), size: 1 }
+      },
+      dtype => Elements::Fixed {
+        bytes: Cow::Borrowed(tensor.raw_data()?),
+        size: element_byte_len(dtype, 1).unwrap_or_default(),
+      },
+    })
+  }
+
+  /// An empty list of elements of the same kind, with room for `capacity` of them.
+  fn empty_like(&self, capacity: usize) -> Elements<'static> {
+    match self {
+      Elements::Fixed { size, .. } => Elements::Fixed { bytes: Cow::Owned(Vec::with_capacity(capacity * size)), size: *size },
+      Elements::Strings(_) => Elements::Strings(Vec::with_capacity(capacity)),
+    }
+  }
+
+  fn gather(&self, indices: &[usize]) -> Elements<'static> {
+    let mut gathered = self.empty_like(indices.len());
+    for &index in indices {
+      gathered.extend_from(self, index..index + 1);
+    }
+    gathered
+  }
+
+  /// Appends the elements of `other` in `range`, which must be of the same kind.
+  fn extend_from(&mut self, other: &Elements, range: Range<usize>) {
+    match (self, other) {
+      (Elements::Fixed { bytes, size }, Elements::Fixed { bytes: other, .. }) => {
+        bytes.to_mut().extend_from_slice(&other[range.start * *size..range.end * *size]);
+      },
+      (Elements::Strings(strings), Elements::Strings(other)) => strings.extend_from_slice(&other[range]),
+      _ => unreachable!("elements of different kinds"),
+    }
+  }
+
+  fn into_tensor(self, dtype: TensorElementType, shape: &[i64]) -> Result<TensorImpl> {
+    match self {
+      Elements::Strings(strings) => TensorImpl::from_array_string(Some(shape.to_vec()), strings),
+      Elements::Fixed { bytes, .. } => match dtype {
+        TensorElementType::Int4 | TensorElementType::Uint4 => {
+          let packed = bytes
+            .chunks(2)
+            .map(|pair| pair[0] | pair.get(1).map_or(0, |hi| hi << 4))
+            .collect::<Vec<_>>();
+          TensorImpl::from_raw_data(dtype, shape, &packed, true)
+        },
+        _ => TensorImpl::from_raw_data(dtype, shape, &bytes, true),
+      },
+    }
+  }
+}
+
+impl TensorImpl {
+  /// Creates a tensor with a different shape that shares this tensor's data. Falls back to copying for strings and
+  /// empty tensors, which have no data buffer to share.
+  fn with_shape(&self, shape: Vec<i64>) -> Result<TensorImpl> {
+    let dtype = self.dtype();
+    let num_elements = self.tensor.shape().num_elements();
+    if dtype == TensorElementType::String {
+      return TensorImpl::from_array_string(Some(shape), self.tensor.try_extract_strings()?.1);
+    }
+    let byte_len = element_byte_len(dtype, num_elements)
+      .ok_or_else(|| Error::new(format!("Tensors of type {} cannot be reshaped", dtype)))?;
+    if byte_len == 0 {
+      return TensorImpl::from_raw_data(dtype, &shape, &[], self.mutable);
+    }
+
+    // The new value only points into the data, so the tensor that owns it is kept alive alongside the view
+    let source = self.shared_tensor();
+    let (data, memory_info) = (source.data_ptr().cast_mut(), source.memory_info().clone());
+    unsafe {
+      TensorImpl::from_borrowed_data(
+        dtype,
+        &shape,
+        data,
+        byte_len,
+        &memory_info,
+        Arc::new((source, self.backing.clone())),
+        self.mutable,
+      )
+    }
+  }
+
+  /// Returns a view of this tensor with a new shape and the same number of elements. One dimension may be `-1` to
+  /// have it inferred from the others.
+  ///
+  /// The view shares this tensor's data, so writes through either one are visible in both.
+  #[frb(sync)]
+  pub fn reshape(&self, shape: Vec<i64>) -> Result<TensorImpl> {
+    let shape = TensorImpl::parse_shape_len(Some(shape), self.tensor.shape().num_elements())?;
+    self.with_shape(shape)
+  }
+
+  /// Returns a copy of this tensor with its dimensions reordered, so that dimension `i` of the result is dimension
+  /// `axes[i]` of this tensor. For example `[0, 3, 1, 2]` turns an NHWC tensor into an NCHW one.
+  #[frb(sync)]
+  pub fn permute(&self, axes: Vec<i64>) -> Result<TensorImpl> {
+    let shape = self.shape();
+    if axes.len() != shape.len() {
+      return Err(Error::new(format!("Expected {} axes to permute a tensor of shape {:?}, got {:?}", shape.len(), shape, axes)));
+    }
+    let axes = axes
+      .iter()
+      .map(|&axis| normalize_axis(axis, shape.len()))
+      .collect::<Result<Vec<_>>>()?;
+    let mut seen = vec![false; shape.len()];
+    for &axis in &axes {
+      if std::mem::replace(&mut seen[axis], true) {
+        return Err(Error::new(format!("Axis {} appears more than once in the permutation", axis)));
+      }
+    }
+
+    let strides = contiguous_strides(&shape);
+    let new_shape: Vec<i64> = axes.iter().map(|&axis| shape[axis]).collect();
+    let indices = strided_indices(
+      0,
+      &new_shape.iter().map(|&d| d as usize).collect::<Vec<_>>(),
+      &axes.iter().map(|&axis| strides[axis] as isize).collect::<Vec<_>>(),
+    );
+
+    Elements::read(self)?.gather(&indices).into_tensor(self.dtype(), &new_shape)
+  }
+
+  /// Returns a copy of this tensor with the order of its dimensions reversed.
+  #[frb(sync)]
+  pub fn transpose(&self) -> Result<TensorImpl> {
+    self.permute((0..self.shape().len() as i64).rev().collect())
+  }
+
+  /// Returns a view of this tensor without the given dimensions of size 1, or without every dimension of size 1 if
+  /// `axes` is left out.
+  #[frb(sync)]
+  pub fn squeeze(&self, axes: Option<Vec<i64>>) -> Result<TensorImpl> {
+    let shape = self.shape();
+    let squeezed = match axes {
+      Some(axes) => {
+        let mut squeezed = vec![false; shape.len()];
+        for axis in axes {
+          let axis = normalize_axis(axis, shape.len())?;
+          if shape[axis] != 1 {
+            return Err(Error::new(format!("Cannot squeeze dimension {} of shape {:?}, its size isn't 1", axis, shape)));
+          }
+          squeezed[axis] = true;
+        }
+        squeezed
+      },
+      None => shape.iter().map(|&d| d == 1).collect(),
+    };
+
+    let new_shape = shape
+      .iter()
+      .zip(squeezed)
+      .filter(|(_, squeezed)| !squeezed)
+      .map(|(&d, _)| d)
+      .collect();
+    self.with_shape(new_shape)
+  }
+
+  /// Returns a view of this tensor with dimensions of size 1 inserted, so that they are at `axes` in the result.
+  /// Negative axes count from the end of the result, as in ONNX.
+  #[frb(sync)]
+  pub fn unsqueeze(&self, axes: Vec<i64>) -> Result<TensorImpl> {
+    let shape = self.shape();
+    let rank = shape.len() + axes.len();
+    let mut inserted = vec![false; rank];
+    for axis in axes {
+      let axis = normalize_axis(axis, rank)?;
+      if std::mem::replace(&mut inserted[axis], true) {
+        return Err(Error::new(format!("Axis {} appears more than once", axis)));
+      }
+    }
+
+    let mut dims = shape.into_iter();
+    let new_shape = inserted
+      .into_iter()
+      .map(|inserted| if inserted { 1 } else { dims.next().unwrap_or(1) })
+      .collect();
+    self.with_shape(new_shape)
+  }
+
+  /// Returns a copy of part of this tensor, following the semantics of the ONNX `Slice` operator.
+  ///
+  /// For each of `axes` (all axes in order if left out) the elements from `starts[i]` up to but excluding `ends[i]`
+  /// are taken, every `steps[i]` elements (1 if left out). Negative indices count from the end of the dimension,
+  /// out of range indices are clamped, and a negative step walks the dimension backwards.
+  #[frb(sync)]
+  pub fn slice(&self, starts: Vec<i64>, ends: Vec<i64>, axes: Option<Vec<i64>>, steps: Option<Vec<i64>>) -> Result<TensorImpl> {
+    let (offset, shape, strides) = self.slice_layout(starts, ends, axes, steps)?;
+    self.gather_strided(offset, &shape, &strides)
+  }
+
+  /// Resolves the arguments of [`TensorImpl::slice`] to the offset, shape and strides, in elements, of the sliced
+  /// part of this tensor's data.
+  pub(crate) fn slice_layout(
+    &self,
+    starts: Vec<i64>,
+    ends: Vec<i64>,
+    axes: Option<Vec<i64>>,
+    steps: Option<Vec<i64>>,
+  ) -> Result<(isize, Vec<usize>, Vec<isize>)> {
+    let shape = self.shape();
+    let axes = axes.unwrap_or_else(|| (0..starts.len() as i64).collect());
+    let steps = steps.unwrap_or_else(|| vec![1; starts.len()]);
+    if ends.len() != starts.len() || axes.len() != starts.len() || steps.len() != starts.len() {
+      return Err(Error::new("starts, ends, axes and steps must all have the same length"));
+    }
+
+    let strides = contiguous_strides(&shape);
+    let mut new_shape: Vec<usize> = shape.iter().map(|&d| d as usize).collect();
+    let mut new_strides: Vec<isize> = strides.iter().map(|&s| s as isize).collect();
+    let mut offset = 0;
+    let mut sliced = vec![false; shape.len()];
+    for i in 0..starts.len() {
+      let axis = normalize_axis(axes[i], shape.len())?;
+      if std::mem::replace(&mut sliced[axis], true) {
+        return Err(Error::new(format!("Axis {} appears more than once", axis)));
+      }
+      let (dim, step) = (shape[axis], steps[i]);
+      let resolve = |index: i64| if index < 0 { index.saturating_add(dim) } else { index };
+      let (start, end) = (resolve(starts[i]), resolve(ends[i]));
+
+      let (start, len) = match step {
+        0 => return Err(Error::new("Slice steps cannot be 0")),
+        // Nothing to clamp to, and `dim - 1` below would be negative
+        _ if dim == 0 => (0, 0),
+        step if step > 0 => {
+          let (start, end) = (start.clamp(0, dim), end.clamp(0, dim));
+          (start, ((end - start).max(0) as u64).div_ceil(step as u64))
+        },
+        step => {
+          let (start, end) = (start.clamp(0, dim - 1), end.clamp(-1, dim - 1));
+          (start, ((start - end).max(0) as u64).div_ceil(step.unsigned_abs()))
+        },
+      };
+
+      new_shape[axis] = len as usize;
+      if len > 0 {
+        offset += start as isize * new_strides[axis];
+      }
+      new_strides[axis] *= step as isize;
+    }
+    Ok((offset, new_shape, new_strides))
+  }
+
+  /// Copies the elements of a strided view of this tensor's data into a new tensor.
+  pub(crate) fn gather_strided(&self, offset: isize, shape: &[usize], strides: &[isize]) -> Result<TensorImpl> {
+    let indices = strided_indices(offset, shape, strides);
+    let shape: Vec<i64> = shape.iter().map(|&d| d as i64).collect();
+    Elements::read(self)?.gather(&indices).into_tensor(self.dtype(), &shape)
+  }
+
+  /// Joins tensors along an existing axis. All tensors must have the same element type and the same size in every
+  /// other dimension.
+  #[frb(sync)]
+  pub fn concat(tensors: Vec<TensorImpl>, axis: i64) -> Result<TensorImpl> {
+    let first = tensors.first().ok_or_else(|| Error::new("Cannot concatenate an empty list of tensors"))?;
+    let (dtype, first_shape) = (first.dtype(), first.shape());
+    let axis = normalize_axis(axis, first_shape.len())?;
+
+    let mut new_shape = first_shape.clone();
+    new_shape[axis] = 0;
+    for tensor in &tensors {
+      let shape = tensor.shape();
+      let matches = shape.len() == first_shape.len()
+        && shape.iter().zip(&first_shape).enumerate().all(|(i, (a, b))| i == axis || a == b);
+      if tensor.dtype() != dtype || !matches {
+        return Err(Error::new(format!(
+          "Cannot concatenate a {} tensor of shape {:?} with a {} tensor of shape {:?} along axis {}",
+          tensor.dtype(),
+          shape,
+          dtype,
+          first_shape,
+          axis
+        )));
+      }
+      new_shape[axis] += shape[axis];
+    }
+
+    // Every tensor contributes a contiguous chunk of `shape[axis] * inner` elements to each outer index
+    let outer = first_shape[..axis].iter().product::<i64>() as usize;
+    let inner = first_shape[axis + 1..].iter().product::<i64>() as usize;
+    let elements = tensors.iter().map(Elements::read).collect::<Result<Vec<_>>>()?;
+    let mut joined = elements[0].empty_like(new_shape.iter().product::<i64>() as usize);
+    for o in 0..outer {
+      for (tensor, elements) in tensors.iter().zip(&elements) {
+        let chunk = tensor.shape()[axis] as usize * inner;
+        joined.extend_from(elements, o * chunk..(o + 1) * chunk);
+      }
+    }
+    joined.into_tensor(dtype, &new_shape)
+  }
+
+  /// Joins tensors of the same type and shape along a new axis, inserted at `axis` in the result.
+  #[frb(sync)]
+  pub fn stack(tensors: Vec<TensorImpl>, axis: i64) -> Result<TensorImpl> {
+    let tensors = tensors
+      .iter()
+      .map(|t| t.unsqueeze(vec![axis]))
+      .collect::<Result<Vec<_>>>()?;
+    TensorImpl::concat(tensors, axis)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ort::error::Result;
+  use crate::api::tensor::TensorImpl;
+
+  #[test]
+  fn tensor_reshape_shares_data() -> Result<()> {
+    let mut tensor = TensorImpl::from_array_f32(Some(vec![2, 3]), vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0])?;
+    let reshaped = tensor.reshape(vec![3, -1])?;
+    assert_eq!(reshaped.shape(), vec![3, 2]);
+
+    tensor.tensor.try_extract_tensor_mut::<f32>()?.1[0] = 10.0;
+    assert_eq!(reshaped.extract_f32()?, vec![10.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+
+    // The view keeps the data alive on its own
+    drop(tensor);
+    assert_eq!(reshaped.unsqueeze(vec![0])?.shape(), vec![1, 3, 2]);
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_permute_nhwc_to_nchw() -> Result<()> {
+    // 1x2x2x3 NHWC, where each value is 10 * pixel + channel
+    let data = (0..4).flat_map(|p| (0..3).map(move |c| 10 * p + c)).collect();
+    let tensor = TensorImpl::from_array_i32(Some(vec![1, 2, 2, 3]), data)?;
+
+    let permuted = tensor.permute(vec![0, 3, 1, 2])?;
+    assert_eq!(permuted.shape(), vec![1, 3, 2, 2]);
+    assert_eq!(permuted.extract_i32()?, vec![0, 10, 20, 30, 1, 11, 21, 31, 2, 12, 22, 32]);
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_slice_negative_step() -> Result<()> {
+    let tensor = TensorImpl::from_array_i64(Some(vec![2, 4]), (0..8).collect())?;
+
+    let sliced = tensor.slice(vec![-1], vec![i64::MIN], Some(vec![1]), Some(vec![-2]))?;
+    assert_eq!(sliced.shape(), vec![2, 2]);
+    assert_eq!(sliced.extract_i64()?, vec![3, 1, 7, 5]);
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_slice_empty_axis() -> Result<()> {
+    let tensor = TensorImpl::from_array_i64(Some(vec![2, 0]), vec![])?;
+
+    for step in [1, -1] {
+      let sliced = tensor.slice(vec![0], vec![i64::MAX], Some(vec![1]), Some(vec![step]))?;
+      assert_eq!(sliced.shape(), vec![2, 0]);
+      assert!(sliced.extract_i64()?.is_empty());
+    }
+    Ok(())
+  }
+
+  #[test]
+  fn tensor_concat_and_stack() -> Result<()> {
+    let a = TensorImpl::from_array_string(Some(vec![1, 2]), vec!["a".to_string(), "b".to_string()])?;
+    let b = TensorImpl::from_array_string(Some(vec![1, 2]), vec!["c".to_string(), "d".to_string()])?;
+
+    let concatenated = TensorImpl::concat(vec![a.clone(), b.clone()], -1)?;
+    assert_eq!(concatenated.shape(), vec![1, 4]);
+    assert_eq!(concatenated.extract_string()?, vec!["a", "b", "c", "d"]);
+
+    let stacked = TensorImpl::stack(vec![a, b], 2)?;
+    assert_eq!(stacked.shape(), vec![1, 2, 2]);
+    assert_eq!(stacked.extract_string()?, vec!["a", "c", "b", "d"]);
+    Ok(())
+  }
+}
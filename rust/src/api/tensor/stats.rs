@@ -0,0 +1,125 @@
+use flutter_rust_bridge::frb;
+use ort::Result;
+use crate::api::tensor::ops::{with_numeric_type, Numeric};
+use crate::api::tensor::{TensorElementType, TensorImpl};
+
+/// Summary statistics of a numeric tensor, see [`TensorImpl::stats`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[frb(dart_metadata=("freezed"))]
+pub struct TensorStats {
+  /// The total number of elements.
+  pub count: u64,
+  /// The smallest finite element, or NaN if there are none.
+  pub min: f64,
+  /// The largest finite element, or NaN if there are none.
+  pub max: f64,
+  /// The mean of the finite elements, or NaN if there are none.
+  pub mean: f64,
+  /// The population standard deviation of the finite elements, or NaN if there are none.
+  pub std: f64,
+  pub nan_count: u64,
+  pub positive_infinity_count: u64,
+  pub negative_infinity_count: u64,
+  pub zero_count: u64,
+}
+
+impl TensorStats {
+  /// If any element is NaN or infinite.
+  #[frb(sync)]
+  pub fn has_non_finite(&self) -> bool {
+    self.nan_count + self.positive_infinity_count + self.negative_infinity_count > 0
+  }
+}
+
+impl TensorImpl {
+  /// Computes summary statistics over the elements of a numeric tensor.
+  ///
+  /// NaN and infinite elements are counted but left out of `min`, `max`, `mean` and `std`, so a handful of bad values
+  /// doesn't hide the distribution of the rest.
+  #[frb(sync)]
+  pub fn stats(&self) -> Result<TensorStats> {
+    let mut stats = TensorStats {
+      count: 0,
+      min: f64::INFINITY,
+      max: f64::NEG_INFINITY,
+      mean: 0.0,
+      std: 0.0,
+      nan_count: 0,
+      positive_infinity_count: 0,
+      negative_infinity_count: 0,
+      zero_count: 0,
+    };
+    // Welford's algorithm, which stays accurate for large tensors
+    let (mut finite, mut m2) = (0u64, 0.0);
+
+    with_numeric_type!(self.dtype(), T => {
+      for &v in self.numeric_data::<T>()? {
+        let v = v.to_f64();
+        stats.count += 1;
+        if v.is_nan() {
+          stats.nan_count += 1;
+        } else if v == f64::INFINITY {
+          stats.positive_infinity_count += 1;
+        } else if v == f64::NEG_INFINITY {
+          stats.negative_infinity_count += 1;
+        } else {
+          if v == 0.0 {
+            stats.zero_count += 1;
+          }
+          stats.min = stats.min.min(v);
+          stats.max = stats.max.max(v);
+          finite += 1;
+          let delta = v - stats.mean;
+          stats.mean += delta / finite as f64;
+          m2 += delta * (v - stats.mean);
+        }
+      }
+      Ok(())
+    })?;
+
+    if finite == 0 {
+      (stats.min, stats.max, stats.mean, stats.std) = (f64::NAN, f64::NAN, f64::NAN, f64::NAN);
+    } else {
+      stats.std = (m2 / finite as f64).sqrt();
+    }
+    Ok(stats)
+  }
+
+  /// Checks a tensor for NaN and infinite elements, returning its statistics if it has any. Only floating point
+  /// tensors can hold such values, so other tensors are skipped without reading their data, as are tensors that
+  /// aren't accessible from the CPU.
+  pub(crate) fn non_finite_stats(&self) -> Result<Option<TensorStats>> {
+    if !self.tensor.memory_info().is_cpu_accessible() {
+      return Ok(None);
+    }
+    match self.dtype() {
+      TensorElementType::Float16 | TensorElementType::Bfloat16 | TensorElementType::Float32 | TensorElementType::Float64 => {
+        let stats = self.stats()?;
+        Ok(stats.has_non_finite().then_some(stats))
+      },
+      _ => Ok(None),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ort::error::Result;
+  use crate::api::tensor::TensorImpl;
+
+  #[test]
+  fn tensor_stats_counts_non_finite() -> Result<()> {
+    let tensor = TensorImpl::from_array_f32(None, vec![1.0, 3.0, 0.0, f32::NAN, f32::INFINITY, f32::NEG_INFINITY, 2.0])?;
+
+    let stats = tensor.stats()?;
+    assert_eq!(stats.count, 7);
+    assert_eq!((stats.min, stats.max, stats.mean), (0.0, 3.0, 1.5));
+    assert!((stats.std - 1.25f64.sqrt()).abs() < 1e-12);
+    assert_eq!((stats.nan_count, stats.positive_infinity_count, stats.negative_infinity_count), (1, 1, 1));
+    assert_eq!(stats.zero_count, 1);
+    assert!(stats.has_non_finite());
+
+    assert!(TensorImpl::from_array_i32(None, vec![1, 2])?.non_finite_stats()?.is_none());
+    Ok(())
+  }
+}
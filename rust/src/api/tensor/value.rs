@@ -0,0 +1,252 @@
+use std::any::Any;
+use std::ptr::NonNull;
+use std::sync::Arc;
+use ort::{AsPointer, Error, Result};
+use ort::memory::Allocator;
+use ort::tensor::TensorElementType;
+use ort::value::{
+  DynMapValueType,
+  DynSequenceValueType,
+  DynTensor,
+  DynTensorValueType,
+  DynValue,
+  DynValueTypeMarker,
+  Map,
+  Sequence,
+  Tensor,
+  TensorValueType,
+  Value,
+  ValueType,
+};
+use crate::api::tensor::TensorImpl;
+
+/// The keys of a [`MapImpl`].
+pub enum MapKeys {
+  Int64(Vec<i64>),
+  String(Vec<String>),
+}
+
+impl MapKeys {
+  fn len(&self) -> usize {
+    match self {
+      MapKeys::Int64(keys) => keys.len(),
+      MapKeys::String(keys) => keys.len(),
+    }
+  }
+}
+
+/// An ONNX map, i.e. the `map(string, float)` entries produced by sklearn-onnx's `ZipMap`.
+///
+/// The value of the key at index `i` of [`MapImpl::keys`] is the element at index `i` of the 1-D [`MapImpl::values`]
+/// tensor.
+pub struct MapImpl {
+  pub keys: MapKeys,
+  pub values: TensorImpl,
+}
+
+/// Any value that can be passed to or returned from a session: a tensor, or one of the non-tensor types supported by
+/// ONNX.
+pub enum ValueImpl {
+  /// A single tensor.
+  Tensor(TensorImpl),
+  /// An ordered list of tensors or maps, all of the same type.
+  Sequence(Vec<ValueImpl>),
+  /// A map from `int64` or `string` keys to scalar values.
+  Map(MapImpl),
+  /// A value that may be absent. An absent optional input is simply not passed to the session.
+  Optional(Option<Box<ValueImpl>>),
+}
+
+impl ValueImpl {
+  /// Converts a value returned by ONNX Runtime. `declared_type` is the type the graph declares for it, used to tell
+  /// optional values apart.
+  pub(crate) fn from_value(value: &DynValue, declared_type: Option<&ValueType>) -> Result<ValueImpl> {
+    let mut has_value = 0;
+    unsafe {
+      ort::error::status_to_result((ort::api().HasValue)(value.ptr(), &mut has_value))?;
+    }
+    if has_value == 0 {
+      return Ok(ValueImpl::Optional(None));
+    }
+
+    let converted = match value.dtype() {
+      ValueType::Tensor { .. } => ValueImpl::Tensor(TensorImpl::from_value_ref(value.view())?),
+      ValueType::Sequence(_) => {
+        let sequence = value.downcast_ref::<DynSequenceValueType>()?;
+        let values = sequence.try_extract_sequence::<DynValueTypeMarker>(&Allocator::default())?;
+        ValueImpl::Sequence(
+          values
+            .iter()
+            .map(|v| ValueImpl::from_value(v, None))
+            .collect::<Result<Vec<_>>>()?
+        )
+      },
+      ValueType::Map { key, .. } => {
+        let keys = map_component(value, 0)?;
+        let keys = match key {
+          TensorElementType::Int64 => MapKeys::Int64(keys.try_extract_tensor::<i64>()?.1.to_vec()),
+          TensorElementType::String => MapKeys::String(keys.try_extract_strings()?.1),
+          _ => return Err(Error::new(format!("Maps with {} keys are not supported", key))),
+        };
+        ValueImpl::Map(MapImpl {
+          keys,
+          values: TensorImpl::from_output(map_component(value, 1)?),
+        })
+      },
+      ty @ ValueType::Optional(_) => return Err(Error::new(format!("Cannot convert a value of type {}", ty))),
+    };
+
+    if matches!(declared_type, Some(ValueType::Optional(_))) {
+      Ok(ValueImpl::Optional(Some(Box::new(converted))))
+    } else {
+      Ok(converted)
+    }
+  }
+
+  /// Converts this into a value that can be passed to ONNX Runtime, or `None` for an absent optional value.
+  ///
+  /// The data of the converted tensors may still depend on their [`TensorImpl::backing`], which are moved into
+  /// `backings` and must be kept alive until the value is dropped.
+  pub(crate) fn into_value(self, backings: &mut Vec<Arc<dyn Any + Send + Sync>>) -> Result<Option<DynValue>> {
+    match self {
+      ValueImpl::Tensor(tensor) => Ok(Some(into_tensor(tensor, backings).into_dyn())),
+      ValueImpl::Sequence(values) => {
+        let values = values
+          .into_iter()
+          .map(|v| {
+            v.into_value(backings)?.ok_or_else(|| Error::new("Sequences cannot contain absent optional values"))
+          })
+          .collect::<Result<Vec<_>>>()?;
+        let first = values.first().ok_or_else(|| Error::new("Cannot create an empty sequence"))?;
+
+        let sequence = if first.is_tensor() {
+          let tensors = values
+            .into_iter()
+            .map(|v| v.downcast::<DynTensorValueType>())
+            .collect::<Result<Vec<_>>>()?;
+          Sequence::new(tensors)?.into_dyn()
+        } else {
+          let maps = values
+            .into_iter()
+            .map(|v| v.downcast::<DynMapValueType>())
+            .collect::<Result<Vec<_>>>()?;
+          Sequence::new(maps)?.into_dyn()
+        };
+        Ok(Some(sequence))
+      },
+      ValueImpl::Map(map) => map.into_value(backings).map(Some),
+      ValueImpl::Optional(value) => match value {
+        Some(value) => value.into_value(backings),
+        None => Ok(None),
+      },
+    }
+  }
+}
+
+impl MapImpl {
+  fn into_value(self, backings: &mut Vec<Arc<dyn Any + Send + Sync>>) -> Result<DynValue> {
+    if self.values.shape().len() != 1 || self.values.shape()[0] as usize != self.keys.len() {
+      return Err(Error::new(format!(
+        "Map values must be a 1-D tensor with one value per key, got shape {:?} for {} keys",
+        self.values.shape(),
+        self.keys.len()
+      )));
+    }
+    let dtype = self.values.dtype();
+    let values = into_tensor(self.values, backings);
+
+    macro_rules! new_map {
+      ($keys:expr, $k:ty) => {
+        match dtype {
+          TensorElementType::Float32 => Map::<$k, f32>::new_kv($keys, downcast_values(values)?)?.into_dyn(),
+          TensorElementType::Float64 => Map::<$k, f64>::new_kv($keys, downcast_values(values)?)?.into_dyn(),
+          TensorElementType::Int64 => Map::<$k, i64>::new_kv($keys, downcast_values(values)?)?.into_dyn(),
+          TensorElementType::String => Map::<$k, String>::new_kv($keys, downcast_values(values)?)?.into_dyn(),
+          dtype => return Err(Error::new(format!("Maps with {} values are not supported", dtype))),
+        }
+      };
+    }
+
+    let value = match self.keys {
+      MapKeys::Int64(keys) => new_map!(Tensor::from_array(([keys.len()], keys))?, i64),
+      MapKeys::String(keys) => new_map!(Tensor::from_string_array(([keys.len()], &*keys))?, String),
+    };
+    Ok(value)
+  }
+}
+
+/// Takes the tensor out of `tensor`, moving what its data depends on into `backings`.
+fn into_tensor(tensor: TensorImpl, backings: &mut Vec<Arc<dyn Any + Send + Sync>>) -> DynTensor {
+  backings.extend(tensor.backing);
+  tensor.tensor
+}
+
+fn downcast_values<T: ort::tensor::IntoTensorElementType + std::fmt::Debug>(values: DynTensor) -> Result<Tensor<T>> {
+  values.into_dyn().downcast::<TensorValueType<T>>()
+}
+
+/// Reads the keys (`index` 0) or values (`index` 1) tensor out of a map value.
+fn map_component(map: &DynValue, index: i32) -> Result<DynTensor> {
+  let allocator = Allocator::default();
+  let mut value_ptr: *mut ort::sys::OrtValue = std::ptr::null_mut();
+  unsafe {
+    ort::error::status_to_result((ort::api().GetValue)(map.ptr(), index, allocator.ptr().cast_mut(), &mut value_ptr))?;
+  }
+  let value_ptr = NonNull::new(value_ptr).ok_or_else(|| Error::new("Failed to read map value"))?;
+  Ok(unsafe { Value::from_ptr(value_ptr, None) })
+}
+
+#[cfg(test)]
+mod tests {
+  use ort::error::Result;
+  use crate::api::tensor::TensorImpl;
+  use crate::api::tensor::value::{MapImpl, MapKeys, ValueImpl};
+
+  #[test]
+  fn map_round_trip() -> Result<()> {
+    let map = ValueImpl::Map(MapImpl {
+      keys: MapKeys::String(vec!["cat".to_string(), "dog".to_string()]),
+      values: TensorImpl::from_array_f32(None, vec![0.25, 0.75])?,
+    });
+
+    let value = map.into_value(&mut Vec::new())?.unwrap();
+    let ValueImpl::Map(map) = ValueImpl::from_value(&value, None)? else {
+      panic!("expected a map");
+    };
+
+    let MapKeys::String(keys) = map.keys else {
+      panic!("expected string keys");
+    };
+    assert_eq!(keys, vec!["cat", "dog"]);
+    assert_eq!(map.values.tensor.try_extract_tensor::<f32>()?.1, &[0.25, 0.75]);
+
+    Ok(())
+  }
+
+  #[test]
+  fn sequence_round_trip() -> Result<()> {
+    let sequence = ValueImpl::Sequence(vec![
+      ValueImpl::Tensor(TensorImpl::from_array_i64(None, vec![1, 2, 3])?),
+      ValueImpl::Tensor(TensorImpl::from_array_i64(None, vec![4])?),
+    ]);
+
+    let value = sequence.into_value(&mut Vec::new())?.unwrap();
+    let ValueImpl::Sequence(values) = ValueImpl::from_value(&value, None)? else {
+      panic!("expected a sequence");
+    };
+
+    assert_eq!(values.len(), 2);
+    let ValueImpl::Tensor(second) = &values[1] else {
+      panic!("expected a tensor");
+    };
+    assert_eq!(second.shape(), vec![1]);
+
+    Ok(())
+  }
+
+  #[test]
+  fn absent_optional_has_no_value() -> Result<()> {
+    assert!(ValueImpl::Optional(None).into_value(&mut Vec::new())?.is_none());
+    Ok(())
+  }
+}